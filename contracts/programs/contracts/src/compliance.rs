@@ -0,0 +1,231 @@
+use anchor_lang::prelude::*;
+use solana_instructions_sysvar::get_instruction_relative;
+use solana_program::hash::hashv;
+use solana_sdk_ids::ed25519_program;
+
+use crate::{
+    constants::*,
+    error::BasketError,
+    pda,
+    state::{AttestationNonce, ComplianceRoot, Config, UserAllowList},
+};
+
+// Allow-list, Merkle and Ed25519 admission shared by the deposit paths.
+//
+// Merkle leaves commit to (user, tier, expiry); interior nodes hash their
+// children in sorted order so proofs carry no left/right flags. Distinct
+// prefixes keep a leaf from ever being reinterpreted as a node.
+
+const LEAF_PREFIX: &[u8] = &[0];
+const NODE_PREFIX: &[u8] = &[1];
+
+/// Proof that the share receiver is a leaf under the basket's `ComplianceRoot`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ComplianceProof {
+    pub tier: u8,
+    /// Unix timestamp after which the leaf no longer admits deposits.
+    pub expiry: i64,
+    /// Sibling hashes from the leaf up to the root.
+    pub proof: Vec<[u8; 32]>,
+}
+
+pub fn leaf_hash(user: &Pubkey, tier: u8, expiry: i64) -> [u8; 32] {
+    hashv(&[LEAF_PREFIX, user.as_ref(), &[tier], &expiry.to_le_bytes()]).to_bytes()
+}
+
+pub fn node_hash(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (lo, hi) = if a <= b { (a, b) } else { (b, a) };
+    hashv(&[NODE_PREFIX, lo, hi]).to_bytes()
+}
+
+/// Check `proof` admits `user` under `root` at `now`.
+pub fn verify_proof(
+    root: &ComplianceRoot,
+    user: &Pubkey,
+    proof: &ComplianceProof,
+    now: i64,
+) -> Result<()> {
+    require!(
+        proof.proof.len() <= MAX_COMPLIANCE_PROOF_LEN,
+        BasketError::ComplianceProofTooLong
+    );
+    require!(now < proof.expiry, BasketError::ComplianceProofExpired);
+
+    let computed = proof
+        .proof
+        .iter()
+        .fold(leaf_hash(user, proof.tier, proof.expiry), |acc, sibling| {
+            node_hash(&acc, sibling)
+        });
+    require!(computed == root.root, BasketError::ComplianceDenied);
+    Ok(())
+}
+
+/// Fields of an Ed25519 compliance attestation signed by `config.whitelist_auth`.
+/// The program id keeps a signature from being accepted by another
+/// deployment sharing the same whitelist authority.
+pub struct Attestation {
+    pub program_id: Pubkey,
+    pub basket: Pubkey,
+    pub user: Pubkey,
+    pub expiry: i64,
+    pub nonce: u64,
+}
+
+// Ed25519 program instruction layout: a count and padding byte, then per
+// signature seven u16 offsets/indices pointing at the signature, public key
+// and message. Index u16::MAX means "this instruction's own data".
+const ED25519_HEADER_LEN: usize = 2;
+const ED25519_OFFSETS_LEN: usize = 14;
+const ED25519_SELF_INDEX: u16 = u16::MAX;
+
+/// Read the attestation verified by the Ed25519 program instruction placed
+/// immediately before the current one. The runtime has already checked the
+/// signature; this binds it to `signer` and decodes the signed message.
+pub fn load_attestation(instructions_sysvar: &AccountInfo, signer: &Pubkey) -> Result<Attestation> {
+    let ix = get_instruction_relative(-1, instructions_sysvar)
+        .map_err(|_| BasketError::AttestationMissing)?;
+    require_keys_eq!(ix.program_id, ed25519_program::ID, BasketError::AttestationMissing);
+
+    let data = &ix.data;
+    require!(
+        data.len() >= ED25519_HEADER_LEN + ED25519_OFFSETS_LEN && data[0] == 1,
+        BasketError::InvalidAttestation
+    );
+    let offset = |i: usize| {
+        let at = ED25519_HEADER_LEN + 2 * i;
+        u16::from_le_bytes([data[at], data[at + 1]])
+    };
+    let (signature_ix, public_key_offset, public_key_ix) = (offset(1), offset(2), offset(3));
+    let (message_offset, message_size, message_ix) = (offset(4), offset(5), offset(6));
+    require!(
+        signature_ix == ED25519_SELF_INDEX
+            && public_key_ix == ED25519_SELF_INDEX
+            && message_ix == ED25519_SELF_INDEX
+            && message_size as usize == ATTESTATION_MESSAGE_LEN,
+        BasketError::InvalidAttestation
+    );
+
+    let slice = |start: u16, len: usize| {
+        data.get(start as usize..start as usize + len)
+            .ok_or(BasketError::InvalidAttestation)
+    };
+    require!(
+        slice(public_key_offset, 32)? == signer.as_ref(),
+        BasketError::InvalidAttestation
+    );
+
+    let message = slice(message_offset, ATTESTATION_MESSAGE_LEN)?;
+    let (program_id, rest) = message.split_at(32);
+    let (basket, rest) = rest.split_at(32);
+    let (user, rest) = rest.split_at(32);
+    let (expiry, nonce) = rest.split_at(8);
+    Ok(Attestation {
+        program_id: Pubkey::try_from(program_id).map_err(|_| BasketError::InvalidAttestation)?,
+        basket: Pubkey::try_from(basket).map_err(|_| BasketError::InvalidAttestation)?,
+        user: Pubkey::try_from(user).map_err(|_| BasketError::InvalidAttestation)?,
+        expiry: i64::from_le_bytes(expiry.try_into().map_err(|_| BasketError::InvalidAttestation)?),
+        nonce: u64::from_le_bytes(nonce.try_into().map_err(|_| BasketError::InvalidAttestation)?),
+    })
+}
+
+/// Accounts a deposit presents to pass `config.compliance_enabled`.
+pub struct ComplianceAccounts<'a, 'info> {
+    pub config: &'a Config,
+    pub basket: Pubkey,
+    /// Account the deposit's shares are for; every route must admit it.
+    pub user: Pubkey,
+    /// Pays for the attestation nonce.
+    pub payer: AccountInfo<'info>,
+    pub user_allow_list: Option<&'a UserAllowList>,
+    pub compliance_root: Option<&'a ComplianceRoot>,
+    pub instructions_sysvar: Option<AccountInfo<'info>>,
+    pub attestation_nonce: Option<AccountInfo<'info>>,
+    pub system_program: AccountInfo<'info>,
+}
+
+impl ComplianceAccounts<'_, '_> {
+    /// Checked in order: an allow-list entry, a proof under the basket's
+    /// compliance root, then an Ed25519 attestation.
+    pub fn check(
+        &self,
+        proof: Option<&ComplianceProof>,
+        now: i64,
+        program_id: &Pubkey,
+    ) -> Result<()> {
+        if !self.config.compliance_enabled {
+            return Ok(());
+        }
+        let Some(allow_list) = self.user_allow_list else {
+            if let Some(root) = self.compliance_root {
+                let proof = proof.ok_or(BasketError::ComplianceDenied)?;
+                return verify_proof(root, &self.user, proof, now);
+            }
+            return self.check_attestation(now, program_id);
+        };
+        require!(
+            allow_list.allowed && allow_list.basket == self.basket && allow_list.user == self.user,
+            BasketError::ComplianceDenied
+        );
+        Ok(())
+    }
+
+    /// Accept an attestation by `config.whitelist_auth` for this program,
+    /// basket and user, spending its nonce so it can't be replayed. Nonces
+    /// are scoped to the basket and user, and to this program by derivation.
+    fn check_attestation(&self, now: i64, program_id: &Pubkey) -> Result<()> {
+        let (Some(sysvar), Some(nonce_account)) =
+            (&self.instructions_sysvar, &self.attestation_nonce)
+        else {
+            return err!(BasketError::ComplianceDenied);
+        };
+
+        let attestation = load_attestation(sysvar, &self.config.whitelist_auth)?;
+        require!(
+            attestation.program_id == *program_id
+                && attestation.basket == self.basket
+                && attestation.user == self.user,
+            BasketError::InvalidAttestation
+        );
+        require!(now < attestation.expiry, BasketError::AttestationExpired);
+
+        let nonce_bytes = attestation.nonce.to_le_bytes();
+        let (expected, bump) = Pubkey::find_program_address(
+            &[
+                ATTESTATION_NONCE_SEED,
+                attestation.basket.as_ref(),
+                attestation.user.as_ref(),
+                &nonce_bytes,
+            ],
+            program_id,
+        );
+        require_keys_eq!(nonce_account.key(), expected, BasketError::InvalidAttestation);
+        require!(
+            nonce_account.owner == &System::id(),
+            BasketError::AttestationReplayed
+        );
+
+        pda::create_pda_account(
+            &self.payer,
+            nonce_account,
+            &self.system_program,
+            8 + AttestationNonce::INIT_SPACE,
+            &[
+                ATTESTATION_NONCE_SEED,
+                attestation.basket.as_ref(),
+                attestation.user.as_ref(),
+                &nonce_bytes,
+                &[bump],
+            ],
+            program_id,
+        )?;
+        AttestationNonce {
+            basket: attestation.basket,
+            user: attestation.user,
+            nonce: attestation.nonce,
+            used_at: now,
+            bump,
+        }
+        .try_serialize(&mut &mut nonce_account.try_borrow_mut_data()?[..])
+    }
+}
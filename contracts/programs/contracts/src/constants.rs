@@ -1,3 +1,5 @@
+use anchor_lang::prelude::*;
+
 // PDA Seeds 
 pub const CONFIG_SEED: &[u8] = b"config";
 pub const BASKET_SEED: &[u8] = b"basket";
@@ -6,6 +8,19 @@ pub const VAULT_AUTHORITY_SEED: &[u8] = b"vault_authority";
 pub const MINT_AUTHORITY_SEED: &[u8] = b"mint_authority";
 pub const FEE_VAULT_SEED: &[u8] = b"fee_vault";
 pub const USER_ALLOW_SEED: &[u8] = b"user_allow";
+pub const METADATA_SEED: &[u8] = b"metadata";
+pub const BASKET_PRICE_SEED: &[u8] = b"basket_price";
+pub const LOCK_TIERS_SEED: &[u8] = b"lock_tiers";
+pub const LOCK_POSITION_SEED: &[u8] = b"lock_position";
+pub const REWARD_POOL_SEED: &[u8] = b"reward_pool";
+pub const STAKE_POSITION_SEED: &[u8] = b"stake_position";
+pub const EPOCH_QUEUE_SEED: &[u8] = b"epoch_queue";
+pub const EPOCH_SEED: &[u8] = b"epoch";
+pub const DEPOSIT_REQUEST_SEED: &[u8] = b"deposit_request";
+pub const REDEEM_REQUEST_SEED: &[u8] = b"redeem_request";
+pub const BASKET_REGISTRY_SEED: &[u8] = b"basket_registry";
+pub const COMPLIANCE_ROOT_SEED: &[u8] = b"compliance_root";
+pub const ATTESTATION_NONCE_SEED: &[u8] = b"attestation_nonce";
 
 // Fee bounds (basis points) 
 pub const FEE_BPS_MIN: u16 = 10;
@@ -15,15 +30,89 @@ pub const BPS_DENOMINATOR: u64 = 10_000;
 // Protocol limits
 pub const MAX_TOKENS_PER_BASKET: u8 = 10;
 pub const MAX_NAME_LEN: usize = 32;
+pub const MAX_SYMBOL_LEN: usize = 10;
+pub const MAX_URI_LEN: usize = 200;
+/// Basket pubkeys per `BasketRegistry` page.
+pub const REGISTRY_PAGE_SIZE: usize = 64;
+/// Id given to the first basket; `Config.next_basket_id` starts here.
+pub const FIRST_BASKET_ID: u64 = 1;
+/// Deepest compliance Merkle proof accepted (2^24 leaves).
+pub const MAX_COMPLIANCE_PROOF_LEN: usize = 24;
+/// Ed25519 compliance attestation: program id, basket, user, expiry (i64 LE),
+/// nonce (u64 LE).
+pub const ATTESTATION_MESSAGE_LEN: usize = 32 + 32 + 32 + 8 + 8;
 
 // QSHARE token config
 pub const QSHARE_DECIMALS: u8 = 6;
+/// Fixed-point scale for NAV-per-share (1.0 == NAV_SCALE).
+pub const NAV_SCALE: u64 = 1_000_000_000;
+pub const NAV_DECIMALS: u8 = 9;
+/// Transfer-hook program enforcing the allow list on Token-2022 QSHARE mints.
+pub const SHARE_HOOK_PROGRAM_ID: Pubkey = pubkey!("Fg6DPXfnvSov9aDYBk6rZ2qsMP5SKyybMmQ2LbH4WsqZ");
+/// Metaplex Token Metadata program, used for legacy SPL share mints.
+pub const TOKEN_METADATA_PROGRAM_ID: Pubkey = pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+
+// Mint extension policy flags (Config.mint_extension_policy / BasketToken.extension_flags)
+pub const MINT_EXT_PERMANENT_DELEGATE: u8 = 1 << 0;
+pub const MINT_EXT_TRANSFER_HOOK: u8 = 1 << 1;
+pub const MINT_EXT_DEFAULT_FROZEN: u8 = 1 << 2;
+pub const MINT_EXT_NON_TRANSFERABLE: u8 = 1 << 3;
+pub const MINT_EXT_FREEZE_AUTHORITY: u8 = 1 << 4;
+pub const MINT_EXT_TRANSFER_FEE: u8 = 1 << 5;
+pub const MINT_EXT_PAUSABLE: u8 = 1 << 6;
+pub const MINT_EXT_CLOSE_AUTHORITY: u8 = 1 << 7;
+/// Flags the policy may allow.
+pub const MINT_EXT_ALL: u8 = MINT_EXT_PERMANENT_DELEGATE
+    | MINT_EXT_DEFAULT_FROZEN
+    | MINT_EXT_NON_TRANSFERABLE
+    | MINT_EXT_FREEZE_AUTHORITY
+    | MINT_EXT_TRANSFER_FEE;
+/// Flags no policy can allow: leg transfers carry no hook accounts, and a
+/// paused or closed leg mint would lock every leg of the basket.
+pub const MINT_EXT_UNSUPPORTED: u8 =
+    MINT_EXT_TRANSFER_HOOK | MINT_EXT_PAUSABLE | MINT_EXT_CLOSE_AUTHORITY;
+
+// Share pricing modes (Basket.mint_pricing_mode / redeem_pricing_mode)
+pub const PRICING_MODE_SPOT: u8 = 0;
+pub const PRICING_MODE_TWAP: u8 = 1;
+pub const PRICING_MODE_MIN: u8 = 2;
+pub const PRICING_MODE_MAX: u8 = 3;
+/// Minimum span of a per-leg TWAP window.
+pub const TWAP_WINDOW_SECS: i64 = 1_800;
+
+// Share locking
+pub const MAX_LOCK_TIERS: usize = 4;
+pub const MAX_LOCK_DURATION_SECS: i64 = 4 * 365 * 86_400;
+pub const EARLY_EXIT_PENALTY_BPS_MAX: u16 = 5_000;
+
+// Reward distribution
+pub const MAX_REWARD_BOOSTS: usize = 4;
+/// Highest stake-weight multiplier (3x).
+pub const REWARD_MULTIPLIER_BPS_MAX: u16 = 30_000;
+/// Fixed-point scale of `RewardPool.acc_reward_per_weight`.
+pub const REWARD_ACC_SCALE: u128 = 1_000_000_000_000;
 
-// Protocol version
-pub const CURRENT_VERSION: u8 = 1;
+// Protocol version. 2 appended fields to `Config`, `Basket` and
+// `BasketToken`; version-1 accounts are grown by `migrate_config` and
+// `migrate_basket`.
+pub const CURRENT_VERSION: u8 = 2;
 
 // Remaining accounts layout sizes 
 /// deposit_multi: [BasketToken, Mint, UserATA, VaultATA, FeeVaultATA] per token
 pub const DEPOSIT_ACCOUNTS_PER_TOKEN: usize = 5;
-/// withdraw_multi: [BasketToken, Mint, VaultATA, UserATA] per token
+/// withdraw_multi: [BasketToken, Mint, VaultATA, ReceiverATA] per token
 pub const WITHDRAW_ACCOUNTS_PER_TOKEN: usize = 4;
+/// request_deposit: [BasketToken, Mint, UserATA, VaultATA, PendingATA, FeeVaultATA] per token
+pub const REQUEST_DEPOSIT_ACCOUNTS_PER_TOKEN: usize = 6;
+/// cancel_request: [BasketToken, Mint, PendingATA, OwnerATA] per token
+pub const CANCEL_DEPOSIT_ACCOUNTS_PER_TOKEN: usize = 4;
+/// settle_epoch: [BasketToken, Mint, VaultATA, PendingATA, PayoutATA] per token
+pub const SETTLE_ACCOUNTS_PER_TOKEN: usize = 5;
+/// close_basket: [BasketToken, Mint, VaultATA, FeeVaultATA, PendingATA, TreasuryATA] per token
+pub const CLOSE_BASKET_ACCOUNTS_PER_TOKEN: usize = 6;
+/// claim_redeem: [BasketToken, Mint, PayoutATA, OwnerATA] per token
+pub const CLAIM_REDEEM_ACCOUNTS_PER_TOKEN: usize = 4;
+/// preview_deposit / preview_withdraw / get_basket_nav: [BasketToken, VaultATA] per token
+pub const PRICING_ACCOUNTS_PER_TOKEN: usize = 2;
+/// migrate_basket: [BasketToken, Mint, VaultATA] per token
+pub const MIGRATE_BASKET_ACCOUNTS_PER_TOKEN: usize = 3;
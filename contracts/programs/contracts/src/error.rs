@@ -65,4 +65,154 @@ pub enum BasketError {
 
     #[msg("User ATA is not owned by a valid token program")]
     InvalidTokenProgramOwner,
+
+    #[msg("Mint has an extension or authority rejected by the protocol policy")]
+    RiskyMintExtension,
+
+    #[msg("Mint extension allowed by policy but not acknowledged by the admin")]
+    MintExtensionNotAcknowledged,
+
+    #[msg("Unknown mint extension policy flag")]
+    InvalidExtensionPolicy,
+
+    #[msg("A transfer-hook share mint requires the Token-2022 program")]
+    TransferHookRequiresToken2022,
+
+    #[msg("Share metadata name, symbol or URI is invalid or too long")]
+    InvalidMetadata,
+
+    #[msg("Metaplex metadata accounts are required for a legacy SPL share mint")]
+    MissingMetadataAccounts,
+
+    #[msg("Unknown share pricing mode")]
+    InvalidPricingMode,
+
+    #[msg("Payout token account is not owned by the receiver")]
+    ReceiverMismatch,
+
+    #[msg("User token account is not owned by the signer")]
+    UserAtaOwnerMismatch,
+
+    #[msg("Required leg amount exceeds the caller's maximum")]
+    MaxAmountInExceeded,
+
+    #[msg("Exact-share mints require a basket with existing supply")]
+    EmptyBasket,
+
+    #[msg("Requested amounts need more shares than the caller's maximum")]
+    MaxSharesInExceeded,
+
+    #[msg("Lock tiers must be ordered, within duration bounds and below the penalty cap")]
+    InvalidLockTiers,
+
+    #[msg("Lock duration is shorter than the basket's lowest tier or too long")]
+    InvalidLockDuration,
+
+    #[msg("A top-up cannot move the unlock time earlier")]
+    LockShortened,
+
+    #[msg("Lock position has not reached its unlock time")]
+    LockNotMatured,
+
+    #[msg("Cannot lock zero shares")]
+    ZeroLockAmount,
+
+    #[msg("Reward boosts must be ordered, within duration bounds and between 1x and the cap")]
+    InvalidRewardBoosts,
+
+    #[msg("Reward funding must release a non-zero amount over a non-zero duration")]
+    InvalidRewardFunding,
+
+    #[msg("Stake is still committed")]
+    StakeLocked,
+
+    #[msg("Cannot stake or unstake zero shares")]
+    ZeroStakeAmount,
+
+    #[msg("No rewards to claim")]
+    NothingToClaim,
+
+    #[msg("A request from an earlier epoch must be claimed first")]
+    UnclaimedRequest,
+
+    #[msg("The same basket leg was passed more than once")]
+    DuplicateLeg,
+
+    #[msg("Escrow token account does not match the expected address")]
+    InvalidEscrowAccount,
+
+    #[msg("Deposit would exceed the basket's value cap or the receiver's share cap")]
+    DepositCapExceeded,
+
+    #[msg("Withdrawal limit must be at most 100% over a positive window")]
+    InvalidWithdrawLimit,
+
+    #[msg("Withdrawal would exceed the basket's outflow limit for this window")]
+    WithdrawLimitExceeded,
+
+    #[msg("Treasury account does not match config")]
+    InvalidTreasury,
+
+    #[msg("Basket has no creation bond to slash")]
+    NoBondPosted,
+
+    #[msg("Basket id must equal config.next_basket_id")]
+    BasketIdMismatch,
+
+    #[msg("Basket still has shares outstanding or tokens in its vaults")]
+    BasketNotEmpty,
+
+    #[msg("Must pass every basket leg to close the basket")]
+    IncompleteClose,
+
+    #[msg("No allow-list entries passed")]
+    NoAllowListEntries,
+
+    #[msg("Users, flags and allow-list accounts must have equal lengths")]
+    AllowListBatchLengthMismatch,
+
+    #[msg("Allow-list account does not match the user's PDA")]
+    InvalidAllowListEntry,
+
+    #[msg("Compliance root version must increase")]
+    StaleComplianceRoot,
+
+    #[msg("Compliance proof has expired")]
+    ComplianceProofExpired,
+
+    #[msg("Compliance proof is too long")]
+    ComplianceProofTooLong,
+
+    #[msg("No Ed25519 attestation precedes this instruction")]
+    AttestationMissing,
+
+    #[msg("Attestation is malformed, not signed by the whitelist authority, or for another deposit")]
+    InvalidAttestation,
+
+    #[msg("Attestation has expired")]
+    AttestationExpired,
+
+    #[msg("Attestation nonce already used")]
+    AttestationReplayed,
+
+    #[msg("Basket has shares outstanding but holds no value; deposits cannot be priced")]
+    ZeroNav,
+
+    #[msg("Request's epoch has settled; claim it instead")]
+    RequestSettled,
+
+    #[msg("No request passed to cancel")]
+    NoRequest,
+
+    #[msg("Cancelling a redemption needs the owner's share account and the share escrow")]
+    MissingShareAccounts,
+
+    #[msg("Account is not on the version-1 layout")]
+    AlreadyMigrated,
+
+    #[msg("Account is on an older layout and must be migrated first")]
+    AccountNotMigrated,
+
+    #[msg("Mint has a transfer hook, pause or close authority, which legs cannot support")]
+    UnsupportedMintExtension,
 }
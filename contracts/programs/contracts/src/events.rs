@@ -1,5 +1,10 @@
 use anchor_lang::prelude::*;
 
+use crate::{
+    constants::MAX_NAME_LEN,
+    state::{LockTier, RewardBoost},
+};
+
 #[event]
 pub struct ConfigInitialized {
     pub admin: Pubkey,
@@ -14,6 +19,7 @@ pub struct ConfigUpdated {
     pub whitelist_auth: Pubkey,
     pub compliance_enabled: bool,
     pub new_admin: Pubkey,
+    pub mint_extension_policy: u8,
 }
 
 #[event]
@@ -23,11 +29,104 @@ pub struct AllowListUpdated {
     pub allowed: bool,
 }
 
+#[event]
+pub struct AllowListBatchUpdated {
+    pub basket: Pubkey,
+    pub users: Vec<Pubkey>,
+    pub allowed: Vec<bool>,
+}
+
+#[event]
+pub struct ComplianceRootUpdated {
+    pub basket: Pubkey,
+    pub root: [u8; 32],
+    pub old_version: u64,
+    pub new_version: u64,
+}
+
+#[event]
+pub struct AllowListEntryClosed {
+    pub basket: Pubkey,
+    pub user: Pubkey,
+    pub recipient: Pubkey,
+}
+
+#[event]
+pub struct ConfigMigrated {
+    pub version: u8,
+    pub next_basket_id: u64,
+}
+
+#[event]
+pub struct BasketMigrated {
+    pub basket: Pubkey,
+    pub version: u8,
+    pub registry_page: u32,
+}
+
 #[event]
 pub struct BasketCreated {
     pub basket_id: u64,
     pub owner: Pubkey,
     pub share_mint: Pubkey,
+    pub share_transfer_hook: bool,
+    pub creation_fee_lamports: u64,
+    pub bond_lamports: u64,
+}
+
+#[event]
+pub struct CreationPolicyUpdated {
+    pub permissionless_creation: bool,
+    pub creation_fee_lamports: u64,
+    pub creation_bond_lamports: u64,
+    pub treasury: Pubkey,
+}
+
+#[event]
+pub struct BasketBondSlashed {
+    pub basket: Pubkey,
+    pub treasury: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct BasketUpdated {
+    pub basket: Pubkey,
+    pub old_name: [u8; MAX_NAME_LEN],
+    pub new_name: [u8; MAX_NAME_LEN],
+    pub old_fee_bps_override: Option<u16>,
+    pub new_fee_bps_override: Option<u16>,
+}
+
+#[event]
+pub struct BasketOwnershipTransferStarted {
+    pub basket: Pubkey,
+    pub owner: Pubkey,
+    pub pending_owner: Pubkey,
+}
+
+#[event]
+pub struct BasketOwnershipTransferred {
+    pub basket: Pubkey,
+    pub previous_owner: Pubkey,
+    pub new_owner: Pubkey,
+}
+
+#[event]
+pub struct BasketClosed {
+    pub basket: Pubkey,
+    pub basket_id: u64,
+    pub owner: Pubkey,
+    /// Fees swept to the treasury, one per leg.
+    pub fees_swept: Vec<u64>,
+}
+
+#[event]
+pub struct BasketMetadataUpdated {
+    pub basket: Pubkey,
+    pub share_mint: Pubkey,
+    pub symbol: String,
+    pub uri: String,
 }
 
 #[event]
@@ -35,12 +134,14 @@ pub struct TokenAdded {
     pub basket: Pubkey,
     pub mint: Pubkey,
     pub vault_ata: Pubkey,
+    pub extension_flags: u8,
 }
 
 #[event]
 pub struct DepositCompleted {
     pub basket: Pubkey,
     pub user: Pubkey,
+    pub receiver: Pubkey,
     pub shares_minted: u64,
 }
 
@@ -48,5 +149,182 @@ pub struct DepositCompleted {
 pub struct WithdrawCompleted {
     pub basket: Pubkey,
     pub user: Pubkey,
+    pub receiver: Pubkey,
+    pub shares_burned: u64,
+}
+
+#[event]
+pub struct BasketPriceRefreshed {
+    pub basket: Pubkey,
+    pub nav_per_share: u64,
+    pub confidence: u64,
+    pub total_supply: u64,
+    pub slot: u64,
+}
+
+#[event]
+pub struct BasketPricingUpdated {
+    pub basket: Pubkey,
+    pub mint_pricing_mode: u8,
+    pub redeem_pricing_mode: u8,
+}
+
+#[event]
+pub struct BasketLimitsUpdated {
+    pub basket: Pubkey,
+    pub max_total_value: u64,
+    pub max_user_shares: u64,
+    pub max_withdraw_bps: u16,
+    pub withdraw_window_secs: i64,
+}
+
+#[event]
+pub struct EmergencyModeUpdated {
+    pub basket: Pubkey,
+    pub enabled: bool,
+}
+
+/// Emitted by an outflow rejected by the withdrawal limiter. Logged with
+/// `emit!` so it stays readable in the failed transaction's logs.
+#[event]
+pub struct WithdrawLimiterTripped {
+    pub basket: Pubkey,
+    pub shares_requested: u64,
+    pub headroom: u64,
+}
+
+#[event]
+pub struct LockTiersUpdated {
+    pub basket: Pubkey,
+    pub tiers: Vec<LockTier>,
+}
+
+#[event]
+pub struct SharesLocked {
+    pub basket: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub total_locked: u64,
+    pub unlock_ts: i64,
+    pub tier: u8,
+}
+
+#[event]
+pub struct SharesUnlocked {
+    pub basket: Pubkey,
+    pub owner: Pubkey,
+    pub amount_returned: u64,
+    /// Non-zero only for early exits.
+    pub penalty_burned: u64,
+}
+
+#[event]
+pub struct RewardPoolCreated {
+    pub pool: Pubkey,
+    pub basket: Pubkey,
+    pub reward_mint: Pubkey,
+    pub boosts: Vec<RewardBoost>,
+}
+
+#[event]
+pub struct RewardsFunded {
+    pub pool: Pubkey,
+    pub amount: u64,
+    pub reward_rate: u64,
+    pub period_end: i64,
+}
+
+#[event]
+pub struct SharesStaked {
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub weight: u64,
+    pub unlock_ts: i64,
+}
+
+#[event]
+pub struct SharesUnstaked {
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub weight: u64,
+}
+
+#[event]
+pub struct StakeReweighted {
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub weight: u64,
+}
+
+#[event]
+pub struct RewardsClaimed {
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct EpochOperatorUpdated {
+    pub basket: Pubkey,
+    pub operator: Pubkey,
+}
+
+#[event]
+pub struct DepositRequested {
+    pub basket: Pubkey,
+    pub owner: Pubkey,
+    pub epoch: u64,
+    /// Net deposit value, normalised to QSHARE decimals.
+    pub value: u64,
+}
+
+#[event]
+pub struct RedeemRequested {
+    pub basket: Pubkey,
+    pub owner: Pubkey,
+    pub epoch: u64,
+    pub shares: u64,
+}
+
+#[event]
+pub struct RequestCancelled {
+    pub basket: Pubkey,
+    pub owner: Pubkey,
+    pub epoch: u64,
+    /// Deposit value withdrawn from the queue, normalised to QSHARE decimals.
+    pub value: u64,
+    /// Tokens refunded per leg; empty if no deposit was cancelled.
+    pub amounts_out: Vec<u64>,
+    /// Escrowed shares returned.
+    pub shares: u64,
+}
+
+#[event]
+pub struct EpochSettled {
+    pub basket: Pubkey,
+    pub epoch: u64,
+    pub nav_per_share: u64,
+    pub deposit_value: u64,
+    pub shares_minted: u64,
     pub shares_burned: u64,
+    pub amounts_out: Vec<u64>,
+}
+
+#[event]
+pub struct DepositClaimed {
+    pub basket: Pubkey,
+    pub owner: Pubkey,
+    pub epoch: u64,
+    pub shares: u64,
+}
+
+#[event]
+pub struct RedeemClaimed {
+    pub basket: Pubkey,
+    pub owner: Pubkey,
+    pub epoch: u64,
+    pub shares: u64,
+    pub amounts_out: Vec<u64>,
 }
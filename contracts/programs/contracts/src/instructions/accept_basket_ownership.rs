@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::BasketError, events::*, state::*};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AcceptBasketOwnership<'info> {
+    pub new_owner: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = basket.load_current()?.pending_owner == new_owner.key() @ BasketError::Unauthorized,
+    )]
+    pub basket: AccountLoader<'info, Basket>,
+}
+
+impl<'info> AcceptBasketOwnership<'info> {
+    pub fn handler(ctx: Context<AcceptBasketOwnership>) -> Result<()> {
+        let mut basket = ctx.accounts.basket.load_current_mut()?;
+        let previous_owner = basket.owner;
+        basket.owner = ctx.accounts.new_owner.key();
+        basket.pending_owner = Pubkey::default();
+        drop(basket);
+
+        emit_cpi!(BasketOwnershipTransferred {
+            basket: ctx.accounts.basket.key(),
+            previous_owner,
+            new_owner: ctx.accounts.new_owner.key(),
+        });
+
+        Ok(())
+    }
+}
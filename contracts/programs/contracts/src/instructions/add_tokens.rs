@@ -4,22 +4,26 @@ use anchor_spl::{
     token_interface::{Mint, TokenAccount, TokenInterface},
 };
 
-use crate::{constants::*, error::BasketError, events::*, state::*};
+use crate::{constants::*, error::BasketError, events::*, legs, state::*};
 
 #[event_cpi]
 #[derive(Accounts)]
 pub struct AddTokens<'info> {
+    /// Basket owner or protocol admin.
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub authority: Signer<'info>,
 
     #[account(
         seeds = [CONFIG_SEED],
         bump = config.bump,
-        has_one = admin @ BasketError::Unauthorized,
     )]
     pub config: Box<Account<'info, Config>>,
 
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = basket.load_current()?.is_manager(&authority.key(), &config.admin)
+            @ BasketError::Unauthorized,
+    )]
     pub basket: AccountLoader<'info, Basket>,
 
     /// The underlying SPL token mint being registered.
@@ -31,7 +35,7 @@ pub struct AddTokens<'info> {
 
     #[account(
         init,
-        payer = admin,
+        payer = authority,
         space = 8 + BasketToken::INIT_SPACE,
         seeds = [
             BASKET_TOKEN_SEED,
@@ -45,7 +49,7 @@ pub struct AddTokens<'info> {
     /// Vault ATA for this mint (owned by vault_authority).
     #[account(
         init,
-        payer = admin,
+        payer = authority,
         associated_token::mint = underlying_mint,
         associated_token::authority = vault_authority,
         associated_token::token_program = token_program,
@@ -54,7 +58,7 @@ pub struct AddTokens<'info> {
 
     #[account(
         init,
-        payer = admin,
+        payer = authority,
         token::mint = underlying_mint,
         token::authority = vault_authority,
         seeds = [
@@ -72,8 +76,16 @@ pub struct AddTokens<'info> {
 }
 
 impl<'info> AddTokens<'info> {
-    pub fn handler(ctx: Context<AddTokens>) -> Result<()> {
-        let mut basket = ctx.accounts.basket.load_mut()?;
+    pub fn handler(ctx: Context<AddTokens>, acknowledged_extensions: u8) -> Result<()> {
+        let extension_flags =
+            legs::inspect_mint_extensions(&ctx.accounts.underlying_mint.to_account_info())?;
+        Self::check_extension_policy(
+            extension_flags,
+            ctx.accounts.config.mint_extension_policy,
+            acknowledged_extensions,
+        )?;
+
+        let mut basket = ctx.accounts.basket.load_current_mut()?;
 
         require!(
             ctx.accounts.vault_authority.key() == basket.vault_authority,
@@ -92,6 +104,8 @@ impl<'info> AddTokens<'info> {
         // Drop the borrow before writing to basket_token (same tx scope)
         drop(basket);
 
+        let now = Clock::get()?.unix_timestamp;
+
         ctx.accounts.basket_token.set_inner(BasketToken {
             basket: ctx.accounts.basket.key(),
             mint: ctx.accounts.underlying_mint.key(),
@@ -100,14 +114,42 @@ impl<'info> AddTokens<'info> {
             decimals: ctx.accounts.underlying_mint.decimals,
             enabled: true,
             bump: ctx.bumps.basket_token,
+            extension_flags,
+            last_price: 0,
+            price_cumulative: 0,
+            last_update_ts: now,
+            twap_anchor_cumulative: 0,
+            twap_anchor_ts: now,
+            twap_price: 0,
+            twap_updated_ts: 0,
         });
 
         emit_cpi!(TokenAdded {
             basket: ctx.accounts.basket.key(),
             mint: ctx.accounts.underlying_mint.key(),
             vault_ata: ctx.accounts.vault_ata.key(),
+            extension_flags,
         });
 
         Ok(())
     }
+
+    /// Unsupported extensions are refused outright; every other detected
+    /// flag must be permitted by the global policy and explicitly
+    /// acknowledged by the caller for this mint.
+    fn check_extension_policy(detected: u8, policy: u8, acknowledged: u8) -> Result<()> {
+        require!(
+            detected & MINT_EXT_UNSUPPORTED == 0,
+            BasketError::UnsupportedMintExtension
+        );
+        require!(
+            detected & !policy == 0,
+            BasketError::RiskyMintExtension
+        );
+        require!(
+            detected & !acknowledged == 0,
+            BasketError::MintExtensionNotAcknowledged
+        );
+        Ok(())
+    }
 }
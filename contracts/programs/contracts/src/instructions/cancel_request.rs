@@ -0,0 +1,242 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::{
+    constants::*,
+    error::BasketError,
+    events::*,
+    instructions::lock_shares::transfer_shares,
+    legs::{self, UserAtaOwner},
+    state::*,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelRequest<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub basket: AccountLoader<'info, Basket>,
+
+    #[account(
+        mut,
+        seeds = [EPOCH_QUEUE_SEED, basket.key().as_ref()],
+        bump = epoch_queue.bump,
+    )]
+    pub epoch_queue: Box<Account<'info, EpochQueue>>,
+
+    #[account(
+        mut,
+        close = owner,
+        has_one = owner @ BasketError::Unauthorized,
+        seeds = [DEPOSIT_REQUEST_SEED, basket.key().as_ref(), owner.key().as_ref()],
+        bump = deposit_request.bump,
+    )]
+    pub deposit_request: Option<Box<Account<'info, DepositRequest>>>,
+
+    #[account(
+        mut,
+        close = owner,
+        has_one = owner @ BasketError::Unauthorized,
+        seeds = [REDEEM_REQUEST_SEED, basket.key().as_ref(), owner.key().as_ref()],
+        bump = redeem_request.bump,
+    )]
+    pub redeem_request: Option<Box<Account<'info, RedeemRequest>>>,
+
+    #[account(
+        address = basket.load_current()?.share_mint @ BasketError::ShareMintMismatch,
+        mint::token_program = share_token_program,
+    )]
+    pub share_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Vault authority PDA — signs the return of escrowed shares.
+    /// CHECK: Must match the basket's vault authority.
+    #[account(address = basket.load_current()?.vault_authority @ BasketError::VaultAuthMismatch)]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    /// Required with `redeem_request`.
+    #[account(
+        mut,
+        associated_token::mint = share_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = share_token_program,
+    )]
+    pub owner_share_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Required with `redeem_request`.
+    #[account(
+        mut,
+        associated_token::mint = share_mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = share_token_program,
+    )]
+    pub share_escrow: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    /// Token program owning the QSHARE mint (SPL Token or Token-2022).
+    pub share_token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> CancelRequest<'info> {
+    /// Withdraw the owner's deposit and/or redemption request from the
+    /// current epoch before it settles, so an absent operator can't hold
+    /// funds in the queue. A deposit is refunded exactly the net amount of
+    /// each leg it escrowed; the fee taken at request time is not returned.
+    /// Escrowed shares go back in full.
+    ///
+    /// Remaining accounts: with `deposit_request`, one
+    /// `[BasketToken, Mint, PendingATA, OwnerATA]` group per leg; then the
+    /// share transfer-hook's extra accounts, if the mint has one.
+    pub fn handler(ctx: Context<'_, '_, 'info, 'info, CancelRequest<'info>>) -> Result<()> {
+        let accounts = &ctx.accounts;
+        require!(
+            accounts.deposit_request.is_some() || accounts.redeem_request.is_some(),
+            BasketError::NoRequest
+        );
+        let epoch = accounts.epoch_queue.current_epoch;
+
+        let mut remaining = ctx.remaining_accounts;
+        let mut value = 0;
+        let mut amounts_out = Vec::new();
+        if let Some(request) = accounts.deposit_request.as_ref() {
+            require!(request.epoch == epoch, BasketError::RequestSettled);
+            let expected = (accounts.basket.load_current()?.token_count as usize)
+                .checked_mul(CANCEL_DEPOSIT_ACCOUNTS_PER_TOKEN)
+                .ok_or(BasketError::ArithmeticOverflow)?;
+            require!(
+                remaining.len() >= expected,
+                BasketError::InvalidRemainingAccounts
+            );
+            let (leg_accounts, hook_accounts) = remaining.split_at(expected);
+            amounts_out = Self::refund_deposit(accounts, leg_accounts, request)?;
+            value = request.value;
+            remaining = hook_accounts;
+        }
+
+        let mut shares = 0;
+        if let Some(request) = accounts.redeem_request.as_ref() {
+            require!(request.epoch == epoch, BasketError::RequestSettled);
+            Self::refund_shares(accounts, remaining, request.shares)?;
+            shares = request.shares;
+        } else {
+            require!(
+                remaining.is_empty(),
+                BasketError::InvalidRemainingAccounts
+            );
+        }
+
+        let queue = &mut ctx.accounts.epoch_queue;
+        queue.pending_deposit_value = queue
+            .pending_deposit_value
+            .checked_sub(value)
+            .ok_or(BasketError::ArithmeticOverflow)?;
+        queue.pending_redeem_shares = queue
+            .pending_redeem_shares
+            .checked_sub(shares)
+            .ok_or(BasketError::ArithmeticOverflow)?;
+
+        emit_cpi!(RequestCancelled {
+            basket: ctx.accounts.basket.key(),
+            owner: ctx.accounts.owner.key(),
+            epoch,
+            value,
+            amounts_out,
+            shares,
+        });
+
+        Ok(())
+    }
+
+    /// Return the net amount of each leg the request escrowed. Tokens sent
+    /// to the escrow by anything other than a request stay put.
+    fn refund_deposit(
+        accounts: &CancelRequest<'info>,
+        remaining: &'info [AccountInfo<'info>],
+        request: &DepositRequest,
+    ) -> Result<Vec<u64>> {
+        let basket_key = accounts.basket.key();
+        let queue_key = accounts.epoch_queue.key();
+        let queue_seeds: &[&[u8]] = &[
+            EPOCH_QUEUE_SEED,
+            basket_key.as_ref(),
+            &[accounts.epoch_queue.bump],
+        ];
+        let token_program = accounts.token_program.key();
+
+        let mut basket_tokens = Vec::with_capacity(remaining.len());
+        let mut amounts_out = Vec::with_capacity(remaining.len());
+        for leg in remaining.chunks_exact(CANCEL_DEPOSIT_ACCOUNTS_PER_TOKEN) {
+            let basket_token = legs::load_basket_token(&leg[0], basket_key)?;
+            let (mint_info, pending_ata_info, owner_ata_info) = (&leg[1], &leg[2], &leg[3]);
+            require!(
+                mint_info.key() == basket_token.mint,
+                BasketError::MintConfigMismatch
+            );
+            legs::validate_escrow_ata(
+                pending_ata_info,
+                &queue_key,
+                &basket_token.mint,
+                &token_program,
+            )?;
+            legs::validate_user_ata(
+                owner_ata_info,
+                &basket_token.mint,
+                UserAtaOwner::Signer(accounts.owner.key()),
+                &token_program,
+            )?;
+            basket_tokens.push(leg[0].key());
+
+            let amount_out = request.leg_amount(&basket_token.mint);
+            if amount_out > 0 {
+                token_interface::transfer_checked(
+                    CpiContext::new_with_signer(
+                        accounts.token_program.to_account_info(),
+                        TransferChecked {
+                            from: pending_ata_info.clone(),
+                            to: owner_ata_info.clone(),
+                            authority: accounts.epoch_queue.to_account_info(),
+                            mint: mint_info.clone(),
+                        },
+                        &[queue_seeds],
+                    ),
+                    amount_out,
+                    basket_token.decimals,
+                )?;
+            }
+            amounts_out.push(amount_out);
+        }
+        legs::require_distinct_legs(&basket_tokens)?;
+        Ok(amounts_out)
+    }
+
+    fn refund_shares(
+        accounts: &CancelRequest<'info>,
+        hook_accounts: &'info [AccountInfo<'info>],
+        shares: u64,
+    ) -> Result<()> {
+        let (Some(owner_share_ata), Some(share_escrow)) = (
+            accounts.owner_share_ata.as_ref(),
+            accounts.share_escrow.as_ref(),
+        ) else {
+            return err!(BasketError::MissingShareAccounts);
+        };
+
+        let basket = accounts.basket.load_current()?;
+        let basket_id_bytes = basket.basket_id.to_le_bytes();
+        let vault_auth_seeds: &[&[u8]] = &[
+            VAULT_AUTHORITY_SEED,
+            basket_id_bytes.as_ref(),
+            &[basket.vault_authority_bump],
+        ];
+        transfer_shares(
+            &accounts.share_token_program,
+            share_escrow.to_account_info(),
+            &accounts.share_mint,
+            owner_share_ata.to_account_info(),
+            accounts.vault_authority.to_account_info(),
+            hook_accounts,
+            shares,
+            &[vault_auth_seeds],
+        )
+    }
+}
@@ -0,0 +1,126 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    constants::*, error::BasketError, events::*, instructions::lock_shares::transfer_shares,
+    state::*,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimDeposit<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub basket: AccountLoader<'info, Basket>,
+
+    #[account(
+        mut,
+        close = owner,
+        has_one = owner @ BasketError::Unauthorized,
+        seeds = [DEPOSIT_REQUEST_SEED, basket.key().as_ref(), owner.key().as_ref()],
+        bump = deposit_request.bump,
+    )]
+    pub deposit_request: Box<Account<'info, DepositRequest>>,
+
+    /// Exists only once the request's epoch has been settled.
+    #[account(
+        mut,
+        seeds = [
+            EPOCH_SEED,
+            basket.key().as_ref(),
+            deposit_request.epoch.to_le_bytes().as_ref(),
+        ],
+        bump = epoch.bump,
+    )]
+    pub epoch: Box<Account<'info, Epoch>>,
+
+    #[account(
+        address = basket.load_current()?.share_mint @ BasketError::ShareMintMismatch,
+        mint::token_program = share_token_program,
+    )]
+    pub share_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = share_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = share_token_program,
+    )]
+    pub owner_share_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Vault authority PDA — signs transfers out of the escrow.
+    /// CHECK: Must match the basket's vault authority.
+    #[account(address = basket.load_current()?.vault_authority @ BasketError::VaultAuthMismatch)]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = share_mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = share_token_program,
+    )]
+    pub share_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Token program owning the QSHARE mint (SPL Token or Token-2022).
+    pub share_token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimDeposit<'info> {
+    /// Take the shares a settled deposit request was filled with and close
+    /// the request. Remaining accounts carry the share transfer-hook's extra
+    /// accounts, if the mint has one.
+    pub fn handler(ctx: Context<'_, '_, 'info, 'info, ClaimDeposit<'info>>) -> Result<()> {
+        let value = ctx.accounts.deposit_request.value;
+        let epoch = &mut ctx.accounts.epoch;
+        let shares =
+            Epoch::pro_rata(epoch.unclaimed_shares, value, epoch.unclaimed_deposit_value)
+                .ok_or(BasketError::ArithmeticOverflow)?;
+        epoch.unclaimed_shares = epoch
+            .unclaimed_shares
+            .checked_sub(shares)
+            .ok_or(BasketError::ArithmeticOverflow)?;
+        epoch.unclaimed_deposit_value = epoch
+            .unclaimed_deposit_value
+            .checked_sub(value)
+            .ok_or(BasketError::ArithmeticOverflow)?;
+        let epoch_id = epoch.epoch_id;
+
+        let accounts = &ctx.accounts;
+        let basket = accounts.basket.load_current()?;
+        let basket_id_bytes = basket.basket_id.to_le_bytes();
+        let vault_auth_seeds: &[&[u8]] = &[
+            VAULT_AUTHORITY_SEED,
+            basket_id_bytes.as_ref(),
+            &[basket.vault_authority_bump],
+        ];
+        if shares > 0 {
+            transfer_shares(
+                &accounts.share_token_program,
+                accounts.share_escrow.to_account_info(),
+                &accounts.share_mint,
+                accounts.owner_share_ata.to_account_info(),
+                accounts.vault_authority.to_account_info(),
+                ctx.remaining_accounts,
+                shares,
+                &[vault_auth_seeds],
+            )?;
+        }
+        drop(basket);
+
+        emit_cpi!(DepositClaimed {
+            basket: accounts.basket.key(),
+            owner: accounts.owner.key(),
+            epoch: epoch_id,
+            shares,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,150 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, TokenInterface, TransferChecked};
+
+use crate::{
+    constants::*,
+    error::BasketError,
+    events::*,
+    legs::{self, UserAtaOwner},
+    pricing,
+    state::*,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimRedeem<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub basket: AccountLoader<'info, Basket>,
+
+    #[account(
+        mut,
+        close = owner,
+        has_one = owner @ BasketError::Unauthorized,
+        seeds = [REDEEM_REQUEST_SEED, basket.key().as_ref(), owner.key().as_ref()],
+        bump = redeem_request.bump,
+    )]
+    pub redeem_request: Box<Account<'info, RedeemRequest>>,
+
+    /// Exists only once the request's epoch has been settled; owns the
+    /// payout ATAs.
+    #[account(
+        mut,
+        seeds = [
+            EPOCH_SEED,
+            basket.key().as_ref(),
+            redeem_request.epoch.to_le_bytes().as_ref(),
+        ],
+        bump = epoch.bump,
+    )]
+    pub epoch: Box<Account<'info, Epoch>>,
+
+    #[account(
+        mut,
+        seeds = [EPOCH_QUEUE_SEED, basket.key().as_ref()],
+        bump = epoch_queue.bump,
+    )]
+    pub epoch_queue: Box<Account<'info, EpochQueue>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> ClaimRedeem<'info> {
+    /// Take a settled redemption's pro-rata slice of each leg's payout and
+    /// close the request. Every leg must be present so no slice is left for
+    /// other claimers to absorb.
+    pub fn handler(ctx: Context<'_, '_, 'info, 'info, ClaimRedeem<'info>>) -> Result<()> {
+        let accounts = &ctx.accounts;
+        let remaining = ctx.remaining_accounts;
+
+        let token_count = accounts.basket.load_current()?.token_count as usize;
+        require!(
+            remaining.len()
+                == token_count
+                    .checked_mul(CLAIM_REDEEM_ACCOUNTS_PER_TOKEN)
+                    .ok_or(BasketError::ArithmeticOverflow)?,
+            BasketError::InvalidRemainingAccounts
+        );
+
+        let basket_key = accounts.basket.key();
+        let epoch_key = accounts.epoch.key();
+        let token_program = accounts.token_program.key();
+        let shares = accounts.redeem_request.shares;
+        let unclaimed = accounts.epoch.unclaimed_redeem_shares;
+        let epoch_id_bytes = accounts.epoch.epoch_id.to_le_bytes();
+        let epoch_seeds: &[&[u8]] = &[
+            EPOCH_SEED,
+            basket_key.as_ref(),
+            epoch_id_bytes.as_ref(),
+            &[accounts.epoch.bump],
+        ];
+
+        let mut basket_tokens = Vec::with_capacity(token_count);
+        let mut amounts_out = Vec::with_capacity(token_count);
+        for leg in remaining.chunks_exact(CLAIM_REDEEM_ACCOUNTS_PER_TOKEN) {
+            let basket_token = legs::load_basket_token(&leg[0], basket_key)?;
+            let (mint_info, payout_ata_info, owner_ata_info) = (&leg[1], &leg[2], &leg[3]);
+            require!(
+                mint_info.key() == basket_token.mint,
+                BasketError::MintConfigMismatch
+            );
+            legs::validate_escrow_ata(
+                payout_ata_info,
+                &epoch_key,
+                &basket_token.mint,
+                &token_program,
+            )?;
+            legs::validate_user_ata(
+                owner_ata_info,
+                &basket_token.mint,
+                UserAtaOwner::Signer(accounts.owner.key()),
+                &token_program,
+            )?;
+            basket_tokens.push(leg[0].key());
+
+            let payout_balance = pricing::read_vault_balance(payout_ata_info)?;
+            let amount_out = Epoch::pro_rata(payout_balance, shares, unclaimed)
+                .ok_or(BasketError::ArithmeticOverflow)?;
+            if amount_out > 0 {
+                token_interface::transfer_checked(
+                    CpiContext::new_with_signer(
+                        accounts.token_program.to_account_info(),
+                        TransferChecked {
+                            from: payout_ata_info.clone(),
+                            to: owner_ata_info.clone(),
+                            authority: accounts.epoch.to_account_info(),
+                            mint: mint_info.clone(),
+                        },
+                        &[epoch_seeds],
+                    ),
+                    amount_out,
+                    basket_token.decimals,
+                )?;
+            }
+            amounts_out.push(amount_out);
+        }
+        legs::require_distinct_legs(&basket_tokens)?;
+
+        let epoch = &mut ctx.accounts.epoch;
+        epoch.unclaimed_redeem_shares = unclaimed
+            .checked_sub(shares)
+            .ok_or(BasketError::ArithmeticOverflow)?;
+        let epoch_id = epoch.epoch_id;
+        let queue = &mut ctx.accounts.epoch_queue;
+        queue.unclaimed_redeem_shares = queue
+            .unclaimed_redeem_shares
+            .checked_sub(shares)
+            .ok_or(BasketError::ArithmeticOverflow)?;
+
+        emit_cpi!(RedeemClaimed {
+            basket: basket_key,
+            owner: ctx.accounts.owner.key(),
+            epoch: epoch_id,
+            shares,
+            amounts_out,
+        });
+
+        Ok(())
+    }
+}
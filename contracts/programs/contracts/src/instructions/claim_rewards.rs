@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+use crate::{constants::*, error::BasketError, events::*, state::*};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimRewards<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = reward_mint @ BasketError::InvalidBasketWiring,
+        has_one = reward_vault @ BasketError::InvalidBasketWiring,
+        seeds = [REWARD_POOL_SEED, reward_pool.basket.as_ref(), reward_mint.key().as_ref()],
+        bump = reward_pool.bump,
+    )]
+    pub reward_pool: Box<Account<'info, RewardPool>>,
+
+    #[account(
+        mut,
+        has_one = owner @ BasketError::Unauthorized,
+        seeds = [STAKE_POSITION_SEED, reward_pool.key().as_ref(), owner.key().as_ref()],
+        bump = stake_position.bump,
+    )]
+    pub stake_position: Box<Account<'info, StakePosition>>,
+
+    #[account(mint::token_program = reward_token_program)]
+    pub reward_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut)]
+    pub reward_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = reward_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = reward_token_program,
+    )]
+    pub owner_reward_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub reward_token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimRewards<'info> {
+    /// Pay out everything the position has earned. A boost whose
+    /// commitment has ended drops back to 1x here.
+    pub fn handler(ctx: Context<ClaimRewards>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let pool = &mut ctx.accounts.reward_pool;
+        let position = &mut ctx.accounts.stake_position;
+
+        position.sync(pool, now)?;
+
+        let amount = position.rewards_owed;
+        require!(amount > 0, BasketError::NothingToClaim);
+        position.rewards_owed = 0;
+
+        let basket = pool.basket;
+        let reward_mint = pool.reward_mint;
+        let pool_seeds: &[&[u8]] = &[
+            REWARD_POOL_SEED,
+            basket.as_ref(),
+            reward_mint.as_ref(),
+            &[pool.bump],
+        ];
+        let accounts = &ctx.accounts;
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                accounts.reward_token_program.to_account_info(),
+                TransferChecked {
+                    from: accounts.reward_vault.to_account_info(),
+                    mint: accounts.reward_mint.to_account_info(),
+                    to: accounts.owner_reward_ata.to_account_info(),
+                    authority: accounts.reward_pool.to_account_info(),
+                },
+                &[pool_seeds],
+            ),
+            amount,
+            accounts.reward_mint.decimals,
+        )?;
+
+        emit_cpi!(RewardsClaimed {
+            pool: accounts.reward_pool.key(),
+            owner: accounts.owner.key(),
+            amount,
+        });
+
+        Ok(())
+    }
+}
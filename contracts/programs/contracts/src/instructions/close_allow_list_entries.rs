@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, error::BasketError, events::*, state::*};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseAllowListEntries<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.whitelist_auth == authority.key() @ BasketError::Unauthorized,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    /// CHECK: Only receives the entries' rent.
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+}
+
+impl<'info> CloseAllowListEntries<'info> {
+    /// Batch form of `close_allow_list_entry`: closes every `UserAllowList`
+    /// passed (writable) in remaining_accounts, across any baskets.
+    pub fn handler(ctx: Context<'_, '_, 'info, 'info, CloseAllowListEntries<'info>>) -> Result<()> {
+        require!(
+            !ctx.remaining_accounts.is_empty(),
+            BasketError::NoAllowListEntries
+        );
+
+        for info in ctx.remaining_accounts {
+            let entry: Account<UserAllowList> = Account::try_from(info)?;
+            let (basket, user) = (entry.basket, entry.user);
+            entry.close(ctx.accounts.recipient.to_account_info())?;
+
+            emit_cpi!(AllowListEntryClosed {
+                basket,
+                user,
+                recipient: ctx.accounts.recipient.key(),
+            });
+        }
+
+        Ok(())
+    }
+}
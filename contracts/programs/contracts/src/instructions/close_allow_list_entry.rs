@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, error::BasketError, events::*, state::*};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseAllowListEntry<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.whitelist_auth == authority.key() @ BasketError::Unauthorized,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        close = recipient,
+        seeds = [
+            USER_ALLOW_SEED,
+            user_allow_list.basket.as_ref(),
+            user_allow_list.user.as_ref(),
+        ],
+        bump = user_allow_list.bump,
+    )]
+    pub user_allow_list: Box<Account<'info, UserAllowList>>,
+
+    /// CHECK: Only receives the entry's rent.
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+}
+
+impl<'info> CloseAllowListEntry<'info> {
+    /// Delete an allow-list entry, refunding its rent to `recipient`. The
+    /// user is then treated as never allow-listed.
+    pub fn handler(ctx: Context<CloseAllowListEntry>) -> Result<()> {
+        emit_cpi!(AllowListEntryClosed {
+            basket: ctx.accounts.user_allow_list.basket,
+            user: ctx.accounts.user_allow_list.user,
+            recipient: ctx.accounts.recipient.key(),
+        });
+
+        Ok(())
+    }
+}
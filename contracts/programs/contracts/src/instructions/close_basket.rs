@@ -0,0 +1,321 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, CloseAccount, Mint, TokenInterface, TransferChecked};
+
+use crate::{
+    constants::*,
+    error::BasketError,
+    events::*,
+    legs::{self, UserAtaOwner},
+    pda, pricing,
+    state::*,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseBasket<'info> {
+    /// Basket owner or protocol admin.
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    /// The basket owner. Receives the rent (and any creation bond) of every
+    /// closed account, whoever signs.
+    #[account(mut)]
+    pub owner: SystemAccount<'info>,
+
+    #[account(
+        mut,
+        close = owner,
+        constraint = basket.load_current()?.owner == owner.key() @ BasketError::Unauthorized,
+        constraint = basket.load_current()?.is_manager(&authority.key(), &config.admin)
+            @ BasketError::Unauthorized,
+        constraint = basket.load_current()?.share_mint == share_mint.key()
+            @ BasketError::ShareMintMismatch,
+    )]
+    pub basket: AccountLoader<'info, Basket>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [BASKET_PRICE_SEED, basket.key().as_ref()],
+        bump = basket_price.load()?.bump,
+    )]
+    pub basket_price: AccountLoader<'info, BasketPrice>,
+
+    /// Closed if lock tiers were ever set.
+    /// CHECK: Address fixed by seeds; only touched if owned by this program.
+    #[account(mut, seeds = [LOCK_TIERS_SEED, basket.key().as_ref()], bump)]
+    pub lock_tiers: UncheckedAccount<'info>,
+
+    /// Closed if the queue was ever opened; it must hold no pending deposits
+    /// or unclaimed redemptions.
+    /// CHECK: Address fixed by seeds; only touched if owned by this program.
+    #[account(mut, seeds = [EPOCH_QUEUE_SEED, basket.key().as_ref()], bump)]
+    pub epoch_queue: UncheckedAccount<'info>,
+
+    /// Closed if a compliance root was ever set.
+    /// CHECK: Address fixed by seeds; only touched if owned by this program.
+    #[account(mut, seeds = [COMPLIANCE_ROOT_SEED, basket.key().as_ref()], bump)]
+    pub compliance_root: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = share_mint.supply == 0 @ BasketError::BasketNotEmpty,
+        mint::token_program = share_token_program,
+    )]
+    pub share_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Vault authority PDA — signs the vault closures.
+    /// CHECK: Validated against the basket's derivation in the handler.
+    pub vault_authority: UncheckedAccount<'info>,
+
+    /// The vault authority's QSHARE escrow shared by locks, stakes and
+    /// redemptions; closed if it exists.
+    /// CHECK: Validated as the vault authority's share ATA in the handler.
+    #[account(mut)]
+    pub share_escrow: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    /// Token program owning the QSHARE mint (SPL Token or Token-2022).
+    pub share_token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> CloseBasket<'info> {
+    /// Wind down an empty basket: with no shares outstanding, every vault
+    /// drained and no queued request left unpaid, sweep each leg's fees to
+    /// the protocol treasury and close the leg's accounts, then the basket
+    /// and its per-basket PDAs and escrows. The basket stays listed in the
+    /// registry. Per-user records (allow-list entries, request and stake
+    /// positions) are closed by their own instructions.
+    ///
+    /// Settled `Epoch` accounts and their payout ATAs are left behind: once
+    /// the basket is gone no instruction can close them, so their rent and
+    /// any rounding dust in the payout ATAs stay stranded.
+    pub fn handler(ctx: Context<'_, '_, 'info, 'info, CloseBasket<'info>>) -> Result<()> {
+        let accounts = &ctx.accounts;
+        let remaining = ctx.remaining_accounts;
+
+        let basket = accounts.basket.load_current()?;
+        let expected = (basket.token_count as usize)
+            .checked_mul(CLOSE_BASKET_ACCOUNTS_PER_TOKEN)
+            .ok_or(BasketError::ArithmeticOverflow)?;
+        require!(remaining.len() == expected, BasketError::IncompleteClose);
+
+        let basket_id = basket.basket_id;
+        let basket_id_bytes = basket_id.to_le_bytes();
+        let vault_auth_seeds: &[&[u8]] = &[
+            VAULT_AUTHORITY_SEED,
+            basket_id_bytes.as_ref(),
+            &[basket.vault_authority_bump],
+        ];
+        require!(
+            accounts.vault_authority.key() == basket.vault_authority,
+            BasketError::VaultAuthMismatch
+        );
+        drop(basket);
+
+        let basket_key = accounts.basket.key();
+        let queue_seeds: &[&[u8]] = &[
+            EPOCH_QUEUE_SEED,
+            basket_key.as_ref(),
+            &[ctx.bumps.epoch_queue],
+        ];
+
+        let leg_keys: Vec<Pubkey> = remaining
+            .chunks(CLOSE_BASKET_ACCOUNTS_PER_TOKEN)
+            .map(|leg| leg[0].key())
+            .collect();
+        legs::require_distinct_legs(&leg_keys)?;
+
+        Self::close_epoch_queue(accounts, ctx.program_id)?;
+
+        let fees_swept = remaining
+            .chunks(CLOSE_BASKET_ACCOUNTS_PER_TOKEN)
+            .map(|leg| Self::close_leg(accounts, leg, vault_auth_seeds, queue_seeds))
+            .collect::<Result<Vec<_>>>()?;
+
+        Self::close_share_escrow(accounts, vault_auth_seeds)?;
+        let owner_info = accounts.owner.to_account_info();
+        for info in [&accounts.lock_tiers, &accounts.compliance_root] {
+            if info.owner == ctx.program_id {
+                pda::close_pda_account(info, &owner_info)?;
+            }
+        }
+
+        emit_cpi!(BasketClosed {
+            basket: basket_key,
+            basket_id,
+            owner: accounts.owner.key(),
+            fees_swept,
+        });
+
+        Ok(())
+    }
+
+    fn close_epoch_queue(accounts: &CloseBasket<'info>, program_id: &Pubkey) -> Result<()> {
+        let info = &accounts.epoch_queue;
+        if info.owner != program_id {
+            return Ok(());
+        }
+        let queue = EpochQueue::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+        require!(
+            queue.pending_deposit_value == 0 && queue.unclaimed_redeem_shares == 0,
+            BasketError::BasketNotEmpty
+        );
+        pda::close_pda_account(info, &accounts.owner.to_account_info())
+    }
+
+    /// Close one leg and return the fees swept from its fee vault.
+    fn close_leg(
+        accounts: &CloseBasket<'info>,
+        leg: &'info [AccountInfo<'info>],
+        vault_auth_seeds: &[&[u8]],
+        queue_seeds: &[&[u8]],
+    ) -> Result<u64> {
+        let (basket_token_info, mint_info, vault_ata_info) = (&leg[0], &leg[1], &leg[2]);
+        let (fee_vault_info, pending_ata_info, treasury_ata_info) = (&leg[3], &leg[4], &leg[5]);
+
+        let basket_token: Account<BasketToken> = Account::try_from(basket_token_info)?;
+        require!(
+            basket_token.basket == accounts.basket.key(),
+            BasketError::InvalidBasketWiring
+        );
+        require!(
+            mint_info.key() == basket_token.mint,
+            BasketError::MintConfigMismatch
+        );
+        require!(
+            vault_ata_info.key() == basket_token.vault_ata
+                && fee_vault_info.key() == basket_token.fee_vault_ata,
+            BasketError::InvalidBasketWiring
+        );
+        let token_program = accounts.token_program.key();
+        legs::validate_escrow_ata(
+            pending_ata_info,
+            &accounts.epoch_queue.key(),
+            &basket_token.mint,
+            &token_program,
+        )?;
+        legs::validate_user_ata(
+            treasury_ata_info,
+            &basket_token.mint,
+            UserAtaOwner::Receiver(accounts.config.treasury),
+            &token_program,
+        )?;
+
+        require!(
+            pricing::read_vault_balance(vault_ata_info)? == 0,
+            BasketError::BasketNotEmpty
+        );
+        Self::close_token_account(
+            accounts,
+            vault_ata_info,
+            accounts.vault_authority.to_account_info(),
+            vault_auth_seeds,
+        )?;
+
+        let fees = Self::sweep_and_close(
+            accounts,
+            &basket_token,
+            mint_info,
+            fee_vault_info,
+            treasury_ata_info,
+            accounts.vault_authority.to_account_info(),
+            vault_auth_seeds,
+        )?;
+
+        // With no deposit pending, anything left in the queue's escrow was
+        // sent there directly; it goes to the treasury rather than blocking
+        // the close.
+        if pending_ata_info.owner == &token_program && !pending_ata_info.data_is_empty() {
+            Self::sweep_and_close(
+                accounts,
+                &basket_token,
+                mint_info,
+                pending_ata_info,
+                treasury_ata_info,
+                accounts.epoch_queue.to_account_info(),
+                queue_seeds,
+            )?;
+        }
+
+        basket_token.close(accounts.owner.to_account_info())?;
+        Ok(fees)
+    }
+
+    /// Move the whole balance of `from` to the treasury, then close it.
+    /// The rent goes to the owner.
+    fn sweep_and_close(
+        accounts: &CloseBasket<'info>,
+        basket_token: &BasketToken,
+        mint_info: &'info AccountInfo<'info>,
+        from: &'info AccountInfo<'info>,
+        treasury_ata_info: &'info AccountInfo<'info>,
+        authority: AccountInfo<'info>,
+        seeds: &[&[u8]],
+    ) -> Result<u64> {
+        let amount = pricing::read_vault_balance(from)?;
+        if amount > 0 {
+            token_interface::transfer_checked(
+                CpiContext::new_with_signer(
+                    accounts.token_program.to_account_info(),
+                    TransferChecked {
+                        from: from.clone(),
+                        to: treasury_ata_info.clone(),
+                        authority: authority.clone(),
+                        mint: mint_info.clone(),
+                    },
+                    &[seeds],
+                ),
+                amount,
+                basket_token.decimals,
+            )?;
+        }
+        Self::close_token_account(accounts, from, authority, seeds)?;
+        Ok(amount)
+    }
+
+    fn close_token_account(
+        accounts: &CloseBasket<'info>,
+        info: &AccountInfo<'info>,
+        authority: AccountInfo<'info>,
+        seeds: &[&[u8]],
+    ) -> Result<()> {
+        token_interface::close_account(CpiContext::new_with_signer(
+            accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: info.clone(),
+                destination: accounts.owner.to_account_info(),
+                authority,
+            },
+            &[seeds],
+        ))
+    }
+
+    fn close_share_escrow(accounts: &CloseBasket<'info>, vault_auth_seeds: &[&[u8]]) -> Result<()> {
+        let share_token_program = accounts.share_token_program.key();
+        legs::validate_escrow_ata(
+            &accounts.share_escrow,
+            &accounts.vault_authority.key(),
+            &accounts.share_mint.key(),
+            &share_token_program,
+        )?;
+        if accounts.share_escrow.owner != &share_token_program
+            || accounts.share_escrow.data_is_empty()
+        {
+            return Ok(());
+        }
+        token_interface::close_account(CpiContext::new_with_signer(
+            accounts.share_token_program.to_account_info(),
+            CloseAccount {
+                account: accounts.share_escrow.to_account_info(),
+                destination: accounts.owner.to_account_info(),
+                authority: accounts.vault_authority.to_account_info(),
+            },
+            &[vault_auth_seeds],
+        ))
+    }
+}
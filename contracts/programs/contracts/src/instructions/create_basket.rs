@@ -1,31 +1,74 @@
-use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{Mint, TokenInterface};
+use anchor_lang::{
+    prelude::*,
+    system_program::{self, CreateAccount, Transfer},
+};
+use anchor_spl::{
+    token_2022::spl_token_2022::{extension::ExtensionType, state::Mint as MintState},
+    token_2022_extensions::{
+        metadata_pointer_initialize,
+        spl_pod::optional_keys::OptionalNonZeroPubkey,
+        spl_token_metadata_interface::state::TokenMetadata,
+        token_metadata_initialize, transfer_hook_initialize, MetadataPointerInitialize,
+        TokenMetadataInitialize, TransferHookInitialize,
+    },
+    token_interface::{self, InitializeMint2, Token2022, TokenInterface},
+};
 
-use crate::{constants::*, error::BasketError, events::*, state::*};
+use crate::{
+    constants::*,
+    error::BasketError,
+    events::*,
+    metadata::{self, MetaplexAccounts, ShareMetadata},
+    pricing,
+    state::*,
+};
 
 #[event_cpi]
 #[derive(Accounts)]
 #[instruction(basket_id: u64)]
 pub struct CreateBasket<'info> {
+    /// Recorded as the basket owner. Anyone may create a basket while
+    /// `config.permissionless_creation` is set; otherwise only the admin.
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub creator: Signer<'info>,
 
     #[account(
+        mut,
         seeds = [CONFIG_SEED],
         bump = config.bump,
-        has_one = admin @ BasketError::Unauthorized,
+        constraint = config.permissionless_creation || creator.key() == config.admin
+            @ BasketError::Unauthorized,
+        constraint = basket_id == config.next_basket_id @ BasketError::BasketIdMismatch,
     )]
     pub config: Box<Account<'info, Config>>,
 
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = 8 + BasketRegistry::INIT_SPACE,
+        seeds = [BASKET_REGISTRY_SEED, config.registry_page.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub basket_registry: Box<Account<'info, BasketRegistry>>,
+
     #[account(
         init,
-        payer = admin,
+        payer = creator,
         space = 8 + std::mem::size_of::<Basket>(),
         seeds = [BASKET_SEED, basket_id.to_le_bytes().as_ref()],
         bump,
     )]
     pub basket: AccountLoader<'info, Basket>,
 
+    #[account(
+        init,
+        payer = creator,
+        space = 8 + std::mem::size_of::<BasketPrice>(),
+        seeds = [BASKET_PRICE_SEED, basket.key().as_ref()],
+        bump,
+    )]
+    pub basket_price: AccountLoader<'info, BasketPrice>,
+
     /// Vault authority PDA — owns all vault token accounts for this basket.
     /// CHECK: Validated by seeds constraint.
     #[account(
@@ -42,15 +85,36 @@ pub struct CreateBasket<'info> {
     )]
     pub mint_authority: UncheckedAccount<'info>,
 
-    /// The QSHARE SPL mint, created here under program control.
+    /// The QSHARE mint, created in the handler so Token-2022 extensions can
+    /// be enabled per basket.
+    #[account(mut)]
+    pub share_mint: Signer<'info>,
+
+    /// Metaplex metadata PDA — required for legacy SPL share mints, unused
+    /// for Token-2022.
+    /// CHECK: Validated by seeds constraint; created by the Metaplex CPI.
     #[account(
-        init,
-        payer = admin,
-        mint::decimals = QSHARE_DECIMALS,
-        mint::authority = mint_authority,
-        mint::token_program = token_program,
+        mut,
+        seeds = [
+            METADATA_SEED,
+            TOKEN_METADATA_PROGRAM_ID.as_ref(),
+            share_mint.key().as_ref(),
+        ],
+        bump,
+        seeds::program = TOKEN_METADATA_PROGRAM_ID,
     )]
-    pub share_mint: Box<InterfaceAccount<'info, Mint>>,
+    pub share_metadata: Option<UncheckedAccount<'info>>,
+
+    /// Required with `share_metadata`.
+    /// CHECK: Validated by address constraint.
+    #[account(address = TOKEN_METADATA_PROGRAM_ID)]
+    pub token_metadata_program: Option<UncheckedAccount<'info>>,
+
+    /// Receives the creation fee; required only when a non-admin creator
+    /// owes one.
+    /// CHECK: Validated by address constraint.
+    #[account(mut, address = config.treasury @ BasketError::InvalidTreasury)]
+    pub treasury: Option<UncheckedAccount<'info>>,
 
     pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
@@ -58,10 +122,13 @@ pub struct CreateBasket<'info> {
 
 impl<'info> CreateBasket<'info> {
     pub fn handler(
-        ctx: Context<CreateBasket>,
+        mut ctx: Context<CreateBasket>,
         basket_id: u64,
         name: &[u8; MAX_NAME_LEN],
         fee_bps_override: Option<u16>,
+        share_transfer_hook: bool,
+        symbol: String,
+        uri: String,
     ) -> Result<()> {
         if let Some(bps) = fee_bps_override {
             require!(
@@ -70,26 +137,266 @@ impl<'info> CreateBasket<'info> {
             );
         }
 
+        let share_metadata = ShareMetadata::from_basket_name(name, symbol, uri)?;
+        let basket_id_bytes = basket_id.to_le_bytes();
+        let mint_auth_seeds: &[&[u8]] = &[
+            MINT_AUTHORITY_SEED,
+            basket_id_bytes.as_ref(),
+            &[ctx.bumps.mint_authority],
+        ];
+
+        Self::create_share_mint(&ctx, share_transfer_hook, &share_metadata)?;
+        Self::init_share_metadata(&ctx, &share_metadata, mint_auth_seeds)?;
+        let (creation_fee_lamports, bond_lamports) = Self::collect_creation_payment(&ctx)?;
+
         let mut basket = ctx.accounts.basket.load_init()?;
-        basket.owner = ctx.accounts.admin.key();
+        basket.owner = ctx.accounts.creator.key();
         basket.share_mint = ctx.accounts.share_mint.key();
         basket.vault_authority = ctx.accounts.vault_authority.key();
         basket.basket_id = basket_id;
         basket.name = *name;
-        basket.fee_bps_override = fee_bps_override.unwrap_or(0);
-        basket.has_fee_override = u8::from(fee_bps_override.is_some());
+        basket.set_fee_override(fee_bps_override);
         basket.token_count = 0;
         basket.version = CURRENT_VERSION;
         basket.basket_bump = ctx.bumps.basket;
         basket.vault_authority_bump = ctx.bumps.vault_authority;
         basket.mint_authority_bump = ctx.bumps.mint_authority;
+        basket.mint_pricing_mode = PRICING_MODE_SPOT;
+        basket.redeem_pricing_mode = PRICING_MODE_SPOT;
+        basket.bond_lamports = bond_lamports;
+        drop(basket);
+
+        let mut basket_price = ctx.accounts.basket_price.load_init()?;
+        basket_price.basket = ctx.accounts.basket.key();
+        basket_price.nav_decimals = NAV_DECIMALS;
+        basket_price.version = CURRENT_VERSION;
+        basket_price.bump = ctx.bumps.basket_price;
+        basket_price.publish(&pricing::basket_nav(&[], 0)?, &Clock::get()?);
+        drop(basket_price);
+
+        Self::register_basket(&mut ctx)?;
 
         emit_cpi!(BasketCreated {
             basket_id,
-            owner: ctx.accounts.admin.key(),
+            owner: ctx.accounts.creator.key(),
             share_mint: ctx.accounts.share_mint.key(),
+            share_transfer_hook,
+            creation_fee_lamports,
+            bond_lamports,
         });
 
         Ok(())
     }
+
+    /// Append the basket to the current registry page, moving on to a new
+    /// page once it fills, and advance the basket id counter.
+    fn register_basket(ctx: &mut Context<CreateBasket>) -> Result<()> {
+        let basket = ctx.accounts.basket.key();
+        let config = &mut ctx.accounts.config;
+        let registry = &mut ctx.accounts.basket_registry;
+
+        registry.page = config.registry_page;
+        registry.bump = ctx.bumps.basket_registry;
+        registry.push(basket);
+        if registry.is_full() {
+            config.registry_page = config
+                .registry_page
+                .checked_add(1)
+                .ok_or(BasketError::ArithmeticOverflow)?;
+        }
+        config.next_basket_id = config
+            .next_basket_id
+            .checked_add(1)
+            .ok_or(BasketError::ArithmeticOverflow)?;
+
+        Ok(())
+    }
+
+    /// Charge a non-admin creator the configured creation fee (to the
+    /// treasury) and bond (onto the basket account). The admin pays neither.
+    fn collect_creation_payment(ctx: &Context<CreateBasket>) -> Result<(u64, u64)> {
+        let accounts = &ctx.accounts;
+        if accounts.creator.key() == accounts.config.admin {
+            return Ok((0, 0));
+        }
+
+        let system_program = accounts.system_program.to_account_info();
+        let creator = accounts.creator.to_account_info();
+
+        let fee = accounts.config.creation_fee_lamports;
+        if fee > 0 {
+            let treasury = accounts
+                .treasury
+                .as_ref()
+                .ok_or(BasketError::InvalidTreasury)?;
+            system_program::transfer(
+                CpiContext::new(
+                    system_program.clone(),
+                    Transfer {
+                        from: creator.clone(),
+                        to: treasury.to_account_info(),
+                    },
+                ),
+                fee,
+            )?;
+        }
+
+        let bond = accounts.config.creation_bond_lamports;
+        if bond > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    system_program,
+                    Transfer {
+                        from: creator,
+                        to: accounts.basket.to_account_info(),
+                    },
+                ),
+                bond,
+            )?;
+        }
+
+        Ok((fee, bond))
+    }
+
+    /// Allocate and initialise the QSHARE mint. With `share_transfer_hook`
+    /// the mint must be Token-2022 and routes every transfer through the
+    /// share-hook program's allow-list check. Token-2022 mints always carry
+    /// a metadata pointer to themselves.
+    fn create_share_mint(
+        ctx: &Context<CreateBasket>,
+        share_transfer_hook: bool,
+        share_metadata: &ShareMetadata,
+    ) -> Result<()> {
+        let accounts = &ctx.accounts;
+        let token_program = accounts.token_program.to_account_info();
+        let share_mint = accounts.share_mint.to_account_info();
+        let is_token_2022 = accounts.token_program.key() == Token2022::id();
+
+        let mut extensions = Vec::new();
+        if share_transfer_hook {
+            require!(is_token_2022, BasketError::TransferHookRequiresToken2022);
+            extensions.push(ExtensionType::TransferHook);
+        }
+        if is_token_2022 {
+            extensions.push(ExtensionType::MetadataPointer);
+        }
+        let space = ExtensionType::try_calculate_account_len::<MintState>(&extensions)?;
+
+        // The metadata extension is appended (with a realloc) after the mint
+        // is initialised, so fund it upfront.
+        let metadata_space = if is_token_2022 {
+            Self::token_metadata(ctx, share_metadata).tlv_size_of()?
+        } else {
+            0
+        };
+
+        system_program::create_account(
+            CpiContext::new(
+                accounts.system_program.to_account_info(),
+                CreateAccount {
+                    from: accounts.creator.to_account_info(),
+                    to: share_mint.clone(),
+                },
+            ),
+            Rent::get()?.minimum_balance(space + metadata_space),
+            space as u64,
+            token_program.key,
+        )?;
+
+        if share_transfer_hook {
+            transfer_hook_initialize(
+                CpiContext::new(
+                    token_program.clone(),
+                    TransferHookInitialize {
+                        token_program_id: token_program.clone(),
+                        mint: share_mint.clone(),
+                    },
+                ),
+                Some(accounts.mint_authority.key()),
+                Some(SHARE_HOOK_PROGRAM_ID),
+            )?;
+        }
+
+        if is_token_2022 {
+            metadata_pointer_initialize(
+                CpiContext::new(
+                    token_program.clone(),
+                    MetadataPointerInitialize {
+                        token_program_id: token_program.clone(),
+                        mint: share_mint.clone(),
+                    },
+                ),
+                Some(accounts.mint_authority.key()),
+                Some(share_mint.key()),
+            )?;
+        }
+
+        token_interface::initialize_mint2(
+            CpiContext::new(token_program, InitializeMint2 { mint: share_mint }),
+            QSHARE_DECIMALS,
+            &accounts.mint_authority.key(),
+            None,
+        )
+    }
+
+    /// Publish name, symbol and URI: in-mint metadata for Token-2022, a
+    /// Metaplex metadata account for legacy SPL mints.
+    fn init_share_metadata(
+        ctx: &Context<CreateBasket>,
+        share_metadata: &ShareMetadata,
+        mint_auth_seeds: &[&[u8]],
+    ) -> Result<()> {
+        let accounts = &ctx.accounts;
+
+        if accounts.token_program.key() == Token2022::id() {
+            let share_mint = accounts.share_mint.to_account_info();
+            let mint_authority = accounts.mint_authority.to_account_info();
+            return token_metadata_initialize(
+                CpiContext::new_with_signer(
+                    accounts.token_program.to_account_info(),
+                    TokenMetadataInitialize {
+                        program_id: accounts.token_program.to_account_info(),
+                        metadata: share_mint.clone(),
+                        update_authority: mint_authority.clone(),
+                        mint_authority,
+                        mint: share_mint,
+                    },
+                    &[mint_auth_seeds],
+                ),
+                share_metadata.name.clone(),
+                share_metadata.symbol.clone(),
+                share_metadata.uri.clone(),
+            );
+        }
+
+        let (Some(metadata_account), Some(token_metadata_program)) =
+            (&accounts.share_metadata, &accounts.token_metadata_program)
+        else {
+            return err!(BasketError::MissingMetadataAccounts);
+        };
+
+        metadata::create_metaplex_metadata(
+            &MetaplexAccounts {
+                metadata: &metadata_account.to_account_info(),
+                mint: &accounts.share_mint.to_account_info(),
+                mint_authority: &accounts.mint_authority.to_account_info(),
+                payer: &accounts.creator.to_account_info(),
+                system_program: &accounts.system_program.to_account_info(),
+                token_metadata_program: &token_metadata_program.to_account_info(),
+            },
+            share_metadata,
+            mint_auth_seeds,
+        )
+    }
+
+    fn token_metadata(ctx: &Context<CreateBasket>, share_metadata: &ShareMetadata) -> TokenMetadata {
+        TokenMetadata {
+            update_authority: OptionalNonZeroPubkey(ctx.accounts.mint_authority.key()),
+            mint: ctx.accounts.share_mint.key(),
+            name: share_metadata.name.clone(),
+            symbol: share_metadata.symbol.clone(),
+            uri: share_metadata.uri.clone(),
+            additional_metadata: Vec::new(),
+        }
+    }
 }
@@ -3,8 +3,17 @@ use anchor_spl::{
     associated_token::AssociatedToken,
     token_interface::{self, Mint, MintTo, TokenAccount, TokenInterface, TransferChecked},
 };
-
-use crate::{constants::*, error::BasketError, events::*, state::*};
+use solana_sdk_ids::sysvar::instructions as instructions_sysvar;
+
+use crate::{
+    compliance::{ComplianceAccounts, ComplianceProof},
+    constants::*,
+    error::BasketError,
+    events::*,
+    legs::{self, LegAccounts, UserAtaOwner},
+    pricing::{self, DepositQuote, LegState},
+    state::*,
+};
 
 #[event_cpi]
 #[derive(Accounts)]
@@ -12,6 +21,10 @@ pub struct DepositMulti<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
 
+    /// Owner of the minted shares; may differ from the depositing signer.
+    /// CHECK: Any account may receive shares; bound via the ATA constraint.
+    pub receiver: UncheckedAccount<'info>,
+
     #[account(
         seeds = [CONFIG_SEED],
         bump = config.bump,
@@ -20,27 +33,57 @@ pub struct DepositMulti<'info> {
 
     pub basket: AccountLoader<'info, Basket>,
 
+    #[account(
+        mut,
+        seeds = [BASKET_PRICE_SEED, basket.key().as_ref()],
+        bump = basket_price.load()?.bump,
+    )]
+    pub basket_price: AccountLoader<'info, BasketPrice>,
+
     /// Mint authority PDA — signs the QSHARE mint_to CPI.
     /// CHECK: Validated via `validate_mint_authority`.
     pub mint_authority: UncheckedAccount<'info>,
 
-    #[account(mut)]
+    #[account(
+        mut,
+        mint::token_program = share_token_program,
+    )]
     pub share_mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(
         init_if_needed,
         payer = user,
         associated_token::mint = share_mint,
-        associated_token::authority = user,
-        associated_token::token_program = token_program,
+        associated_token::authority = receiver,
+        associated_token::token_program = share_token_program,
     )]
-    pub user_share_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub receiver_share_ata: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    /// Optional compliance allow-list entry.
-    /// Must be provided when `config.compliance_enabled` is true.
+    /// Optional compliance allow-list entry for the receiver. When
+    /// `config.compliance_enabled` is true, either this, `compliance_root`
+    /// with a proof, or an Ed25519 attestation must be provided.
     pub user_allow_list: Option<Box<Account<'info, UserAllowList>>>,
 
+    /// Basket Merkle root the `compliance_proof` argument is checked against.
+    #[account(
+        seeds = [COMPLIANCE_ROOT_SEED, basket.key().as_ref()],
+        bump = compliance_root.bump,
+    )]
+    pub compliance_root: Option<Box<Account<'info, ComplianceRoot>>>,
+
+    /// Read to find the Ed25519 attestation preceding this instruction.
+    /// CHECK: Address-constrained to the instructions sysvar.
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+
+    /// `AttestationNonce` PDA created to spend the attestation's nonce.
+    /// CHECK: Derivation and emptiness validated in `check_attestation`.
+    #[account(mut)]
+    pub attestation_nonce: Option<UncheckedAccount<'info>>,
+
     pub token_program: Interface<'info, TokenInterface>,
+    /// Token program owning the QSHARE mint (SPL Token or Token-2022).
+    pub share_token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
@@ -58,12 +101,36 @@ impl<'info> DepositMulti<'info> {
     pub fn handler(
         ctx: Context<'_, '_, 'info, 'info, DepositMulti<'info>>,
         amounts: Vec<u64>,
+        compliance_proof: Option<ComplianceProof>,
+    ) -> Result<()> {
+        Self::execute(ctx, amounts.len(), compliance_proof, |legs, total_supply, fee_bps, mode| {
+            pricing::quote_deposit(&amounts, legs, total_supply, fee_bps, mode)
+        })
+    }
+
+    /// ERC-4626 `mint`: deposit whatever each leg requires for exactly
+    /// `shares`, bounded per leg by `max_amounts_in`.
+    pub fn mint_exact_shares_handler(
+        ctx: Context<'_, '_, 'info, 'info, DepositMulti<'info>>,
+        shares: u64,
+        max_amounts_in: Vec<u64>,
+        compliance_proof: Option<ComplianceProof>,
+    ) -> Result<()> {
+        Self::execute(ctx, max_amounts_in.len(), compliance_proof, |legs, total_supply, fee_bps, mode| {
+            pricing::quote_mint_exact(shares, &max_amounts_in, legs, total_supply, fee_bps, mode)
+        })
+    }
+
+    fn execute(
+        ctx: Context<'_, '_, 'info, 'info, DepositMulti<'info>>,
+        num_tokens: usize,
+        compliance_proof: Option<ComplianceProof>,
+        quote: impl FnOnce(&[LegState], u64, u16, u8) -> Result<DepositQuote>,
     ) -> Result<()> {
         let accounts = &ctx.accounts;
         let remaining = ctx.remaining_accounts;
-        let num_tokens = amounts.len();
 
-        let basket = accounts.basket.load()?;
+        let basket = accounts.basket.load_current()?;
 
         // Fix #7: Enforce that deposit covers ALL basket tokens
         Self::validate_remaining_layout(remaining.len(), num_tokens, basket.token_count)?;
@@ -76,78 +143,102 @@ impl<'info> DepositMulti<'info> {
             &[basket.mint_authority_bump],
         ];
         Self::validate_mint_authority(accounts, mint_auth_seeds, ctx.program_id)?;
-        Self::check_compliance(accounts)?;
+        let clock = Clock::get()?;
+        Self::compliance_accounts(accounts).check(
+            compliance_proof.as_ref(),
+            clock.unix_timestamp,
+            ctx.program_id,
+        )?;
 
         let fee_bps = basket.effective_fee_bps(accounts.config.fee_bps);
+        let mint_pricing_mode = basket.mint_pricing_mode;
         drop(basket);
-        // Fix #2: Pre-read all vault balances BEFORE any transfers
-        let total_supply = accounts.share_mint.supply;
-        let mut pre_vault_balances: Vec<u64> = Vec::with_capacity(num_tokens);
-        for i in 0..num_tokens {
-            let base = i * DEPOSIT_ACCOUNTS_PER_TOKEN;
-            let vault_ata_info = &remaining[base + 3];
-            let balance = Self::read_vault_balance(vault_ata_info)?;
-            pre_vault_balances.push(balance);
-        }
-
-        // Compute total vault value (sum of all vault balances, normalised)
-        let mut total_vault_value: u128 = 0;
-        for i in 0..num_tokens {
-            let base = i * DEPOSIT_ACCOUNTS_PER_TOKEN;
-            let basket_token: Account<BasketToken> = Account::try_from(&remaining[base])?;
-            total_vault_value = total_vault_value
-                .checked_add(Self::normalise_amount(pre_vault_balances[i], basket_token.decimals)?)
-                .ok_or(BasketError::ArithmeticOverflow)?;
-        }
 
-        let mut total_shares: u64 = 0;
+        let mut legs = (0..num_tokens)
+            .map(|i| Self::parse_and_validate_leg(accounts, remaining, i))
+            .collect::<Result<Vec<_>>>()?;
+        legs::require_distinct_legs(
+            &legs
+                .iter()
+                .map(|leg| leg.basket_token.key())
+                .collect::<Vec<_>>(),
+        )?;
 
-        for i in 0..num_tokens {
-            let leg = Self::parse_and_validate_leg(
-                remaining,
-                i,
-                accounts.basket.key(),
-            )?;
-
-            let amount = amounts[i];
-            require!(amount > 0, BasketError::ZeroDeposit);
-
-            let (net_amount, fee_amount) = Self::compute_fee(amount, fee_bps)?;
+        // Fix #2: Pre-read all vault balances BEFORE any transfers
+        let leg_states = legs
+            .iter_mut()
+            .map(|leg| {
+                let vault_balance = pricing::read_vault_balance(leg.vault_ata_info)?;
+                Ok(pricing::observe_leg(
+                    &mut leg.basket_token,
+                    vault_balance,
+                    clock.unix_timestamp,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let quote = quote(
+            &leg_states,
+            accounts.share_mint.supply,
+            fee_bps,
+            mint_pricing_mode,
+        )?;
+        let total_shares = quote.shares;
+
+        let post_states = leg_states
+            .iter()
+            .zip(&quote.legs)
+            .map(|(leg, leg_quote)| {
+                Ok(LegState {
+                    vault_balance: leg
+                        .vault_balance
+                        .checked_add(leg_quote.net_amount)
+                        .ok_or(BasketError::ArithmeticOverflow)?,
+                    ..*leg
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let receiver_shares = accounts
+            .receiver_share_ata
+            .amount
+            .checked_add(total_shares)
+            .ok_or(BasketError::ArithmeticOverflow)?;
+        accounts
+            .basket
+            .load_current()?
+            .check_deposit_caps(pricing::total_value(&post_states)?, receiver_shares)?;
 
-            Self::transfer_to_vault(accounts, &leg, net_amount)?;
+        for (leg, leg_quote) in legs.iter().zip(&quote.legs) {
+            Self::transfer_to_vault(accounts, leg, leg_quote.net_amount)?;
 
-            if fee_amount > 0 {
-                Self::transfer_to_fee_vault(accounts, &leg, fee_amount)?;
+            if leg_quote.fee_amount > 0 {
+                Self::transfer_to_fee_vault(accounts, leg, leg_quote.fee_amount)?;
             }
-
-            // Fix #2: Vault-weighted share pricing
-            let normalised_deposit = Self::normalise_amount(net_amount, leg.basket_token.decimals)?;
-
-            let shares_for_leg = if total_supply == 0 {
-                // First depositor: shares = normalised deposit value
-                normalised_deposit as u64
-            } else {
-                // Subsequent: shares = (deposit_value / total_vault_value) * total_supply
-                normalised_deposit
-                    .checked_mul(total_supply as u128)
-                    .ok_or(BasketError::ArithmeticOverflow)?
-                    .checked_div(total_vault_value)
-                    .ok_or(BasketError::ArithmeticOverflow)? as u64
-            };
-
-            total_shares = total_shares
-                .checked_add(shares_for_leg)
-                .ok_or(BasketError::ArithmeticOverflow)?;
         }
 
-        // Fix #6: Reject deposits that produce zero shares
-        require!(total_shares > 0, BasketError::ZeroSharesMinted);
-
         Self::mint_shares(accounts, mint_auth_seeds, total_shares)?;
 
+        let total_supply = accounts
+            .share_mint
+            .supply
+            .checked_add(total_shares)
+            .ok_or(BasketError::ArithmeticOverflow)?;
+        for (leg, post_state) in legs.iter_mut().zip(&post_states) {
+            pricing::record_leg_price(
+                &mut leg.basket_token,
+                post_state,
+                total_supply,
+                ctx.program_id,
+            )?;
+        }
+        accounts
+            .basket_price
+            .load_mut()?
+            .publish(&pricing::basket_nav(&post_states, total_supply)?, &clock);
+
         emit_cpi!(DepositCompleted {
             basket: accounts.basket.key(),
             user: accounts.user.key(),
+            receiver: accounts.receiver.key(),
             shares_minted: total_shares,
         });
 
@@ -201,108 +292,62 @@ impl<'info> DepositMulti<'info> {
         Ok(())
     }
 
-    fn check_compliance(accounts: &DepositMulti<'info>) -> Result<()> {
-        if !accounts.config.compliance_enabled {
-            return Ok(());
+    /// Every compliance route must admit the receiver, not the signer.
+    fn compliance_accounts<'a>(accounts: &'a DepositMulti<'info>) -> ComplianceAccounts<'a, 'info> {
+        ComplianceAccounts {
+            config: &accounts.config,
+            basket: accounts.basket.key(),
+            user: accounts.receiver.key(),
+            payer: accounts.user.to_account_info(),
+            user_allow_list: accounts.user_allow_list.as_deref().map(|entry| &**entry),
+            compliance_root: accounts.compliance_root.as_deref().map(|root| &**root),
+            instructions_sysvar: accounts
+                .instructions_sysvar
+                .as_ref()
+                .map(|sysvar| sysvar.to_account_info()),
+            attestation_nonce: accounts
+                .attestation_nonce
+                .as_ref()
+                .map(|nonce| nonce.to_account_info()),
+            system_program: accounts.system_program.to_account_info(),
         }
-        let allow_list = accounts
-            .user_allow_list
-            .as_ref()
-            .ok_or(BasketError::ComplianceDenied)?;
-        require!(allow_list.allowed, BasketError::ComplianceDenied);
-        require!(
-            allow_list.basket == accounts.basket.key(),
-            BasketError::ComplianceDenied
-        );
-        require!(
-            allow_list.user == accounts.user.key(),
-            BasketError::ComplianceDenied
-        );
-        Ok(())
     }
 
     // -- Per-leg parsing ------------------------------------------------------
 
     fn parse_and_validate_leg(
+        accounts: &DepositMulti<'info>,
         remaining: &'info [AccountInfo<'info>],
         index: usize,
-        basket_key: Pubkey,
     ) -> Result<TokenLeg<'info>> {
         let base = index * DEPOSIT_ACCOUNTS_PER_TOKEN;
-        let basket_token: Account<BasketToken> = Account::try_from(&remaining[base])?;
+        let leg = legs::validate_leg(
+            LegAccounts {
+                basket_token: &remaining[base],
+                mint: &remaining[base + 1],
+                user_ata: &remaining[base + 2],
+                vault_ata: &remaining[base + 3],
+            },
+            accounts.basket.key(),
+            UserAtaOwner::Signer(accounts.user.key()),
+            &accounts.token_program.key(),
+        )?;
 
-        require!(
-            basket_token.basket == basket_key,
-            BasketError::InvalidBasketWiring
-        );
-        require!(basket_token.enabled, BasketError::TokenNotEnabled);
-
-        let mint_info = &remaining[base + 1];
-        require!(
-            mint_info.key() == basket_token.mint,
-            BasketError::MintConfigMismatch
-        );
-
-        let vault_ata_info = &remaining[base + 3];
         let fee_vault_info = &remaining[base + 4];
         require!(
-            vault_ata_info.key() == basket_token.vault_ata,
-            BasketError::InvalidBasketWiring
-        );
-        require!(
-            fee_vault_info.key() == basket_token.fee_vault_ata,
+            fee_vault_info.key() == leg.basket_token.fee_vault_ata,
             BasketError::InvalidBasketWiring
         );
 
         Ok(TokenLeg {
-            basket_token,
-            mint_info,
-            user_ata_info: &remaining[base + 2],
-            vault_ata_info,
+            basket_token: leg.basket_token,
+            mint_info: leg.mint_info,
+            user_ata_info: leg.user_ata_info,
+            vault_ata_info: leg.vault_ata_info,
             fee_vault_info,
         })
     }
 
-    fn compute_fee(amount: u64, fee_bps: u16) -> Result<(u64, u64)> {
-        let fee = (amount as u128)
-            .checked_mul(fee_bps as u128)
-            .ok_or(BasketError::ArithmeticOverflow)?
-            .checked_div(BPS_DENOMINATOR as u128)
-            .ok_or(BasketError::ArithmeticOverflow)? as u64;
-        let net = amount
-            .checked_sub(fee)
-            .ok_or(BasketError::ArithmeticOverflow)?;
-        Ok((net, fee))
-    }
-
-    /// Normalise a token amount to QSHARE decimal precision (u128 intermediate).
-    fn normalise_amount(amount: u64, token_decimals: u8) -> Result<u128> {
-        let amount_128 = amount as u128;
-        if token_decimals >= QSHARE_DECIMALS {
-            let divisor = 10u128
-                .checked_pow((token_decimals - QSHARE_DECIMALS) as u32)
-                .ok_or(BasketError::ArithmeticOverflow)?;
-            amount_128
-                .checked_div(divisor)
-                .ok_or(BasketError::ArithmeticOverflow.into())
-        } else {
-            let multiplier = 10u128
-                .checked_pow((QSHARE_DECIMALS - token_decimals) as u32)
-                .ok_or(BasketError::ArithmeticOverflow)?;
-            amount_128
-                .checked_mul(multiplier)
-                .ok_or(BasketError::ArithmeticOverflow.into())
-        }
-    }
-
-    /// Read vault balance using proper TokenAccount deserialization.
-    fn read_vault_balance<'a>(vault_ata_info: &'a AccountInfo<'a>) -> Result<u64> {
-        let vault_ata: InterfaceAccount<TokenAccount> =
-            InterfaceAccount::try_from(vault_ata_info)
-                .map_err(|_| BasketError::InvalidBasketWiring)?;
-        Ok(vault_ata.amount)
-    }
-
     fn transfer_to_vault(
         accounts: &DepositMulti<'info>,
         leg: &TokenLeg<'info>,
@@ -350,10 +395,10 @@ impl<'info> DepositMulti<'info> {
     ) -> Result<()> {
         token_interface::mint_to(
             CpiContext::new_with_signer(
-                accounts.token_program.to_account_info(),
+                accounts.share_token_program.to_account_info(),
                 MintTo {
                     mint: accounts.share_mint.to_account_info(),
-                    to: accounts.user_share_ata.to_account_info(),
+                    to: accounts.receiver_share_ata.to_account_info(),
                     authority: accounts.mint_authority.to_account_info(),
                 },
                 &[mint_auth_seeds],
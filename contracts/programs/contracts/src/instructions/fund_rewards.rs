@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::{constants::*, error::BasketError, events::*, state::*};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct FundRewards<'info> {
+    /// Basket owner or protocol admin.
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        constraint = basket.load_current()?.is_manager(&authority.key(), &config.admin)
+            @ BasketError::Unauthorized,
+    )]
+    pub basket: AccountLoader<'info, Basket>,
+
+    #[account(
+        mut,
+        has_one = basket @ BasketError::InvalidBasketWiring,
+        has_one = reward_mint @ BasketError::InvalidBasketWiring,
+        has_one = reward_vault @ BasketError::InvalidBasketWiring,
+        seeds = [REWARD_POOL_SEED, basket.key().as_ref(), reward_mint.key().as_ref()],
+        bump = reward_pool.bump,
+    )]
+    pub reward_pool: Box<Account<'info, RewardPool>>,
+
+    #[account(mint::token_program = reward_token_program)]
+    pub reward_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = reward_mint,
+        token::authority = authority,
+        token::token_program = reward_token_program,
+    )]
+    pub funder_reward_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub reward_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub reward_token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> FundRewards<'info> {
+    /// Stream `amount` plus anything still undistributed evenly over the
+    /// next `duration_secs`. Only the amount that actually lands in the
+    /// vault is credited, so fee-on-transfer mints cannot over-promise.
+    pub fn handler(ctx: Context<FundRewards>, amount: u64, duration_secs: i64) -> Result<()> {
+        require!(
+            amount > 0 && duration_secs > 0,
+            BasketError::InvalidRewardFunding
+        );
+
+        let accounts = &mut *ctx.accounts;
+        let balance_before = accounts.reward_vault.amount;
+        token_interface::transfer_checked(
+            CpiContext::new(
+                accounts.reward_token_program.to_account_info(),
+                TransferChecked {
+                    from: accounts.funder_reward_ata.to_account_info(),
+                    mint: accounts.reward_mint.to_account_info(),
+                    to: accounts.reward_vault.to_account_info(),
+                    authority: accounts.authority.to_account_info(),
+                },
+            ),
+            amount,
+            accounts.reward_mint.decimals,
+        )?;
+        accounts.reward_vault.reload()?;
+        let received = accounts
+            .reward_vault
+            .amount
+            .checked_sub(balance_before)
+            .ok_or(BasketError::ArithmeticOverflow)?;
+
+        let now = Clock::get()?.unix_timestamp;
+        let pool = &mut accounts.reward_pool;
+        pool.accrue(now)?;
+        let undistributed = if now < pool.period_end {
+            (pool.reward_rate as u128)
+                .checked_mul((pool.period_end - now) as u128)
+                .ok_or(BasketError::ArithmeticOverflow)?
+        } else {
+            0
+        };
+        let reward_rate: u64 = (undistributed
+            .checked_add(received as u128)
+            .ok_or(BasketError::ArithmeticOverflow)?
+            / duration_secs as u128)
+            .try_into()
+            .map_err(|_| BasketError::ArithmeticOverflow)?;
+        require!(reward_rate > 0, BasketError::InvalidRewardFunding);
+
+        let period_end = now
+            .checked_add(duration_secs)
+            .ok_or(BasketError::ArithmeticOverflow)?;
+        pool.reward_rate = reward_rate;
+        pool.period_end = period_end;
+        pool.last_update_ts = now;
+
+        emit_cpi!(RewardsFunded {
+            pool: ctx.accounts.reward_pool.key(),
+            amount: received,
+            reward_rate,
+            period_end,
+        });
+
+        Ok(())
+    }
+}
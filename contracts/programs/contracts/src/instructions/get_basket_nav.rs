@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::{
+    constants::*,
+    error::BasketError,
+    pricing::{self, BasketNav},
+    state::*,
+};
+
+#[derive(Accounts)]
+pub struct GetBasketNav<'info> {
+    #[account(
+        constraint = basket.load_current()?.share_mint == share_mint.key()
+            @ BasketError::ShareMintMismatch,
+    )]
+    pub basket: AccountLoader<'info, Basket>,
+
+    /// The QSHARE mint for this basket.
+    pub share_mint: Box<InterfaceAccount<'info, Mint>>,
+}
+
+impl<'info> GetBasketNav<'info> {
+    /// Price QSHARE from the vault balances of every leg. Returned via return
+    /// data so lending protocols can CPI in rather than re-derive the NAV.
+    pub fn handler(ctx: Context<'_, '_, 'info, 'info, GetBasketNav<'info>>) -> Result<BasketNav> {
+        let accounts = &ctx.accounts;
+        let basket = accounts.basket.load_current()?;
+
+        require!(
+            basket.version == CURRENT_VERSION,
+            BasketError::UnsupportedVersion
+        );
+
+        let leg_states = pricing::load_leg_states(
+            ctx.remaining_accounts,
+            accounts.basket.key(),
+            basket.token_count,
+        )?;
+
+        pricing::basket_nav(&leg_states, accounts.share_mint.supply)
+    }
+}
@@ -39,6 +39,13 @@ impl<'info> InitConfig<'info> {
             compliance_enabled,
             version: CURRENT_VERSION,
             bump: ctx.bumps.config,
+            mint_extension_policy: 0,
+            permissionless_creation: false,
+            creation_fee_lamports: 0,
+            creation_bond_lamports: 0,
+            treasury: ctx.accounts.admin.key(),
+            next_basket_id: FIRST_BASKET_ID,
+            registry_page: 0,
         });
 
         emit_cpi!(ConfigInitialized {
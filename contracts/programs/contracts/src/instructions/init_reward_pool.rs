@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{constants::*, error::BasketError, events::*, state::*};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitRewardPool<'info> {
+    /// Basket owner or protocol admin.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        constraint = basket.load_current()?.is_manager(&authority.key(), &config.admin)
+            @ BasketError::Unauthorized,
+    )]
+    pub basket: AccountLoader<'info, Basket>,
+
+    #[account(mint::token_program = reward_token_program)]
+    pub reward_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + RewardPool::INIT_SPACE,
+        seeds = [REWARD_POOL_SEED, basket.key().as_ref(), reward_mint.key().as_ref()],
+        bump,
+    )]
+    pub reward_pool: Box<Account<'info, RewardPool>>,
+
+    #[account(
+        init,
+        payer = authority,
+        associated_token::mint = reward_mint,
+        associated_token::authority = reward_pool,
+        associated_token::token_program = reward_token_program,
+    )]
+    pub reward_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub reward_token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitRewardPool<'info> {
+    /// Open a reward stream for `reward_mint`. `boosts` map stake
+    /// commitments to weight multipliers; uncommitted stakes weigh 1x.
+    pub fn handler(ctx: Context<InitRewardPool>, boosts: Vec<RewardBoost>) -> Result<()> {
+        Self::validate_boosts(&boosts)?;
+
+        let mut stored = [RewardBoost::default(); MAX_REWARD_BOOSTS];
+        stored[..boosts.len()].copy_from_slice(&boosts);
+        ctx.accounts.reward_pool.set_inner(RewardPool {
+            basket: ctx.accounts.basket.key(),
+            reward_mint: ctx.accounts.reward_mint.key(),
+            reward_vault: ctx.accounts.reward_vault.key(),
+            total_staked: 0,
+            total_weight: 0,
+            reward_rate: 0,
+            period_end: 0,
+            last_update_ts: Clock::get()?.unix_timestamp,
+            acc_reward_per_weight: 0,
+            boosts: stored,
+            boost_count: boosts.len() as u8,
+            bump: ctx.bumps.reward_pool,
+        });
+
+        emit_cpi!(RewardPoolCreated {
+            pool: ctx.accounts.reward_pool.key(),
+            basket: ctx.accounts.basket.key(),
+            reward_mint: ctx.accounts.reward_mint.key(),
+            boosts,
+        });
+
+        Ok(())
+    }
+
+    fn validate_boosts(boosts: &[RewardBoost]) -> Result<()> {
+        require!(
+            boosts.len() <= MAX_REWARD_BOOSTS,
+            BasketError::InvalidRewardBoosts
+        );
+
+        let mut previous_duration = 0;
+        let mut previous_multiplier = BPS_DENOMINATOR as u16;
+        for boost in boosts {
+            require!(
+                boost.min_duration_secs > previous_duration
+                    && boost.min_duration_secs <= MAX_LOCK_DURATION_SECS,
+                BasketError::InvalidRewardBoosts
+            );
+            require!(
+                boost.multiplier_bps >= previous_multiplier
+                    && boost.multiplier_bps <= REWARD_MULTIPLIER_BPS_MAX,
+                BasketError::InvalidRewardBoosts
+            );
+            previous_duration = boost.min_duration_secs;
+            previous_multiplier = boost.multiplier_bps;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,169 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_2022::spl_token_2022::onchain,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{constants::*, error::BasketError, events::*, state::*};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct LockShares<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub basket: AccountLoader<'info, Basket>,
+
+    #[account(
+        seeds = [LOCK_TIERS_SEED, basket.key().as_ref()],
+        bump = lock_tiers.bump,
+    )]
+    pub lock_tiers: Box<Account<'info, LockTiers>>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + LockPosition::INIT_SPACE,
+        seeds = [LOCK_POSITION_SEED, basket.key().as_ref(), owner.key().as_ref()],
+        bump,
+    )]
+    pub lock_position: Box<Account<'info, LockPosition>>,
+
+    #[account(
+        address = basket.load_current()?.share_mint @ BasketError::ShareMintMismatch,
+        mint::token_program = share_token_program,
+    )]
+    pub share_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = share_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = share_token_program,
+    )]
+    pub owner_share_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Vault authority PDA — owns the pooled lock escrow.
+    /// CHECK: Must match the basket's vault authority.
+    #[account(address = basket.load_current()?.vault_authority @ BasketError::VaultAuthMismatch)]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    /// Escrow for every position in the basket; balances are tracked per
+    /// `LockPosition`.
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = share_mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = share_token_program,
+    )]
+    pub lock_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Token program owning the QSHARE mint (SPL Token or Token-2022).
+    pub share_token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> LockShares<'info> {
+    /// Escrow `amount` QSHARE until `unlock_ts`. Topping up an open position
+    /// adds to it and may extend, but never shorten, the lock; it keeps the
+    /// higher of its current tier and penalty and those of the new duration,
+    /// so a top-up can't soften the exit terms of what is already locked.
+    /// Remaining accounts carry the share transfer-hook's extra accounts, if
+    /// the mint has one.
+    pub fn handler(
+        ctx: Context<'_, '_, 'info, 'info, LockShares<'info>>,
+        amount: u64,
+        unlock_ts: i64,
+    ) -> Result<()> {
+        require!(amount > 0, BasketError::ZeroLockAmount);
+
+        let now = Clock::get()?.unix_timestamp;
+        let duration = unlock_ts.saturating_sub(now);
+        require!(
+            duration <= MAX_LOCK_DURATION_SECS,
+            BasketError::InvalidLockDuration
+        );
+        let (tier, lock_tier) = ctx
+            .accounts
+            .lock_tiers
+            .tier_for(duration)
+            .ok_or(BasketError::InvalidLockDuration)?;
+
+        let accounts = &ctx.accounts;
+        transfer_shares(
+            &accounts.share_token_program,
+            accounts.owner_share_ata.to_account_info(),
+            &accounts.share_mint,
+            accounts.lock_vault.to_account_info(),
+            accounts.owner.to_account_info(),
+            ctx.remaining_accounts,
+            amount,
+            &[],
+        )?;
+
+        let position = &mut ctx.accounts.lock_position;
+        if position.amount == 0 {
+            position.basket = ctx.accounts.basket.key();
+            position.owner = ctx.accounts.owner.key();
+            position.locked_ts = now;
+            position.tier = tier;
+            position.early_exit_penalty_bps = lock_tier.early_exit_penalty_bps;
+            position.bump = ctx.bumps.lock_position;
+        } else {
+            require!(
+                unlock_ts >= position.unlock_ts,
+                BasketError::LockShortened
+            );
+            position.tier = position.tier.max(tier);
+            position.early_exit_penalty_bps = position
+                .early_exit_penalty_bps
+                .max(lock_tier.early_exit_penalty_bps);
+        }
+        position.amount = position
+            .amount
+            .checked_add(amount)
+            .ok_or(BasketError::ArithmeticOverflow)?;
+        position.unlock_ts = unlock_ts;
+
+        emit_cpi!(SharesLocked {
+            basket: ctx.accounts.basket.key(),
+            owner: ctx.accounts.owner.key(),
+            amount,
+            total_locked: ctx.accounts.lock_position.amount,
+            unlock_ts,
+            tier: ctx.accounts.lock_position.tier,
+        });
+
+        Ok(())
+    }
+}
+
+/// `transfer_checked` for QSHARE, resolving transfer-hook accounts from
+/// `hook_accounts` when the share mint has a hook.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn transfer_shares<'info>(
+    share_token_program: &Interface<'info, TokenInterface>,
+    from: AccountInfo<'info>,
+    share_mint: &InterfaceAccount<'info, Mint>,
+    to: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+    hook_accounts: &[AccountInfo<'info>],
+    amount: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    onchain::invoke_transfer_checked(
+        share_token_program.key,
+        from,
+        share_mint.to_account_info(),
+        to,
+        authority,
+        hook_accounts,
+        amount,
+        share_mint.decimals,
+        signer_seeds,
+    )
+    .map_err(Into::into)
+}
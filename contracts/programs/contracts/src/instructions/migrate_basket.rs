@@ -0,0 +1,228 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::{
+    constants::*,
+    error::BasketError,
+    events::*,
+    legs, pda,
+    pricing::{self, LegState},
+    state::*,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateBasket<'info> {
+    /// Pays the added rent and the registry page, if a new one is opened.
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = admin @ BasketError::Unauthorized,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + BasketRegistry::INIT_SPACE,
+        seeds = [BASKET_REGISTRY_SEED, config.registry_page.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub basket_registry: Box<Account<'info, BasketRegistry>>,
+
+    /// Version-1 basket; only loaded once grown.
+    #[account(mut)]
+    pub basket: AccountLoader<'info, Basket>,
+
+    /// Version-1 baskets predate price publishing.
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + std::mem::size_of::<BasketPrice>(),
+        seeds = [BASKET_PRICE_SEED, basket.key().as_ref()],
+        bump,
+    )]
+    pub basket_price: AccountLoader<'info, BasketPrice>,
+
+    /// The QSHARE mint for this basket; checked once the basket is grown.
+    pub share_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateBasket<'info> {
+    /// Grow a version-1 basket and every one of its `BasketToken` legs to
+    /// the current layout, filling in each appended field as the current
+    /// `create_basket` and `add_tokens` would, and publish the basket's
+    /// first `BasketPrice` so the pricing instructions that require it work
+    /// straight away. The basket is listed in the registry but keeps its
+    /// id. Run `migrate_config` first.
+    ///
+    /// Remaining accounts: `[BasketToken (mut), Mint, VaultATA]` for each of
+    /// the basket's `token_count` legs.
+    pub fn handler(mut ctx: Context<'_, '_, 'info, 'info, MigrateBasket<'info>>) -> Result<()> {
+        let accounts = &ctx.accounts;
+        let basket_key = accounts.basket.key();
+        let admin_info = accounts.admin.to_account_info();
+        let system_program_info = accounts.system_program.to_account_info();
+
+        let basket_info = accounts.basket.to_account_info();
+        pda::require_v1_layout(&basket_info, Basket::DISCRIMINATOR, Basket::V1_SIZE)?;
+        pda::grow_pda_account(
+            &admin_info,
+            &basket_info,
+            &system_program_info,
+            8 + std::mem::size_of::<Basket>(),
+        )?;
+
+        let mut basket = accounts.basket.load_current_mut()?;
+        require!(
+            basket.share_mint == accounts.share_mint.key(),
+            BasketError::ShareMintMismatch
+        );
+        basket.version = CURRENT_VERSION;
+        basket.mint_pricing_mode = PRICING_MODE_SPOT;
+        basket.redeem_pricing_mode = PRICING_MODE_SPOT;
+        // Version-1 baskets took deposits without caps.
+        basket.max_total_value = 0;
+        basket.max_user_shares = 0;
+        // Nor did they limit outflows; the admin may arm the limiter later.
+        basket.max_withdraw_bps = 0;
+        basket.emergency_mode = 0;
+        basket.withdraw_window_secs = 0;
+        basket.window_start_ts = 0;
+        basket.window_start_supply = 0;
+        basket.burned_in_window = 0;
+        basket.pending_owner = Pubkey::default();
+        // Admin-created, so nothing was escrowed.
+        basket.bond_lamports = 0;
+        let token_count = basket.token_count as usize;
+        drop(basket);
+
+        let expected = token_count
+            .checked_mul(MIGRATE_BASKET_ACCOUNTS_PER_TOKEN)
+            .ok_or(BasketError::ArithmeticOverflow)?;
+        require!(
+            ctx.remaining_accounts.len() == expected,
+            BasketError::InvalidRemainingAccounts
+        );
+        legs::require_distinct_legs(
+            &ctx.remaining_accounts
+                .chunks_exact(MIGRATE_BASKET_ACCOUNTS_PER_TOKEN)
+                .map(|leg| leg[0].key())
+                .collect::<Vec<_>>(),
+        )?;
+        let clock = Clock::get()?;
+        let total_supply = accounts.share_mint.supply;
+        let leg_states = ctx
+            .remaining_accounts
+            .chunks_exact(MIGRATE_BASKET_ACCOUNTS_PER_TOKEN)
+            .map(|leg| {
+                Self::migrate_leg(
+                    leg,
+                    basket_key,
+                    total_supply,
+                    clock.unix_timestamp,
+                    &admin_info,
+                    &system_program_info,
+                )
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let nav = pricing::basket_nav(&leg_states, total_supply)?;
+        let mut basket_price = accounts.basket_price.load_init()?;
+        basket_price.basket = basket_key;
+        basket_price.nav_decimals = NAV_DECIMALS;
+        basket_price.version = CURRENT_VERSION;
+        basket_price.bump = ctx.bumps.basket_price;
+        basket_price.publish(&nav, &clock);
+        drop(basket_price);
+
+        let registry_page = Self::register_basket(&mut ctx)?;
+
+        emit_cpi!(BasketMigrated {
+            basket: basket_key,
+            version: CURRENT_VERSION,
+            registry_page,
+        });
+
+        Ok(())
+    }
+
+    /// Append the basket to the current registry page as `create_basket`
+    /// does, without touching the id counter.
+    fn register_basket(ctx: &mut Context<MigrateBasket>) -> Result<u32> {
+        let basket = ctx.accounts.basket.key();
+        let config = &mut ctx.accounts.config;
+        let registry = &mut ctx.accounts.basket_registry;
+        let page = config.registry_page;
+
+        registry.page = page;
+        registry.bump = ctx.bumps.basket_registry;
+        registry.push(basket);
+        if registry.is_full() {
+            config.registry_page = page.checked_add(1).ok_or(BasketError::ArithmeticOverflow)?;
+        }
+
+        Ok(page)
+    }
+
+    /// Grow one version-1 `BasketToken`, record the extensions its mint
+    /// carries, open its TWAP accumulator at the current price and return
+    /// its pricing state. The extensions are not checked against the
+    /// policy: the leg is already live, and `add_tokens` keeps any new leg
+    /// to it.
+    fn migrate_leg(
+        leg: &'info [AccountInfo<'info>],
+        basket_key: Pubkey,
+        total_supply: u64,
+        now: i64,
+        admin_info: &AccountInfo<'info>,
+        system_program_info: &AccountInfo<'info>,
+    ) -> Result<LegState> {
+        let (info, mint_info, vault_ata_info) = (&leg[0], &leg[1], &leg[2]);
+        require_keys_eq!(
+            *info.owner,
+            crate::ID,
+            ErrorCode::AccountOwnedByWrongProgram
+        );
+        pda::require_v1_layout(info, BasketToken::DISCRIMINATOR, BasketToken::V1_SPACE)?;
+        pda::grow_pda_account(
+            admin_info,
+            info,
+            system_program_info,
+            8 + BasketToken::INIT_SPACE,
+        )?;
+
+        let mut basket_token: Account<BasketToken> = Account::try_from(info)?;
+        require!(
+            basket_token.basket == basket_key,
+            BasketError::InvalidBasketWiring
+        );
+        require!(
+            mint_info.key() == basket_token.mint,
+            BasketError::MintConfigMismatch
+        );
+        require!(
+            vault_ata_info.key() == basket_token.vault_ata,
+            BasketError::InvalidBasketWiring
+        );
+        basket_token.extension_flags = legs::inspect_mint_extensions(mint_info)?;
+
+        let state = LegState {
+            decimals: basket_token.decimals,
+            vault_balance: pricing::read_vault_balance(vault_ata_info)?,
+            twap_value: None,
+        };
+        // No TWAP until a full window has passed, as for a new leg.
+        basket_token.last_update_ts = now;
+        basket_token.twap_anchor_ts = now;
+        pricing::record_leg_price(&mut basket_token, &state, total_supply, &crate::ID)?;
+
+        Ok(state)
+    }
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, error::BasketError, events::*, pda, state::*};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    /// Must be the admin recorded in the config; pays the added rent.
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// Version-1 config; too short to deserialize as `Config` until grown.
+    /// CHECK: Address fixed by seeds; layout and admin checked in the handler.
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump,
+        owner = crate::ID,
+    )]
+    pub config: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateConfig<'info> {
+    /// Grow a version-1 config to the current layout, filling in each
+    /// appended field as `init_config` would, except `next_basket_id`:
+    /// baskets created before the upgrade chose their own ids, so the admin
+    /// passes one past the highest in use. Those baskets join the registry
+    /// on `migrate_basket`.
+    pub fn handler(ctx: Context<MigrateConfig>, next_basket_id: u64) -> Result<()> {
+        let accounts = &ctx.accounts;
+        let info = accounts.config.to_account_info();
+        pda::require_v1_layout(&info, Config::DISCRIMINATOR, Config::V1_SPACE)?;
+        pda::grow_pda_account(
+            &accounts.admin.to_account_info(),
+            &info,
+            &accounts.system_program.to_account_info(),
+            8 + Config::INIT_SPACE,
+        )?;
+
+        let mut config = Config::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+        require!(
+            config.admin == accounts.admin.key(),
+            BasketError::Unauthorized
+        );
+        require!(
+            next_basket_id >= FIRST_BASKET_ID,
+            BasketError::BasketIdMismatch
+        );
+        config.version = CURRENT_VERSION;
+        // No extension beyond a plain mint until the admin opts in.
+        config.mint_extension_policy = 0;
+        // Creation stays admin-only, with fees paid to the admin until a
+        // treasury is set.
+        config.permissionless_creation = false;
+        config.creation_fee_lamports = 0;
+        config.creation_bond_lamports = 0;
+        config.treasury = config.admin;
+        config.next_basket_id = next_basket_id;
+        config.registry_page = 0;
+        config.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+
+        emit_cpi!(ConfigMigrated {
+            version: CURRENT_VERSION,
+            next_basket_id,
+        });
+
+        Ok(())
+    }
+}
@@ -1,17 +1,89 @@
+pub mod accept_basket_ownership;
 pub mod add_tokens;
+pub mod cancel_request;
+pub mod claim_deposit;
+pub mod claim_redeem;
+pub mod claim_rewards;
+pub mod close_allow_list_entries;
+pub mod close_allow_list_entry;
+pub mod close_basket;
 pub mod create_basket;
 pub mod deposit_multi;
+pub mod fund_rewards;
+pub mod get_basket_nav;
 pub mod init_config;
+pub mod init_reward_pool;
+pub mod lock_shares;
+pub mod migrate_basket;
+pub mod migrate_config;
+pub mod poke_stake;
+pub mod preview_deposit;
+pub mod preview_withdraw;
+pub mod refresh_basket_price;
+pub mod request_deposit;
+pub mod request_redeem;
+pub mod set_basket_pricing;
+pub mod set_compliance_root;
 pub mod set_config;
+pub mod set_creation_policy;
+pub mod set_emergency_mode;
+pub mod set_epoch_operator;
+pub mod set_lock_tiers;
+pub mod settle_epoch;
+pub mod slash_basket_bond;
+pub mod stake;
+pub mod transfer_basket_ownership;
+pub mod unlock_shares;
+pub mod unstake;
 pub mod update_allow_list;
+pub mod update_allow_list_batch;
+pub mod update_basket;
+pub mod update_basket_limits;
+pub mod update_basket_metadata;
 pub mod verify_basket_owner;
 pub mod withdraw_multi;
 
+pub use accept_basket_ownership::*;
 pub use add_tokens::*;
+pub use cancel_request::*;
+pub use claim_deposit::*;
+pub use claim_redeem::*;
+pub use claim_rewards::*;
+pub use close_allow_list_entries::*;
+pub use close_allow_list_entry::*;
+pub use close_basket::*;
 pub use create_basket::*;
 pub use deposit_multi::*;
+pub use fund_rewards::*;
+pub use get_basket_nav::*;
 pub use init_config::*;
+pub use init_reward_pool::*;
+pub use lock_shares::*;
+pub use migrate_basket::*;
+pub use migrate_config::*;
+pub use poke_stake::*;
+pub use preview_deposit::*;
+pub use preview_withdraw::*;
+pub use refresh_basket_price::*;
+pub use request_deposit::*;
+pub use request_redeem::*;
+pub use set_basket_pricing::*;
+pub use set_compliance_root::*;
 pub use set_config::*;
+pub use set_creation_policy::*;
+pub use set_emergency_mode::*;
+pub use set_epoch_operator::*;
+pub use set_lock_tiers::*;
+pub use settle_epoch::*;
+pub use slash_basket_bond::*;
+pub use stake::*;
+pub use transfer_basket_ownership::*;
+pub use unlock_shares::*;
+pub use unstake::*;
 pub use update_allow_list::*;
+pub use update_allow_list_batch::*;
+pub use update_basket::*;
+pub use update_basket_limits::*;
+pub use update_basket_metadata::*;
 pub use verify_basket_owner::*;
 pub use withdraw_multi::*;
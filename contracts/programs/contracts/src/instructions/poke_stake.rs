@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, error::BasketError, events::*, state::*};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct PokeStake<'info> {
+    #[account(
+        mut,
+        seeds = [REWARD_POOL_SEED, reward_pool.basket.as_ref(), reward_pool.reward_mint.as_ref()],
+        bump = reward_pool.bump,
+    )]
+    pub reward_pool: Box<Account<'info, RewardPool>>,
+
+    #[account(
+        mut,
+        seeds = [STAKE_POSITION_SEED, reward_pool.key().as_ref(), stake_position.owner.as_ref()],
+        bump = stake_position.bump,
+    )]
+    pub stake_position: Box<Account<'info, StakePosition>>,
+}
+
+impl<'info> PokeStake<'info> {
+    /// Drop an ended commitment's boost back to 1x. Permissionless, so other
+    /// stakers need not wait for the owner to claim or unstake.
+    pub fn handler(ctx: Context<PokeStake>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let pool = &mut ctx.accounts.reward_pool;
+        let position = &mut ctx.accounts.stake_position;
+        require!(now >= position.unlock_ts, BasketError::StakeLocked);
+
+        position.sync(pool, now)?;
+
+        emit_cpi!(StakeReweighted {
+            pool: pool.key(),
+            owner: position.owner,
+            weight: position.weight,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::{
+    constants::*,
+    error::BasketError,
+    pricing::{self, DepositQuote},
+    state::*,
+};
+
+#[derive(Accounts)]
+pub struct PreviewDeposit<'info> {
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        constraint = basket.load_current()?.share_mint == share_mint.key()
+            @ BasketError::ShareMintMismatch,
+    )]
+    pub basket: AccountLoader<'info, Basket>,
+
+    pub share_mint: Box<InterfaceAccount<'info, Mint>>,
+}
+
+impl<'info> PreviewDeposit<'info> {
+    /// Quote `deposit_multi` without moving funds. The quote is returned via
+    /// return data so callers can simulate it or CPI into it.
+    pub fn handler(
+        ctx: Context<'_, '_, 'info, 'info, PreviewDeposit<'info>>,
+        amounts: Vec<u64>,
+    ) -> Result<DepositQuote> {
+        let accounts = &ctx.accounts;
+        let basket = accounts.basket.load_current()?;
+
+        require!(
+            amounts.len() == basket.token_count as usize,
+            BasketError::IncompleteWithdrawal
+        );
+        let leg_states = pricing::load_leg_states(
+            ctx.remaining_accounts,
+            accounts.basket.key(),
+            basket.token_count,
+        )?;
+
+        pricing::quote_deposit(
+            &amounts,
+            &leg_states,
+            accounts.share_mint.supply,
+            basket.effective_fee_bps(accounts.config.fee_bps),
+            basket.mint_pricing_mode,
+        )
+    }
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::{
+    error::BasketError,
+    pricing::{self, WithdrawQuote},
+    state::*,
+};
+
+#[derive(Accounts)]
+pub struct PreviewWithdraw<'info> {
+    #[account(
+        constraint = basket.load_current()?.share_mint == share_mint.key()
+            @ BasketError::ShareMintMismatch,
+    )]
+    pub basket: AccountLoader<'info, Basket>,
+
+    pub share_mint: Box<InterfaceAccount<'info, Mint>>,
+}
+
+impl<'info> PreviewWithdraw<'info> {
+    /// Quote `withdraw_multi` without burning shares. The quote is returned
+    /// via return data so callers can simulate it or CPI into it.
+    pub fn handler(
+        ctx: Context<'_, '_, 'info, 'info, PreviewWithdraw<'info>>,
+        shares: u64,
+    ) -> Result<WithdrawQuote> {
+        let accounts = &ctx.accounts;
+        let basket = accounts.basket.load_current()?;
+
+        let leg_states = pricing::load_leg_states(
+            ctx.remaining_accounts,
+            accounts.basket.key(),
+            basket.token_count,
+        )?;
+
+        pricing::quote_withdraw(
+            shares,
+            &leg_states,
+            accounts.share_mint.supply,
+            basket.redeem_pricing_mode,
+        )
+    }
+}
@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::{constants::*, error::BasketError, events::*, pricing, state::*};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RefreshBasketPrice<'info> {
+    /// Anyone may crank; pays rent only when the price account is missing.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        constraint = basket.load_current()?.share_mint == share_mint.key()
+            @ BasketError::ShareMintMismatch,
+    )]
+    pub basket: AccountLoader<'info, Basket>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + std::mem::size_of::<BasketPrice>(),
+        seeds = [BASKET_PRICE_SEED, basket.key().as_ref()],
+        bump,
+    )]
+    pub basket_price: AccountLoader<'info, BasketPrice>,
+
+    /// The QSHARE mint for this basket.
+    pub share_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RefreshBasketPrice<'info> {
+    /// Republish the basket NAV from live vault balances and advance every
+    /// leg's TWAP accumulator. Remaining accounts are `[BasketToken (mut),
+    /// VaultATA]` for every leg.
+    pub fn handler(ctx: Context<'_, '_, 'info, 'info, RefreshBasketPrice<'info>>) -> Result<()> {
+        let accounts = &ctx.accounts;
+        let basket = accounts.basket.load_current()?;
+
+        let mut legs = pricing::load_pricing_legs(
+            ctx.remaining_accounts,
+            accounts.basket.key(),
+            basket.token_count,
+        )?;
+        let total_supply = accounts.share_mint.supply;
+        for leg in legs.iter_mut() {
+            pricing::record_leg_price(
+                &mut leg.basket_token,
+                &leg.state,
+                total_supply,
+                ctx.program_id,
+            )?;
+        }
+
+        let leg_states: Vec<_> = legs.iter().map(|leg| leg.state).collect();
+        let nav = pricing::basket_nav(&leg_states, total_supply)?;
+        let clock = Clock::get()?;
+
+        // Accounts created before price publishing are initialised here.
+        let mut basket_price = match accounts.basket_price.load_mut() {
+            Ok(basket_price) => basket_price,
+            Err(_) => accounts.basket_price.load_init()?,
+        };
+        if basket_price.version == 0 {
+            basket_price.basket = accounts.basket.key();
+            basket_price.nav_decimals = NAV_DECIMALS;
+            basket_price.version = CURRENT_VERSION;
+            basket_price.bump = ctx.bumps.basket_price;
+        }
+        basket_price.publish(&nav, &clock);
+
+        emit_cpi!(BasketPriceRefreshed {
+            basket: accounts.basket.key(),
+            nav_per_share: nav.nav_per_share,
+            confidence: nav.confidence,
+            total_supply: nav.total_supply,
+            slot: clock.slot,
+        });
+
+        Ok(())
+    }
+}
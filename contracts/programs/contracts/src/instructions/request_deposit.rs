@@ -0,0 +1,348 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+use solana_sdk_ids::sysvar::instructions as instructions_sysvar;
+
+use crate::{
+    compliance::{ComplianceAccounts, ComplianceProof},
+    constants::*,
+    error::BasketError,
+    events::*,
+    legs::{self, UserAtaOwner},
+    pricing::{self, LegState},
+    state::*,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RequestDeposit<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    pub basket: AccountLoader<'info, Basket>,
+
+    #[account(
+        mut,
+        seeds = [EPOCH_QUEUE_SEED, basket.key().as_ref()],
+        bump = epoch_queue.bump,
+    )]
+    pub epoch_queue: Box<Account<'info, EpochQueue>>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + DepositRequest::INIT_SPACE,
+        seeds = [DEPOSIT_REQUEST_SEED, basket.key().as_ref(), owner.key().as_ref()],
+        bump,
+    )]
+    pub deposit_request: Box<Account<'info, DepositRequest>>,
+
+    #[account(
+        address = basket.load_current()?.share_mint @ BasketError::ShareMintMismatch,
+        mint::token_program = share_token_program,
+    )]
+    pub share_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Where the filled shares are claimed to; its balance counts against
+    /// `max_user_shares`.
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = share_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = share_token_program,
+    )]
+    pub owner_share_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Optional compliance allow-list entry for the owner. When
+    /// `config.compliance_enabled` is true, either this, `compliance_root`
+    /// with a proof, or an Ed25519 attestation must be provided.
+    pub user_allow_list: Option<Box<Account<'info, UserAllowList>>>,
+
+    /// Basket Merkle root the `compliance_proof` argument is checked against.
+    #[account(
+        seeds = [COMPLIANCE_ROOT_SEED, basket.key().as_ref()],
+        bump = compliance_root.bump,
+    )]
+    pub compliance_root: Option<Box<Account<'info, ComplianceRoot>>>,
+
+    /// Read to find the Ed25519 attestation preceding this instruction.
+    /// CHECK: Address-constrained to the instructions sysvar.
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+
+    /// `AttestationNonce` PDA created to spend the attestation's nonce.
+    /// CHECK: Derivation and emptiness validated by `ComplianceAccounts`.
+    #[account(mut)]
+    pub attestation_nonce: Option<UncheckedAccount<'info>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    /// Token program owning the QSHARE mint (SPL Token or Token-2022).
+    pub share_token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RequestDeposit<'info> {
+    /// Queue a deposit of every leg for the current epoch. The fee is taken
+    /// now; the net amounts wait in the queue's ATAs until settlement prices
+    /// them. A request still open in the same epoch is topped up.
+    ///
+    /// Admission and the deposit caps match `deposit_multi`. The caps are
+    /// checked here against the current price, with the whole queue counted
+    /// towards `max_total_value`, so settlement never has to refuse a fill.
+    pub fn handler(
+        ctx: Context<'_, '_, 'info, 'info, RequestDeposit<'info>>,
+        amounts: Vec<u64>,
+        compliance_proof: Option<ComplianceProof>,
+    ) -> Result<()> {
+        let accounts = &ctx.accounts;
+        let remaining = ctx.remaining_accounts;
+
+        let basket = accounts.basket.load_current()?;
+        require!(
+            amounts.len() == basket.token_count as usize,
+            BasketError::IncompleteWithdrawal
+        );
+        require!(
+            remaining.len()
+                == amounts
+                    .len()
+                    .checked_mul(REQUEST_DEPOSIT_ACCOUNTS_PER_TOKEN)
+                    .ok_or(BasketError::ArithmeticOverflow)?,
+            BasketError::InvalidRemainingAccounts
+        );
+        let fee_bps = basket.effective_fee_bps(accounts.config.fee_bps);
+        let (mint_pricing_mode, redeem_pricing_mode) =
+            (basket.mint_pricing_mode, basket.redeem_pricing_mode);
+        drop(basket);
+        let clock = Clock::get()?;
+        Self::compliance_accounts(accounts).check(
+            compliance_proof.as_ref(),
+            clock.unix_timestamp,
+            ctx.program_id,
+        )?;
+
+        let epoch = accounts.epoch_queue.current_epoch;
+        let request = &accounts.deposit_request;
+        require!(
+            request.value == 0 || request.epoch == epoch,
+            BasketError::UnclaimedRequest
+        );
+
+        let basket_key = accounts.basket.key();
+        let queue_key = accounts.epoch_queue.key();
+        let token_program = accounts.token_program.key();
+        let mut basket_tokens = Vec::with_capacity(amounts.len());
+        let mut leg_states = Vec::with_capacity(amounts.len());
+        let mut net_amounts = Vec::with_capacity(amounts.len());
+        let mut value: u64 = 0;
+        for (leg, &amount) in remaining
+            .chunks_exact(REQUEST_DEPOSIT_ACCOUNTS_PER_TOKEN)
+            .zip(&amounts)
+        {
+            require!(amount > 0, BasketError::ZeroDeposit);
+
+            let mut basket_token = legs::load_basket_token(&leg[0], basket_key)?;
+            let (mint_info, user_ata_info, vault_ata_info) = (&leg[1], &leg[2], &leg[3]);
+            let (pending_ata_info, fee_vault_info) = (&leg[4], &leg[5]);
+            require!(
+                mint_info.key() == basket_token.mint,
+                BasketError::MintConfigMismatch
+            );
+            require!(
+                vault_ata_info.key() == basket_token.vault_ata,
+                BasketError::InvalidBasketWiring
+            );
+            legs::validate_user_ata(
+                user_ata_info,
+                &basket_token.mint,
+                UserAtaOwner::Signer(accounts.owner.key()),
+                &token_program,
+            )?;
+            legs::validate_escrow_ata(
+                pending_ata_info,
+                &queue_key,
+                &basket_token.mint,
+                &token_program,
+            )?;
+            require!(
+                fee_vault_info.key() == basket_token.fee_vault_ata,
+                BasketError::InvalidBasketWiring
+            );
+            basket_tokens.push(leg[0].key());
+            let vault_balance = pricing::read_vault_balance(vault_ata_info)?;
+            leg_states.push(pricing::observe_leg(
+                &mut basket_token,
+                vault_balance,
+                clock.unix_timestamp,
+            ));
+
+            let (net_amount, fee_amount) = pricing::compute_fee(amount, fee_bps)?;
+            Self::transfer(
+                accounts,
+                &basket_token,
+                mint_info,
+                user_ata_info,
+                pending_ata_info,
+                net_amount,
+            )?;
+            net_amounts.push((basket_token.mint, net_amount));
+            if fee_amount > 0 {
+                Self::transfer(
+                    accounts,
+                    &basket_token,
+                    mint_info,
+                    user_ata_info,
+                    fee_vault_info,
+                    fee_amount,
+                )?;
+            }
+
+            let normalised: u64 = pricing::normalise_amount(net_amount, basket_token.decimals)?
+                .try_into()
+                .map_err(|_| BasketError::ArithmeticOverflow)?;
+            value = value
+                .checked_add(normalised)
+                .ok_or(BasketError::ArithmeticOverflow)?;
+        }
+        legs::require_distinct_legs(&basket_tokens)?;
+        require!(value > 0, BasketError::ZeroDeposit);
+        Self::check_caps(
+            accounts,
+            &leg_states,
+            value,
+            mint_pricing_mode,
+            redeem_pricing_mode,
+        )?;
+
+        let queue = &mut ctx.accounts.epoch_queue;
+        queue.pending_deposit_value = queue
+            .pending_deposit_value
+            .checked_add(value)
+            .ok_or(BasketError::ArithmeticOverflow)?;
+
+        let request = &mut ctx.accounts.deposit_request;
+        if request.value == 0 {
+            request.basket = basket_key;
+            request.owner = ctx.accounts.owner.key();
+            request.epoch = epoch;
+            request.bump = ctx.bumps.deposit_request;
+        }
+        request.value = request
+            .value
+            .checked_add(value)
+            .ok_or(BasketError::ArithmeticOverflow)?;
+        for (mint, net_amount) in net_amounts {
+            request.add_leg_amount(mint, net_amount)?;
+        }
+
+        emit_cpi!(DepositRequested {
+            basket: basket_key,
+            owner: ctx.accounts.owner.key(),
+            epoch,
+            value,
+        });
+
+        Ok(())
+    }
+
+    fn compliance_accounts<'a>(
+        accounts: &'a RequestDeposit<'info>,
+    ) -> ComplianceAccounts<'a, 'info> {
+        ComplianceAccounts {
+            config: &accounts.config,
+            basket: accounts.basket.key(),
+            user: accounts.owner.key(),
+            payer: accounts.owner.to_account_info(),
+            user_allow_list: accounts.user_allow_list.as_deref().map(|entry| &**entry),
+            compliance_root: accounts.compliance_root.as_deref().map(|root| &**root),
+            instructions_sysvar: accounts
+                .instructions_sysvar
+                .as_ref()
+                .map(|sysvar| sysvar.to_account_info()),
+            attestation_nonce: accounts
+                .attestation_nonce
+                .as_ref()
+                .map(|nonce| nonce.to_account_info()),
+            system_program: accounts.system_program.to_account_info(),
+        }
+    }
+
+    /// Apply the deposit caps as if the queue settled now: the vault plus
+    /// every queued deposit against `max_total_value`, and the owner's
+    /// balance plus the shares their whole request would fill at today's
+    /// price against `max_user_shares`. The quote also rejects a deposit
+    /// that settlement could not price.
+    fn check_caps(
+        accounts: &RequestDeposit<'info>,
+        leg_states: &[LegState],
+        value: u64,
+        mint_pricing_mode: u8,
+        redeem_pricing_mode: u8,
+    ) -> Result<()> {
+        let queued_value = accounts
+            .epoch_queue
+            .pending_deposit_value
+            .checked_add(value)
+            .ok_or(BasketError::ArithmeticOverflow)?;
+        let total_value = pricing::total_value(leg_states)?
+            .checked_add(queued_value as u128)
+            .ok_or(BasketError::ArithmeticOverflow)?;
+
+        let request_value = accounts
+            .deposit_request
+            .value
+            .checked_add(value)
+            .ok_or(BasketError::ArithmeticOverflow)?;
+        let quote = pricing::quote_epoch(
+            request_value,
+            0,
+            leg_states,
+            accounts.share_mint.supply,
+            mint_pricing_mode,
+            redeem_pricing_mode,
+        )?;
+        let owner_shares = accounts
+            .owner_share_ata
+            .amount
+            .checked_add(quote.shares_minted)
+            .ok_or(BasketError::ArithmeticOverflow)?;
+
+        accounts
+            .basket
+            .load_current()?
+            .check_deposit_caps(total_value, owner_shares)
+    }
+
+    fn transfer(
+        accounts: &RequestDeposit<'info>,
+        basket_token: &BasketToken,
+        mint_info: &AccountInfo<'info>,
+        from: &AccountInfo<'info>,
+        to: &AccountInfo<'info>,
+        amount: u64,
+    ) -> Result<()> {
+        token_interface::transfer_checked(
+            CpiContext::new(
+                accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: from.clone(),
+                    to: to.clone(),
+                    authority: accounts.owner.to_account_info(),
+                    mint: mint_info.clone(),
+                },
+            ),
+            amount,
+            basket_token.decimals,
+        )
+    }
+}
@@ -0,0 +1,146 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    constants::*, error::BasketError, events::*, instructions::lock_shares::transfer_shares,
+    state::*,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RequestRedeem<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub basket: AccountLoader<'info, Basket>,
+
+    #[account(
+        mut,
+        seeds = [EPOCH_QUEUE_SEED, basket.key().as_ref()],
+        bump = epoch_queue.bump,
+    )]
+    pub epoch_queue: Box<Account<'info, EpochQueue>>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + RedeemRequest::INIT_SPACE,
+        seeds = [REDEEM_REQUEST_SEED, basket.key().as_ref(), owner.key().as_ref()],
+        bump,
+    )]
+    pub redeem_request: Box<Account<'info, RedeemRequest>>,
+
+    #[account(
+        address = basket.load_current()?.share_mint @ BasketError::ShareMintMismatch,
+        mint::token_program = share_token_program,
+    )]
+    pub share_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = share_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = share_token_program,
+    )]
+    pub owner_share_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Vault authority PDA — owns the pooled QSHARE escrow.
+    /// CHECK: Must match the basket's vault authority.
+    #[account(address = basket.load_current()?.vault_authority @ BasketError::VaultAuthMismatch)]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = share_mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = share_token_program,
+    )]
+    pub share_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Token program owning the QSHARE mint (SPL Token or Token-2022).
+    pub share_token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RequestRedeem<'info> {
+    /// Queue `shares` for redemption in the current epoch. The shares are
+    /// escrowed now and burned at settlement. Remaining accounts carry the
+    /// share transfer-hook's extra accounts, if the mint has one.
+    pub fn handler(
+        ctx: Context<'_, '_, 'info, 'info, RequestRedeem<'info>>,
+        shares: u64,
+    ) -> Result<()> {
+        require!(shares > 0, BasketError::InsufficientShares);
+        require!(
+            ctx.accounts.owner_share_ata.amount >= shares,
+            BasketError::InsufficientShares
+        );
+
+        // An epoch redeeming more than one outflow window allows could never
+        // settle.
+        let pending = ctx
+            .accounts
+            .epoch_queue
+            .pending_redeem_shares
+            .checked_add(shares)
+            .ok_or(BasketError::ArithmeticOverflow)?;
+        let basket = ctx.accounts.basket.load_current()?;
+        require!(
+            !basket.withdraw_limiter_active()
+                || pending <= basket.outflow_limit(ctx.accounts.share_mint.supply),
+            BasketError::WithdrawLimitExceeded
+        );
+        drop(basket);
+
+        let epoch = ctx.accounts.epoch_queue.current_epoch;
+        let request = &ctx.accounts.redeem_request;
+        require!(
+            request.shares == 0 || request.epoch == epoch,
+            BasketError::UnclaimedRequest
+        );
+
+        let accounts = &ctx.accounts;
+        transfer_shares(
+            &accounts.share_token_program,
+            accounts.owner_share_ata.to_account_info(),
+            &accounts.share_mint,
+            accounts.share_escrow.to_account_info(),
+            accounts.owner.to_account_info(),
+            ctx.remaining_accounts,
+            shares,
+            &[],
+        )?;
+
+        let queue = &mut ctx.accounts.epoch_queue;
+        queue.pending_redeem_shares = queue
+            .pending_redeem_shares
+            .checked_add(shares)
+            .ok_or(BasketError::ArithmeticOverflow)?;
+
+        let request = &mut ctx.accounts.redeem_request;
+        if request.shares == 0 {
+            request.basket = ctx.accounts.basket.key();
+            request.owner = ctx.accounts.owner.key();
+            request.epoch = epoch;
+            request.bump = ctx.bumps.redeem_request;
+        }
+        request.shares = request
+            .shares
+            .checked_add(shares)
+            .ok_or(BasketError::ArithmeticOverflow)?;
+
+        emit_cpi!(RedeemRequested {
+            basket: ctx.accounts.basket.key(),
+            owner: ctx.accounts.owner.key(),
+            epoch,
+            shares,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, error::BasketError, events::*, state::*};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetBasketPricing<'info> {
+    /// Basket owner or protocol admin.
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        constraint = basket.load_current()?.is_manager(&authority.key(), &config.admin)
+            @ BasketError::Unauthorized,
+    )]
+    pub basket: AccountLoader<'info, Basket>,
+}
+
+impl<'info> SetBasketPricing<'info> {
+    /// Choose spot, TWAP, min or max pricing independently for minting and
+    /// redemption.
+    pub fn handler(
+        ctx: Context<SetBasketPricing>,
+        mint_pricing_mode: u8,
+        redeem_pricing_mode: u8,
+    ) -> Result<()> {
+        for mode in [mint_pricing_mode, redeem_pricing_mode] {
+            require!(
+                mode <= PRICING_MODE_MAX,
+                BasketError::InvalidPricingMode
+            );
+        }
+
+        let mut basket = ctx.accounts.basket.load_current_mut()?;
+        basket.mint_pricing_mode = mint_pricing_mode;
+        basket.redeem_pricing_mode = redeem_pricing_mode;
+
+        emit_cpi!(BasketPricingUpdated {
+            basket: ctx.accounts.basket.key(),
+            mint_pricing_mode,
+            redeem_pricing_mode,
+        });
+
+        Ok(())
+    }
+}
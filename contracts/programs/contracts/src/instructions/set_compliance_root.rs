@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, error::BasketError, events::*, state::*};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetComplianceRoot<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.whitelist_auth == authority.key() @ BasketError::Unauthorized,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    pub basket: AccountLoader<'info, Basket>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + ComplianceRoot::INIT_SPACE,
+        seeds = [COMPLIANCE_ROOT_SEED, basket.key().as_ref()],
+        bump,
+    )]
+    pub compliance_root: Box<Account<'info, ComplianceRoot>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetComplianceRoot<'info> {
+    /// Publish a new Merkle root for the basket. `version` must exceed the
+    /// current one, so a delayed or replayed publish can't roll back a
+    /// rotation.
+    pub fn handler(ctx: Context<SetComplianceRoot>, root: [u8; 32], version: u64) -> Result<()> {
+        let compliance_root = &mut ctx.accounts.compliance_root;
+        require!(
+            version > compliance_root.version,
+            BasketError::StaleComplianceRoot
+        );
+
+        let old_version = compliance_root.version;
+        compliance_root.set_inner(ComplianceRoot {
+            basket: ctx.accounts.basket.key(),
+            root,
+            version,
+            updated_at: Clock::get()?.unix_timestamp,
+            bump: ctx.bumps.compliance_root,
+        });
+
+        emit_cpi!(ComplianceRootUpdated {
+            basket: ctx.accounts.basket.key(),
+            root,
+            old_version,
+            new_version: version,
+        });
+
+        Ok(())
+    }
+}
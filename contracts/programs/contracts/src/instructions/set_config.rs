@@ -23,6 +23,7 @@ impl<'info> SetConfig<'info> {
         whitelist_auth: Option<Pubkey>,
         compliance_enabled: Option<bool>,
         new_admin: Option<Pubkey>,
+        mint_extension_policy: Option<u8>,
     ) -> Result<()> {
         if let Some(bps) = fee_bps {
             require!(
@@ -44,11 +45,20 @@ impl<'info> SetConfig<'info> {
             ctx.accounts.config.admin = admin;
         }
 
+        if let Some(policy) = mint_extension_policy {
+            require!(
+                policy & !MINT_EXT_ALL == 0,
+                BasketError::InvalidExtensionPolicy
+            );
+            ctx.accounts.config.mint_extension_policy = policy;
+        }
+
         emit_cpi!(ConfigUpdated {
             fee_bps: ctx.accounts.config.fee_bps,
             whitelist_auth: ctx.accounts.config.whitelist_auth,
             compliance_enabled: ctx.accounts.config.compliance_enabled,
             new_admin: ctx.accounts.config.admin,
+            mint_extension_policy: ctx.accounts.config.mint_extension_policy,
         });
 
         Ok(())
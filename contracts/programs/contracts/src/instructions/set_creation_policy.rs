@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, error::BasketError, events::*, state::Config};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetCreationPolicy<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = admin @ BasketError::Unauthorized,
+    )]
+    pub config: Box<Account<'info, Config>>,
+}
+
+impl<'info> SetCreationPolicy<'info> {
+    /// Open or close basket creation to everyone and set what non-admin
+    /// creators pay: a fee to `treasury` and a slashable bond.
+    pub fn handler(
+        ctx: Context<SetCreationPolicy>,
+        permissionless_creation: bool,
+        creation_fee_lamports: u64,
+        creation_bond_lamports: u64,
+        treasury: Pubkey,
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        config.permissionless_creation = permissionless_creation;
+        config.creation_fee_lamports = creation_fee_lamports;
+        config.creation_bond_lamports = creation_bond_lamports;
+        config.treasury = treasury;
+
+        emit_cpi!(CreationPolicyUpdated {
+            permissionless_creation,
+            creation_fee_lamports,
+            creation_bond_lamports,
+            treasury,
+        });
+
+        Ok(())
+    }
+}
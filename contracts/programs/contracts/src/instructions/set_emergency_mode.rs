@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, error::BasketError, events::*, state::*};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetEmergencyMode<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = admin @ BasketError::Unauthorized,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(mut)]
+    pub basket: AccountLoader<'info, Basket>,
+}
+
+impl<'info> SetEmergencyMode<'info> {
+    /// Protocol-admin switch that bypasses a basket's withdrawal limiter so
+    /// holders can exit at once. Leaving emergency mode restarts the window.
+    pub fn handler(ctx: Context<SetEmergencyMode>, enabled: bool) -> Result<()> {
+        let mut basket = ctx.accounts.basket.load_current_mut()?;
+        basket.emergency_mode = enabled as u8;
+        if !enabled {
+            basket.window_start_supply = 0;
+            basket.burned_in_window = 0;
+        }
+
+        emit_cpi!(EmergencyModeUpdated {
+            basket: ctx.accounts.basket.key(),
+            enabled,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, error::BasketError, events::*, state::*};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetEpochOperator<'info> {
+    /// Basket owner or protocol admin.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        constraint = basket.load_current()?.is_manager(&authority.key(), &config.admin)
+            @ BasketError::Unauthorized,
+    )]
+    pub basket: AccountLoader<'info, Basket>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + EpochQueue::INIT_SPACE,
+        seeds = [EPOCH_QUEUE_SEED, basket.key().as_ref()],
+        bump,
+    )]
+    pub epoch_queue: Box<Account<'info, EpochQueue>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetEpochOperator<'info> {
+    /// Open the basket's request queue, or hand settlement to a new
+    /// operator. Pending requests and the epoch counter are untouched.
+    pub fn handler(ctx: Context<SetEpochOperator>, operator: Pubkey) -> Result<()> {
+        let queue = &mut ctx.accounts.epoch_queue;
+        if queue.basket == Pubkey::default() {
+            queue.basket = ctx.accounts.basket.key();
+            queue.bump = ctx.bumps.epoch_queue;
+        }
+        queue.operator = operator;
+
+        emit_cpi!(EpochOperatorUpdated {
+            basket: ctx.accounts.basket.key(),
+            operator,
+        });
+
+        Ok(())
+    }
+}
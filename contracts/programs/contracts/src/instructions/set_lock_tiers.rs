@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, error::BasketError, events::*, state::*};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetLockTiers<'info> {
+    /// Basket owner or protocol admin.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        constraint = basket.load_current()?.is_manager(&authority.key(), &config.admin)
+            @ BasketError::Unauthorized,
+    )]
+    pub basket: AccountLoader<'info, Basket>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + LockTiers::INIT_SPACE,
+        seeds = [LOCK_TIERS_SEED, basket.key().as_ref()],
+        bump,
+    )]
+    pub lock_tiers: Box<Account<'info, LockTiers>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetLockTiers<'info> {
+    /// Replace the basket's lock tiers. An empty list stops new locks;
+    /// existing positions keep the penalty they were opened with.
+    pub fn handler(ctx: Context<SetLockTiers>, tiers: Vec<LockTier>) -> Result<()> {
+        Self::validate_tiers(&tiers)?;
+
+        let mut stored = [LockTier::default(); MAX_LOCK_TIERS];
+        stored[..tiers.len()].copy_from_slice(&tiers);
+        ctx.accounts.lock_tiers.set_inner(LockTiers {
+            basket: ctx.accounts.basket.key(),
+            tiers: stored,
+            tier_count: tiers.len() as u8,
+            bump: ctx.bumps.lock_tiers,
+        });
+
+        emit_cpi!(LockTiersUpdated {
+            basket: ctx.accounts.basket.key(),
+            tiers,
+        });
+
+        Ok(())
+    }
+
+    fn validate_tiers(tiers: &[LockTier]) -> Result<()> {
+        require!(tiers.len() <= MAX_LOCK_TIERS, BasketError::InvalidLockTiers);
+
+        let mut previous = 0;
+        for tier in tiers {
+            require!(
+                tier.min_duration_secs > previous
+                    && tier.min_duration_secs <= MAX_LOCK_DURATION_SECS,
+                BasketError::InvalidLockTiers
+            );
+            require!(
+                tier.early_exit_penalty_bps <= EARLY_EXIT_PENALTY_BPS_MAX,
+                BasketError::InvalidLockTiers
+            );
+            previous = tier.min_duration_secs;
+        }
+        Ok(())
+    }
+}
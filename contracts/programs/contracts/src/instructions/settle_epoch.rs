@@ -0,0 +1,380 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{
+        self, Burn, Mint, MintTo, TokenAccount, TokenInterface, TransferChecked,
+    },
+};
+
+use crate::{
+    constants::*,
+    error::BasketError,
+    events::*,
+    legs,
+    pricing::{self, LegState},
+    state::*,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SettleEpoch<'info> {
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    /// Mutable for the outflow limiter.
+    #[account(mut)]
+    pub basket: AccountLoader<'info, Basket>,
+
+    #[account(
+        mut,
+        seeds = [BASKET_PRICE_SEED, basket.key().as_ref()],
+        bump = basket_price.load()?.bump,
+    )]
+    pub basket_price: AccountLoader<'info, BasketPrice>,
+
+    #[account(
+        mut,
+        has_one = operator @ BasketError::Unauthorized,
+        seeds = [EPOCH_QUEUE_SEED, basket.key().as_ref()],
+        bump = epoch_queue.bump,
+    )]
+    pub epoch_queue: Box<Account<'info, EpochQueue>>,
+
+    #[account(
+        init,
+        payer = operator,
+        space = 8 + Epoch::INIT_SPACE,
+        seeds = [
+            EPOCH_SEED,
+            basket.key().as_ref(),
+            epoch_queue.current_epoch.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub epoch: Box<Account<'info, Epoch>>,
+
+    #[account(
+        mut,
+        address = basket.load_current()?.share_mint @ BasketError::ShareMintMismatch,
+        mint::token_program = share_token_program,
+    )]
+    pub share_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Mint authority PDA — signs the QSHARE mint_to CPI.
+    /// CHECK: Validated via `validate_mint_authority`.
+    pub mint_authority: UncheckedAccount<'info>,
+
+    /// Vault authority PDA — signs payouts and the redemption burn.
+    /// CHECK: Must match the basket's vault authority.
+    #[account(address = basket.load_current()?.vault_authority @ BasketError::VaultAuthMismatch)]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = operator,
+        associated_token::mint = share_mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = share_token_program,
+    )]
+    pub share_escrow: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    /// Token program owning the QSHARE mint (SPL Token or Token-2022).
+    pub share_token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Validated token-leg accounts extracted from remaining_accounts.
+struct SettleLeg<'info> {
+    basket_token: Account<'info, BasketToken>,
+    mint_info: &'info AccountInfo<'info>,
+    vault_ata_info: &'info AccountInfo<'info>,
+    pending_ata_info: &'info AccountInfo<'info>,
+    payout_ata_info: &'info AccountInfo<'info>,
+}
+
+impl<'info> SettleEpoch<'info> {
+    /// Fill every request queued in the current epoch at one price, under
+    /// the basket's pricing modes, and open the next epoch. Redemption
+    /// payouts move to the epoch's ATAs and the escrowed shares are burned;
+    /// queued deposits move into the vault and their shares are minted to
+    /// the escrow. Owners then claim. Deposit caps were enforced when each
+    /// deposit was requested, so they can't hold up a fill.
+    pub fn handler(ctx: Context<'_, '_, 'info, 'info, SettleEpoch<'info>>) -> Result<()> {
+        let accounts = &ctx.accounts;
+        let remaining = ctx.remaining_accounts;
+
+        let basket = accounts.basket.load_current()?;
+        let expected = (basket.token_count as usize)
+            .checked_mul(SETTLE_ACCOUNTS_PER_TOKEN)
+            .ok_or(BasketError::ArithmeticOverflow)?;
+        require!(
+            remaining.len() == expected,
+            BasketError::InvalidRemainingAccounts
+        );
+
+        let basket_id_bytes = basket.basket_id.to_le_bytes();
+        let vault_auth_seeds: &[&[u8]] = &[
+            VAULT_AUTHORITY_SEED,
+            basket_id_bytes.as_ref(),
+            &[basket.vault_authority_bump],
+        ];
+        let mint_auth_seeds: &[&[u8]] = &[
+            MINT_AUTHORITY_SEED,
+            basket_id_bytes.as_ref(),
+            &[basket.mint_authority_bump],
+        ];
+        Self::validate_mint_authority(accounts, mint_auth_seeds, ctx.program_id)?;
+        let (mint_pricing_mode, redeem_pricing_mode) =
+            (basket.mint_pricing_mode, basket.redeem_pricing_mode);
+        drop(basket);
+
+        let basket_key = accounts.basket.key();
+        let queue_seeds: &[&[u8]] = &[
+            EPOCH_QUEUE_SEED,
+            basket_key.as_ref(),
+            &[accounts.epoch_queue.bump],
+        ];
+
+        let mut legs = remaining
+            .chunks_exact(SETTLE_ACCOUNTS_PER_TOKEN)
+            .map(|leg| Self::parse_and_validate_leg(accounts, leg))
+            .collect::<Result<Vec<_>>>()?;
+        legs::require_distinct_legs(
+            &legs
+                .iter()
+                .map(|leg| leg.basket_token.key())
+                .collect::<Vec<_>>(),
+        )?;
+
+        let clock = Clock::get()?;
+        let leg_states = legs
+            .iter_mut()
+            .map(|leg| {
+                let vault_balance = pricing::read_vault_balance(leg.vault_ata_info)?;
+                Ok(pricing::observe_leg(
+                    &mut leg.basket_token,
+                    vault_balance,
+                    clock.unix_timestamp,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let deposit_value = accounts.epoch_queue.pending_deposit_value;
+        let redeem_shares = accounts.epoch_queue.pending_redeem_shares;
+        // Queued redemptions draw on the same window as withdraw_multi; an
+        // epoch over the headroom waits for the next window.
+        accounts.basket.load_current_mut()?.consume_outflow(
+            basket_key,
+            redeem_shares,
+            accounts.share_mint.supply,
+            clock.unix_timestamp,
+        )?;
+        let quote = pricing::quote_epoch(
+            deposit_value,
+            redeem_shares,
+            &leg_states,
+            accounts.share_mint.supply,
+            mint_pricing_mode,
+            redeem_pricing_mode,
+        )?;
+
+        let mut post_states = Vec::with_capacity(legs.len());
+        for ((leg, state), &amount_out) in legs.iter().zip(&leg_states).zip(&quote.amounts_out) {
+            if amount_out > 0 {
+                Self::transfer(
+                    accounts,
+                    leg,
+                    leg.vault_ata_info,
+                    leg.payout_ata_info,
+                    accounts.vault_authority.to_account_info(),
+                    vault_auth_seeds,
+                    amount_out,
+                )?;
+            }
+
+            let swept = pricing::read_vault_balance(leg.pending_ata_info)?;
+            if swept > 0 {
+                Self::transfer(
+                    accounts,
+                    leg,
+                    leg.pending_ata_info,
+                    leg.vault_ata_info,
+                    accounts.epoch_queue.to_account_info(),
+                    queue_seeds,
+                    swept,
+                )?;
+            }
+
+            post_states.push(LegState {
+                vault_balance: state
+                    .vault_balance
+                    .checked_sub(amount_out)
+                    .ok_or(BasketError::ArithmeticOverflow)?
+                    .checked_add(swept)
+                    .ok_or(BasketError::ArithmeticOverflow)?,
+                ..*state
+            });
+        }
+
+        if redeem_shares > 0 {
+            token_interface::burn(
+                CpiContext::new_with_signer(
+                    accounts.share_token_program.to_account_info(),
+                    Burn {
+                        mint: accounts.share_mint.to_account_info(),
+                        from: accounts.share_escrow.to_account_info(),
+                        authority: accounts.vault_authority.to_account_info(),
+                    },
+                    &[vault_auth_seeds],
+                ),
+                redeem_shares,
+            )?;
+        }
+        if quote.shares_minted > 0 {
+            token_interface::mint_to(
+                CpiContext::new_with_signer(
+                    accounts.share_token_program.to_account_info(),
+                    MintTo {
+                        mint: accounts.share_mint.to_account_info(),
+                        to: accounts.share_escrow.to_account_info(),
+                        authority: accounts.mint_authority.to_account_info(),
+                    },
+                    &[mint_auth_seeds],
+                ),
+                quote.shares_minted,
+            )?;
+        }
+
+        let total_supply = accounts
+            .share_mint
+            .supply
+            .checked_sub(redeem_shares)
+            .ok_or(BasketError::ArithmeticOverflow)?
+            .checked_add(quote.shares_minted)
+            .ok_or(BasketError::ArithmeticOverflow)?;
+        for (leg, post_state) in legs.iter_mut().zip(&post_states) {
+            pricing::record_leg_price(
+                &mut leg.basket_token,
+                post_state,
+                total_supply,
+                ctx.program_id,
+            )?;
+        }
+        accounts
+            .basket_price
+            .load_mut()?
+            .publish(&pricing::basket_nav(&post_states, total_supply)?, &clock);
+
+        let epoch_id = accounts.epoch_queue.current_epoch;
+        let accounts = &mut *ctx.accounts;
+        accounts.epoch.set_inner(Epoch {
+            basket: basket_key,
+            epoch_id,
+            nav_per_share: quote.nav_per_share,
+            unclaimed_deposit_value: deposit_value,
+            unclaimed_shares: quote.shares_minted,
+            unclaimed_redeem_shares: redeem_shares,
+            settled_ts: clock.unix_timestamp,
+            bump: ctx.bumps.epoch,
+        });
+
+        let queue = &mut accounts.epoch_queue;
+        queue.current_epoch = epoch_id
+            .checked_add(1)
+            .ok_or(BasketError::ArithmeticOverflow)?;
+        queue.pending_deposit_value = 0;
+        queue.pending_redeem_shares = 0;
+        queue.unclaimed_redeem_shares = queue
+            .unclaimed_redeem_shares
+            .checked_add(redeem_shares)
+            .ok_or(BasketError::ArithmeticOverflow)?;
+
+        emit_cpi!(EpochSettled {
+            basket: basket_key,
+            epoch: epoch_id,
+            nav_per_share: quote.nav_per_share,
+            deposit_value,
+            shares_minted: quote.shares_minted,
+            shares_burned: redeem_shares,
+            amounts_out: quote.amounts_out,
+        });
+
+        Ok(())
+    }
+
+    fn validate_mint_authority(
+        accounts: &SettleEpoch<'info>,
+        seeds: &[&[u8]],
+        program_id: &Pubkey,
+    ) -> Result<()> {
+        let expected = Pubkey::create_program_address(seeds, program_id)
+            .map_err(|_| BasketError::InvalidBasketWiring)?;
+        require!(
+            accounts.mint_authority.key() == expected,
+            BasketError::InvalidBasketWiring
+        );
+        Ok(())
+    }
+
+    fn parse_and_validate_leg(
+        accounts: &SettleEpoch<'info>,
+        leg: &'info [AccountInfo<'info>],
+    ) -> Result<SettleLeg<'info>> {
+        let basket_token = legs::load_basket_token(&leg[0], accounts.basket.key())?;
+        require!(
+            leg[1].key() == basket_token.mint,
+            BasketError::MintConfigMismatch
+        );
+        require!(
+            leg[2].key() == basket_token.vault_ata,
+            BasketError::InvalidBasketWiring
+        );
+        let token_program = accounts.token_program.key();
+        legs::validate_escrow_ata(
+            &leg[3],
+            &accounts.epoch_queue.key(),
+            &basket_token.mint,
+            &token_program,
+        )?;
+        legs::validate_escrow_ata(
+            &leg[4],
+            &accounts.epoch.key(),
+            &basket_token.mint,
+            &token_program,
+        )?;
+
+        Ok(SettleLeg {
+            basket_token,
+            mint_info: &leg[1],
+            vault_ata_info: &leg[2],
+            pending_ata_info: &leg[3],
+            payout_ata_info: &leg[4],
+        })
+    }
+
+    fn transfer(
+        accounts: &SettleEpoch<'info>,
+        leg: &SettleLeg<'info>,
+        from: &AccountInfo<'info>,
+        to: &AccountInfo<'info>,
+        authority: AccountInfo<'info>,
+        signer_seeds: &[&[u8]],
+        amount: u64,
+    ) -> Result<()> {
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: from.clone(),
+                    to: to.clone(),
+                    authority,
+                    mint: leg.mint_info.clone(),
+                },
+                &[signer_seeds],
+            ),
+            amount,
+            leg.basket_token.decimals,
+        )
+    }
+}
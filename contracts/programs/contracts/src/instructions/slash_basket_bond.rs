@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, error::BasketError, events::*, state::*};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SlashBasketBond<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        has_one = admin @ BasketError::Unauthorized,
+        has_one = treasury @ BasketError::InvalidTreasury,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(mut)]
+    pub basket: AccountLoader<'info, Basket>,
+
+    /// CHECK: Validated against `config.treasury`.
+    #[account(mut)]
+    pub treasury: UncheckedAccount<'info>,
+}
+
+impl<'info> SlashBasketBond<'info> {
+    /// Confiscate an abusive basket's creation bond to the treasury.
+    pub fn handler(ctx: Context<SlashBasketBond>) -> Result<()> {
+        let mut basket = ctx.accounts.basket.load_current_mut()?;
+        let amount = basket.bond_lamports;
+        require!(amount > 0, BasketError::NoBondPosted);
+        basket.bond_lamports = 0;
+        drop(basket);
+
+        let basket_info = ctx.accounts.basket.to_account_info();
+        let treasury_info = ctx.accounts.treasury.to_account_info();
+        basket_info.sub_lamports(amount)?;
+        treasury_info.add_lamports(amount)?;
+
+        emit_cpi!(BasketBondSlashed {
+            basket: ctx.accounts.basket.key(),
+            treasury: ctx.accounts.treasury.key(),
+            amount,
+        });
+
+        Ok(())
+    }
+}
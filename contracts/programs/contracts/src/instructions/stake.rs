@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{
+    constants::*, error::BasketError, events::*, instructions::lock_shares::transfer_shares,
+    state::*,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct Stake<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub basket: AccountLoader<'info, Basket>,
+
+    #[account(
+        mut,
+        has_one = basket @ BasketError::InvalidBasketWiring,
+        seeds = [REWARD_POOL_SEED, basket.key().as_ref(), reward_pool.reward_mint.as_ref()],
+        bump = reward_pool.bump,
+    )]
+    pub reward_pool: Box<Account<'info, RewardPool>>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + StakePosition::INIT_SPACE,
+        seeds = [STAKE_POSITION_SEED, reward_pool.key().as_ref(), owner.key().as_ref()],
+        bump,
+    )]
+    pub stake_position: Box<Account<'info, StakePosition>>,
+
+    #[account(
+        address = basket.load_current()?.share_mint @ BasketError::ShareMintMismatch,
+        mint::token_program = share_token_program,
+    )]
+    pub share_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = share_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = share_token_program,
+    )]
+    pub owner_share_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Vault authority PDA — owns the staked QSHARE.
+    /// CHECK: Must match the basket's vault authority.
+    #[account(address = basket.load_current()?.vault_authority @ BasketError::VaultAuthMismatch)]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    /// The vault authority's QSHARE account, shared with lock positions;
+    /// balances are tracked per position.
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = share_mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = share_token_program,
+    )]
+    pub stake_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Token program owning the QSHARE mint (SPL Token or Token-2022).
+    pub share_token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> Stake<'info> {
+    /// Stake `amount` QSHARE committed for `lock_duration_secs`. A top-up
+    /// keeps the later of the old and new unlock times, and the multiplier
+    /// is re-selected from the time left until then. Remaining accounts
+    /// carry the share transfer-hook's extra accounts, if any.
+    pub fn handler(
+        ctx: Context<'_, '_, 'info, 'info, Stake<'info>>,
+        amount: u64,
+        lock_duration_secs: i64,
+    ) -> Result<()> {
+        require!(amount > 0, BasketError::ZeroStakeAmount);
+        require!(
+            (0..=MAX_LOCK_DURATION_SECS).contains(&lock_duration_secs),
+            BasketError::InvalidLockDuration
+        );
+
+        let accounts = &ctx.accounts;
+        transfer_shares(
+            &accounts.share_token_program,
+            accounts.owner_share_ata.to_account_info(),
+            &accounts.share_mint,
+            accounts.stake_vault.to_account_info(),
+            accounts.owner.to_account_info(),
+            ctx.remaining_accounts,
+            amount,
+            &[],
+        )?;
+
+        let now = Clock::get()?.unix_timestamp;
+        let pool_key = ctx.accounts.reward_pool.key();
+        let pool = &mut ctx.accounts.reward_pool;
+        let position = &mut ctx.accounts.stake_position;
+        if position.pool == Pubkey::default() {
+            position.pool = pool_key;
+            position.owner = ctx.accounts.owner.key();
+            position.bump = ctx.bumps.stake_position;
+        }
+
+        position.sync(pool, now)?;
+
+        let unlock_ts = position.unlock_ts.max(
+            now.checked_add(lock_duration_secs)
+                .ok_or(BasketError::ArithmeticOverflow)?,
+        );
+        let total = position
+            .amount
+            .checked_add(amount)
+            .ok_or(BasketError::ArithmeticOverflow)?;
+        position.unlock_ts = unlock_ts;
+        let multiplier_bps = pool.multiplier_for(unlock_ts - now);
+        position.reweight(pool, total, multiplier_bps)?;
+        let weight = position.weight;
+
+        emit_cpi!(SharesStaked {
+            pool: pool_key,
+            owner: ctx.accounts.owner.key(),
+            amount,
+            weight,
+            unlock_ts,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::BasketError, events::*, state::*};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct TransferBasketOwnership<'info> {
+    /// Owner only: unlike other basket-scoped actions the admin can't
+    /// reassign a curator's basket.
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = basket.load_current()?.owner == owner.key() @ BasketError::Unauthorized,
+    )]
+    pub basket: AccountLoader<'info, Basket>,
+}
+
+impl<'info> TransferBasketOwnership<'info> {
+    /// Nominate `new_owner`, who takes over on `accept_basket_ownership`.
+    /// A later nomination replaces this one; `Pubkey::default()` cancels.
+    pub fn handler(ctx: Context<TransferBasketOwnership>, new_owner: Pubkey) -> Result<()> {
+        ctx.accounts.basket.load_current_mut()?.pending_owner = new_owner;
+
+        emit_cpi!(BasketOwnershipTransferStarted {
+            basket: ctx.accounts.basket.key(),
+            owner: ctx.accounts.owner.key(),
+            pending_owner: new_owner,
+        });
+
+        Ok(())
+    }
+}
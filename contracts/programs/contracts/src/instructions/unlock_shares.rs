@@ -0,0 +1,141 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Burn, Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    constants::*, error::BasketError, events::*, instructions::lock_shares::transfer_shares,
+    state::*,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UnlockShares<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub basket: AccountLoader<'info, Basket>,
+
+    #[account(
+        mut,
+        close = owner,
+        has_one = owner @ BasketError::Unauthorized,
+        seeds = [LOCK_POSITION_SEED, basket.key().as_ref(), owner.key().as_ref()],
+        bump = lock_position.bump,
+    )]
+    pub lock_position: Box<Account<'info, LockPosition>>,
+
+    #[account(
+        mut,
+        address = basket.load_current()?.share_mint @ BasketError::ShareMintMismatch,
+        mint::token_program = share_token_program,
+    )]
+    pub share_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = share_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = share_token_program,
+    )]
+    pub owner_share_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Vault authority PDA — signs transfers and burns out of the escrow.
+    /// CHECK: Must match the basket's vault authority.
+    #[account(address = basket.load_current()?.vault_authority @ BasketError::VaultAuthMismatch)]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = share_mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = share_token_program,
+    )]
+    pub lock_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Token program owning the QSHARE mint (SPL Token or Token-2022).
+    pub share_token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> UnlockShares<'info> {
+    /// Return a matured position in full and close it.
+    pub fn handler(ctx: Context<'_, '_, 'info, 'info, UnlockShares<'info>>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            now >= ctx.accounts.lock_position.unlock_ts,
+            BasketError::LockNotMatured
+        );
+        Self::release(ctx, 0)
+    }
+
+    /// Leave before maturity: the position's tier penalty is burned, which
+    /// raises NAV for every remaining holder, and the rest is returned.
+    /// A matured position exits without penalty.
+    pub fn exit_early_handler(ctx: Context<'_, '_, 'info, 'info, UnlockShares<'info>>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let position = &ctx.accounts.lock_position;
+        let penalty = if now >= position.unlock_ts {
+            0
+        } else {
+            ((position.amount as u128)
+                .checked_mul(position.early_exit_penalty_bps as u128)
+                .ok_or(BasketError::ArithmeticOverflow)?
+                / BPS_DENOMINATOR as u128) as u64
+        };
+        Self::release(ctx, penalty)
+    }
+
+    fn release(
+        ctx: Context<'_, '_, 'info, 'info, UnlockShares<'info>>,
+        penalty: u64,
+    ) -> Result<()> {
+        let accounts = &ctx.accounts;
+        let amount = accounts.lock_position.amount;
+        let amount_returned = amount
+            .checked_sub(penalty)
+            .ok_or(BasketError::ArithmeticOverflow)?;
+
+        let basket = accounts.basket.load_current()?;
+        let basket_id_bytes = basket.basket_id.to_le_bytes();
+        let vault_auth_seeds: &[&[u8]] = &[
+            VAULT_AUTHORITY_SEED,
+            basket_id_bytes.as_ref(),
+            &[basket.vault_authority_bump],
+        ];
+
+        if penalty > 0 {
+            token_interface::burn(
+                CpiContext::new_with_signer(
+                    accounts.share_token_program.to_account_info(),
+                    Burn {
+                        mint: accounts.share_mint.to_account_info(),
+                        from: accounts.lock_vault.to_account_info(),
+                        authority: accounts.vault_authority.to_account_info(),
+                    },
+                    &[vault_auth_seeds],
+                ),
+                penalty,
+            )?;
+        }
+        if amount_returned > 0 {
+            transfer_shares(
+                &accounts.share_token_program,
+                accounts.lock_vault.to_account_info(),
+                &accounts.share_mint,
+                accounts.owner_share_ata.to_account_info(),
+                accounts.vault_authority.to_account_info(),
+                ctx.remaining_accounts,
+                amount_returned,
+                &[vault_auth_seeds],
+            )?;
+        }
+        drop(basket);
+
+        emit_cpi!(SharesUnlocked {
+            basket: accounts.basket.key(),
+            owner: accounts.owner.key(),
+            amount_returned,
+            penalty_burned: penalty,
+        });
+
+        Ok(())
+    }
+}
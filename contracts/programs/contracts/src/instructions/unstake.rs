@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::{
+    constants::*, error::BasketError, events::*, instructions::lock_shares::transfer_shares,
+    state::*,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct Unstake<'info> {
+    pub owner: Signer<'info>,
+
+    pub basket: AccountLoader<'info, Basket>,
+
+    #[account(
+        mut,
+        has_one = basket @ BasketError::InvalidBasketWiring,
+        seeds = [REWARD_POOL_SEED, basket.key().as_ref(), reward_pool.reward_mint.as_ref()],
+        bump = reward_pool.bump,
+    )]
+    pub reward_pool: Box<Account<'info, RewardPool>>,
+
+    #[account(
+        mut,
+        has_one = owner @ BasketError::Unauthorized,
+        seeds = [STAKE_POSITION_SEED, reward_pool.key().as_ref(), owner.key().as_ref()],
+        bump = stake_position.bump,
+    )]
+    pub stake_position: Box<Account<'info, StakePosition>>,
+
+    #[account(
+        address = basket.load_current()?.share_mint @ BasketError::ShareMintMismatch,
+        mint::token_program = share_token_program,
+    )]
+    pub share_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = share_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = share_token_program,
+    )]
+    pub owner_share_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Vault authority PDA — signs the return transfer.
+    /// CHECK: Must match the basket's vault authority.
+    #[account(address = basket.load_current()?.vault_authority @ BasketError::VaultAuthMismatch)]
+    pub vault_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = share_mint,
+        associated_token::authority = vault_authority,
+        associated_token::token_program = share_token_program,
+    )]
+    pub stake_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Token program owning the QSHARE mint (SPL Token or Token-2022).
+    pub share_token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> Unstake<'info> {
+    /// Return `amount` QSHARE once the commitment has ended. Settled
+    /// rewards stay claimable; the remainder is re-weighted at 1x.
+    pub fn handler(ctx: Context<'_, '_, 'info, 'info, Unstake<'info>>, amount: u64) -> Result<()> {
+        require!(amount > 0, BasketError::ZeroStakeAmount);
+
+        let now = Clock::get()?.unix_timestamp;
+        let pool = &mut ctx.accounts.reward_pool;
+        let position = &mut ctx.accounts.stake_position;
+        require!(now >= position.unlock_ts, BasketError::StakeLocked);
+        let remaining = position
+            .amount
+            .checked_sub(amount)
+            .ok_or(BasketError::InsufficientShares)?;
+
+        position.sync(pool, now)?;
+        position.reweight(pool, remaining, BPS_DENOMINATOR as u16)?;
+        let weight = position.weight;
+
+        let accounts = &ctx.accounts;
+        let basket = accounts.basket.load_current()?;
+        let basket_id_bytes = basket.basket_id.to_le_bytes();
+        let vault_auth_seeds: &[&[u8]] = &[
+            VAULT_AUTHORITY_SEED,
+            basket_id_bytes.as_ref(),
+            &[basket.vault_authority_bump],
+        ];
+        transfer_shares(
+            &accounts.share_token_program,
+            accounts.stake_vault.to_account_info(),
+            &accounts.share_mint,
+            accounts.owner_share_ata.to_account_info(),
+            accounts.vault_authority.to_account_info(),
+            ctx.remaining_accounts,
+            amount,
+            &[vault_auth_seeds],
+        )?;
+        drop(basket);
+
+        emit_cpi!(SharesUnstaked {
+            pool: accounts.reward_pool.key(),
+            owner: accounts.owner.key(),
+            amount,
+            weight,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, error::BasketError, events::*, pda, state::*};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateAllowListBatch<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = config.whitelist_auth == authority.key() @ BasketError::Unauthorized,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    pub basket: AccountLoader<'info, Basket>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> UpdateAllowListBatch<'info> {
+    /// Batch form of `update_allow_list`: remaining_accounts carries the
+    /// (writable) `UserAllowList` PDA of each user, in order. Missing entries
+    /// are created, existing ones overwritten.
+    pub fn handler(
+        ctx: Context<'_, '_, 'info, 'info, UpdateAllowListBatch<'info>>,
+        users: Vec<Pubkey>,
+        allowed: Vec<bool>,
+    ) -> Result<()> {
+        require!(!users.is_empty(), BasketError::NoAllowListEntries);
+        require!(
+            allowed.len() == users.len() && ctx.remaining_accounts.len() == users.len(),
+            BasketError::AllowListBatchLengthMismatch
+        );
+
+        let basket = ctx.accounts.basket.key();
+        for ((user, &allowed), info) in users.iter().zip(&allowed).zip(ctx.remaining_accounts) {
+            let (expected, bump) = Pubkey::find_program_address(
+                &[USER_ALLOW_SEED, basket.as_ref(), user.as_ref()],
+                ctx.program_id,
+            );
+            require_keys_eq!(info.key(), expected, BasketError::InvalidAllowListEntry);
+
+            if info.owner == &System::id() {
+                Self::create_entry(&ctx, info, user, bump)?;
+            }
+
+            let mut entry: Account<UserAllowList> = Account::try_from(info)?;
+            entry.set_inner(UserAllowList {
+                basket,
+                user: *user,
+                allowed,
+                bump,
+            });
+            entry.exit(ctx.program_id)?;
+        }
+
+        emit_cpi!(AllowListBatchUpdated {
+            basket,
+            users,
+            allowed,
+        });
+
+        Ok(())
+    }
+
+    /// Allocate an empty `UserAllowList` PDA and stamp its discriminator.
+    fn create_entry(
+        ctx: &Context<'_, '_, 'info, 'info, UpdateAllowListBatch<'info>>,
+        info: &'info AccountInfo<'info>,
+        user: &Pubkey,
+        bump: u8,
+    ) -> Result<()> {
+        let accounts = &ctx.accounts;
+        let basket = accounts.basket.key();
+        pda::create_pda_account(
+            &accounts.authority.to_account_info(),
+            info,
+            &accounts.system_program.to_account_info(),
+            8 + UserAllowList::INIT_SPACE,
+            &[USER_ALLOW_SEED, basket.as_ref(), user.as_ref(), &[bump]],
+            ctx.program_id,
+        )?;
+
+        info.try_borrow_mut_data()?[..8].copy_from_slice(UserAllowList::DISCRIMINATOR);
+        Ok(())
+    }
+}
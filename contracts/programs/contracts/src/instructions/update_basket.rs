@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, error::BasketError, events::*, state::*};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateBasket<'info> {
+    /// Basket owner or protocol admin.
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        constraint = basket.load_current()?.is_manager(&authority.key(), &config.admin)
+            @ BasketError::Unauthorized,
+    )]
+    pub basket: AccountLoader<'info, Basket>,
+}
+
+impl<'info> UpdateBasket<'info> {
+    /// Rename the basket and/or set its fee override; `Some(0)` clears the
+    /// override and `None` leaves a field as is. A new name reaches the
+    /// share metadata on the next `update_basket_metadata`.
+    pub fn handler(
+        ctx: Context<UpdateBasket>,
+        name: Option<[u8; MAX_NAME_LEN]>,
+        fee_bps_override: Option<u16>,
+    ) -> Result<()> {
+        let fee_bps_override = fee_bps_override.map(|bps| (bps != 0).then_some(bps));
+        if let Some(Some(bps)) = fee_bps_override {
+            require!(
+                (FEE_BPS_MIN..=FEE_BPS_MAX).contains(&bps),
+                BasketError::InvalidFee
+            );
+        }
+
+        let mut basket = ctx.accounts.basket.load_current_mut()?;
+        let old_name = basket.name;
+        let old_fee_bps_override = basket.fee_override();
+
+        if let Some(name) = name {
+            basket.name = name;
+        }
+        if let Some(fee_bps_override) = fee_bps_override {
+            basket.set_fee_override(fee_bps_override);
+        }
+
+        let new_name = basket.name;
+        let new_fee_bps_override = basket.fee_override();
+        drop(basket);
+
+        emit_cpi!(BasketUpdated {
+            basket: ctx.accounts.basket.key(),
+            old_name,
+            new_name,
+            old_fee_bps_override,
+            new_fee_bps_override,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, error::BasketError, events::*, state::*};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateBasketLimits<'info> {
+    /// Basket owner or protocol admin.
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        constraint = basket.load_current()?.is_manager(&authority.key(), &config.admin)
+            @ BasketError::Unauthorized,
+    )]
+    pub basket: AccountLoader<'info, Basket>,
+}
+
+impl<'info> UpdateBasketLimits<'info> {
+    /// Set the deposit caps enforced by `deposit_multi` and
+    /// `mint_exact_shares`, and the outflow limiter enforced by
+    /// `withdraw_multi` and `withdraw_exact_amounts`; 0 lifts a cap or the
+    /// limiter. Lowering a cap below current levels only blocks further
+    /// deposits. Any change restarts the outflow window.
+    ///
+    /// A tight limiter can hold holders' exits for as long as the curator
+    /// likes, so only the protocol admin may change it; the owner passes
+    /// the current limiter settings back unchanged.
+    pub fn handler(
+        ctx: Context<UpdateBasketLimits>,
+        max_total_value: u64,
+        max_user_shares: u64,
+        max_withdraw_bps: u16,
+        withdraw_window_secs: i64,
+    ) -> Result<()> {
+        require!(
+            max_withdraw_bps as u64 <= BPS_DENOMINATOR,
+            BasketError::InvalidWithdrawLimit
+        );
+        require!(
+            max_withdraw_bps == 0 || withdraw_window_secs > 0,
+            BasketError::InvalidWithdrawLimit
+        );
+
+        let mut basket = ctx.accounts.basket.load_current_mut()?;
+        require!(
+            (max_withdraw_bps == basket.max_withdraw_bps
+                && withdraw_window_secs == basket.withdraw_window_secs)
+                || ctx.accounts.authority.key() == ctx.accounts.config.admin,
+            BasketError::Unauthorized
+        );
+        basket.max_total_value = max_total_value;
+        basket.max_user_shares = max_user_shares;
+        basket.max_withdraw_bps = max_withdraw_bps;
+        basket.withdraw_window_secs = withdraw_window_secs;
+        basket.window_start_ts = 0;
+        basket.window_start_supply = 0;
+        basket.burned_in_window = 0;
+
+        emit_cpi!(BasketLimitsUpdated {
+            basket: ctx.accounts.basket.key(),
+            max_total_value,
+            max_user_shares,
+            max_withdraw_bps,
+            withdraw_window_secs,
+        });
+
+        Ok(())
+    }
+}
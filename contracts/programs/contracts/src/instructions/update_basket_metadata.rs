@@ -0,0 +1,187 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::spl_token_2022::{
+        extension::{BaseStateWithExtensions, StateWithExtensions},
+        state::Mint as MintState,
+    },
+    token_2022_extensions::{
+        spl_token_metadata_interface::state::{Field, TokenMetadata},
+        token_metadata_update_field, TokenMetadataUpdateField,
+    },
+    token_interface::{Mint, Token2022, TokenInterface},
+};
+
+use crate::{
+    constants::*,
+    error::BasketError,
+    events::*,
+    metadata::{self, MetaplexAccounts, ShareMetadata},
+    state::*,
+};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateBasketMetadata<'info> {
+    /// Basket owner or protocol admin.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        constraint = basket.load_current()?.is_manager(&authority.key(), &config.admin)
+            @ BasketError::Unauthorized,
+        constraint = basket.load_current()?.share_mint == share_mint.key()
+            @ BasketError::ShareMintMismatch,
+    )]
+    pub basket: AccountLoader<'info, Basket>,
+
+    /// Mint authority PDA — metadata update authority.
+    /// CHECK: Validated in handler against the stored bump.
+    pub mint_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        mint::token_program = token_program,
+    )]
+    pub share_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Metaplex metadata PDA — only used for legacy SPL share mints.
+    /// CHECK: Validated by seeds constraint; owned by Metaplex.
+    #[account(
+        mut,
+        seeds = [
+            METADATA_SEED,
+            TOKEN_METADATA_PROGRAM_ID.as_ref(),
+            share_mint.key().as_ref(),
+        ],
+        bump,
+        seeds::program = TOKEN_METADATA_PROGRAM_ID,
+    )]
+    pub share_metadata: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Validated by address constraint.
+    #[account(address = TOKEN_METADATA_PROGRAM_ID)]
+    pub token_metadata_program: Option<UncheckedAccount<'info>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> UpdateBasketMetadata<'info> {
+    /// Republish the share metadata: name is always taken from the basket,
+    /// symbol and URI replace the current values.
+    pub fn handler(ctx: Context<UpdateBasketMetadata>, symbol: String, uri: String) -> Result<()> {
+        let basket = ctx.accounts.basket.load_current()?;
+        let share_metadata = ShareMetadata::from_basket_name(&basket.name, symbol, uri)?;
+
+        let basket_id_bytes = basket.basket_id.to_le_bytes();
+        let mint_auth_seeds: &[&[u8]] = &[
+            MINT_AUTHORITY_SEED,
+            basket_id_bytes.as_ref(),
+            &[basket.mint_authority_bump],
+        ];
+        let expected = Pubkey::create_program_address(mint_auth_seeds, ctx.program_id)
+            .map_err(|_| BasketError::InvalidBasketWiring)?;
+        require!(
+            ctx.accounts.mint_authority.key() == expected,
+            BasketError::InvalidBasketWiring
+        );
+
+        if ctx.accounts.token_program.key() == Token2022::id() {
+            Self::update_token_metadata(&ctx, &share_metadata, mint_auth_seeds)?;
+        } else {
+            Self::update_metaplex_metadata(&ctx, &share_metadata, mint_auth_seeds)?;
+        }
+
+        emit_cpi!(BasketMetadataUpdated {
+            basket: ctx.accounts.basket.key(),
+            share_mint: ctx.accounts.share_mint.key(),
+            symbol: share_metadata.symbol,
+            uri: share_metadata.uri,
+        });
+
+        Ok(())
+    }
+
+    fn update_token_metadata(
+        ctx: &Context<UpdateBasketMetadata>,
+        share_metadata: &ShareMetadata,
+        mint_auth_seeds: &[&[u8]],
+    ) -> Result<()> {
+        let accounts = &ctx.accounts;
+        let share_mint = accounts.share_mint.to_account_info();
+
+        // Fields may grow; top up rent before Token-2022 reallocs the mint.
+        let new_len = {
+            let data = share_mint.try_borrow_data()?;
+            let mint = StateWithExtensions::<MintState>::unpack(&data)?;
+            let current = mint.get_variable_len_extension::<TokenMetadata>()?;
+            let mut updated = current.clone();
+            updated.update(Field::Name, share_metadata.name.clone());
+            updated.update(Field::Symbol, share_metadata.symbol.clone());
+            updated.update(Field::Uri, share_metadata.uri.clone());
+            (data.len() - current.tlv_size_of()?)
+                .checked_add(updated.tlv_size_of()?)
+                .ok_or(BasketError::ArithmeticOverflow)?
+        };
+        metadata::top_up_rent(
+            &accounts.authority.to_account_info(),
+            &share_mint,
+            &accounts.system_program.to_account_info(),
+            new_len,
+        )?;
+
+        for (field, value) in [
+            (Field::Name, &share_metadata.name),
+            (Field::Symbol, &share_metadata.symbol),
+            (Field::Uri, &share_metadata.uri),
+        ] {
+            token_metadata_update_field(
+                CpiContext::new_with_signer(
+                    accounts.token_program.to_account_info(),
+                    TokenMetadataUpdateField {
+                        program_id: accounts.token_program.to_account_info(),
+                        metadata: share_mint.clone(),
+                        update_authority: accounts.mint_authority.to_account_info(),
+                    },
+                    &[mint_auth_seeds],
+                ),
+                field,
+                value.clone(),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn update_metaplex_metadata(
+        ctx: &Context<UpdateBasketMetadata>,
+        share_metadata: &ShareMetadata,
+        mint_auth_seeds: &[&[u8]],
+    ) -> Result<()> {
+        let accounts = &ctx.accounts;
+        let (Some(metadata_account), Some(token_metadata_program)) =
+            (&accounts.share_metadata, &accounts.token_metadata_program)
+        else {
+            return err!(BasketError::MissingMetadataAccounts);
+        };
+
+        metadata::update_metaplex_metadata(
+            &MetaplexAccounts {
+                metadata: &metadata_account.to_account_info(),
+                mint: &accounts.share_mint.to_account_info(),
+                mint_authority: &accounts.mint_authority.to_account_info(),
+                payer: &accounts.authority.to_account_info(),
+                system_program: &accounts.system_program.to_account_info(),
+                token_metadata_program: &token_metadata_program.to_account_info(),
+            },
+            share_metadata,
+            mint_auth_seeds,
+        )
+    }
+}
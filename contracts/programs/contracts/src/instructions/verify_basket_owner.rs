@@ -23,7 +23,7 @@ impl<'info> VerifyBasketOwner<'info> {
         expected_owner: Pubkey,
     ) -> Result<()> {
         let accounts = &ctx.accounts;
-        let basket = accounts.basket.load()?;
+        let basket = accounts.basket.load_current()?;
 
         require!(
             basket.version == CURRENT_VERSION,
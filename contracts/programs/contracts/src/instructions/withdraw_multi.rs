@@ -1,7 +1,14 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{self, Burn, Mint, TokenAccount, TokenInterface, TransferChecked};
 
-use crate::{constants::*, error::BasketError, events::*, state::*};
+use crate::{
+    constants::*,
+    error::BasketError,
+    events::*,
+    legs::{self, LegAccounts, UserAtaOwner, ValidatedLeg},
+    pricing::{self, LegState, WithdrawQuote},
+    state::*,
+};
 
 #[event_cpi]
 #[derive(Accounts)]
@@ -15,46 +22,77 @@ pub struct WithdrawMulti<'info> {
     )]
     pub config: Box<Account<'info, Config>>,
 
+    /// Mutable for the outflow limiter's window counters.
+    #[account(mut)]
     pub basket: AccountLoader<'info, Basket>,
 
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [BASKET_PRICE_SEED, basket.key().as_ref()],
+        bump = basket_price.load()?.bump,
+    )]
+    pub basket_price: AccountLoader<'info, BasketPrice>,
+
+    #[account(
+        mut,
+        mint::token_program = share_token_program,
+    )]
     pub share_mint: Box<InterfaceAccount<'info, Mint>>,
 
     #[account(
         mut,
         associated_token::mint = share_mint,
         associated_token::authority = user,
-        associated_token::token_program = token_program,
+        associated_token::token_program = share_token_program,
     )]
     pub user_share_ata: Box<InterfaceAccount<'info, TokenAccount>>,
 
+    /// Owner of every payout token account; may differ from the signer.
+    /// CHECK: Payout ATAs are validated against this key per leg.
+    pub receiver: UncheckedAccount<'info>,
+
     /// Vault authority PDA — signs transfers out.
     /// CHECK: Validated via `validate_vault_authority`.
     pub vault_authority: UncheckedAccount<'info>,
 
     pub token_program: Interface<'info, TokenInterface>,
+    /// Token program owning the QSHARE mint (SPL Token or Token-2022).
+    pub share_token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
 
-/// Validated token-leg accounts extracted from remaining_accounts.
-struct WithdrawLeg<'info> {
-    basket_token: Account<'info, BasketToken>,
-    mint_info: &'info AccountInfo<'info>,
-    vault_ata_info: &'info AccountInfo<'info>,
-    user_ata_info: &'info AccountInfo<'info>,
-}
-
 impl<'info> WithdrawMulti<'info> {
     pub fn handler(
         ctx: Context<'_, '_, 'info, 'info, WithdrawMulti<'info>>,
         shares_to_burn: u64,
+    ) -> Result<()> {
+        Self::execute(ctx, |legs, total_supply, mode| {
+            pricing::quote_withdraw(shares_to_burn, legs, total_supply, mode)
+        })
+    }
+
+    /// Burn the fewest shares whose pro-rata payout covers `amounts_out`,
+    /// bounded by `max_shares_in`. Legs with a zero request still pay out
+    /// their pro-rata slice.
+    pub fn withdraw_exact_amounts_handler(
+        ctx: Context<'_, '_, 'info, 'info, WithdrawMulti<'info>>,
+        amounts_out: Vec<u64>,
+        max_shares_in: u64,
+    ) -> Result<()> {
+        Self::execute(ctx, |legs, total_supply, mode| {
+            pricing::quote_withdraw_exact(&amounts_out, max_shares_in, legs, total_supply, mode)
+        })
+    }
+
+    fn execute(
+        ctx: Context<'_, '_, 'info, 'info, WithdrawMulti<'info>>,
+        quote: impl FnOnce(&[LegState], u64, u8) -> Result<WithdrawQuote>,
     ) -> Result<()> {
         let accounts = &ctx.accounts;
         let remaining = ctx.remaining_accounts;
 
-        let basket = accounts.basket.load()?;
+        let basket = accounts.basket.load_current()?;
         let num_tokens = Self::validate_remaining_layout(remaining.len(), basket.token_count)?;
-        Self::validate_burn_input(accounts, shares_to_burn)?;
         Self::validate_share_mint(accounts, &basket)?;
 
         let basket_id_bytes = basket.basket_id.to_le_bytes();
@@ -64,35 +102,87 @@ impl<'info> WithdrawMulti<'info> {
             &[basket.vault_authority_bump],
         ];
         Self::validate_vault_authority(accounts, vault_auth_seeds, ctx.program_id)?;
+        let redeem_pricing_mode = basket.redeem_pricing_mode;
         drop(basket);
 
-        let total_supply = accounts.share_mint.supply;
-
-        Self::burn_shares(accounts, shares_to_burn)?;
+        let mut legs = (0..num_tokens)
+            .map(|i| Self::parse_and_validate_leg(accounts, remaining, i))
+            .collect::<Result<Vec<_>>>()?;
+        legs::require_distinct_legs(
+            &legs
+                .iter()
+                .map(|leg| leg.basket_token.key())
+                .collect::<Vec<_>>(),
+        )?;
+        let clock = Clock::get()?;
+        let leg_states = legs
+            .iter_mut()
+            .map(|leg| {
+                let vault_balance = pricing::read_vault_balance(leg.vault_ata_info)?;
+                Ok(pricing::observe_leg(
+                    &mut leg.basket_token,
+                    vault_balance,
+                    clock.unix_timestamp,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let quote = quote(&leg_states, accounts.share_mint.supply, redeem_pricing_mode)?;
+        let shares_to_burn = quote.shares_burned;
+        Self::validate_burn_input(accounts, shares_to_burn)?;
 
-        for i in 0..num_tokens {
-            let leg = Self::parse_and_validate_leg(
-                remaining,
-                i,
-                accounts.basket.key(),
-            )?;
+        let basket_key = accounts.basket.key();
+        accounts.basket.load_current_mut()?.consume_outflow(
+            basket_key,
+            shares_to_burn,
+            accounts.share_mint.supply,
+            clock.unix_timestamp,
+        )?;
 
-            let amount_out = Self::compute_proportional_payout(
-                &leg,
-                shares_to_burn,
-                total_supply,
-            )?;
+        Self::burn_shares(accounts, shares_to_burn)?;
 
+        for (leg, &amount_out) in legs.iter().zip(&quote.amounts_out) {
             if amount_out == 0 {
                 continue;
             }
 
-            Self::transfer_from_vault(accounts, &leg, vault_auth_seeds, amount_out)?;
+            Self::transfer_from_vault(accounts, leg, vault_auth_seeds, amount_out)?;
+        }
+
+        let post_states = leg_states
+            .iter()
+            .zip(&quote.amounts_out)
+            .map(|(leg, &amount_out)| {
+                Ok(LegState {
+                    vault_balance: leg
+                        .vault_balance
+                        .checked_sub(amount_out)
+                        .ok_or(BasketError::ArithmeticOverflow)?,
+                    ..*leg
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let total_supply = accounts
+            .share_mint
+            .supply
+            .checked_sub(shares_to_burn)
+            .ok_or(BasketError::ArithmeticOverflow)?;
+        for (leg, post_state) in legs.iter_mut().zip(&post_states) {
+            pricing::record_leg_price(
+                &mut leg.basket_token,
+                post_state,
+                total_supply,
+                ctx.program_id,
+            )?;
         }
+        accounts
+            .basket_price
+            .load_mut()?
+            .publish(&pricing::basket_nav(&post_states, total_supply)?, &clock);
 
         emit_cpi!(WithdrawCompleted {
             basket: accounts.basket.key(),
             user: accounts.user.key(),
+            receiver: accounts.receiver.key(),
             shares_burned: shares_to_burn,
         });
 
@@ -149,61 +239,22 @@ impl<'info> WithdrawMulti<'info> {
     }
 
     fn parse_and_validate_leg(
+        accounts: &WithdrawMulti<'info>,
         remaining: &'info [AccountInfo<'info>],
         index: usize,
-        basket_key: Pubkey,
-    ) -> Result<WithdrawLeg<'info>> {
+    ) -> Result<ValidatedLeg<'info>> {
         let base = index * WITHDRAW_ACCOUNTS_PER_TOKEN;
-        let basket_token: Account<BasketToken> = Account::try_from(&remaining[base])?;
-
-        require!(
-            basket_token.basket == basket_key,
-            BasketError::InvalidBasketWiring
-        );
-        require!(basket_token.enabled, BasketError::TokenNotEnabled);
-
-        let mint_info = &remaining[base + 1];
-        require!(
-            mint_info.key() == basket_token.mint,
-            BasketError::MintConfigMismatch
-        );
-
-        let vault_ata_info = &remaining[base + 2];
-        require!(
-            vault_ata_info.key() == basket_token.vault_ata,
-            BasketError::InvalidBasketWiring
-        );
-
-        Ok(WithdrawLeg {
-            basket_token,
-            mint_info,
-            vault_ata_info,
-            user_ata_info: &remaining[base + 3],
-        })
-    }
-
-    /// `amount_out = vault_balance * shares_to_burn / total_supply`
-    fn compute_proportional_payout(
-        leg: &WithdrawLeg<'info>,
-        shares_to_burn: u64,
-        total_supply: u64,
-    ) -> Result<u64> {
-        let vault_balance = Self::read_vault_balance(leg.vault_ata_info)?;
-
-        let amount_out = (vault_balance as u128)
-            .checked_mul(shares_to_burn as u128)
-            .ok_or(BasketError::ArithmeticOverflow)?
-            .checked_div(total_supply as u128)
-            .ok_or(BasketError::ArithmeticOverflow)? as u64;
-
-        Ok(amount_out)
-    }
-
-    fn read_vault_balance<'a>(vault_ata_info: &'a AccountInfo<'a>) -> Result<u64> {
-        let vault_ata: InterfaceAccount<TokenAccount> =
-            InterfaceAccount::try_from(vault_ata_info)
-                .map_err(|_| BasketError::InvalidBasketWiring)?;
-        Ok(vault_ata.amount)
+        legs::validate_leg(
+            LegAccounts {
+                basket_token: &remaining[base],
+                mint: &remaining[base + 1],
+                vault_ata: &remaining[base + 2],
+                user_ata: &remaining[base + 3],
+            },
+            accounts.basket.key(),
+            UserAtaOwner::Receiver(accounts.receiver.key()),
+            &accounts.token_program.key(),
+        )
     }
 
     fn burn_shares(
@@ -212,7 +263,7 @@ impl<'info> WithdrawMulti<'info> {
     ) -> Result<()> {
         token_interface::burn(
             CpiContext::new(
-                accounts.token_program.to_account_info(),
+                accounts.share_token_program.to_account_info(),
                 Burn {
                     mint: accounts.share_mint.to_account_info(),
                     from: accounts.user_share_ata.to_account_info(),
@@ -225,7 +276,7 @@ impl<'info> WithdrawMulti<'info> {
 
     fn transfer_from_vault(
         accounts: &WithdrawMulti<'info>,
-        leg: &WithdrawLeg<'info>,
+        leg: &ValidatedLeg<'info>,
         vault_auth_seeds: &[&[u8]],
         amount: u64,
     ) -> Result<()> {
@@ -0,0 +1,188 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::get_associated_token_address_with_program_id,
+    token_2022::spl_token_2022::{
+        extension::{
+            default_account_state::DefaultAccountState, mint_close_authority::MintCloseAuthority,
+            BaseStateWithExtensions, ExtensionType, StateWithExtensions,
+        },
+        state::{AccountState, Mint as MintState},
+    },
+    token_interface::TokenAccount,
+};
+
+use crate::{constants::*, error::BasketError, state::BasketToken};
+
+// Validation of the per-token accounts passed through remaining_accounts,
+// shared by every instruction that moves basket legs.
+
+/// Raw accounts for one basket leg, picked out of an instruction-specific
+/// remaining_accounts layout.
+pub struct LegAccounts<'info> {
+    pub basket_token: &'info AccountInfo<'info>,
+    pub mint: &'info AccountInfo<'info>,
+    pub vault_ata: &'info AccountInfo<'info>,
+    pub user_ata: &'info AccountInfo<'info>,
+}
+
+/// A leg whose accounts all match its `BasketToken`.
+pub struct ValidatedLeg<'info> {
+    pub basket_token: Account<'info, BasketToken>,
+    pub mint_info: &'info AccountInfo<'info>,
+    pub vault_ata_info: &'info AccountInfo<'info>,
+    /// Token account of the signer (deposits) or receiver (withdrawals).
+    pub user_ata_info: &'info AccountInfo<'info>,
+}
+
+/// Expected owner of the user-side token account of a leg.
+#[derive(Clone, Copy)]
+pub enum UserAtaOwner {
+    Signer(Pubkey),
+    Receiver(Pubkey),
+}
+
+pub fn validate_leg<'info>(
+    accounts: LegAccounts<'info>,
+    basket_key: Pubkey,
+    user_ata_owner: UserAtaOwner,
+    token_program: &Pubkey,
+) -> Result<ValidatedLeg<'info>> {
+    let basket_token = load_basket_token(accounts.basket_token, basket_key)?;
+
+    require!(
+        accounts.mint.key() == basket_token.mint,
+        BasketError::MintConfigMismatch
+    );
+    require!(
+        accounts.vault_ata.key() == basket_token.vault_ata,
+        BasketError::InvalidBasketWiring
+    );
+    validate_user_ata(
+        accounts.user_ata,
+        &basket_token.mint,
+        user_ata_owner,
+        token_program,
+    )?;
+
+    Ok(ValidatedLeg {
+        basket_token,
+        mint_info: accounts.mint,
+        vault_ata_info: accounts.vault_ata,
+        user_ata_info: accounts.user_ata,
+    })
+}
+
+/// Deserialize an enabled `BasketToken` belonging to `basket_key`.
+pub fn load_basket_token<'info>(
+    info: &'info AccountInfo<'info>,
+    basket_key: Pubkey,
+) -> Result<Account<'info, BasketToken>> {
+    let basket_token: Account<BasketToken> = Account::try_from(info)?;
+    require!(
+        basket_token.basket == basket_key,
+        BasketError::InvalidBasketWiring
+    );
+    require!(basket_token.enabled, BasketError::TokenNotEnabled);
+    Ok(basket_token)
+}
+
+/// The user-side account must be a token account of the leg's token
+/// program, for the leg's mint, owned by the signer or declared receiver.
+pub fn validate_user_ata<'info>(
+    info: &'info AccountInfo<'info>,
+    mint: &Pubkey,
+    owner: UserAtaOwner,
+    token_program: &Pubkey,
+) -> Result<()> {
+    require!(
+        info.owner == token_program,
+        BasketError::InvalidTokenProgramOwner
+    );
+    let token_account: InterfaceAccount<TokenAccount> = InterfaceAccount::try_from(info)
+        .map_err(|_| BasketError::InvalidTokenProgramOwner)?;
+
+    require!(
+        token_account.mint == *mint,
+        BasketError::UserAtaMintMismatch
+    );
+    match owner {
+        UserAtaOwner::Signer(key) => require!(
+            token_account.owner == key,
+            BasketError::UserAtaOwnerMismatch
+        ),
+        UserAtaOwner::Receiver(key) => require!(
+            token_account.owner == key,
+            BasketError::ReceiverMismatch
+        ),
+    }
+    Ok(())
+}
+
+/// An escrow must be the ATA of `authority` for the leg's mint, so its
+/// address alone binds it to the queue or epoch that signs for it.
+pub fn validate_escrow_ata(
+    info: &AccountInfo,
+    authority: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+) -> Result<()> {
+    require!(
+        info.key() == get_associated_token_address_with_program_id(authority, mint, token_program),
+        BasketError::InvalidEscrowAccount
+    );
+    Ok(())
+}
+
+/// Reject a layout that names the same `BasketToken` twice, which would let
+/// it stand in for a leg it skipped.
+pub fn require_distinct_legs(basket_tokens: &[Pubkey]) -> Result<()> {
+    for (i, key) in basket_tokens.iter().enumerate() {
+        require!(
+            !basket_tokens[..i].contains(key),
+            BasketError::DuplicateLeg
+        );
+    }
+    Ok(())
+}
+
+/// Collect the `MINT_EXT_*` flags for every risky authority or Token-2022
+/// extension present on the mint. Legacy SPL mints only ever report
+/// `MINT_EXT_FREEZE_AUTHORITY`.
+pub fn inspect_mint_extensions(mint_info: &AccountInfo) -> Result<u8> {
+    let data = mint_info.try_borrow_data()?;
+    let mint = StateWithExtensions::<MintState>::unpack(&data)?;
+
+    let mut flags = 0u8;
+    if mint.base.freeze_authority.is_some() {
+        flags |= MINT_EXT_FREEZE_AUTHORITY;
+    }
+
+    for extension in mint.get_extension_types()? {
+        flags |= match extension {
+            ExtensionType::PermanentDelegate => MINT_EXT_PERMANENT_DELEGATE,
+            ExtensionType::TransferHook => MINT_EXT_TRANSFER_HOOK,
+            ExtensionType::NonTransferable => MINT_EXT_NON_TRANSFERABLE,
+            ExtensionType::TransferFeeConfig => MINT_EXT_TRANSFER_FEE,
+            ExtensionType::Pausable => MINT_EXT_PAUSABLE,
+            ExtensionType::MintCloseAuthority => {
+                let close_authority = mint.get_extension::<MintCloseAuthority>()?.close_authority;
+                if Option::<Pubkey>::from(close_authority).is_some() {
+                    MINT_EXT_CLOSE_AUTHORITY
+                } else {
+                    0
+                }
+            }
+            ExtensionType::DefaultAccountState => {
+                let default_state = mint.get_extension::<DefaultAccountState>()?;
+                if default_state.state == AccountState::Frozen as u8 {
+                    MINT_EXT_DEFAULT_FROZEN
+                } else {
+                    0
+                }
+            }
+            _ => 0,
+        };
+    }
+
+    Ok(flags)
+}
@@ -1,14 +1,21 @@
+pub mod compliance;
 pub mod constants;
 pub mod error;
 pub mod events;
 pub mod instructions;
+pub mod legs;
+pub mod metadata;
+pub mod pda;
+pub mod pricing;
 pub mod state;
 
 use anchor_lang::prelude::*;
 
+pub use compliance::ComplianceProof;
 pub use constants::*;
 pub use events::*;
 pub use instructions::*;
+pub use pricing::{BasketNav, DepositLegQuote, DepositQuote, WithdrawQuote};
 pub use state::*;
 
 declare_id!("2rQ6Auaeqovph67yWxhFpuhitrJJkGU3jrZwUUSYJKs6");
@@ -32,8 +39,16 @@ pub mod contracts {
         whitelist_auth: Option<Pubkey>,
         compliance_enabled: Option<bool>,
         new_admin: Option<Pubkey>,
+        mint_extension_policy: Option<u8>,
     ) -> Result<()> {
-        SetConfig::handler(ctx, fee_bps, whitelist_auth, compliance_enabled, new_admin)
+        SetConfig::handler(
+            ctx,
+            fee_bps,
+            whitelist_auth,
+            compliance_enabled,
+            new_admin,
+            mint_extension_policy,
+        )
     }
 
     pub fn create_basket(
@@ -41,23 +56,161 @@ pub mod contracts {
         basket_id: u64,
         name: [u8; MAX_NAME_LEN],
         fee_bps_override: Option<u16>,
+        share_transfer_hook: bool,
+        symbol: String,
+        uri: String,
     ) -> Result<()> {
-        CreateBasket::handler(ctx, basket_id, &name, fee_bps_override)
+        CreateBasket::handler(
+            ctx,
+            basket_id,
+            &name,
+            fee_bps_override,
+            share_transfer_hook,
+            symbol,
+            uri,
+        )
     }
 
-    pub fn add_tokens(ctx: Context<AddTokens>) -> Result<()> {
-        AddTokens::handler(ctx)
+    pub fn set_creation_policy(
+        ctx: Context<SetCreationPolicy>,
+        permissionless_creation: bool,
+        creation_fee_lamports: u64,
+        creation_bond_lamports: u64,
+        treasury: Pubkey,
+    ) -> Result<()> {
+        SetCreationPolicy::handler(
+            ctx,
+            permissionless_creation,
+            creation_fee_lamports,
+            creation_bond_lamports,
+            treasury,
+        )
+    }
+
+    pub fn slash_basket_bond(ctx: Context<SlashBasketBond>) -> Result<()> {
+        SlashBasketBond::handler(ctx)
+    }
+
+    pub fn migrate_config(ctx: Context<MigrateConfig>, next_basket_id: u64) -> Result<()> {
+        MigrateConfig::handler(ctx, next_basket_id)
+    }
+
+    pub fn migrate_basket<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MigrateBasket<'info>>,
+    ) -> Result<()> {
+        MigrateBasket::handler(ctx)
+    }
+
+    pub fn update_basket(
+        ctx: Context<UpdateBasket>,
+        name: Option<[u8; MAX_NAME_LEN]>,
+        fee_bps_override: Option<u16>,
+    ) -> Result<()> {
+        UpdateBasket::handler(ctx, name, fee_bps_override)
+    }
+
+    pub fn transfer_basket_ownership(
+        ctx: Context<TransferBasketOwnership>,
+        new_owner: Pubkey,
+    ) -> Result<()> {
+        TransferBasketOwnership::handler(ctx, new_owner)
+    }
+
+    pub fn accept_basket_ownership(ctx: Context<AcceptBasketOwnership>) -> Result<()> {
+        AcceptBasketOwnership::handler(ctx)
+    }
+
+    pub fn close_basket<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CloseBasket<'info>>,
+    ) -> Result<()> {
+        CloseBasket::handler(ctx)
+    }
+
+    pub fn update_basket_metadata(
+        ctx: Context<UpdateBasketMetadata>,
+        symbol: String,
+        uri: String,
+    ) -> Result<()> {
+        UpdateBasketMetadata::handler(ctx, symbol, uri)
+    }
+
+    pub fn set_basket_pricing(
+        ctx: Context<SetBasketPricing>,
+        mint_pricing_mode: u8,
+        redeem_pricing_mode: u8,
+    ) -> Result<()> {
+        SetBasketPricing::handler(ctx, mint_pricing_mode, redeem_pricing_mode)
+    }
+
+    pub fn update_basket_limits(
+        ctx: Context<UpdateBasketLimits>,
+        max_total_value: u64,
+        max_user_shares: u64,
+        max_withdraw_bps: u16,
+        withdraw_window_secs: i64,
+    ) -> Result<()> {
+        UpdateBasketLimits::handler(
+            ctx,
+            max_total_value,
+            max_user_shares,
+            max_withdraw_bps,
+            withdraw_window_secs,
+        )
+    }
+
+    pub fn set_emergency_mode(ctx: Context<SetEmergencyMode>, enabled: bool) -> Result<()> {
+        SetEmergencyMode::handler(ctx, enabled)
+    }
+
+    pub fn add_tokens(ctx: Context<AddTokens>, acknowledged_extensions: u8) -> Result<()> {
+        AddTokens::handler(ctx, acknowledged_extensions)
     }
 
     pub fn update_allow_list(ctx: Context<UpdateAllowList>, allowed: bool) -> Result<()> {
         UpdateAllowList::handler(ctx, allowed)
     }
 
+    pub fn update_allow_list_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, UpdateAllowListBatch<'info>>,
+        users: Vec<Pubkey>,
+        allowed: Vec<bool>,
+    ) -> Result<()> {
+        UpdateAllowListBatch::handler(ctx, users, allowed)
+    }
+
+    pub fn set_compliance_root(
+        ctx: Context<SetComplianceRoot>,
+        root: [u8; 32],
+        version: u64,
+    ) -> Result<()> {
+        SetComplianceRoot::handler(ctx, root, version)
+    }
+
+    pub fn close_allow_list_entry(ctx: Context<CloseAllowListEntry>) -> Result<()> {
+        CloseAllowListEntry::handler(ctx)
+    }
+
+    pub fn close_allow_list_entries<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CloseAllowListEntries<'info>>,
+    ) -> Result<()> {
+        CloseAllowListEntries::handler(ctx)
+    }
+
     pub fn deposit_multi<'info>(
         ctx: Context<'_, '_, 'info, 'info, DepositMulti<'info>>,
         amounts: Vec<u64>,
+        compliance_proof: Option<ComplianceProof>,
     ) -> Result<()> {
-        DepositMulti::handler(ctx, amounts)
+        DepositMulti::handler(ctx, amounts, compliance_proof)
+    }
+
+    pub fn mint_exact_shares<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DepositMulti<'info>>,
+        shares: u64,
+        max_amounts_in: Vec<u64>,
+        compliance_proof: Option<ComplianceProof>,
+    ) -> Result<()> {
+        DepositMulti::mint_exact_shares_handler(ctx, shares, max_amounts_in, compliance_proof)
     }
 
     pub fn withdraw_multi<'info>(
@@ -67,6 +220,141 @@ pub mod contracts {
         WithdrawMulti::handler(ctx, shares_to_burn)
     }
 
+    pub fn withdraw_exact_amounts<'info>(
+        ctx: Context<'_, '_, 'info, 'info, WithdrawMulti<'info>>,
+        amounts_out: Vec<u64>,
+        max_shares_in: u64,
+    ) -> Result<()> {
+        WithdrawMulti::withdraw_exact_amounts_handler(ctx, amounts_out, max_shares_in)
+    }
+
+    pub fn set_lock_tiers(ctx: Context<SetLockTiers>, tiers: Vec<LockTier>) -> Result<()> {
+        SetLockTiers::handler(ctx, tiers)
+    }
+
+    pub fn lock_shares<'info>(
+        ctx: Context<'_, '_, 'info, 'info, LockShares<'info>>,
+        amount: u64,
+        unlock_ts: i64,
+    ) -> Result<()> {
+        LockShares::handler(ctx, amount, unlock_ts)
+    }
+
+    pub fn unlock_shares<'info>(
+        ctx: Context<'_, '_, 'info, 'info, UnlockShares<'info>>,
+    ) -> Result<()> {
+        UnlockShares::handler(ctx)
+    }
+
+    pub fn exit_lock_early<'info>(
+        ctx: Context<'_, '_, 'info, 'info, UnlockShares<'info>>,
+    ) -> Result<()> {
+        UnlockShares::exit_early_handler(ctx)
+    }
+
+    pub fn init_reward_pool(
+        ctx: Context<InitRewardPool>,
+        boosts: Vec<RewardBoost>,
+    ) -> Result<()> {
+        InitRewardPool::handler(ctx, boosts)
+    }
+
+    pub fn fund_rewards(ctx: Context<FundRewards>, amount: u64, duration_secs: i64) -> Result<()> {
+        FundRewards::handler(ctx, amount, duration_secs)
+    }
+
+    pub fn stake<'info>(
+        ctx: Context<'_, '_, 'info, 'info, Stake<'info>>,
+        amount: u64,
+        lock_duration_secs: i64,
+    ) -> Result<()> {
+        Stake::handler(ctx, amount, lock_duration_secs)
+    }
+
+    pub fn unstake<'info>(
+        ctx: Context<'_, '_, 'info, 'info, Unstake<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        Unstake::handler(ctx, amount)
+    }
+
+    pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
+        ClaimRewards::handler(ctx)
+    }
+
+    pub fn poke_stake(ctx: Context<PokeStake>) -> Result<()> {
+        PokeStake::handler(ctx)
+    }
+
+    pub fn set_epoch_operator(ctx: Context<SetEpochOperator>, operator: Pubkey) -> Result<()> {
+        SetEpochOperator::handler(ctx, operator)
+    }
+
+    pub fn request_deposit<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RequestDeposit<'info>>,
+        amounts: Vec<u64>,
+        compliance_proof: Option<ComplianceProof>,
+    ) -> Result<()> {
+        RequestDeposit::handler(ctx, amounts, compliance_proof)
+    }
+
+    pub fn request_redeem<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RequestRedeem<'info>>,
+        shares: u64,
+    ) -> Result<()> {
+        RequestRedeem::handler(ctx, shares)
+    }
+
+    pub fn settle_epoch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SettleEpoch<'info>>,
+    ) -> Result<()> {
+        SettleEpoch::handler(ctx)
+    }
+
+    pub fn cancel_request<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CancelRequest<'info>>,
+    ) -> Result<()> {
+        CancelRequest::handler(ctx)
+    }
+
+    pub fn claim_deposit<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimDeposit<'info>>,
+    ) -> Result<()> {
+        ClaimDeposit::handler(ctx)
+    }
+
+    pub fn claim_redeem<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimRedeem<'info>>,
+    ) -> Result<()> {
+        ClaimRedeem::handler(ctx)
+    }
+
+    pub fn preview_deposit<'info>(
+        ctx: Context<'_, '_, 'info, 'info, PreviewDeposit<'info>>,
+        amounts: Vec<u64>,
+    ) -> Result<DepositQuote> {
+        PreviewDeposit::handler(ctx, amounts)
+    }
+
+    pub fn preview_withdraw<'info>(
+        ctx: Context<'_, '_, 'info, 'info, PreviewWithdraw<'info>>,
+        shares: u64,
+    ) -> Result<WithdrawQuote> {
+        PreviewWithdraw::handler(ctx, shares)
+    }
+
+    pub fn get_basket_nav<'info>(
+        ctx: Context<'_, '_, 'info, 'info, GetBasketNav<'info>>,
+    ) -> Result<BasketNav> {
+        GetBasketNav::handler(ctx)
+    }
+
+    pub fn refresh_basket_price<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RefreshBasketPrice<'info>>,
+    ) -> Result<()> {
+        RefreshBasketPrice::handler(ctx)
+    }
+
     pub fn verify_basket_owner<'info>(
         ctx: Context<'_, '_, 'info, 'info, VerifyBasketOwner<'info>>,
         expected_owner: Pubkey,
@@ -0,0 +1,160 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        instruction::{AccountMeta, Instruction},
+        program::invoke_signed,
+    },
+    system_program::{self, Transfer},
+};
+
+use crate::{constants::*, error::BasketError};
+
+// Metaplex Token Metadata instruction discriminators
+const CREATE_METADATA_ACCOUNT_V3: u8 = 33;
+const UPDATE_METADATA_ACCOUNT_V2: u8 = 15;
+
+/// Name, symbol and URI published for a basket's QSHARE mint.
+pub struct ShareMetadata {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+}
+
+impl ShareMetadata {
+    /// Build metadata from the basket's fixed-size name (NUL padded).
+    pub fn from_basket_name(
+        name: &[u8; MAX_NAME_LEN],
+        symbol: String,
+        uri: String,
+    ) -> Result<Self> {
+        require!(
+            symbol.len() <= MAX_SYMBOL_LEN,
+            BasketError::InvalidMetadata
+        );
+        require!(uri.len() <= MAX_URI_LEN, BasketError::InvalidMetadata);
+
+        let len = name.iter().position(|&b| b == 0).unwrap_or(MAX_NAME_LEN);
+        let name = String::from_utf8(name[..len].to_vec())
+            .map_err(|_| BasketError::InvalidMetadata)?;
+
+        Ok(Self { name, symbol, uri })
+    }
+
+    /// Borsh encoding of Metaplex `DataV2` with no royalties, creators,
+    /// collection or uses.
+    fn to_metaplex_data_v2(&self) -> Result<Vec<u8>> {
+        let mut data = Vec::new();
+        self.name.serialize(&mut data)?;
+        self.symbol.serialize(&mut data)?;
+        self.uri.serialize(&mut data)?;
+        0u16.serialize(&mut data)?; // seller_fee_basis_points
+        data.extend_from_slice(&[0, 0, 0]); // creators, collection, uses: None
+        Ok(data)
+    }
+}
+
+/// Accounts for the Metaplex CPIs on legacy SPL share mints.
+pub struct MetaplexAccounts<'a, 'info> {
+    pub metadata: &'a AccountInfo<'info>,
+    pub mint: &'a AccountInfo<'info>,
+    pub mint_authority: &'a AccountInfo<'info>,
+    pub payer: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub token_metadata_program: &'a AccountInfo<'info>,
+}
+
+/// `CreateMetadataAccountV3` with the mint authority PDA as update authority.
+pub fn create_metaplex_metadata(
+    accounts: &MetaplexAccounts,
+    metadata: &ShareMetadata,
+    mint_auth_seeds: &[&[u8]],
+) -> Result<()> {
+    let mut data = vec![CREATE_METADATA_ACCOUNT_V3];
+    data.extend(metadata.to_metaplex_data_v2()?);
+    data.push(1); // is_mutable
+    data.push(0); // collection_details: None
+
+    let ix = Instruction {
+        program_id: TOKEN_METADATA_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(accounts.metadata.key(), false),
+            AccountMeta::new_readonly(accounts.mint.key(), false),
+            AccountMeta::new_readonly(accounts.mint_authority.key(), true),
+            AccountMeta::new(accounts.payer.key(), true),
+            AccountMeta::new_readonly(accounts.mint_authority.key(), true),
+            AccountMeta::new_readonly(accounts.system_program.key(), false),
+        ],
+        data,
+    };
+
+    invoke_signed(
+        &ix,
+        &[
+            accounts.metadata.clone(),
+            accounts.mint.clone(),
+            accounts.mint_authority.clone(),
+            accounts.payer.clone(),
+            accounts.system_program.clone(),
+            accounts.token_metadata_program.clone(),
+        ],
+        &[mint_auth_seeds],
+    )
+    .map_err(Into::into)
+}
+
+/// `UpdateMetadataAccountV2` replacing name, symbol and URI.
+pub fn update_metaplex_metadata(
+    accounts: &MetaplexAccounts,
+    metadata: &ShareMetadata,
+    mint_auth_seeds: &[&[u8]],
+) -> Result<()> {
+    let mut data = vec![UPDATE_METADATA_ACCOUNT_V2, 1];
+    data.extend(metadata.to_metaplex_data_v2()?);
+    data.extend_from_slice(&[0, 0, 0]); // update_authority, primary_sale_happened, is_mutable: None
+
+    let ix = Instruction {
+        program_id: TOKEN_METADATA_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(accounts.metadata.key(), false),
+            AccountMeta::new_readonly(accounts.mint_authority.key(), true),
+        ],
+        data,
+    };
+
+    invoke_signed(
+        &ix,
+        &[
+            accounts.metadata.clone(),
+            accounts.mint_authority.clone(),
+            accounts.token_metadata_program.clone(),
+        ],
+        &[mint_auth_seeds],
+    )
+    .map_err(Into::into)
+}
+
+/// Transfer enough lamports from `payer` for `account` to stay rent-exempt
+/// once it grows to `new_len` bytes.
+pub fn top_up_rent<'info>(
+    payer: &AccountInfo<'info>,
+    account: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    new_len: usize,
+) -> Result<()> {
+    let required = Rent::get()?.minimum_balance(new_len);
+    let current = account.lamports();
+    if required <= current {
+        return Ok(());
+    }
+
+    system_program::transfer(
+        CpiContext::new(
+            system_program.clone(),
+            Transfer {
+                from: payer.clone(),
+                to: account.clone(),
+            },
+        ),
+        required - current,
+    )
+}
@@ -0,0 +1,124 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{self, Allocate, Assign, CreateAccount, Transfer},
+};
+
+use crate::error::BasketError;
+
+// Creation, growth and closing of program-owned PDAs that can't be declared
+// as typed accounts. Creation mirrors `init`: a PDA already holding lamports
+// is topped up, allocated and assigned rather than created, so a stray
+// transfer can't block it.
+
+pub fn create_pda_account<'info>(
+    payer: &AccountInfo<'info>,
+    target: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    seeds: &[&[u8]],
+    program_id: &Pubkey,
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(space);
+
+    if target.lamports() == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                CreateAccount {
+                    from: payer.clone(),
+                    to: target.clone(),
+                },
+                &[seeds],
+            ),
+            rent,
+            space as u64,
+            program_id,
+        );
+    }
+
+    let top_up = rent.saturating_sub(target.lamports());
+    if top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: target.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Allocate {
+                account_to_allocate: target.clone(),
+            },
+            &[seeds],
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            Assign {
+                account_to_assign: target.clone(),
+            },
+            &[seeds],
+        ),
+        program_id,
+    )
+}
+
+/// Require `target` to hold the version-1 layout of the account with
+/// `discriminator`: `v1_space` bytes after it.
+pub fn require_v1_layout(target: &AccountInfo, discriminator: &[u8], v1_space: usize) -> Result<()> {
+    let data = target.try_borrow_data()?;
+    require!(
+        data.starts_with(discriminator),
+        ErrorCode::AccountDiscriminatorMismatch
+    );
+    require!(
+        data.len() == discriminator.len() + v1_space,
+        BasketError::AlreadyMigrated
+    );
+    Ok(())
+}
+
+/// Grow a program-owned account to `space` bytes, zero-filling the tail and
+/// topping its rent up from `payer`.
+pub fn grow_pda_account<'info>(
+    payer: &AccountInfo<'info>,
+    target: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+) -> Result<()> {
+    let top_up = Rent::get()?
+        .minimum_balance(space)
+        .saturating_sub(target.lamports());
+    if top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: target.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+    target.resize(space).map_err(Into::into)
+}
+
+/// Mirrors the `close` constraint for a PDA that may or may not exist.
+pub fn close_pda_account<'info>(
+    target: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+) -> Result<()> {
+    destination.add_lamports(target.lamports())?;
+    target.sub_lamports(target.lamports())?;
+    target.assign(&system_program::ID);
+    target.resize(0).map_err(Into::into)
+}
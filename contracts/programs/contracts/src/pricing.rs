@@ -0,0 +1,576 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::{constants::*, error::BasketError, legs, state::BasketToken};
+
+// Share pricing shared by the state-changing instructions and their previews,
+// so quotes returned to integrators can never drift from execution.
+
+/// Pre-transfer state of one basket leg.
+#[derive(Clone, Copy, Debug)]
+pub struct LegState {
+    pub decimals: u8,
+    pub vault_balance: u64,
+    /// Leg value per QSHARE averaged over the last TWAP window, if any.
+    pub twap_value: Option<u64>,
+}
+
+/// A validated `BasketToken` with its accumulator observed up to now.
+pub struct PricingLeg<'info> {
+    pub basket_token: Account<'info, BasketToken>,
+    pub state: LegState,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DepositLegQuote {
+    pub amount_in: u64,
+    pub net_amount: u64,
+    pub fee_amount: u64,
+    pub shares: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct DepositQuote {
+    pub shares: u64,
+    pub legs: Vec<DepositLegQuote>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct WithdrawQuote {
+    pub shares_burned: u64,
+    pub amounts_out: Vec<u64>,
+}
+
+/// Fill of one queued epoch: every request is priced at `nav_per_share`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EpochQuote {
+    pub nav_per_share: u64,
+    pub shares_minted: u64,
+    pub amounts_out: Vec<u64>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct BasketNav {
+    /// Sum of all vault balances, normalised to QSHARE decimals.
+    pub total_value: u128,
+    pub total_supply: u64,
+    /// Value of one whole QSHARE, scaled by `NAV_SCALE`.
+    pub nav_per_share: u64,
+    /// Rounding bound on `nav_per_share`, scaled by `NAV_SCALE`. Legs are
+    /// valued at par, so this covers only decimal normalisation and says
+    /// nothing about the market value of the underlying tokens.
+    pub confidence: u64,
+}
+
+/// Load pricing inputs from a `[BasketToken, VaultATA]`-per-token layout,
+/// requiring every enabled leg of the basket to be present.
+pub fn load_leg_states<'info>(
+    remaining: &'info [AccountInfo<'info>],
+    basket_key: Pubkey,
+    token_count: u8,
+) -> Result<Vec<LegState>> {
+    Ok(load_pricing_legs(remaining, basket_key, token_count)?
+        .into_iter()
+        .map(|leg| leg.state)
+        .collect())
+}
+
+/// As `load_leg_states`, keeping the observed `BasketToken` accounts so the
+/// caller can persist the accumulator update.
+pub fn load_pricing_legs<'info>(
+    remaining: &'info [AccountInfo<'info>],
+    basket_key: Pubkey,
+    token_count: u8,
+) -> Result<Vec<PricingLeg<'info>>> {
+    let now = Clock::get()?.unix_timestamp;
+    let expected = (token_count as usize)
+        .checked_mul(PRICING_ACCOUNTS_PER_TOKEN)
+        .ok_or(BasketError::ArithmeticOverflow)?;
+    require!(
+        remaining.len() == expected,
+        BasketError::InvalidRemainingAccounts
+    );
+    legs::require_distinct_legs(
+        &remaining
+            .chunks_exact(PRICING_ACCOUNTS_PER_TOKEN)
+            .map(|leg| leg[0].key())
+            .collect::<Vec<_>>(),
+    )?;
+
+    remaining
+        .chunks_exact(PRICING_ACCOUNTS_PER_TOKEN)
+        .map(|leg| {
+            let mut basket_token = legs::load_basket_token(&leg[0], basket_key)?;
+            require!(
+                leg[1].key() == basket_token.vault_ata,
+                BasketError::InvalidBasketWiring
+            );
+            let vault_balance = read_vault_balance(&leg[1])?;
+            Ok(PricingLeg {
+                state: observe_leg(&mut basket_token, vault_balance, now),
+                basket_token,
+            })
+        })
+        .collect()
+}
+
+/// Advance a leg's TWAP accumulator to `now` and snapshot its pricing state.
+pub fn observe_leg(basket_token: &mut BasketToken, vault_balance: u64, now: i64) -> LegState {
+    basket_token.observe(now);
+    LegState {
+        decimals: basket_token.decimals,
+        vault_balance,
+        twap_value: basket_token.twap(),
+    }
+}
+
+/// Record a leg's post-touch price as the next accumulator sample and
+/// persist the `BasketToken`.
+pub fn record_leg_price(
+    basket_token: &mut Account<BasketToken>,
+    leg: &LegState,
+    total_supply: u64,
+    program_id: &Pubkey,
+) -> Result<()> {
+    basket_token.last_price = leg_value_per_share(leg, total_supply)?;
+    basket_token.exit(program_id)
+}
+
+/// Value of one leg per whole QSHARE, scaled by `NAV_SCALE`.
+pub fn leg_value_per_share(leg: &LegState, total_supply: u64) -> Result<u64> {
+    if total_supply == 0 {
+        return Ok(0);
+    }
+    normalise_amount(leg.vault_balance, leg.decimals)?
+        .checked_mul(NAV_SCALE as u128)
+        .ok_or(BasketError::ArithmeticOverflow)?
+        .checked_div(total_supply as u128)
+        .ok_or(BasketError::ArithmeticOverflow)?
+        .try_into()
+        .map_err(|_| BasketError::ArithmeticOverflow.into())
+}
+
+/// Read vault balance using proper TokenAccount deserialization.
+pub fn read_vault_balance<'a>(vault_ata_info: &'a AccountInfo<'a>) -> Result<u64> {
+    let vault_ata: InterfaceAccount<TokenAccount> =
+        InterfaceAccount::try_from(vault_ata_info)
+            .map_err(|_| BasketError::InvalidBasketWiring)?;
+    Ok(vault_ata.amount)
+}
+
+/// Vault-weighted share pricing for a multi-leg deposit.
+/// First depositor: shares = normalised deposit value.
+/// Subsequent (spot): shares = (deposit_value / total_vault_value) * total_supply.
+/// Other modes: shares = deposit_value / mode price.
+pub fn quote_deposit(
+    amounts: &[u64],
+    legs: &[LegState],
+    total_supply: u64,
+    fee_bps: u16,
+    mint_pricing_mode: u8,
+) -> Result<DepositQuote> {
+    require!(
+        amounts.len() == legs.len(),
+        BasketError::InvalidRemainingAccounts
+    );
+
+    let total_vault_value = total_value(legs)?;
+    require!(
+        total_supply == 0 || total_vault_value > 0,
+        BasketError::ZeroNav
+    );
+    let price = if total_supply == 0 {
+        None
+    } else {
+        mode_price(
+            mint_pricing_mode,
+            legs,
+            spot_nav(total_vault_value, total_supply)?,
+        )?
+    };
+
+    let mut total_shares: u64 = 0;
+    let mut leg_quotes = Vec::with_capacity(legs.len());
+    for (leg, &amount) in legs.iter().zip(amounts) {
+        require!(amount > 0, BasketError::ZeroDeposit);
+
+        let (net_amount, fee_amount) = compute_fee(amount, fee_bps)?;
+        let normalised_deposit = normalise_amount(net_amount, leg.decimals)?;
+
+        let shares = if total_supply == 0 {
+            normalised_deposit as u64
+        } else if let Some(price) = price {
+            normalised_deposit
+                .checked_mul(NAV_SCALE as u128)
+                .ok_or(BasketError::ArithmeticOverflow)?
+                .checked_div(price as u128)
+                .ok_or(BasketError::ArithmeticOverflow)? as u64
+        } else {
+            normalised_deposit
+                .checked_mul(total_supply as u128)
+                .ok_or(BasketError::ArithmeticOverflow)?
+                .checked_div(total_vault_value)
+                .ok_or(BasketError::ArithmeticOverflow)? as u64
+        };
+
+        total_shares = total_shares
+            .checked_add(shares)
+            .ok_or(BasketError::ArithmeticOverflow)?;
+        leg_quotes.push(DepositLegQuote {
+            amount_in: amount,
+            net_amount,
+            fee_amount,
+            shares,
+        });
+    }
+
+    // Reject deposits that produce zero shares
+    require!(total_shares > 0, BasketError::ZeroSharesMinted);
+
+    Ok(DepositQuote {
+        shares: total_shares,
+        legs: leg_quotes,
+    })
+}
+
+/// Exact-share mint: each leg must contribute its pro-rata slice of the
+/// vault, rounded up, scaled by the mode price where it differs from spot.
+/// The fee is grossed up on top so the vault receives the full slice.
+pub fn quote_mint_exact(
+    shares: u64,
+    max_amounts_in: &[u64],
+    legs: &[LegState],
+    total_supply: u64,
+    fee_bps: u16,
+    mint_pricing_mode: u8,
+) -> Result<DepositQuote> {
+    require!(
+        max_amounts_in.len() == legs.len(),
+        BasketError::InvalidRemainingAccounts
+    );
+    require!(shares > 0, BasketError::ZeroSharesMinted);
+    // Proportional amounts are undefined until the first deposit sets a ratio.
+    require!(total_supply > 0, BasketError::EmptyBasket);
+
+    let total_vault_value = total_value(legs)?;
+    let spot = spot_nav(total_vault_value, total_supply)?;
+    let price = mode_price(mint_pricing_mode, legs, spot)?.filter(|&price| price != spot);
+
+    let mut leg_quotes = Vec::with_capacity(legs.len());
+    for (leg, &max_amount_in) in legs.iter().zip(max_amounts_in) {
+        let mut net_amount = mul_div_ceil(leg.vault_balance, shares, total_supply)?;
+        if let Some(price) = price {
+            net_amount = mul_div_ceil(net_amount, price, spot)?;
+        }
+        let amount_in = gross_up_fee(net_amount, fee_bps)?;
+        require!(
+            amount_in <= max_amount_in,
+            BasketError::MaxAmountInExceeded
+        );
+
+        let leg_shares = normalise_amount(net_amount, leg.decimals)?
+            .checked_mul(total_supply as u128)
+            .ok_or(BasketError::ArithmeticOverflow)?
+            .checked_div(total_vault_value)
+            .ok_or(BasketError::ArithmeticOverflow)? as u64;
+        leg_quotes.push(DepositLegQuote {
+            amount_in,
+            net_amount,
+            fee_amount: amount_in - net_amount,
+            shares: leg_shares,
+        });
+    }
+
+    Ok(DepositQuote {
+        shares,
+        legs: leg_quotes,
+    })
+}
+
+/// Pro-rata redemption across every leg. A mode price below spot scales
+/// every payout down; redemption never pays more than the pro-rata share.
+pub fn quote_withdraw(
+    shares_to_burn: u64,
+    legs: &[LegState],
+    total_supply: u64,
+    redeem_pricing_mode: u8,
+) -> Result<WithdrawQuote> {
+    require!(shares_to_burn > 0, BasketError::InsufficientShares);
+    require!(
+        shares_to_burn <= total_supply,
+        BasketError::InsufficientShares
+    );
+
+    let spot = spot_nav(total_value(legs)?, total_supply)?;
+    let haircut = mode_price(redeem_pricing_mode, legs, spot)?.filter(|&price| price < spot);
+
+    let amounts_out = legs
+        .iter()
+        .map(|leg| {
+            let payout = proportional_payout(leg.vault_balance, shares_to_burn, total_supply)?;
+            match haircut {
+                Some(price) => Ok((payout as u128)
+                    .checked_mul(price as u128)
+                    .ok_or(BasketError::ArithmeticOverflow)?
+                    .checked_div(spot as u128)
+                    .ok_or(BasketError::ArithmeticOverflow)? as u64),
+                None => Ok(payout),
+            }
+        })
+        .collect::<Result<Vec<u64>>>()?;
+
+    Ok(WithdrawQuote {
+        shares_burned: shares_to_burn,
+        amounts_out,
+    })
+}
+
+/// Inverse of `quote_withdraw`: the fewest shares whose payout covers every
+/// requested amount, rounded up against the redeemer.
+pub fn quote_withdraw_exact(
+    amounts_out: &[u64],
+    max_shares_in: u64,
+    legs: &[LegState],
+    total_supply: u64,
+    redeem_pricing_mode: u8,
+) -> Result<WithdrawQuote> {
+    require!(
+        amounts_out.len() == legs.len(),
+        BasketError::InvalidRemainingAccounts
+    );
+    require!(total_supply > 0, BasketError::InsufficientShares);
+
+    let spot = spot_nav(total_value(legs)?, total_supply)?;
+    let haircut = mode_price(redeem_pricing_mode, legs, spot)?.filter(|&price| price < spot);
+
+    let mut shares_to_burn: u64 = 0;
+    for (leg, &amount_out) in legs.iter().zip(amounts_out) {
+        if amount_out == 0 {
+            continue;
+        }
+        require!(leg.vault_balance > 0, BasketError::InsufficientShares);
+
+        // Undo the haircut first, then the pro-rata division.
+        let payout = match haircut {
+            Some(price) => mul_div_ceil(amount_out, spot, price)?,
+            None => amount_out,
+        };
+        let shares = mul_div_ceil(payout, total_supply, leg.vault_balance)?;
+        shares_to_burn = shares_to_burn.max(shares);
+    }
+    require!(
+        shares_to_burn <= max_shares_in,
+        BasketError::MaxSharesInExceeded
+    );
+
+    quote_withdraw(shares_to_burn, legs, total_supply, redeem_pricing_mode)
+}
+
+/// Fill an epoch's queued requests against the pre-settlement vault at one
+/// price: deposits mint as `quote_deposit` would under `mint_pricing_mode`
+/// and redemptions pay out as `quote_withdraw` would under
+/// `redeem_pricing_mode`. Deposits into a basket whose outstanding shares
+/// hold no value cannot be priced and fail with `ZeroNav`; their owners can
+/// cancel them.
+pub fn quote_epoch(
+    deposit_value: u64,
+    redeem_shares: u64,
+    legs: &[LegState],
+    total_supply: u64,
+    mint_pricing_mode: u8,
+    redeem_pricing_mode: u8,
+) -> Result<EpochQuote> {
+    require!(
+        redeem_shares <= total_supply,
+        BasketError::InsufficientShares
+    );
+
+    let nav = basket_nav(legs, total_supply)?;
+    let shares_minted = if deposit_value == 0 {
+        0
+    } else if total_supply == 0 {
+        deposit_value
+    } else {
+        require!(nav.total_value > 0, BasketError::ZeroNav);
+        let (numerator, denominator) =
+            match mode_price(mint_pricing_mode, legs, nav.nav_per_share)? {
+                Some(price) => (NAV_SCALE as u128, price as u128),
+                None => (total_supply as u128, nav.total_value),
+            };
+        (deposit_value as u128)
+            .checked_mul(numerator)
+            .ok_or(BasketError::ArithmeticOverflow)?
+            .checked_div(denominator)
+            .ok_or(BasketError::ArithmeticOverflow)?
+            .try_into()
+            .map_err(|_| BasketError::ArithmeticOverflow)?
+    };
+    require!(
+        deposit_value == 0 || shares_minted > 0,
+        BasketError::ZeroSharesMinted
+    );
+
+    let amounts_out = if redeem_shares == 0 {
+        vec![0; legs.len()]
+    } else {
+        quote_withdraw(redeem_shares, legs, total_supply, redeem_pricing_mode)?.amounts_out
+    };
+
+    Ok(EpochQuote {
+        nav_per_share: nav.nav_per_share,
+        shares_minted,
+        amounts_out,
+    })
+}
+
+/// Per-share price selected by a `PRICING_MODE_*`, or `None` for spot.
+/// Falls back to spot until every leg has completed a TWAP window, and
+/// whenever the TWAP or spot price is zero.
+pub fn mode_price(mode: u8, legs: &[LegState], spot: u64) -> Result<Option<u64>> {
+    if mode == PRICING_MODE_SPOT {
+        return Ok(None);
+    }
+
+    let mut twap: u64 = 0;
+    for leg in legs {
+        let Some(value) = leg.twap_value else {
+            return Ok(None);
+        };
+        twap = twap
+            .checked_add(value)
+            .ok_or(BasketError::ArithmeticOverflow)?;
+    }
+
+    if twap == 0 || spot == 0 {
+        return Ok(None);
+    }
+
+    match mode {
+        PRICING_MODE_TWAP => Ok(Some(twap)),
+        PRICING_MODE_MIN => Ok(Some(twap.min(spot))),
+        PRICING_MODE_MAX => Ok(Some(twap.max(spot))),
+        _ => err!(BasketError::InvalidPricingMode),
+    }
+}
+
+/// `total_value * NAV_SCALE / total_supply`
+fn spot_nav(total_value: u128, total_supply: u64) -> Result<u64> {
+    total_value
+        .checked_mul(NAV_SCALE as u128)
+        .ok_or(BasketError::ArithmeticOverflow)?
+        .checked_div(total_supply as u128)
+        .ok_or(BasketError::ArithmeticOverflow)?
+        .try_into()
+        .map_err(|_| BasketError::ArithmeticOverflow.into())
+}
+
+/// NAV per share; an empty basket prices at 1.0.
+pub fn basket_nav(legs: &[LegState], total_supply: u64) -> Result<BasketNav> {
+    let total_value = total_value(legs)?;
+    if total_supply == 0 {
+        return Ok(BasketNav {
+            total_value,
+            total_supply,
+            nav_per_share: NAV_SCALE,
+            confidence: 0,
+        });
+    }
+
+    let uncertainty = legs.iter().try_fold(0u128, |acc, leg| {
+        acc.checked_add(leg_value_uncertainty(leg))
+            .ok_or(BasketError::ArithmeticOverflow)
+    })?;
+    let nav_per_share = spot_nav(total_value, total_supply)?;
+    let confidence = uncertainty
+        .checked_mul(NAV_SCALE as u128)
+        .ok_or(BasketError::ArithmeticOverflow)?
+        .div_ceil(total_supply as u128)
+        .try_into()
+        .map_err(|_| BasketError::ArithmeticOverflow)?;
+
+    Ok(BasketNav {
+        total_value,
+        total_supply,
+        nav_per_share,
+        confidence,
+    })
+}
+
+/// Rounding bound of a leg's normalised value, in QSHARE base units: the
+/// sub-unit remainder dropped when normalising a leg with more decimals
+/// than QSHARE. This is not a price uncertainty; legs are valued at par.
+fn leg_value_uncertainty(leg: &LegState) -> u128 {
+    u128::from(leg.decimals > QSHARE_DECIMALS)
+}
+
+/// Sum of all vault balances, normalised to QSHARE decimals.
+pub fn total_value(legs: &[LegState]) -> Result<u128> {
+    legs.iter().try_fold(0u128, |acc, leg| {
+        acc.checked_add(normalise_amount(leg.vault_balance, leg.decimals)?)
+            .ok_or(BasketError::ArithmeticOverflow.into())
+    })
+}
+
+/// `amount_out = vault_balance * shares_to_burn / total_supply`
+pub fn proportional_payout(
+    vault_balance: u64,
+    shares_to_burn: u64,
+    total_supply: u64,
+) -> Result<u64> {
+    Ok((vault_balance as u128)
+        .checked_mul(shares_to_burn as u128)
+        .ok_or(BasketError::ArithmeticOverflow)?
+        .checked_div(total_supply as u128)
+        .ok_or(BasketError::ArithmeticOverflow)? as u64)
+}
+
+/// `ceil(amount * numerator / denominator)`
+fn mul_div_ceil(amount: u64, numerator: u64, denominator: u64) -> Result<u64> {
+    require!(denominator > 0, BasketError::ArithmeticOverflow);
+    (amount as u128)
+        .checked_mul(numerator as u128)
+        .ok_or(BasketError::ArithmeticOverflow)?
+        .div_ceil(denominator as u128)
+        .try_into()
+        .map_err(|_| BasketError::ArithmeticOverflow.into())
+}
+
+/// Smallest gross amount whose net of `compute_fee` covers `net_amount`.
+fn gross_up_fee(net_amount: u64, fee_bps: u16) -> Result<u64> {
+    let net_bps = BPS_DENOMINATOR
+        .checked_sub(fee_bps as u64)
+        .ok_or(BasketError::InvalidFee)?;
+    mul_div_ceil(net_amount, BPS_DENOMINATOR, net_bps)
+}
+
+pub fn compute_fee(amount: u64, fee_bps: u16) -> Result<(u64, u64)> {
+    let fee = (amount as u128)
+        .checked_mul(fee_bps as u128)
+        .ok_or(BasketError::ArithmeticOverflow)?
+        .checked_div(BPS_DENOMINATOR as u128)
+        .ok_or(BasketError::ArithmeticOverflow)? as u64;
+    let net = amount
+        .checked_sub(fee)
+        .ok_or(BasketError::ArithmeticOverflow)?;
+    Ok((net, fee))
+}
+
+/// Normalise a token amount to QSHARE decimal precision (u128 intermediate).
+pub fn normalise_amount(amount: u64, token_decimals: u8) -> Result<u128> {
+    let amount_128 = amount as u128;
+    if token_decimals >= QSHARE_DECIMALS {
+        let divisor = 10u128
+            .checked_pow((token_decimals - QSHARE_DECIMALS) as u32)
+            .ok_or(BasketError::ArithmeticOverflow)?;
+        amount_128
+            .checked_div(divisor)
+            .ok_or(BasketError::ArithmeticOverflow.into())
+    } else {
+        let multiplier = 10u128
+            .checked_pow((QSHARE_DECIMALS - token_decimals) as u32)
+            .ok_or(BasketError::ArithmeticOverflow)?;
+        amount_128
+            .checked_mul(multiplier)
+            .ok_or(BasketError::ArithmeticOverflow.into())
+    }
+}
@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+use static_assertions::const_assert_eq;
+
+/// Marks a compliance attestation nonce as spent for `user` in `basket`; its
+/// existence is what blocks replay.
+#[account]
+#[derive(InitSpace)]
+pub struct AttestationNonce {
+    pub basket: Pubkey,
+    pub user: Pubkey,
+    pub nonce: u64,
+    pub used_at: i64,
+    pub bump: u8,
+}
+
+const_assert_eq!(AttestationNonce::INIT_SPACE, 81);
@@ -1,9 +1,16 @@
+use std::cell::{Ref, RefMut};
+
 use anchor_lang::prelude::*;
 use static_assertions::const_assert_eq;
 
-use crate::constants::MAX_NAME_LEN;
+use crate::{
+    constants::{BPS_DENOMINATOR, MAX_NAME_LEN},
+    error::BasketError,
+    events::WithdrawLimiterTripped,
+};
 
-// Per-basket state account using zero-copy deserialization.
+// Per-basket state account using zero-copy deserialization. Fields are only
+// ever appended; accounts on an older layout are grown by `migrate_basket`.
 #[account(zero_copy)]
 #[repr(C)]
 pub struct Basket {
@@ -19,11 +26,56 @@ pub struct Basket {
     pub basket_bump: u8,
     pub vault_authority_bump: u8,
     pub mint_authority_bump: u8,
+    /// `PRICING_MODE_*` used when minting shares.
+    pub mint_pricing_mode: u8,
+    /// `PRICING_MODE_*` used when redeeming shares.
+    pub redeem_pricing_mode: u8,
+    pub _padding: [u8; 6],
+    /// Cap on total vault value after a deposit, normalised to QSHARE
+    /// decimals; 0 is uncapped.
+    pub max_total_value: u64,
+    /// Cap on the receiver's QSHARE balance after a deposit; 0 is uncapped.
+    pub max_user_shares: u64,
+    /// Share of supply, in bps, burnable per outflow window; 0 disables the
+    /// withdrawal limiter.
+    pub max_withdraw_bps: u16,
+    /// Set by the protocol admin to lift the limiter for an orderly exit.
+    pub emergency_mode: u8,
+    pub _limiter_padding: [u8; 5],
+    pub withdraw_window_secs: i64,
+    pub window_start_ts: i64,
+    /// Supply when the current window opened; the limit is a share of it.
+    pub window_start_supply: u64,
+    pub burned_in_window: u64,
+    /// Nominee of an ownership transfer awaiting acceptance; default when
+    /// none is pending.
+    pub pending_owner: Pubkey,
+    /// Creation bond held as lamports on this account above rent.
+    pub bond_lamports: u64,
 }
 
-const_assert_eq!(std::mem::size_of::<Basket>(), 144);
+const_assert_eq!(std::mem::size_of::<Basket>(), 248);
 
 impl Basket {
+    /// Size of the version-1 layout, which ended at `mint_authority_bump`.
+    pub const V1_SIZE: usize = 144;
+
+    /// Basket-scoped actions may be run by the basket's owner (its curator)
+    /// or by the protocol admin. Handing the basket to a new owner is the
+    /// one exception and stays with the owner alone.
+    pub fn is_manager(&self, authority: &Pubkey, admin: &Pubkey) -> bool {
+        authority == &self.owner || authority == admin
+    }
+
+    pub fn fee_override(&self) -> Option<u16> {
+        (self.has_fee_override == 1).then_some(self.fee_bps_override)
+    }
+
+    pub fn set_fee_override(&mut self, fee_bps_override: Option<u16>) {
+        self.fee_bps_override = fee_bps_override.unwrap_or(0);
+        self.has_fee_override = u8::from(fee_bps_override.is_some());
+    }
+
     pub fn effective_fee_bps(&self, global_fee_bps: u16) -> u16 {
         if self.has_fee_override == 1 {
             self.fee_bps_override
@@ -31,4 +83,100 @@ impl Basket {
             global_fee_bps
         }
     }
+
+    /// Enforce `max_total_value` against the vault value after a deposit
+    /// and `max_user_shares` against the receiver's balance after it. A
+    /// zero cap is unset.
+    pub fn check_deposit_caps(&self, total_value: u128, user_shares: u64) -> Result<()> {
+        require!(
+            self.max_total_value == 0 || total_value <= self.max_total_value as u128,
+            BasketError::DepositCapExceeded
+        );
+        require!(
+            self.max_user_shares == 0 || user_shares <= self.max_user_shares,
+            BasketError::DepositCapExceeded
+        );
+        Ok(())
+    }
+
+    pub fn withdraw_limiter_active(&self) -> bool {
+        self.max_withdraw_bps > 0 && self.emergency_mode == 0
+    }
+
+    /// Roll the outflow window forward to `now`, opening a new one against
+    /// `supply` once the last has elapsed (or after a reset, which zeroes
+    /// the supply snapshot), and return the shares still burnable in it.
+    pub fn outflow_headroom(&mut self, supply: u64, now: i64) -> u64 {
+        if self.window_start_supply == 0
+            || now.saturating_sub(self.window_start_ts) >= self.withdraw_window_secs
+        {
+            self.window_start_ts = now;
+            self.window_start_supply = supply;
+            self.burned_in_window = 0;
+        }
+        self.outflow_limit(self.window_start_supply)
+            .saturating_sub(self.burned_in_window)
+    }
+
+    /// Most shares one window can release against `supply`.
+    pub fn outflow_limit(&self, supply: u64) -> u64 {
+        ((supply as u128).saturating_mul(self.max_withdraw_bps as u128) / BPS_DENOMINATOR as u128)
+            as u64
+    }
+
+    /// Count `shares` burned at `now` against the outflow window, failing
+    /// if they exceed its headroom. A no-op while the limiter is off.
+    pub fn consume_outflow(
+        &mut self,
+        basket: Pubkey,
+        shares: u64,
+        supply: u64,
+        now: i64,
+    ) -> Result<()> {
+        if !self.withdraw_limiter_active() {
+            return Ok(());
+        }
+        let headroom = self.outflow_headroom(supply, now);
+        if shares > headroom {
+            emit!(WithdrawLimiterTripped {
+                basket,
+                shares_requested: shares,
+                headroom,
+            });
+            return err!(BasketError::WithdrawLimitExceeded);
+        }
+        self.burned_in_window = self
+            .burned_in_window
+            .checked_add(shares)
+            .ok_or(BasketError::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
+
+/// `AccountLoader::load` assumes the account is long enough for the current
+/// layout and panics on an older, shorter one. Every basket load goes
+/// through these instead, which fail cleanly until `migrate_basket` has run.
+pub trait BasketLoader {
+    fn load_current(&self) -> Result<Ref<'_, Basket>>;
+    fn load_current_mut(&self) -> Result<RefMut<'_, Basket>>;
+}
+
+impl BasketLoader for AccountLoader<'_, Basket> {
+    fn load_current(&self) -> Result<Ref<'_, Basket>> {
+        require_current_layout(self)?;
+        self.load()
+    }
+
+    fn load_current_mut(&self) -> Result<RefMut<'_, Basket>> {
+        require_current_layout(self)?;
+        self.load_mut()
+    }
+}
+
+fn require_current_layout(basket: &AccountLoader<Basket>) -> Result<()> {
+    require!(
+        basket.as_ref().data_len() >= 8 + std::mem::size_of::<Basket>(),
+        BasketError::AccountNotMigrated
+    );
+    Ok(())
 }
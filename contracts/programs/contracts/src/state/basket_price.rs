@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use static_assertions::const_assert_eq;
+
+use crate::pricing::BasketNav;
+
+/// Published QSHARE price for one basket, read directly by lending markets.
+///
+/// The layout is stable across upgrades; new fields are only ever taken from
+/// `_reserved`. Offsets exclude the 8-byte Anchor discriminator, all
+/// integers are little-endian:
+///
+/// | offset | size | field                 |
+/// |--------|------|-----------------------|
+/// | 0      | 32   | basket                |
+/// | 32     | 8    | nav_per_share (u64)   |
+/// | 40     | 8    | confidence (u64)      |
+/// | 48     | 8    | total_supply (u64)    |
+/// | 56     | 8    | last_update_slot (u64)|
+/// | 64     | 8    | last_update_ts (i64)  |
+/// | 72     | 1    | nav_decimals (u8)     |
+/// | 73     | 1    | version (u8)          |
+/// | 74     | 1    | bump (u8)             |
+/// | 75     | 5    | _reserved             |
+///
+/// `nav_per_share` and `confidence` are fixed-point with `nav_decimals`
+/// decimals. `confidence` is only the rounding bound from normalising leg
+/// decimals; legs are valued at par, so it carries no market-price
+/// uncertainty and consumers must apply their own oracle haircuts.
+#[account(zero_copy)]
+#[repr(C)]
+pub struct BasketPrice {
+    pub basket: Pubkey,
+    pub nav_per_share: u64,
+    pub confidence: u64,
+    pub total_supply: u64,
+    pub last_update_slot: u64,
+    pub last_update_ts: i64,
+    pub nav_decimals: u8,
+    pub version: u8,
+    pub bump: u8,
+    pub _reserved: [u8; 5],
+}
+
+const_assert_eq!(std::mem::size_of::<BasketPrice>(), 80);
+
+impl BasketPrice {
+    pub fn publish(&mut self, nav: &BasketNav, clock: &Clock) {
+        self.nav_per_share = nav.nav_per_share;
+        self.confidence = nav.confidence;
+        self.total_supply = nav.total_supply;
+        self.last_update_slot = clock.slot;
+        self.last_update_ts = clock.unix_timestamp;
+    }
+}
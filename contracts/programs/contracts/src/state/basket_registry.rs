@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use static_assertions::const_assert_eq;
+
+use crate::constants::REGISTRY_PAGE_SIZE;
+
+/// Append-only page of basket pubkeys in creation order. Pages fill one at
+/// a time, so walking pages `0..=Config.registry_page` lists every basket.
+#[account]
+#[derive(InitSpace)]
+pub struct BasketRegistry {
+    pub page: u32,
+    pub count: u8,
+    pub bump: u8,
+    pub baskets: [Pubkey; REGISTRY_PAGE_SIZE],
+}
+
+const_assert_eq!(BasketRegistry::INIT_SPACE, 2054);
+
+impl BasketRegistry {
+    pub fn entries(&self) -> &[Pubkey] {
+        &self.baskets[..self.count as usize]
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.count as usize == REGISTRY_PAGE_SIZE
+    }
+
+    pub fn push(&mut self, basket: Pubkey) {
+        self.baskets[self.count as usize] = basket;
+        self.count += 1;
+    }
+}
@@ -1,7 +1,10 @@
 use anchor_lang::prelude::*;
 use static_assertions::const_assert_eq;
 
-/// Per-mint whitelist entry for a basket.
+use crate::constants::TWAP_WINDOW_SECS;
+
+/// Per-mint whitelist entry for a basket. Fields are only ever appended; a
+/// version-1 entry is grown by `migrate_basket` along with its basket.
 #[account]
 #[derive(InitSpace)]
 pub struct BasketToken {
@@ -12,6 +15,60 @@ pub struct BasketToken {
     pub decimals: u8,
     pub enabled: bool,
     pub bump: u8,
+    /// `MINT_EXT_*` flags detected on the mint and accepted at registration.
+    pub extension_flags: u8,
+    /// Leg value per QSHARE (`NAV_SCALE`) recorded at the last touch.
+    pub last_price: u64,
+    /// Running sum of `last_price * seconds`; wraps like any TWAP accumulator.
+    pub price_cumulative: u128,
+    pub last_update_ts: i64,
+    /// Accumulator snapshot opening the current TWAP window.
+    pub twap_anchor_cumulative: u128,
+    pub twap_anchor_ts: i64,
+    /// Average of `last_price` over the last completed window.
+    pub twap_price: u64,
+    /// When `twap_price` was last computed; 0 until a window has completed.
+    pub twap_updated_ts: i64,
 }
 
-const_assert_eq!(BasketToken::INIT_SPACE, 131);
+const_assert_eq!(BasketToken::INIT_SPACE, 204);
+
+impl BasketToken {
+    /// Space of the version-1 layout, which ended at `bump`.
+    pub const V1_SPACE: usize = 131;
+
+    /// Accumulate the last recorded price up to `now`, closing the TWAP
+    /// window once it spans at least `TWAP_WINDOW_SECS`. A zero price (no
+    /// shares outstanding) restarts the window and discards the last TWAP,
+    /// so averaging only begins at the first priced observation.
+    pub fn observe(&mut self, now: i64) {
+        if self.last_price == 0 {
+            self.last_update_ts = now;
+            self.twap_anchor_cumulative = self.price_cumulative;
+            self.twap_anchor_ts = now;
+            self.twap_updated_ts = 0;
+            return;
+        }
+
+        let elapsed = now.saturating_sub(self.last_update_ts).max(0) as u128;
+        self.price_cumulative = self
+            .price_cumulative
+            .wrapping_add((self.last_price as u128).wrapping_mul(elapsed));
+        self.last_update_ts = now;
+
+        let span = now.saturating_sub(self.twap_anchor_ts);
+        if span >= TWAP_WINDOW_SECS {
+            self.twap_price = (self
+                .price_cumulative
+                .wrapping_sub(self.twap_anchor_cumulative)
+                / span as u128) as u64;
+            self.twap_updated_ts = now;
+            self.twap_anchor_cumulative = self.price_cumulative;
+            self.twap_anchor_ts = now;
+        }
+    }
+
+    pub fn twap(&self) -> Option<u64> {
+        (self.twap_updated_ts != 0 && self.twap_price != 0).then_some(self.twap_price)
+    }
+}
@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+use static_assertions::const_assert_eq;
+
+/// Merkle root of a basket's compliance allow-list, an alternative to one
+/// `UserAllowList` per user. Leaves are built by `compliance::leaf_hash`.
+#[account]
+#[derive(InitSpace)]
+pub struct ComplianceRoot {
+    pub basket: Pubkey,
+    pub root: [u8; 32],
+    /// Strictly increasing on every publish; 0 until the first one.
+    pub version: u64,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+const_assert_eq!(ComplianceRoot::INIT_SPACE, 81);
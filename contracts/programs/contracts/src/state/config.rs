@@ -1,7 +1,8 @@
 use anchor_lang::prelude::*;
 use static_assertions::const_assert_eq;
 
-/// Global protocol configuration
+/// Global protocol configuration. Fields are only ever appended; a
+/// version-1 config is grown by `migrate_config`.
 #[account]
 #[derive(InitSpace)]
 pub struct Config {
@@ -11,6 +12,25 @@ pub struct Config {
     pub compliance_enabled: bool,
     pub version: u8,
     pub bump: u8,
+    /// Bitmap of `MINT_EXT_*` flags that `add_tokens` may accept when acknowledged.
+    pub mint_extension_policy: u8,
+    /// Whether anyone, not only the admin, may call `create_basket`.
+    pub permissionless_creation: bool,
+    /// SOL paid to `treasury` by non-admin basket creators.
+    pub creation_fee_lamports: u64,
+    /// SOL non-admin creators escrow in the basket account, slashable by the
+    /// admin.
+    pub creation_bond_lamports: u64,
+    pub treasury: Pubkey,
+    /// Id `create_basket` must be called with next.
+    pub next_basket_id: u64,
+    /// `BasketRegistry` page the next basket is appended to.
+    pub registry_page: u32,
 }
 
-const_assert_eq!(Config::INIT_SPACE, 69);
+const_assert_eq!(Config::INIT_SPACE, 131);
+
+impl Config {
+    /// Space of the version-1 layout, which ended at `bump`.
+    pub const V1_SPACE: usize = 69;
+}
@@ -0,0 +1,135 @@
+use anchor_lang::prelude::*;
+use static_assertions::const_assert_eq;
+
+use crate::{constants::MAX_TOKENS_PER_BASKET, error::BasketError};
+
+/// Request queue for a basket's asynchronous deposits and redemptions.
+///
+/// Deposited tokens wait in ATAs owned by this account; shares waiting to be
+/// redeemed sit in the vault authority's QSHARE escrow.
+#[account]
+#[derive(InitSpace)]
+pub struct EpochQueue {
+    pub basket: Pubkey,
+    /// Signer allowed to settle epochs.
+    pub operator: Pubkey,
+    /// Epoch currently accepting requests.
+    pub current_epoch: u64,
+    /// Net value requested for deposit this epoch, normalised to QSHARE decimals.
+    pub pending_deposit_value: u64,
+    /// Shares requested for redemption this epoch.
+    pub pending_redeem_shares: u64,
+    /// Shares redeemed in settled epochs whose payout is still unclaimed;
+    /// the basket cannot close while any remain.
+    pub unclaimed_redeem_shares: u64,
+    pub bump: u8,
+}
+
+const_assert_eq!(EpochQueue::INIT_SPACE, 97);
+
+/// Fill record of one settled epoch, drawn down as requests are claimed.
+///
+/// Redemption payouts wait in ATAs owned by this account; minted shares sit
+/// in the vault authority's QSHARE escrow.
+#[account]
+#[derive(InitSpace)]
+pub struct Epoch {
+    pub basket: Pubkey,
+    pub epoch_id: u64,
+    /// Spot NAV per share at settlement, scaled by `NAV_SCALE`; a non-spot
+    /// pricing mode fills at its own price.
+    pub nav_per_share: u64,
+    /// Deposit value not yet claimed.
+    pub unclaimed_deposit_value: u64,
+    /// Minted shares not yet claimed.
+    pub unclaimed_shares: u64,
+    /// Redeemed shares whose payout has not yet been claimed.
+    pub unclaimed_redeem_shares: u64,
+    pub settled_ts: i64,
+    pub bump: u8,
+}
+
+const_assert_eq!(Epoch::INIT_SPACE, 81);
+
+impl Epoch {
+    /// Pro-rata slice of `remaining` for `part` of `total`; the last claim
+    /// takes whatever rounding left behind.
+    pub fn pro_rata(remaining: u64, part: u64, total: u64) -> Option<u64> {
+        if part == total {
+            return Some(remaining);
+        }
+        let share = (remaining as u128)
+            .checked_mul(part as u128)?
+            .checked_div(total as u128)?;
+        u64::try_from(share).ok()
+    }
+}
+
+/// Net amount of one leg escrowed by a deposit request.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq, InitSpace)]
+pub struct RequestLeg {
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+/// One owner's pending deposit into a basket.
+#[account]
+#[derive(InitSpace)]
+pub struct DepositRequest {
+    pub basket: Pubkey,
+    pub owner: Pubkey,
+    pub epoch: u64,
+    /// Net deposit value, normalised to QSHARE decimals.
+    pub value: u64,
+    /// Tokens escrowed per leg, net of the fee; a cancel refunds exactly
+    /// these.
+    pub legs: [RequestLeg; MAX_TOKENS_PER_BASKET as usize],
+    pub leg_count: u8,
+    pub bump: u8,
+}
+
+const_assert_eq!(DepositRequest::INIT_SPACE, 482);
+
+impl DepositRequest {
+    /// Add `amount` of `mint` to the request, taking a new slot for a leg
+    /// it has not escrowed yet.
+    pub fn add_leg_amount(&mut self, mint: Pubkey, amount: u64) -> Result<()> {
+        let count = self.leg_count as usize;
+        let index = match self.legs[..count].iter().position(|leg| leg.mint == mint) {
+            Some(index) => index,
+            None => {
+                require!(count < self.legs.len(), BasketError::MaxTokensExceeded);
+                self.legs[count].mint = mint;
+                self.leg_count += 1;
+                count
+            }
+        };
+        let leg = &mut self.legs[index];
+        leg.amount = leg
+            .amount
+            .checked_add(amount)
+            .ok_or(BasketError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Net amount of `mint` escrowed by the request.
+    pub fn leg_amount(&self, mint: &Pubkey) -> u64 {
+        self.legs[..self.leg_count as usize]
+            .iter()
+            .find(|leg| leg.mint == *mint)
+            .map_or(0, |leg| leg.amount)
+    }
+}
+
+/// One owner's pending redemption from a basket.
+#[account]
+#[derive(InitSpace)]
+pub struct RedeemRequest {
+    pub basket: Pubkey,
+    pub owner: Pubkey,
+    pub epoch: u64,
+    pub shares: u64,
+    pub bump: u8,
+}
+
+const_assert_eq!(RedeemRequest::INIT_SPACE, 81);
@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+use static_assertions::const_assert_eq;
+
+/// QSHARE escrowed by one owner in one basket until `unlock_ts`.
+#[account]
+#[derive(InitSpace)]
+pub struct LockPosition {
+    pub basket: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub locked_ts: i64,
+    pub unlock_ts: i64,
+    /// Index into `LockTiers` at the last lock or top-up.
+    pub tier: u8,
+    /// Penalty snapshotted from the tier so later tier edits cannot change it.
+    pub early_exit_penalty_bps: u16,
+    pub bump: u8,
+}
+
+const_assert_eq!(LockPosition::INIT_SPACE, 92);
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use static_assertions::const_assert_eq;
+
+use crate::constants::MAX_LOCK_TIERS;
+
+/// Minimum lock duration and the early-exit penalty it carries.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq, InitSpace)]
+pub struct LockTier {
+    pub min_duration_secs: i64,
+    /// Share of the locked amount burned on early exit.
+    pub early_exit_penalty_bps: u16,
+}
+
+/// Per-basket lock-duration tiers, ordered by `min_duration_secs`.
+#[account]
+#[derive(InitSpace)]
+pub struct LockTiers {
+    pub basket: Pubkey,
+    pub tiers: [LockTier; MAX_LOCK_TIERS],
+    pub tier_count: u8,
+    pub bump: u8,
+}
+
+const_assert_eq!(LockTiers::INIT_SPACE, 74);
+
+impl LockTiers {
+    pub fn active(&self) -> &[LockTier] {
+        &self.tiers[..self.tier_count as usize]
+    }
+
+    /// Longest tier whose minimum `duration` satisfies, with its index.
+    pub fn tier_for(&self, duration: i64) -> Option<(u8, LockTier)> {
+        self.active()
+            .iter()
+            .enumerate()
+            .rev()
+            .find(|(_, tier)| duration >= tier.min_duration_secs)
+            .map(|(index, tier)| (index as u8, *tier))
+    }
+}
@@ -1,9 +1,27 @@
+pub mod attestation_nonce;
 pub mod basket;
+pub mod basket_price;
+pub mod basket_registry;
 pub mod basket_token;
+pub mod compliance_root;
 pub mod config;
+pub mod epoch_queue;
+pub mod lock_position;
+pub mod lock_tiers;
+pub mod reward_pool;
+pub mod stake_position;
 pub mod user_allow_list;
 
+pub use attestation_nonce::*;
 pub use basket::*;
+pub use basket_price::*;
+pub use basket_registry::*;
 pub use basket_token::*;
+pub use compliance_root::*;
 pub use config::*;
+pub use epoch_queue::*;
+pub use lock_position::*;
+pub use lock_tiers::*;
+pub use reward_pool::*;
+pub use stake_position::*;
 pub use user_allow_list::*;
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use static_assertions::const_assert_eq;
+
+use crate::{
+    constants::{BPS_DENOMINATOR, MAX_REWARD_BOOSTS, REWARD_ACC_SCALE},
+    error::BasketError,
+};
+
+/// Weight multiplier earned by committing a stake for `min_duration_secs`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq, InitSpace)]
+pub struct RewardBoost {
+    pub min_duration_secs: i64,
+    /// 10_000 == 1x.
+    pub multiplier_bps: u16,
+}
+
+/// Streams one reward mint to QSHARE stakers of a basket, pro rata to
+/// boosted stake weight.
+#[account]
+#[derive(InitSpace)]
+pub struct RewardPool {
+    pub basket: Pubkey,
+    pub reward_mint: Pubkey,
+    pub reward_vault: Pubkey,
+    pub total_staked: u64,
+    pub total_weight: u64,
+    /// Reward tokens released per second until `period_end`.
+    pub reward_rate: u64,
+    pub period_end: i64,
+    pub last_update_ts: i64,
+    /// Rewards per unit of weight, scaled by `REWARD_ACC_SCALE`.
+    pub acc_reward_per_weight: u128,
+    pub boosts: [RewardBoost; MAX_REWARD_BOOSTS],
+    pub boost_count: u8,
+    pub bump: u8,
+}
+
+const_assert_eq!(RewardPool::INIT_SPACE, 194);
+
+impl RewardPool {
+    /// Release rewards up to `now` (capped at `period_end`) into the
+    /// accumulator. Time with no stakers releases nothing and pushes
+    /// `period_end` back by as much, so rewards funded for it stream to
+    /// the next stakers instead of stranding in the vault.
+    pub fn accrue(&mut self, now: i64) -> Result<()> {
+        if self.total_weight == 0 {
+            if self.last_update_ts < self.period_end && now > self.last_update_ts {
+                self.period_end = self
+                    .period_end
+                    .checked_add(now - self.last_update_ts)
+                    .ok_or(BasketError::ArithmeticOverflow)?;
+            }
+            self.last_update_ts = self.last_update_ts.max(now);
+            return Ok(());
+        }
+
+        let until = now.min(self.period_end);
+        if until <= self.last_update_ts {
+            return Ok(());
+        }
+        let released = (self.reward_rate as u128)
+            .checked_mul((until - self.last_update_ts) as u128)
+            .ok_or(BasketError::ArithmeticOverflow)?;
+        self.acc_reward_per_weight = self
+            .acc_reward_per_weight
+            .checked_add(
+                released
+                    .checked_mul(REWARD_ACC_SCALE)
+                    .ok_or(BasketError::ArithmeticOverflow)?
+                    / self.total_weight as u128,
+            )
+            .ok_or(BasketError::ArithmeticOverflow)?;
+        self.last_update_ts = until;
+        Ok(())
+    }
+
+    /// Multiplier for a stake committed for `duration` seconds; 1x below
+    /// the first boost.
+    pub fn multiplier_for(&self, duration: i64) -> u16 {
+        self.boosts[..self.boost_count as usize]
+            .iter()
+            .rev()
+            .find(|boost| duration >= boost.min_duration_secs)
+            .map_or(BPS_DENOMINATOR as u16, |boost| boost.multiplier_bps)
+    }
+}
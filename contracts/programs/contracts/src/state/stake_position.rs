@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use static_assertions::const_assert_eq;
+
+use crate::{
+    constants::{BPS_DENOMINATOR, REWARD_ACC_SCALE},
+    error::BasketError,
+    state::RewardPool,
+};
+
+/// One owner's QSHARE stake in a `RewardPool`.
+#[account]
+#[derive(InitSpace)]
+pub struct StakePosition {
+    pub pool: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    /// `amount * multiplier_bps / BPS_DENOMINATOR`.
+    pub weight: u64,
+    pub multiplier_bps: u16,
+    pub unlock_ts: i64,
+    /// `weight * acc_reward_per_weight` at the last settlement.
+    pub reward_debt: u128,
+    /// Settled rewards not yet claimed.
+    pub rewards_owed: u64,
+    pub bump: u8,
+}
+
+const_assert_eq!(StakePosition::INIT_SPACE, 115);
+
+impl StakePosition {
+    /// Accrue the pool to `now` and settle the position. A boost whose
+    /// commitment has ended drops back to 1x, earning boosted rewards only
+    /// up to `unlock_ts` when the pool has not yet been accrued past it.
+    pub fn sync(&mut self, pool: &mut RewardPool, now: i64) -> Result<()> {
+        if now >= self.unlock_ts && self.multiplier_bps != BPS_DENOMINATOR as u16 {
+            pool.accrue(self.unlock_ts)?;
+            self.settle(pool)?;
+            let amount = self.amount;
+            self.reweight(pool, amount, BPS_DENOMINATOR as u16)?;
+        }
+        pool.accrue(now)?;
+        self.settle(pool)
+    }
+
+    /// Move rewards earned since the last settlement into `rewards_owed`.
+    /// The pool must already be accrued to now.
+    pub fn settle(&mut self, pool: &RewardPool) -> Result<()> {
+        let accumulated = self.accumulated(pool)?;
+        let earned = accumulated
+            .checked_sub(self.reward_debt)
+            .ok_or(BasketError::ArithmeticOverflow)?;
+        self.reward_debt = accumulated;
+        self.rewards_owed = self
+            .rewards_owed
+            .checked_add(u64::try_from(earned).map_err(|_| BasketError::ArithmeticOverflow)?)
+            .ok_or(BasketError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Re-weight a settled position and keep the pool totals in step.
+    pub fn reweight(
+        &mut self,
+        pool: &mut RewardPool,
+        amount: u64,
+        multiplier_bps: u16,
+    ) -> Result<()> {
+        let weight = ((amount as u128)
+            .checked_mul(multiplier_bps as u128)
+            .ok_or(BasketError::ArithmeticOverflow)?
+            / BPS_DENOMINATOR as u128)
+            .try_into()
+            .map_err(|_| BasketError::ArithmeticOverflow)?;
+
+        pool.total_staked = pool
+            .total_staked
+            .checked_sub(self.amount)
+            .and_then(|total| total.checked_add(amount))
+            .ok_or(BasketError::ArithmeticOverflow)?;
+        pool.total_weight = pool
+            .total_weight
+            .checked_sub(self.weight)
+            .and_then(|total| total.checked_add(weight))
+            .ok_or(BasketError::ArithmeticOverflow)?;
+
+        self.amount = amount;
+        self.weight = weight;
+        self.multiplier_bps = multiplier_bps;
+        self.reward_debt = self.accumulated(pool)?;
+        Ok(())
+    }
+
+    fn accumulated(&self, pool: &RewardPool) -> Result<u128> {
+        Ok((self.weight as u128)
+            .checked_mul(pool.acc_reward_per_weight)
+            .ok_or(BasketError::ArithmeticOverflow)?
+            / REWARD_ACC_SCALE)
+    }
+}
@@ -0,0 +1,14 @@
+// PDA Seeds
+pub const EXTRA_ACCOUNT_METAS_SEED: &[u8] = b"extra-account-metas";
+
+// Execute account layout: the interface's fixed accounts come first
+// [source, mint, destination, owner, extra_account_meta_list], then the
+// extra accounts below, in this order.
+pub const BASKETLOCK_PROGRAM_INDEX: u8 = 5;
+pub const CONFIG_INDEX: u8 = 6;
+pub const BASKET_INDEX: u8 = 7;
+pub const DESTINATION_ALLOW_LIST_INDEX: u8 = 8;
+pub const EXTRA_ACCOUNT_META_COUNT: usize = 4;
+
+/// Offset of `owner` inside an SPL token account.
+pub const TOKEN_ACCOUNT_OWNER_OFFSET: u8 = 32;
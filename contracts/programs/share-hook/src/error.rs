@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+#[derive(PartialEq)]
+pub enum ShareHookError {
+    #[msg("Hook invoked outside of a Token-2022 transfer")]
+    NotTransferring,
+
+    #[msg("Basket share mint does not match the transferred mint")]
+    BasketMintMismatch,
+
+    #[msg("Recipient is not on the compliance allow list")]
+    ComplianceDenied,
+}
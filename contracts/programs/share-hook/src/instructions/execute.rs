@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::spl_token_2022::{
+        extension::{
+            transfer_hook::TransferHookAccount, BaseStateWithExtensions, StateWithExtensions,
+        },
+        state::Account as TokenAccountState,
+    },
+    token_interface::{Mint, TokenAccount},
+};
+use contracts::{
+    state::{Basket, BasketLoader, Config, UserAllowList},
+    CONFIG_SEED, USER_ALLOW_SEED,
+};
+
+use crate::{constants::*, error::ShareHookError};
+
+#[derive(Accounts)]
+pub struct Execute<'info> {
+    #[account(token::mint = mint)]
+    pub source_token: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(token::mint = mint)]
+    pub destination_token: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Source owner or delegate — already authorised by Token-2022.
+    pub owner: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by seeds constraint.
+    #[account(
+        seeds = [EXTRA_ACCOUNT_METAS_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by address constraint.
+    #[account(address = contracts::ID)]
+    pub basketlock_program: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        seeds::program = contracts::ID,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        constraint = basket.load_current()?.share_mint == mint.key() @ ShareHookError::BasketMintMismatch,
+    )]
+    pub basket: AccountLoader<'info, Basket>,
+
+    /// Allow-list entry for the recipient; may not exist.
+    /// CHECK: Validated by seeds constraint, deserialised in handler.
+    #[account(
+        seeds = [
+            USER_ALLOW_SEED,
+            basket.key().as_ref(),
+            destination_token.owner.as_ref(),
+        ],
+        bump,
+        seeds::program = contracts::ID,
+    )]
+    pub destination_allow_list: UncheckedAccount<'info>,
+}
+
+impl<'info> Execute<'info> {
+    pub fn handler(ctx: Context<Execute>, _amount: u64) -> Result<()> {
+        let accounts = &ctx.accounts;
+        Self::assert_is_transferring(&accounts.source_token.to_account_info())?;
+
+        if !accounts.config.compliance_enabled {
+            return Ok(());
+        }
+
+        // Shares moving into or out of program custody (vault authority)
+        // are internal bookkeeping, not secondary transfers.
+        let vault_authority = accounts.basket.load_current()?.vault_authority;
+        if accounts.source_token.owner == vault_authority
+            || accounts.destination_token.owner == vault_authority
+        {
+            return Ok(());
+        }
+
+        Self::check_allow_list(
+            &accounts.destination_allow_list,
+            accounts.basket.key(),
+            accounts.destination_token.owner,
+        )
+    }
+
+    /// Reject direct invocations: Token-2022 sets `transferring` on the
+    /// source account only for the duration of the hook CPI.
+    fn assert_is_transferring(source_info: &AccountInfo) -> Result<()> {
+        let data = source_info.try_borrow_data()?;
+        let source = StateWithExtensions::<TokenAccountState>::unpack(&data)?;
+        let hook_account = source.get_extension::<TransferHookAccount>()?;
+        require!(
+            bool::from(hook_account.transferring),
+            ShareHookError::NotTransferring
+        );
+        Ok(())
+    }
+
+    fn check_allow_list(
+        allow_list_info: &AccountInfo,
+        basket_key: Pubkey,
+        recipient: Pubkey,
+    ) -> Result<()> {
+        require!(
+            *allow_list_info.owner == contracts::ID,
+            ShareHookError::ComplianceDenied
+        );
+        let data = allow_list_info.try_borrow_data()?;
+        let allow_list = UserAllowList::try_deserialize(&mut &data[..])
+            .map_err(|_| ShareHookError::ComplianceDenied)?;
+        require!(allow_list.allowed, ShareHookError::ComplianceDenied);
+        require!(
+            allow_list.basket == basket_key,
+            ShareHookError::ComplianceDenied
+        );
+        require!(
+            allow_list.user == recipient,
+            ShareHookError::ComplianceDenied
+        );
+        Ok(())
+    }
+}
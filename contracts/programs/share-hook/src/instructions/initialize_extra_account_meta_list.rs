@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, Token2022};
+use contracts::{
+    state::{Basket, BasketLoader},
+    CONFIG_SEED, USER_ALLOW_SEED,
+};
+use spl_tlv_account_resolution::{account::ExtraAccountMeta, seeds::Seed, state::ExtraAccountMetaList};
+use spl_transfer_hook_interface::instruction::ExecuteInstruction;
+
+use crate::{constants::*, error::ShareHookError};
+
+/// Permissionless: every meta is derived from the basket, so anyone may pay
+/// to initialise the list once the QSHARE mint exists.
+#[derive(Accounts)]
+pub struct InitializeExtraAccountMetaList<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Written as an `ExtraAccountMetaList` TLV in the handler.
+    #[account(
+        init,
+        payer = payer,
+        space = ExtraAccountMetaList::size_of(EXTRA_ACCOUNT_META_COUNT).unwrap(),
+        seeds = [EXTRA_ACCOUNT_METAS_SEED, mint.key().as_ref()],
+        bump,
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+
+    #[account(mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        constraint = basket.load_current()?.share_mint == mint.key() @ ShareHookError::BasketMintMismatch,
+    )]
+    pub basket: AccountLoader<'info, Basket>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitializeExtraAccountMetaList<'info> {
+    pub fn handler(ctx: Context<InitializeExtraAccountMetaList>) -> Result<()> {
+        let (config_key, _) = Pubkey::find_program_address(&[CONFIG_SEED], &contracts::ID);
+
+        let extra_account_metas = [
+            ExtraAccountMeta::new_with_pubkey(&contracts::ID, false, false)?,
+            ExtraAccountMeta::new_with_pubkey(&config_key, false, false)?,
+            ExtraAccountMeta::new_with_pubkey(&ctx.accounts.basket.key(), false, false)?,
+            // UserAllowList PDA of the destination token account's owner
+            ExtraAccountMeta::new_external_pda_with_seeds(
+                BASKETLOCK_PROGRAM_INDEX,
+                &[
+                    Seed::Literal {
+                        bytes: USER_ALLOW_SEED.to_vec(),
+                    },
+                    Seed::AccountKey {
+                        index: BASKET_INDEX,
+                    },
+                    Seed::AccountData {
+                        account_index: 2,
+                        data_index: TOKEN_ACCOUNT_OWNER_OFFSET,
+                        length: 32,
+                    },
+                ],
+                false,
+                false,
+            )?,
+        ];
+
+        ExtraAccountMetaList::init::<ExecuteInstruction>(
+            &mut ctx.accounts.extra_account_meta_list.try_borrow_mut_data()?,
+            &extra_account_metas,
+        )?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,5 @@
+pub mod execute;
+pub mod initialize_extra_account_meta_list;
+
+pub use execute::*;
+pub use initialize_extra_account_meta_list::*;
@@ -0,0 +1,33 @@
+pub mod constants;
+pub mod error;
+pub mod instructions;
+
+use anchor_lang::prelude::*;
+use spl_discriminator::SplDiscriminate;
+use spl_transfer_hook_interface::instruction::{
+    ExecuteInstruction, InitializeExtraAccountMetaListInstruction,
+};
+
+pub use constants::*;
+pub use instructions::*;
+
+declare_id!("Fg6DPXfnvSov9aDYBk6rZ2qsMP5SKyybMmQ2LbH4WsqZ");
+
+/// Token-2022 transfer hook for QSHARE mints: every secondary transfer must
+/// land in a wallet allow-listed for the basket while compliance is enabled.
+#[program]
+pub mod share_hook {
+    use super::*;
+
+    #[instruction(discriminator = InitializeExtraAccountMetaListInstruction::SPL_DISCRIMINATOR_SLICE)]
+    pub fn initialize_extra_account_meta_list(
+        ctx: Context<InitializeExtraAccountMetaList>,
+    ) -> Result<()> {
+        InitializeExtraAccountMetaList::handler(ctx)
+    }
+
+    #[instruction(discriminator = ExecuteInstruction::SPL_DISCRIMINATOR_SLICE)]
+    pub fn execute(ctx: Context<Execute>, amount: u64) -> Result<()> {
+        Execute::handler(ctx, amount)
+    }
+}
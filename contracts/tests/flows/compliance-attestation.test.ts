@@ -0,0 +1,232 @@
+/**
+ * Deposit admitted by an Ed25519 attestation instead of an allow-list PDA.
+ *
+ * The whitelist authority signs (program, basket, user, expiry, nonce)
+ * off-chain; the depositor places the Ed25519 verify instruction right before
+ * deposit_multi.
+ */
+import { expect } from "chai";
+import {
+  Ed25519Program,
+  Keypair,
+  PublicKey,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+import { TOKEN_PROGRAM_ID, getAssociatedTokenAddress } from "@solana/spl-token";
+import {
+  createTestContext,
+  expectError,
+  fundAccount,
+  createTestMint,
+  createTestAta,
+  mintTestTokens,
+  getTokenBalance,
+  findBasketPda,
+  findVaultAuthorityPda,
+  findMintAuthorityPda,
+  findBasketTokenPda,
+  findFeeVaultPda,
+  findAttestationNoncePda,
+  findMetadataPda,
+  TOKEN_METADATA_PROGRAM_ID,
+  type TestContext,
+} from "../setup";
+
+describe("compliance attestation", () => {
+  let ctx: TestContext;
+  const whitelistAuth = Keypair.generate();
+  const basketId = new BN(1);
+  const expiry = new BN(2_000_000_000);
+
+  let basketPda: PublicKey;
+  let vaultAuthority: PublicKey;
+  let mintAuthority: PublicKey;
+  let shareMintKp: Keypair;
+  let usdcMint: PublicKey;
+  let user: Keypair;
+  let userUsdcAta: PublicKey;
+
+  function encodeName(name: string): number[] {
+    const buf = Buffer.alloc(32, 0);
+    buf.write(name, "utf-8");
+    return Array.from(buf);
+  }
+
+  function attestation(opts: {
+    signer?: Keypair;
+    programId?: PublicKey;
+    basket?: PublicKey;
+    user?: PublicKey;
+    expiry?: BN;
+    nonce: BN;
+  }): TransactionInstruction {
+    const message = Buffer.concat([
+      (opts.programId ?? ctx.program.programId).toBuffer(),
+      (opts.basket ?? basketPda).toBuffer(),
+      (opts.user ?? user.publicKey).toBuffer(),
+      (opts.expiry ?? expiry).toTwos(64).toArrayLike(Buffer, "le", 8),
+      opts.nonce.toArrayLike(Buffer, "le", 8),
+    ]);
+    return Ed25519Program.createInstructionWithPrivateKey({
+      privateKey: (opts.signer ?? whitelistAuth).secretKey,
+      message,
+    });
+  }
+
+  async function deposit(pre: TransactionInstruction[], nonce: BN, amount = 100_000_000) {
+    return ctx.program.methods
+      .depositMulti([new BN(amount)], null)
+      .accounts({
+        user: user.publicKey,
+        receiver: user.publicKey,
+        basket: basketPda,
+        mintAuthority,
+        shareMint: shareMintKp.publicKey,
+        userAllowList: null,
+        complianceRoot: null,
+        instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+        attestationNonce: findAttestationNoncePda(basketPda, user.publicKey, nonce)[0],
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .remainingAccounts([
+        { pubkey: findBasketTokenPda(basketPda, usdcMint)[0], isWritable: true, isSigner: false },
+        { pubkey: usdcMint, isWritable: false, isSigner: false },
+        { pubkey: userUsdcAta, isWritable: true, isSigner: false },
+        {
+          pubkey: await getAssociatedTokenAddress(usdcMint, vaultAuthority, true),
+          isWritable: true,
+          isSigner: false,
+        },
+        { pubkey: findFeeVaultPda(basketPda, usdcMint)[0], isWritable: true, isSigner: false },
+      ])
+      .preInstructions(pre)
+      .signers([user])
+      .rpc();
+  }
+
+  beforeEach(async () => {
+    ctx = createTestContext();
+
+    await ctx.program.methods
+      .initConfig(20, whitelistAuth.publicKey, true)
+      .accounts({ program: ctx.program.programId })
+      .rpc();
+
+    shareMintKp = Keypair.generate();
+    [basketPda] = findBasketPda(basketId);
+    [vaultAuthority] = findVaultAuthorityPda(basketId);
+    [mintAuthority] = findMintAuthorityPda(basketId);
+
+    await ctx.program.methods
+      .createBasket(basketId, encodeName("Attested"), null, false, "QSHARE", "")
+      .accounts({
+        shareMint: shareMintKp.publicKey,
+        shareMetadata: findMetadataPda(shareMintKp.publicKey)[0],
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        treasury: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .signers([shareMintKp])
+      .rpc();
+
+    usdcMint = await createTestMint(ctx.provider, ctx.admin.publicKey, 6);
+    await ctx.program.methods
+      .addTokens(0)
+      .accounts({
+        basket: basketPda,
+        underlyingMint: usdcMint,
+        vaultAuthority,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .rpc();
+
+    user = Keypair.generate();
+    fundAccount(ctx.svm, user.publicKey);
+    userUsdcAta = await createTestAta(ctx.provider, usdcMint, user.publicKey);
+    await mintTestTokens(ctx.provider, usdcMint, userUsdcAta, ctx.admin, BigInt(1_000_000_000));
+  });
+
+  it("mints shares and spends the nonce", async () => {
+    const nonce = new BN(1);
+    await deposit([attestation({ nonce })], nonce);
+
+    const shareAta = await getAssociatedTokenAddress(shareMintKp.publicKey, user.publicKey);
+    expect(Number(await getTokenBalance(ctx.provider, shareAta))).to.be.greaterThan(0);
+
+    const record = await ctx.program.account.attestationNonce.fetch(
+      findAttestationNoncePda(basketPda, user.publicKey, nonce)[0],
+    );
+    expect(record.nonce.toNumber()).to.equal(1);
+    expect(record.basket.toBase58()).to.equal(basketPda.toBase58());
+    expect(record.user.toBase58()).to.equal(user.publicKey.toBase58());
+  });
+
+  it("rejects a replayed nonce", async () => {
+    const nonce = new BN(2);
+    await deposit([attestation({ nonce })], nonce);
+
+    // A different amount keeps the transaction from being a byte-for-byte duplicate.
+    await expectError(
+      deposit([attestation({ nonce })], nonce, 50_000_000),
+      "AttestationReplayed",
+    );
+  });
+
+  it("rejects an attestation signed by another key", async () => {
+    const nonce = new BN(3);
+    await expectError(
+      deposit([attestation({ signer: Keypair.generate(), nonce })], nonce),
+      "InvalidAttestation",
+    );
+  });
+
+  it("rejects an attestation for another user", async () => {
+    const nonce = new BN(4);
+    await expectError(
+      deposit([attestation({ user: Keypair.generate().publicKey, nonce })], nonce),
+      "InvalidAttestation",
+    );
+  });
+
+  it("rejects an attestation for another basket", async () => {
+    const nonce = new BN(9);
+    await expectError(
+      deposit([attestation({ basket: Keypair.generate().publicKey, nonce })], nonce),
+      "InvalidAttestation",
+    );
+  });
+
+  it("rejects an attestation for another program", async () => {
+    const nonce = new BN(10);
+    await expectError(
+      deposit([attestation({ programId: Keypair.generate().publicKey, nonce })], nonce),
+      "InvalidAttestation",
+    );
+  });
+
+  it("rejects an expired attestation", async () => {
+    const nonce = new BN(5);
+    const clock = ctx.svm.getClock();
+    clock.unixTimestamp = BigInt(expiry.toString());
+    ctx.svm.setClock(clock);
+
+    await expectError(deposit([attestation({ nonce })], nonce), "AttestationExpired");
+  });
+
+  it("rejects a nonce account for a different nonce", async () => {
+    await expectError(
+      deposit([attestation({ nonce: new BN(6) })], new BN(7)),
+      "InvalidAttestation",
+    );
+  });
+
+  it("rejects a deposit without the Ed25519 instruction", async () => {
+    await expectError(deposit([], new BN(8)), "AttestationMissing");
+  });
+});
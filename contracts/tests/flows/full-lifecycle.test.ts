@@ -22,13 +22,15 @@ import {
   findMintAuthorityPda,
   findBasketTokenPda,
   findFeeVaultPda,
+  findMetadataPda,
+  TOKEN_METADATA_PROGRAM_ID,
 } from "../setup";
 
 describe("full lifecycle", () => {
   it("config → basket → tokens → deposit → withdraw", async () => {
     const ctx = createTestContext();
     const whitelistAuth = Keypair.generate();
-    const basketId = new BN(42);
+    const basketId = new BN(1);
 
     function encodeName(name: string): number[] {
       const buf = Buffer.alloc(32, 0);
@@ -49,9 +51,12 @@ describe("full lifecycle", () => {
     const [mintAuthority] = findMintAuthorityPda(basketId);
 
     await ctx.program.methods
-      .createBasket(basketId, encodeName("E2E Basket"), null)
+      .createBasket(basketId, encodeName("E2E Basket"), null, false, "QSHARE", "")
       .accounts({
         shareMint: shareMintKp.publicKey,
+        shareMetadata: findMetadataPda(shareMintKp.publicKey)[0],
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        treasury: null,
         tokenProgram: TOKEN_PROGRAM_ID,
         program: ctx.program.programId,
       })
@@ -71,7 +76,7 @@ describe("full lifecycle", () => {
     );
 
     await ctx.program.methods
-      .addTokens()
+      .addTokens(0)
       .accounts({
         basket: basketPda,
         underlyingMint: usdcMint,
@@ -90,7 +95,7 @@ describe("full lifecycle", () => {
     );
 
     await ctx.program.methods
-      .addTokens()
+      .addTokens(0)
       .accounts({
         basket: basketPda,
         underlyingMint: usdtMint,
@@ -138,23 +143,28 @@ describe("full lifecycle", () => {
     );
 
     await ctx.program.methods
-      .depositMulti([new BN(500_000_000), new BN(300_000_000)])
+      .depositMulti([new BN(500_000_000), new BN(300_000_000)], null)
       .accounts({
         user: user.publicKey,
+        receiver: user.publicKey,
         basket: basketPda,
         mintAuthority,
         shareMint: shareMintKp.publicKey,
         userAllowList: null,
+        complianceRoot: null,
+        instructionsSysvar: null,
+        attestationNonce: null,
         tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
         program: ctx.program.programId,
       })
       .remainingAccounts([
-        { pubkey: btUsdc, isWritable: false, isSigner: false },
+        { pubkey: btUsdc, isWritable: true, isSigner: false },
         { pubkey: usdcMint, isWritable: false, isSigner: false },
         { pubkey: userUsdcAta, isWritable: true, isSigner: false },
         { pubkey: vaultUsdc, isWritable: true, isSigner: false },
         { pubkey: fvUsdc, isWritable: true, isSigner: false },
-        { pubkey: btUsdt, isWritable: false, isSigner: false },
+        { pubkey: btUsdt, isWritable: true, isSigner: false },
         { pubkey: usdtMint, isWritable: false, isSigner: false },
         { pubkey: userUsdtAta, isWritable: true, isSigner: false },
         { pubkey: vaultUsdt, isWritable: true, isSigner: false },
@@ -174,18 +184,20 @@ describe("full lifecycle", () => {
       .withdrawMulti(new BN(Number(sharesAfterDeposit)))
       .accounts({
         user: user.publicKey,
+        receiver: user.publicKey,
         basket: basketPda,
         shareMint: shareMintKp.publicKey,
         vaultAuthority,
         tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
         program: ctx.program.programId,
       })
       .remainingAccounts([
-        { pubkey: btUsdc, isWritable: false, isSigner: false },
+        { pubkey: btUsdc, isWritable: true, isSigner: false },
         { pubkey: usdcMint, isWritable: false, isSigner: false },
         { pubkey: vaultUsdc, isWritable: true, isSigner: false },
         { pubkey: userUsdcAta, isWritable: true, isSigner: false },
-        { pubkey: btUsdt, isWritable: false, isSigner: false },
+        { pubkey: btUsdt, isWritable: true, isSigner: false },
         { pubkey: usdtMint, isWritable: false, isSigner: false },
         { pubkey: vaultUsdt, isWritable: true, isSigner: false },
         { pubkey: userUsdtAta, isWritable: true, isSigner: false },
@@ -0,0 +1,223 @@
+import { expect } from "chai";
+import { Keypair, PublicKey, Transaction } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+import {
+  TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+  createAssociatedTokenAccountInstruction,
+  createTransferCheckedWithTransferHookInstruction,
+  getAssociatedTokenAddressSync,
+  getAssociatedTokenAddress,
+} from "@solana/spl-token";
+import {
+  createTestContext,
+  fundAccount,
+  createTestMint,
+  createTestAta,
+  mintTestTokens,
+  getTokenBalance,
+  findBasketPda,
+  findVaultAuthorityPda,
+  findMintAuthorityPda,
+  findBasketTokenPda,
+  findFeeVaultPda,
+  findUserAllowListPda,
+  type TestContext,
+} from "../setup";
+
+describe("flow: Token-2022 QSHARE with compliance transfer hook", () => {
+  let ctx: TestContext;
+  const whitelistAuth = Keypair.generate();
+  const basketId = new BN(1);
+
+  let basketPda: PublicKey;
+  let shareMintKp: Keypair;
+  let user: Keypair;
+  let userShareAta: PublicKey;
+
+  function encodeName(name: string): number[] {
+    const buf = Buffer.alloc(32, 0);
+    buf.write(name, "utf-8");
+    return Array.from(buf);
+  }
+
+  async function allowList(who: PublicKey): Promise<void> {
+    await ctx.program.methods
+      .updateAllowList(true)
+      .accounts({
+        authority: whitelistAuth.publicKey,
+        basket: basketPda,
+        user: who,
+        program: ctx.program.programId,
+      })
+      .signers([whitelistAuth])
+      .rpc();
+  }
+
+  async function createShareAta(owner: PublicKey): Promise<PublicKey> {
+    const ata = getAssociatedTokenAddressSync(
+      shareMintKp.publicKey,
+      owner,
+      false,
+      TOKEN_2022_PROGRAM_ID,
+    );
+    await ctx.provider.sendAndConfirm?.(
+      new Transaction().add(
+        createAssociatedTokenAccountInstruction(
+          ctx.admin.publicKey,
+          ata,
+          owner,
+          shareMintKp.publicKey,
+          TOKEN_2022_PROGRAM_ID,
+        ),
+      ),
+      [],
+    );
+    return ata;
+  }
+
+  async function transferShares(
+    destination: PublicKey,
+    amount: bigint,
+  ): Promise<void> {
+    const ix = await createTransferCheckedWithTransferHookInstruction(
+      ctx.provider.connection,
+      userShareAta,
+      shareMintKp.publicKey,
+      destination,
+      user.publicKey,
+      amount,
+      6,
+      [],
+      "confirmed",
+      TOKEN_2022_PROGRAM_ID,
+    );
+    await ctx.provider.sendAndConfirm?.(new Transaction().add(ix), [user]);
+  }
+
+  beforeEach(async () => {
+    ctx = createTestContext();
+    fundAccount(ctx.svm, whitelistAuth.publicKey);
+
+    await ctx.program.methods
+      .initConfig(20, whitelistAuth.publicKey, true)
+      .accounts({ program: ctx.program.programId })
+      .rpc();
+
+    shareMintKp = Keypair.generate();
+    [basketPda] = findBasketPda(basketId);
+    const [vaultAuthority] = findVaultAuthorityPda(basketId);
+    const [mintAuthority] = findMintAuthorityPda(basketId);
+
+    await ctx.program.methods
+      .createBasket(basketId, encodeName("Hooked Basket"), null, true, "QSHARE", "")
+      .accounts({
+        shareMint: shareMintKp.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .signers([shareMintKp])
+      .rpc();
+
+    await ctx.shareHook.methods
+      .initializeExtraAccountMetaList()
+      .accounts({
+        mint: shareMintKp.publicKey,
+        basket: basketPda,
+      })
+      .rpc();
+
+    const usdcMint = await createTestMint(ctx.provider, ctx.admin.publicKey, 6);
+    await ctx.program.methods
+      .addTokens(0)
+      .accounts({
+        basket: basketPda,
+        underlyingMint: usdcMint,
+        vaultAuthority,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .rpc();
+
+    user = Keypair.generate();
+    fundAccount(ctx.svm, user.publicKey);
+    await allowList(user.publicKey);
+
+    const userUsdcAta = await createTestAta(
+      ctx.provider,
+      usdcMint,
+      user.publicKey,
+    );
+    await mintTestTokens(
+      ctx.provider,
+      usdcMint,
+      userUsdcAta,
+      ctx.admin,
+      BigInt(1_000_000_000),
+    );
+    userShareAta = getAssociatedTokenAddressSync(
+      shareMintKp.publicKey,
+      user.publicKey,
+      false,
+      TOKEN_2022_PROGRAM_ID,
+    );
+
+    const [userAllowList] = findUserAllowListPda(basketPda, user.publicKey);
+    await ctx.program.methods
+      .depositMulti([new BN(1_000_000_000)], null)
+      .accounts({
+        user: user.publicKey,
+        receiver: user.publicKey,
+        basket: basketPda,
+        mintAuthority,
+        shareMint: shareMintKp.publicKey,
+        userAllowList,
+        complianceRoot: null,
+        instructionsSysvar: null,
+        attestationNonce: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_2022_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .remainingAccounts([
+        { pubkey: findBasketTokenPda(basketPda, usdcMint)[0], isWritable: true, isSigner: false },
+        { pubkey: usdcMint, isWritable: false, isSigner: false },
+        { pubkey: userUsdcAta, isWritable: true, isSigner: false },
+        {
+          pubkey: await getAssociatedTokenAddress(usdcMint, vaultAuthority, true),
+          isWritable: true,
+          isSigner: false,
+        },
+        { pubkey: findFeeVaultPda(basketPda, usdcMint)[0], isWritable: true, isSigner: false },
+      ])
+      .signers([user])
+      .rpc();
+  });
+
+  it("blocks QSHARE transfers to wallets that are not allow-listed", async () => {
+    const outsider = Keypair.generate();
+    const outsiderAta = await createShareAta(outsider.publicKey);
+
+    try {
+      await transferShares(outsiderAta, BigInt(1_000));
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("ComplianceDenied");
+    }
+  });
+
+  it("allows QSHARE transfers to allow-listed wallets", async () => {
+    const recipient = Keypair.generate();
+    await allowList(recipient.publicKey);
+    const recipientAta = await createShareAta(recipient.publicKey);
+
+    await transferShares(recipientAta, BigInt(1_000));
+
+    const balance = await getTokenBalance(
+      ctx.provider,
+      recipientAta,
+      TOKEN_2022_PROGRAM_ID,
+    );
+    expect(Number(balance)).to.equal(1_000);
+  });
+});
@@ -1,16 +1,29 @@
 import { expect } from "chai";
-import { Keypair, PublicKey } from "@solana/web3.js";
+import { Keypair, PublicKey, SystemProgram, Transaction } from "@solana/web3.js";
 import { BN } from "@coral-xyz/anchor";
-import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import {
+  TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+  ExtensionType,
+  getMintLen,
+  createInitializeMintInstruction,
+  createInitializeMintCloseAuthorityInstruction,
+  createInitializeTransferHookInstruction,
+} from "@solana/spl-token";
 import {
   createTestContext,
   createTestMint,
   findBasketPda,
   findVaultAuthorityPda,
   findBasketTokenPda,
+  findMetadataPda,
+  TOKEN_METADATA_PROGRAM_ID,
   type TestContext,
 } from "../../setup";
 
+const MINT_EXT_FREEZE_AUTHORITY = 1 << 4;
+const MINT_EXT_ALL = 0b111101;
+
 describe("add_tokens", () => {
   let ctx: TestContext;
   const whitelistAuth = Keypair.generate();
@@ -37,9 +50,12 @@ describe("add_tokens", () => {
     [vaultAuthority] = findVaultAuthorityPda(basketId);
 
     await ctx.program.methods
-      .createBasket(basketId, encodeName("Test Basket"), null)
+      .createBasket(basketId, encodeName("Test Basket"), null, false, "QSHARE", "")
       .accounts({
         shareMint: shareMint.publicKey,
+        shareMetadata: findMetadataPda(shareMint.publicKey)[0],
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        treasury: null,
         tokenProgram: TOKEN_PROGRAM_ID,
         program: ctx.program.programId,
       })
@@ -55,7 +71,7 @@ describe("add_tokens", () => {
     const usdcMint = await createTestMint(ctx.provider, ctx.admin.publicKey, 6);
 
     await ctx.program.methods
-      .addTokens()
+      .addTokens(0)
       .accounts({
         basket: basketPda,
         underlyingMint: usdcMint,
@@ -83,7 +99,7 @@ describe("add_tokens", () => {
     const usdtMint = await createTestMint(ctx.provider, ctx.admin.publicKey, 6);
 
     await ctx.program.methods
-      .addTokens()
+      .addTokens(0)
       .accounts({
         basket: basketPda,
         underlyingMint: usdcMint,
@@ -94,7 +110,7 @@ describe("add_tokens", () => {
       .rpc();
 
     await ctx.program.methods
-      .addTokens()
+      .addTokens(0)
       .accounts({
         basket: basketPda,
         underlyingMint: usdtMint,
@@ -107,4 +123,142 @@ describe("add_tokens", () => {
     const basket = await ctx.program.account.basket.fetch(basketPda);
     expect(basket.tokenCount).to.equal(2);
   });
+
+  it("rejects a mint with a freeze authority under the default policy", async () => {
+    const frozenMint = await createTestMint(
+      ctx.provider,
+      ctx.admin.publicKey,
+      6,
+      ctx.admin.publicKey,
+    );
+
+    try {
+      await ctx.program.methods
+        .addTokens(MINT_EXT_FREEZE_AUTHORITY)
+        .accounts({
+          basket: basketPda,
+          underlyingMint: frozenMint,
+          vaultAuthority,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          program: ctx.program.programId,
+        })
+        .rpc();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("RiskyMintExtension");
+    }
+  });
+
+  it("requires acknowledgement even when the policy allows the extension", async () => {
+    const frozenMint = await createTestMint(
+      ctx.provider,
+      ctx.admin.publicKey,
+      6,
+      ctx.admin.publicKey,
+    );
+
+    await ctx.program.methods
+      .setConfig(null, null, null, null, MINT_EXT_FREEZE_AUTHORITY)
+      .accounts({ program: ctx.program.programId })
+      .rpc();
+
+    try {
+      await ctx.program.methods
+        .addTokens(0)
+        .accounts({
+          basket: basketPda,
+          underlyingMint: frozenMint,
+          vaultAuthority,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          program: ctx.program.programId,
+        })
+        .rpc();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("MintExtensionNotAcknowledged");
+    }
+
+    await ctx.program.methods
+      .addTokens(MINT_EXT_FREEZE_AUTHORITY)
+      .accounts({
+        basket: basketPda,
+        underlyingMint: frozenMint,
+        vaultAuthority,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .rpc();
+
+    const [basketTokenPda] = findBasketTokenPda(basketPda, frozenMint);
+    const basketToken = await ctx.program.account.basketToken.fetch(
+      basketTokenPda,
+    );
+    expect(basketToken.extensionFlags).to.equal(MINT_EXT_FREEZE_AUTHORITY);
+  });
+
+  describe("with an unsupported Token-2022 extension", () => {
+    /** A Token-2022 mint carrying one extension, authority the admin. */
+    async function createExtensionMint(extension: ExtensionType): Promise<PublicKey> {
+      const mint = Keypair.generate();
+      const space = getMintLen([extension]);
+      const init =
+        extension === ExtensionType.TransferHook
+          ? createInitializeTransferHookInstruction(
+              mint.publicKey,
+              ctx.admin.publicKey,
+              ctx.shareHook.programId,
+              TOKEN_2022_PROGRAM_ID,
+            )
+          : createInitializeMintCloseAuthorityInstruction(
+              mint.publicKey,
+              ctx.admin.publicKey,
+              TOKEN_2022_PROGRAM_ID,
+            );
+      const tx = new Transaction().add(
+        SystemProgram.createAccount({
+          fromPubkey: ctx.admin.publicKey,
+          newAccountPubkey: mint.publicKey,
+          space,
+          lamports: Number(ctx.svm.minimumBalanceForRentExemption(BigInt(space))),
+          programId: TOKEN_2022_PROGRAM_ID,
+        }),
+        init,
+        createInitializeMintInstruction(
+          mint.publicKey,
+          6,
+          ctx.admin.publicKey,
+          null,
+          TOKEN_2022_PROGRAM_ID,
+        ),
+      );
+      await ctx.provider.sendAndConfirm!(tx, [mint]);
+      return mint.publicKey;
+    }
+
+    for (const extension of [ExtensionType.TransferHook, ExtensionType.MintCloseAuthority]) {
+      it(`rejects ${ExtensionType[extension]} even when acknowledged`, async () => {
+        const mint = await createExtensionMint(extension);
+        await ctx.program.methods
+          .setConfig(null, null, null, null, MINT_EXT_ALL)
+          .accounts({ program: ctx.program.programId })
+          .rpc();
+
+        try {
+          await ctx.program.methods
+            .addTokens(0xff)
+            .accounts({
+              basket: basketPda,
+              underlyingMint: mint,
+              vaultAuthority,
+              tokenProgram: TOKEN_2022_PROGRAM_ID,
+              program: ctx.program.programId,
+            })
+            .rpc();
+          expect.fail("should have thrown");
+        } catch (err: any) {
+          expect(err.toString()).to.include("UnsupportedMintExtension");
+        }
+      });
+    }
+  });
 });
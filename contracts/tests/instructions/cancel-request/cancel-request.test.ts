@@ -0,0 +1,169 @@
+import { expect } from "chai";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { TOKEN_PROGRAM_ID, getAssociatedTokenAddressSync } from "@solana/spl-token";
+import {
+  createTestContext,
+  expectError,
+  mintTestTokens,
+  getTokenBalance,
+  findEpochPda,
+  findDepositRequestPda,
+  findRedeemRequestPda,
+  createEpochFixture,
+  newEpochUser,
+  depositNow,
+  requestDeposit,
+  requestRedeem,
+  settle,
+  shareBalance,
+  type TestContext,
+  type EpochFixture,
+  type EpochUser,
+} from "../../setup";
+
+describe("cancel_request", () => {
+  let ctx: TestContext;
+  let fx: EpochFixture;
+  const operator = Keypair.generate();
+
+  let basketPda: PublicKey;
+  let vaultAuthority: PublicKey;
+  let shareMint: PublicKey;
+  let usdcMint: PublicKey;
+  let basketTokenPda: PublicKey;
+  let epochQueue: PublicKey;
+  let pendingAta: PublicKey;
+  let shareEscrow: PublicKey;
+
+  function cancel(
+    user: EpochUser,
+    { deposit, redeem }: { deposit: boolean; redeem: boolean },
+  ) {
+    return ctx.program.methods
+      .cancelRequest()
+      .accounts({
+        owner: user.kp.publicKey,
+        basket: basketPda,
+        depositRequest: deposit ? findDepositRequestPda(basketPda, user.kp.publicKey)[0] : null,
+        redeemRequest: redeem ? findRedeemRequestPda(basketPda, user.kp.publicKey)[0] : null,
+        shareMint,
+        vaultAuthority,
+        ownerShareAta: redeem
+          ? getAssociatedTokenAddressSync(shareMint, user.kp.publicKey)
+          : null,
+        shareEscrow: redeem ? shareEscrow : null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .remainingAccounts(
+        deposit
+          ? [
+              { pubkey: basketTokenPda, isWritable: false, isSigner: false },
+              { pubkey: usdcMint, isWritable: false, isSigner: false },
+              { pubkey: pendingAta, isWritable: true, isSigner: false },
+              { pubkey: user.usdcAta, isWritable: true, isSigner: false },
+            ]
+          : [],
+      )
+      .signers([user.kp])
+      .rpc();
+  }
+
+  async function queue() {
+    return ctx.program.account.epochQueue.fetch(epochQueue);
+  }
+
+  beforeEach(async () => {
+    ctx = createTestContext();
+    fx = await createEpochFixture(ctx, operator);
+    ({
+      basket: basketPda,
+      vaultAuthority,
+      shareMint,
+      usdcMint,
+      basketToken: basketTokenPda,
+      epochQueue,
+      pendingAta,
+      shareEscrow,
+    } = fx);
+  });
+
+  it("refunds a queued deposit net of the fee", async () => {
+    const user = await newEpochUser(fx);
+    await requestDeposit(fx, user, 1_000_000_000);
+    await cancel(user, { deposit: true, redeem: false });
+
+    expect(Number(await getTokenBalance(ctx.provider, user.usdcAta))).to.equal(998_000_000);
+    expect(Number(await getTokenBalance(ctx.provider, pendingAta))).to.equal(0);
+    expect((await queue()).pendingDepositValue.toNumber()).to.equal(0);
+    const [requestPda] = findDepositRequestPda(basketPda, user.kp.publicKey);
+    expect(ctx.svm.getAccount(requestPda)).to.equal(null);
+  });
+
+  it("refunds each request exactly what it escrowed", async () => {
+    const a = await newEpochUser(fx);
+    const b = await newEpochUser(fx);
+    await requestDeposit(fx, a, 250_000_000);
+    await requestDeposit(fx, b, 750_000_000);
+    await cancel(a, { deposit: true, redeem: false });
+
+    expect(Number(await getTokenBalance(ctx.provider, a.usdcAta))).to.equal(999_500_000);
+    expect(Number(await getTokenBalance(ctx.provider, pendingAta))).to.equal(748_500_000);
+    expect((await queue()).pendingDepositValue.toNumber()).to.equal(748_500_000);
+
+    // The remaining request still settles in full.
+    const epochId = await settle(fx);
+    const epoch = await ctx.program.account.epoch.fetch(findEpochPda(basketPda, epochId)[0]);
+    expect(epoch.unclaimedDepositValue.toNumber()).to.equal(748_500_000);
+  });
+
+  it("leaves tokens sent straight to the escrow behind", async () => {
+    const user = await newEpochUser(fx);
+    await requestDeposit(fx, user, 1_000_000_000);
+    await mintTestTokens(ctx.provider, usdcMint, pendingAta, ctx.admin, BigInt(1_000_000));
+    await cancel(user, { deposit: true, redeem: false });
+
+    expect(Number(await getTokenBalance(ctx.provider, user.usdcAta))).to.equal(998_000_000);
+    expect(Number(await getTokenBalance(ctx.provider, pendingAta))).to.equal(1_000_000);
+  });
+
+  it("returns escrowed shares", async () => {
+    const user = await newEpochUser(fx);
+    await depositNow(fx, user);
+    await requestRedeem(fx, user, 400_000_000);
+    await cancel(user, { deposit: false, redeem: true });
+
+    expect(await shareBalance(fx, user.kp.publicKey)).to.equal(998_000_000);
+    expect(Number(await getTokenBalance(ctx.provider, shareEscrow))).to.equal(0);
+    expect((await queue()).pendingRedeemShares.toNumber()).to.equal(0);
+  });
+
+  it("cancels both requests at once", async () => {
+    const user = await newEpochUser(fx);
+    await depositNow(fx, user);
+    await requestRedeem(fx, user, 400_000_000);
+    await mintTestTokens(ctx.provider, usdcMint, user.usdcAta, ctx.admin, BigInt(500_000_000));
+    await requestDeposit(fx, user, 500_000_000);
+    await cancel(user, { deposit: true, redeem: true });
+
+    expect(await shareBalance(fx, user.kp.publicKey)).to.equal(998_000_000);
+    expect(Number(await getTokenBalance(ctx.provider, user.usdcAta))).to.equal(499_000_000);
+    const pending = await queue();
+    expect(pending.pendingDepositValue.toNumber()).to.equal(0);
+    expect(pending.pendingRedeemShares.toNumber()).to.equal(0);
+  });
+
+  it("rejects a request whose epoch has settled", async () => {
+    const user = await newEpochUser(fx);
+    await requestDeposit(fx, user, 1_000_000_000);
+    await settle(fx);
+
+    await expectError(cancel(user, { deposit: true, redeem: false }), "RequestSettled");
+  });
+
+  it("rejects a call with no request", async () => {
+    const user = await newEpochUser(fx);
+    await expectError(cancel(user, { deposit: false, redeem: false }), "NoRequest");
+  });
+});
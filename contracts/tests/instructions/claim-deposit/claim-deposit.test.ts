@@ -0,0 +1,85 @@
+import { expect } from "chai";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import {
+  createTestContext,
+  getTokenBalance,
+  findEpochPda,
+  findDepositRequestPda,
+  createEpochFixture,
+  newEpochUser,
+  requestDeposit,
+  settle,
+  claimDeposit,
+  shareBalance,
+  type TestContext,
+  type EpochFixture,
+} from "../../setup";
+
+describe("claim_deposit", () => {
+  let ctx: TestContext;
+  let fx: EpochFixture;
+  const operator = Keypair.generate();
+
+  let basketPda: PublicKey;
+  let shareEscrow: PublicKey;
+
+  beforeEach(async () => {
+    ctx = createTestContext();
+    fx = await createEpochFixture(ctx, operator);
+    ({ basket: basketPda, shareEscrow } = fx);
+  });
+
+  it("delivers the filled shares and closes the request", async () => {
+    const user = await newEpochUser(fx);
+    await requestDeposit(fx, user, 1_000_000_000);
+    const epochId = await settle(fx);
+
+    await claimDeposit(fx, user, epochId);
+
+    expect(await shareBalance(fx, user.kp.publicKey)).to.equal(998_000_000);
+    expect(Number(await getTokenBalance(ctx.provider, shareEscrow))).to.equal(0);
+    const [requestPda] = findDepositRequestPda(basketPda, user.kp.publicKey);
+    expect(ctx.svm.getAccount(requestPda)).to.be.null;
+  });
+
+  it("splits an epoch's shares pro rata", async () => {
+    const a = await newEpochUser(fx);
+    const b = await newEpochUser(fx);
+    await requestDeposit(fx, a, 250_000_000);
+    await requestDeposit(fx, b, 750_000_000);
+    const epochId = await settle(fx);
+
+    await claimDeposit(fx, b, epochId);
+    await claimDeposit(fx, a, epochId);
+
+    expect(await shareBalance(fx, a.kp.publicKey)).to.equal(249_500_000);
+    expect(await shareBalance(fx, b.kp.publicKey)).to.equal(748_500_000);
+    const [epochPda] = findEpochPda(basketPda, epochId);
+    const epoch = await ctx.program.account.epoch.fetch(epochPda);
+    expect(epoch.unclaimedShares.toNumber()).to.equal(0);
+  });
+
+  it("allows a new request once claimed", async () => {
+    const user = await newEpochUser(fx);
+    await requestDeposit(fx, user, 500_000_000);
+    const epochId = await settle(fx);
+    await claimDeposit(fx, user, epochId);
+
+    await requestDeposit(fx, user, 500_000_000);
+    const [requestPda] = findDepositRequestPda(basketPda, user.kp.publicKey);
+    const request = await ctx.program.account.depositRequest.fetch(requestPda);
+    expect(request.epoch.toNumber()).to.equal(1);
+  });
+
+  it("rejects a claim before settlement", async () => {
+    const user = await newEpochUser(fx);
+    await requestDeposit(fx, user, 500_000_000);
+
+    try {
+      await claimDeposit(fx, user, 0);
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("AccountNotInitialized");
+    }
+  });
+});
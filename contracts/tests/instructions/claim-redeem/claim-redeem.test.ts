@@ -0,0 +1,104 @@
+import { expect } from "chai";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { TOKEN_PROGRAM_ID, getAssociatedTokenAddress } from "@solana/spl-token";
+import {
+  createTestContext,
+  getTokenBalance,
+  findEpochPda,
+  findRedeemRequestPda,
+  createEpochFixture,
+  newEpochUser,
+  depositNow,
+  requestRedeem,
+  settle,
+  claimRedeem,
+  type TestContext,
+  type EpochFixture,
+} from "../../setup";
+
+describe("claim_redeem", () => {
+  let ctx: TestContext;
+  let fx: EpochFixture;
+  const operator = Keypair.generate();
+
+  let basketPda: PublicKey;
+  let usdcMint: PublicKey;
+  let basketTokenPda: PublicKey;
+  let vaultAta: PublicKey;
+  let epochQueue: PublicKey;
+
+  beforeEach(async () => {
+    ctx = createTestContext();
+    fx = await createEpochFixture(ctx, operator);
+    ({ basket: basketPda, usdcMint, basketToken: basketTokenPda, vaultAta, epochQueue } = fx);
+  });
+
+  it("pays out the redemption and closes the request", async () => {
+    const user = await newEpochUser(fx);
+    await depositNow(fx, user);
+    await requestRedeem(fx, user, 499_000_000);
+    const epochId = await settle(fx);
+
+    await claimRedeem(fx, user, epochId);
+
+    expect(Number(await getTokenBalance(ctx.provider, user.usdcAta))).to.equal(499_000_000);
+    const [requestPda] = findRedeemRequestPda(basketPda, user.kp.publicKey);
+    expect(ctx.svm.getAccount(requestPda)).to.be.null;
+    const [epochPda] = findEpochPda(basketPda, epochId);
+    const payoutAta = await getAssociatedTokenAddress(usdcMint, epochPda, true);
+    expect(Number(await getTokenBalance(ctx.provider, payoutAta))).to.equal(0);
+  });
+
+  it("splits an epoch's payout pro rata", async () => {
+    const a = await newEpochUser(fx);
+    const b = await newEpochUser(fx);
+    await depositNow(fx, a);
+    await depositNow(fx, b);
+    await requestRedeem(fx, a, 100_000_000);
+    await requestRedeem(fx, b, 300_000_000);
+    const epochId = await settle(fx);
+
+    let queue = await ctx.program.account.epochQueue.fetch(epochQueue);
+    expect(queue.unclaimedRedeemShares.toNumber()).to.equal(400_000_000);
+
+    await claimRedeem(fx, a, epochId);
+    await claimRedeem(fx, b, epochId);
+
+    queue = await ctx.program.account.epochQueue.fetch(epochQueue);
+    expect(queue.unclaimedRedeemShares.toNumber()).to.equal(0);
+
+    expect(Number(await getTokenBalance(ctx.provider, a.usdcAta))).to.equal(100_000_000);
+    expect(Number(await getTokenBalance(ctx.provider, b.usdcAta))).to.equal(300_000_000);
+  });
+
+  it("rejects a payout account that is not the epoch's", async () => {
+    const user = await newEpochUser(fx);
+    await depositNow(fx, user);
+    await requestRedeem(fx, user, 100_000_000);
+    const epochId = await settle(fx);
+    const [epoch] = findEpochPda(basketPda, epochId);
+
+    try {
+      await ctx.program.methods
+        .claimRedeem()
+        .accounts({
+          owner: user.kp.publicKey,
+          basket: basketPda,
+          epoch,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          program: ctx.program.programId,
+        })
+        .remainingAccounts([
+          { pubkey: basketTokenPda, isWritable: false, isSigner: false },
+          { pubkey: usdcMint, isWritable: false, isSigner: false },
+          { pubkey: vaultAta, isWritable: true, isSigner: false },
+          { pubkey: user.usdcAta, isWritable: true, isSigner: false },
+        ])
+        .signers([user.kp])
+        .rpc();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("InvalidEscrowAccount");
+    }
+  });
+});
@@ -0,0 +1,108 @@
+import { expect } from "chai";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { getAssociatedTokenAddress } from "@solana/spl-token";
+import {
+  createTestContext,
+  createRewardFixture,
+  newHolder,
+  fundRewards,
+  stake,
+  claimRewards,
+  type TestContext,
+  type RewardFixture,
+} from "../../setup";
+
+const DAY = 86_400;
+
+describe("claim_rewards", () => {
+  let ctx: TestContext;
+  let fx: RewardFixture;
+
+  let shareMint: PublicKey;
+  let rewardPool: PublicKey;
+  let user: Keypair;
+  let userShareAta: PublicKey;
+
+  function warp(seconds: number) {
+    const clock = ctx.svm.getClock();
+    clock.unixTimestamp += BigInt(seconds);
+    ctx.svm.setClock(clock);
+  }
+
+  beforeEach(async () => {
+    ctx = createTestContext();
+    fx = await createRewardFixture(ctx);
+    ({ shareMint, rewardPool } = fx);
+
+    user = await newHolder(fx);
+    userShareAta = await getAssociatedTokenAddress(shareMint, user.publicKey);
+  });
+
+  it("pays a sole staker the whole stream", async () => {
+    await stake(fx, user, 100_000_000, 0);
+    await fundRewards(fx, 1_000_000, 1_000);
+    warp(1_000);
+
+    const claimed = await claimRewards(fx, user);
+    expect(Number(claimed)).to.be.within(999_999, 1_000_000);
+  });
+
+  it("splits rewards by boosted weight", async () => {
+    const other = await newHolder(fx);
+    await stake(fx, user, 100_000_000, 0);
+    await stake(fx, other, 100_000_000, 90 * DAY);
+    await fundRewards(fx, 3_000_000, 1_000);
+    warp(1_000);
+
+    // 1x vs 2x weight.
+    expect(Number(await claimRewards(fx, user))).to.be.within(999_999, 1_000_000);
+    expect(Number(await claimRewards(fx, other))).to.be.within(1_999_999, 2_000_000);
+  });
+
+  it("stops accruing at the end of the period", async () => {
+    await stake(fx, user, 100_000_000, 0);
+    await fundRewards(fx, 1_000_000, 1_000);
+    warp(5_000);
+
+    expect(Number(await claimRewards(fx, user))).to.be.at.most(1_000_000);
+  });
+
+  it("holds the stream back while nobody is staked", async () => {
+    await fundRewards(fx, 1_000_000, 1_000);
+    warp(600);
+    await stake(fx, user, 100_000_000, 0);
+
+    // The 600 idle seconds are added to the period rather than lost.
+    const pool = await ctx.program.account.rewardPool.fetch(rewardPool);
+    const now = Number(ctx.svm.getClock().unixTimestamp);
+    expect(pool.periodEnd.toNumber()).to.equal(now + 1_000);
+
+    warp(1_000);
+    const claimed = await claimRewards(fx, user);
+    expect(Number(claimed)).to.be.within(999_999, 1_000_000);
+  });
+
+  it("rejects a claim with nothing owed", async () => {
+    await stake(fx, user, 100_000_000, 0);
+
+    try {
+      await claimRewards(fx, user);
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("NothingToClaim");
+    }
+  });
+
+  it("earns boosted rewards only until the commitment ends", async () => {
+    const other = await newHolder(fx);
+    await stake(fx, user, 100_000_000, 0);
+    await stake(fx, other, 100_000_000, 30 * DAY);
+    // 100 reward units per second over 40 days.
+    await fundRewards(fx, 345_600_000, 40 * DAY);
+    warp(40 * DAY);
+
+    // 1.5x vs 1x for 30 days, then 1x vs 1x for 10 days.
+    expect(Number(await claimRewards(fx, other))).to.be.within(198_719_990, 198_720_000);
+    expect(Number(await claimRewards(fx, user))).to.be.within(146_879_990, 146_880_000);
+  });
+});
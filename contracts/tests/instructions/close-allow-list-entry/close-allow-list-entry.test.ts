@@ -0,0 +1,168 @@
+import { expect } from "chai";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import {
+  createTestContext,
+  expectError,
+  fundAccount,
+  findBasketPda,
+  findUserAllowListPda,
+  findMetadataPda,
+  TOKEN_METADATA_PROGRAM_ID,
+  type TestContext,
+} from "../../setup";
+
+describe("close_allow_list_entry", () => {
+  let ctx: TestContext;
+  const whitelistAuth = Keypair.generate();
+  const basketId = new BN(1);
+  let basketPda: PublicKey;
+
+  function encodeName(name: string): number[] {
+    const buf = Buffer.alloc(32, 0);
+    buf.write(name, "utf-8");
+    return Array.from(buf);
+  }
+
+  async function allow(user: PublicKey): Promise<PublicKey> {
+    await ctx.program.methods
+      .updateAllowList(true)
+      .accounts({
+        authority: whitelistAuth.publicKey,
+        basket: basketPda,
+        user,
+        program: ctx.program.programId,
+      })
+      .signers([whitelistAuth])
+      .rpc();
+    return findUserAllowListPda(basketPda, user)[0];
+  }
+
+  beforeEach(async () => {
+    ctx = createTestContext();
+    fundAccount(ctx.svm, whitelistAuth.publicKey);
+
+    await ctx.program.methods
+      .initConfig(20, whitelistAuth.publicKey, true)
+      .accounts({ program: ctx.program.programId })
+      .rpc();
+
+    const shareMint = Keypair.generate();
+    [basketPda] = findBasketPda(basketId);
+
+    await ctx.program.methods
+      .createBasket(basketId, encodeName("Allow Close"), null, false, "QSHARE", "")
+      .accounts({
+        shareMint: shareMint.publicKey,
+        shareMetadata: findMetadataPda(shareMint.publicKey)[0],
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        treasury: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .signers([shareMint])
+      .rpc();
+  });
+
+  it("closes an entry and refunds rent to the recipient", async () => {
+    const entry = await allow(Keypair.generate().publicKey);
+    const recipient = Keypair.generate().publicKey;
+    const rent = ctx.svm.getBalance(entry)!;
+
+    await ctx.program.methods
+      .closeAllowListEntry()
+      .accounts({
+        authority: whitelistAuth.publicKey,
+        userAllowList: entry,
+        recipient,
+        program: ctx.program.programId,
+      })
+      .signers([whitelistAuth])
+      .rpc();
+
+    expect(ctx.svm.getAccount(entry)).to.be.null;
+    expect(ctx.svm.getBalance(recipient)!.toString()).to.equal(rent.toString());
+  });
+
+  it("rejects a caller other than the whitelist authority", async () => {
+    const entry = await allow(Keypair.generate().publicKey);
+
+    await expectError(
+      ctx.program.methods
+        .closeAllowListEntry()
+        .accounts({
+          authority: ctx.admin.publicKey,
+          userAllowList: entry,
+          recipient: ctx.admin.publicKey,
+          program: ctx.program.programId,
+        })
+        .rpc(),
+      "Unauthorized",
+    );
+  });
+
+  it("closes a batch of entries in one instruction", async () => {
+    const entries: PublicKey[] = [];
+    for (let i = 0; i < 3; i++) {
+      entries.push(await allow(Keypair.generate().publicKey));
+    }
+    const recipient = Keypair.generate().publicKey;
+    const rent = entries.reduce((sum, e) => sum + ctx.svm.getBalance(e)!, BigInt(0));
+
+    await ctx.program.methods
+      .closeAllowListEntries()
+      .accounts({
+        authority: whitelistAuth.publicKey,
+        recipient,
+        program: ctx.program.programId,
+      })
+      .remainingAccounts(
+        entries.map((pubkey) => ({ pubkey, isWritable: true, isSigner: false })),
+      )
+      .signers([whitelistAuth])
+      .rpc();
+
+    for (const entry of entries) {
+      expect(ctx.svm.getAccount(entry)).to.be.null;
+    }
+    expect(ctx.svm.getBalance(recipient)!.toString()).to.equal(rent.toString());
+  });
+
+  it("rejects an empty batch", async () => {
+    await expectError(
+      ctx.program.methods
+        .closeAllowListEntries()
+        .accounts({
+          authority: whitelistAuth.publicKey,
+          recipient: whitelistAuth.publicKey,
+          program: ctx.program.programId,
+        })
+        .signers([whitelistAuth])
+        .rpc(),
+      "NoAllowListEntries",
+    );
+  });
+
+  it("rejects a batch containing a non-entry account", async () => {
+    const entry = await allow(Keypair.generate().publicKey);
+
+    await expectError(
+      ctx.program.methods
+        .closeAllowListEntries()
+        .accounts({
+          authority: whitelistAuth.publicKey,
+          recipient: whitelistAuth.publicKey,
+          program: ctx.program.programId,
+        })
+        .remainingAccounts([
+          { pubkey: entry, isWritable: true, isSigner: false },
+          { pubkey: basketPda, isWritable: true, isSigner: false },
+        ])
+        .signers([whitelistAuth])
+        .rpc(),
+      "AccountDiscriminatorMismatch",
+    );
+    expect(ctx.svm.getAccount(entry)).to.not.be.null;
+  });
+});
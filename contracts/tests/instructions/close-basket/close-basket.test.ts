@@ -0,0 +1,261 @@
+import { expect } from "chai";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+import { TOKEN_PROGRAM_ID, getAssociatedTokenAddress } from "@solana/spl-token";
+import {
+  createTestContext,
+  expectError,
+  fundAccount,
+  createTestMint,
+  createTestAta,
+  mintTestTokens,
+  getTokenBalance,
+  findBasketPda,
+  findBasketPricePda,
+  findVaultAuthorityPda,
+  findMintAuthorityPda,
+  findBasketTokenPda,
+  findFeeVaultPda,
+  findLockTiersPda,
+  findEpochQueuePda,
+  findMetadataPda,
+  TOKEN_METADATA_PROGRAM_ID,
+  type TestContext,
+} from "../../setup";
+
+describe("close_basket", () => {
+  let ctx: TestContext;
+  const whitelistAuth = Keypair.generate();
+  const treasury = Keypair.generate();
+  const basketId = new BN(1);
+
+  let basketPda: PublicKey;
+  let basketPricePda: PublicKey;
+  let vaultAuthority: PublicKey;
+  let mintAuthority: PublicKey;
+  let shareMintKp: Keypair;
+  let usdcMint: PublicKey;
+  let basketTokenPda: PublicKey;
+  let feeVaultPda: PublicKey;
+  let vaultAta: PublicKey;
+  let epochQueue: PublicKey;
+  let pendingAta: PublicKey;
+  let treasuryUsdcAta: PublicKey;
+
+  function encodeName(name: string): number[] {
+    const buf = Buffer.alloc(32, 0);
+    buf.write(name, "utf-8");
+    return Array.from(buf);
+  }
+
+  function legAccounts(feeDestination = treasuryUsdcAta) {
+    return [
+      { pubkey: basketTokenPda, isWritable: true, isSigner: false },
+      { pubkey: usdcMint, isWritable: false, isSigner: false },
+      { pubkey: vaultAta, isWritable: true, isSigner: false },
+      { pubkey: feeVaultPda, isWritable: true, isSigner: false },
+      { pubkey: pendingAta, isWritable: true, isSigner: false },
+      { pubkey: feeDestination, isWritable: true, isSigner: false },
+    ];
+  }
+
+  async function closeBasket(signer = ctx.admin, legs = legAccounts()) {
+    await ctx.program.methods
+      .closeBasket()
+      .accounts({
+        authority: signer.publicKey,
+        owner: ctx.admin.publicKey,
+        basket: basketPda,
+        shareMint: shareMintKp.publicKey,
+        vaultAuthority,
+        shareEscrow: await getAssociatedTokenAddress(shareMintKp.publicKey, vaultAuthority, true),
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .remainingAccounts(legs)
+      .signers(signer === ctx.admin ? [] : [signer])
+      .rpc();
+  }
+
+  /** Deposit 1,000 USDC from a fresh wallet; returns its share ATA. */
+  async function deposit(): Promise<{ kp: Keypair; usdcAta: PublicKey; shareAta: PublicKey }> {
+    const kp = Keypair.generate();
+    fundAccount(ctx.svm, kp.publicKey);
+    const usdcAta = await createTestAta(ctx.provider, usdcMint, kp.publicKey);
+    await mintTestTokens(ctx.provider, usdcMint, usdcAta, ctx.admin, BigInt(1_000_000_000));
+
+    await ctx.program.methods
+      .depositMulti([new BN(1_000_000_000)], null)
+      .accounts({
+        user: kp.publicKey,
+        receiver: kp.publicKey,
+        basket: basketPda,
+        mintAuthority,
+        shareMint: shareMintKp.publicKey,
+        userAllowList: null,
+        complianceRoot: null,
+        instructionsSysvar: null,
+        attestationNonce: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .remainingAccounts([
+        { pubkey: basketTokenPda, isWritable: true, isSigner: false },
+        { pubkey: usdcMint, isWritable: false, isSigner: false },
+        { pubkey: usdcAta, isWritable: true, isSigner: false },
+        { pubkey: vaultAta, isWritable: true, isSigner: false },
+        { pubkey: feeVaultPda, isWritable: true, isSigner: false },
+      ])
+      .signers([kp])
+      .rpc();
+
+    const shareAta = await getAssociatedTokenAddress(shareMintKp.publicKey, kp.publicKey);
+    return { kp, usdcAta, shareAta };
+  }
+
+  beforeEach(async () => {
+    ctx = createTestContext();
+
+    await ctx.program.methods
+      .initConfig(20, whitelistAuth.publicKey, false)
+      .accounts({ program: ctx.program.programId })
+      .rpc();
+
+    shareMintKp = Keypair.generate();
+    [basketPda] = findBasketPda(basketId);
+    [basketPricePda] = findBasketPricePda(basketPda);
+    [vaultAuthority] = findVaultAuthorityPda(basketId);
+    [mintAuthority] = findMintAuthorityPda(basketId);
+
+    await ctx.program.methods
+      .createBasket(basketId, encodeName("Closing"), null, false, "QSHARE", "")
+      .accounts({
+        shareMint: shareMintKp.publicKey,
+        shareMetadata: findMetadataPda(shareMintKp.publicKey)[0],
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        treasury: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .signers([shareMintKp])
+      .rpc();
+
+    await ctx.program.methods
+      .setCreationPolicy(false, new BN(0), new BN(0), treasury.publicKey)
+      .accounts({ program: ctx.program.programId })
+      .rpc();
+
+    usdcMint = await createTestMint(ctx.provider, ctx.admin.publicKey, 6);
+    [basketTokenPda] = findBasketTokenPda(basketPda, usdcMint);
+    [feeVaultPda] = findFeeVaultPda(basketPda, usdcMint);
+    vaultAta = await getAssociatedTokenAddress(usdcMint, vaultAuthority, true);
+    [epochQueue] = findEpochQueuePda(basketPda);
+    pendingAta = await getAssociatedTokenAddress(usdcMint, epochQueue, true);
+    treasuryUsdcAta = await createTestAta(ctx.provider, usdcMint, treasury.publicKey);
+
+    await ctx.program.methods
+      .addTokens(0)
+      .accounts({
+        basket: basketPda,
+        underlyingMint: usdcMint,
+        vaultAuthority,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .rpc();
+  });
+
+  it("closes an empty basket and its legs, refunding the owner", async () => {
+    const closed = [basketPda, basketPricePda, basketTokenPda, vaultAta, feeVaultPda];
+    const rent = closed.reduce((sum, key) => sum + Number(ctx.svm.getBalance(key)), 0);
+    const ownerBefore = Number(ctx.svm.getBalance(ctx.admin.publicKey));
+
+    await closeBasket();
+
+    for (const key of closed) {
+      expect(ctx.svm.getAccount(key)).to.be.null;
+    }
+    // The owner also pays the transaction fee.
+    const refunded = Number(ctx.svm.getBalance(ctx.admin.publicKey)) - ownerBefore;
+    expect(refunded).to.be.greaterThan(rent - 10_000);
+  });
+
+  it("rejects a basket with shares outstanding", async () => {
+    await deposit();
+    await expectError(closeBasket(), "BasketNotEmpty");
+  });
+
+  it("sweeps collected fees to the treasury", async () => {
+    const user = await deposit();
+    const shares = await getTokenBalance(ctx.provider, user.shareAta);
+
+    await ctx.program.methods
+      .withdrawMulti(new BN(Number(shares)))
+      .accounts({
+        user: user.kp.publicKey,
+        receiver: user.kp.publicKey,
+        basket: basketPda,
+        shareMint: shareMintKp.publicKey,
+        vaultAuthority,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .remainingAccounts([
+        { pubkey: basketTokenPda, isWritable: true, isSigner: false },
+        { pubkey: usdcMint, isWritable: false, isSigner: false },
+        { pubkey: vaultAta, isWritable: true, isSigner: false },
+        { pubkey: user.usdcAta, isWritable: true, isSigner: false },
+      ])
+      .signers([user.kp])
+      .rpc();
+
+    expect(Number(await getTokenBalance(ctx.provider, vaultAta))).to.equal(0);
+    const fees = await getTokenBalance(ctx.provider, feeVaultPda);
+    expect(Number(fees)).to.be.greaterThan(0);
+
+    await closeBasket();
+
+    expect(ctx.svm.getAccount(feeVaultPda)).to.be.null;
+    expect((await getTokenBalance(ctx.provider, treasuryUsdcAta)).toString()).to.equal(
+      fees.toString(),
+    );
+  });
+
+  it("rejects a fee destination other than the treasury's ATA", async () => {
+    const ownerUsdcAta = await createTestAta(ctx.provider, usdcMint, ctx.admin.publicKey);
+    await expectError(closeBasket(ctx.admin, legAccounts(ownerUsdcAta)), "ReceiverMismatch");
+  });
+
+  it("closes the lock tiers, epoch queue and its escrow", async () => {
+    const [lockTiers] = findLockTiersPda(basketPda);
+    await ctx.program.methods
+      .setLockTiers([{ minDurationSecs: new BN(86_400), earlyExitPenaltyBps: 500 }])
+      .accounts({ basket: basketPda, program: ctx.program.programId })
+      .rpc();
+    await ctx.program.methods
+      .setEpochOperator(ctx.admin.publicKey)
+      .accounts({ basket: basketPda, program: ctx.program.programId })
+      .rpc();
+    await createTestAta(ctx.provider, usdcMint, epochQueue, true);
+
+    await closeBasket();
+
+    for (const key of [lockTiers, epochQueue, pendingAta]) {
+      expect(ctx.svm.getAccount(key)).to.be.null;
+    }
+  });
+
+  it("requires every leg", async () => {
+    await expectError(closeBasket(ctx.admin, []), "IncompleteClose");
+  });
+
+  it("rejects a non-owner", async () => {
+    const stranger = Keypair.generate();
+    fundAccount(ctx.svm, stranger.publicKey);
+
+    await expectError(closeBasket(stranger), "Unauthorized");
+  });
+});
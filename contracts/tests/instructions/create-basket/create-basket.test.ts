@@ -1,11 +1,15 @@
 import { expect } from "chai";
 import { Keypair } from "@solana/web3.js";
 import { BN } from "@coral-xyz/anchor";
-import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID } from "@solana/spl-token";
 import {
   createTestContext,
   findBasketPda,
+  findBasketRegistryPda,
+  findConfigPda,
   findVaultAuthorityPda,
+  findMetadataPda,
+  TOKEN_METADATA_PROGRAM_ID,
   type TestContext,
 } from "../../setup";
 
@@ -32,9 +36,12 @@ describe("create_basket", () => {
     const shareMint = Keypair.generate();
 
     await ctx.program.methods
-      .createBasket(basketId, encodeName("Test Basket"), null)
+      .createBasket(basketId, encodeName("Test Basket"), null, false, "QSHARE", "")
       .accounts({
         shareMint: shareMint.publicKey,
+        shareMetadata: findMetadataPda(shareMint.publicKey)[0],
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        treasury: null,
         tokenProgram: TOKEN_PROGRAM_ID,
         program: ctx.program.programId,
       })
@@ -53,16 +60,22 @@ describe("create_basket", () => {
     );
     expect(basket.basketId.toNumber()).to.equal(1);
     expect(basket.tokenCount).to.equal(0);
-    expect(basket.version).to.equal(1);
+    expect(basket.version).to.equal(2);
+
+    const metadata = ctx.svm.getAccount(findMetadataPda(shareMint.publicKey)[0]);
+    expect(metadata?.owner.toBase58()).to.equal(TOKEN_METADATA_PROGRAM_ID.toBase58());
   });
 
   it("creates a basket with fee override", async () => {
     const shareMint = Keypair.generate();
 
     await ctx.program.methods
-      .createBasket(basketId, encodeName("Fee Basket"), 30)
+      .createBasket(basketId, encodeName("Fee Basket"), 30, false, "QSHARE", "")
       .accounts({
         shareMint: shareMint.publicKey,
+        shareMetadata: findMetadataPda(shareMint.publicKey)[0],
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        treasury: null,
         tokenProgram: TOKEN_PROGRAM_ID,
         program: ctx.program.programId,
       })
@@ -75,12 +88,64 @@ describe("create_basket", () => {
     expect(basket.feeBpsOverride).to.equal(30);
   });
 
+  it("registers the basket and advances the id counter", async () => {
+    const shareMint = Keypair.generate();
+    await ctx.program.methods
+      .createBasket(basketId, encodeName("Registered"), null, false, "QSHARE", "")
+      .accounts({
+        shareMint: shareMint.publicKey,
+        shareMetadata: findMetadataPda(shareMint.publicKey)[0],
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        treasury: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .signers([shareMint])
+      .rpc();
+
+    const [configPda] = findConfigPda();
+    const config = await ctx.program.account.config.fetch(configPda);
+    expect(config.nextBasketId.toNumber()).to.equal(2);
+    expect(config.registryPage).to.equal(0);
+
+    const [registryPda] = findBasketRegistryPda(0);
+    const registry = await ctx.program.account.basketRegistry.fetch(registryPda);
+    const [basketPda] = findBasketPda(basketId);
+    expect(registry.page).to.equal(0);
+    expect(registry.count).to.equal(1);
+    expect(registry.baskets[0].toBase58()).to.equal(basketPda.toBase58());
+  });
+
+  it("rejects a basket_id other than the next one", async () => {
+    const shareMint = Keypair.generate();
+    try {
+      await ctx.program.methods
+        .createBasket(new BN(2), encodeName("Skipped"), null, false, "QSHARE", "")
+        .accounts({
+          shareMint: shareMint.publicKey,
+          shareMetadata: findMetadataPda(shareMint.publicKey)[0],
+          tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+          treasury: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          program: ctx.program.programId,
+        })
+        .signers([shareMint])
+        .rpc();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("BasketIdMismatch");
+    }
+  });
+
   it("rejects duplicate basket_id", async () => {
     const shareMint1 = Keypair.generate();
     await ctx.program.methods
-      .createBasket(basketId, encodeName("First"), null)
+      .createBasket(basketId, encodeName("First"), null, false, "QSHARE", "")
       .accounts({
         shareMint: shareMint1.publicKey,
+        shareMetadata: findMetadataPda(shareMint1.publicKey)[0],
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        treasury: null,
         tokenProgram: TOKEN_PROGRAM_ID,
         program: ctx.program.programId,
       })
@@ -90,9 +155,12 @@ describe("create_basket", () => {
     const shareMint2 = Keypair.generate();
     try {
       await ctx.program.methods
-        .createBasket(basketId, encodeName("Duplicate"), null)
+        .createBasket(basketId, encodeName("Duplicate"), null, false, "QSHARE", "")
         .accounts({
           shareMint: shareMint2.publicKey,
+          shareMetadata: findMetadataPda(shareMint2.publicKey)[0],
+          tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+          treasury: null,
           tokenProgram: TOKEN_PROGRAM_ID,
           program: ctx.program.programId,
         })
@@ -103,4 +171,67 @@ describe("create_basket", () => {
       expect(err).to.exist;
     }
   });
+
+  it("creates a Token-2022 QSHARE mint with the compliance transfer hook", async () => {
+    const shareMint = Keypair.generate();
+
+    await ctx.program.methods
+      .createBasket(basketId, encodeName("Hooked Basket"), null, true, "QSHARE", "")
+      .accounts({
+        shareMint: shareMint.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .signers([shareMint])
+      .rpc();
+
+    const mintAccount = ctx.svm.getAccount(shareMint.publicKey);
+    expect(mintAccount?.owner.toBase58()).to.equal(
+      TOKEN_2022_PROGRAM_ID.toBase58(),
+    );
+  });
+
+  it("rejects a transfer-hook share mint on the legacy token program", async () => {
+    const shareMint = Keypair.generate();
+
+    try {
+      await ctx.program.methods
+        .createBasket(basketId, encodeName("Hooked Basket"), null, true, "QSHARE", "")
+        .accounts({
+          shareMint: shareMint.publicKey,
+          shareMetadata: findMetadataPda(shareMint.publicKey)[0],
+          tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+          treasury: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          program: ctx.program.programId,
+        })
+        .signers([shareMint])
+        .rpc();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("TransferHookRequiresToken2022");
+    }
+  });
+
+  it("rejects a legacy share mint without the Metaplex accounts", async () => {
+    const shareMint = Keypair.generate();
+
+    try {
+      await ctx.program.methods
+        .createBasket(basketId, encodeName("Nameless"), null, false, "QSHARE", "")
+        .accounts({
+          shareMint: shareMint.publicKey,
+          shareMetadata: null,
+          tokenMetadataProgram: null,
+          treasury: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          program: ctx.program.programId,
+        })
+        .signers([shareMint])
+        .rpc();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("MissingMetadataAccounts");
+    }
+  });
 });
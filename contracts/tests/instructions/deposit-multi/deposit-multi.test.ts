@@ -14,6 +14,8 @@ import {
   findMintAuthorityPda,
   findBasketTokenPda,
   findFeeVaultPda,
+  findMetadataPda,
+  TOKEN_METADATA_PROGRAM_ID,
   type TestContext,
 } from "../../setup";
 import { getAssociatedTokenAddress } from "@solana/spl-token";
@@ -52,9 +54,12 @@ describe("deposit_multi", () => {
     [mintAuthority] = findMintAuthorityPda(basketId);
 
     await ctx.program.methods
-      .createBasket(basketId, encodeName("Deposit Test"), null)
+      .createBasket(basketId, encodeName("Deposit Test"), null, false, "QSHARE", "")
       .accounts({
         shareMint: shareMintKp.publicKey,
+        shareMetadata: findMetadataPda(shareMintKp.publicKey)[0],
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        treasury: null,
         tokenProgram: TOKEN_PROGRAM_ID,
         program: ctx.program.programId,
       })
@@ -67,7 +72,7 @@ describe("deposit_multi", () => {
     vaultAta = await getAssociatedTokenAddress(usdcMint, vaultAuthority, true);
 
     await ctx.program.methods
-      .addTokens()
+      .addTokens(0)
       .accounts({
         basket: basketPda,
         underlyingMint: usdcMint,
@@ -101,18 +106,23 @@ describe("deposit_multi", () => {
     );
 
     await ctx.program.methods
-      .depositMulti([new BN(1_000_000_000)])
+      .depositMulti([new BN(1_000_000_000)], null)
       .accounts({
         user: user.publicKey,
+        receiver: user.publicKey,
         basket: basketPda,
         mintAuthority,
         shareMint: shareMintKp.publicKey,
         userAllowList: null,
+        complianceRoot: null,
+        instructionsSysvar: null,
+        attestationNonce: null,
         tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
         program: ctx.program.programId,
       })
       .remainingAccounts([
-        { pubkey: basketTokenPda, isWritable: false, isSigner: false },
+        { pubkey: basketTokenPda, isWritable: true, isSigner: false },
         { pubkey: usdcMint, isWritable: false, isSigner: false },
         { pubkey: userUsdcAta, isWritable: true, isSigner: false },
         { pubkey: vaultAta, isWritable: true, isSigner: false },
@@ -131,6 +141,64 @@ describe("deposit_multi", () => {
     expect(Number(feeBalance)).to.be.greaterThan(0);
   });
 
+  it("mints QSHARE to a separate receiver", async () => {
+    const custodian = Keypair.generate();
+    const client = Keypair.generate();
+    fundAccount(ctx.svm, custodian.publicKey);
+
+    const custodianUsdcAta = await createTestAta(
+      ctx.provider,
+      usdcMint,
+      custodian.publicKey,
+    );
+    await mintTestTokens(
+      ctx.provider,
+      usdcMint,
+      custodianUsdcAta,
+      ctx.admin,
+      BigInt(1_000_000_000),
+    );
+
+    await ctx.program.methods
+      .depositMulti([new BN(1_000_000_000)], null)
+      .accounts({
+        user: custodian.publicKey,
+        receiver: client.publicKey,
+        basket: basketPda,
+        mintAuthority,
+        shareMint: shareMintKp.publicKey,
+        userAllowList: null,
+        complianceRoot: null,
+        instructionsSysvar: null,
+        attestationNonce: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .remainingAccounts([
+        { pubkey: basketTokenPda, isWritable: true, isSigner: false },
+        { pubkey: usdcMint, isWritable: false, isSigner: false },
+        { pubkey: custodianUsdcAta, isWritable: true, isSigner: false },
+        { pubkey: vaultAta, isWritable: true, isSigner: false },
+        { pubkey: feeVaultPda, isWritable: true, isSigner: false },
+      ])
+      .signers([custodian])
+      .rpc();
+
+    const clientShareAta = await getAssociatedTokenAddress(
+      shareMintKp.publicKey,
+      client.publicKey,
+    );
+    const clientShares = await getTokenBalance(ctx.provider, clientShareAta);
+    expect(Number(clientShares)).to.equal(998_000_000);
+
+    const custodianShareAta = await getAssociatedTokenAddress(
+      shareMintKp.publicKey,
+      custodian.publicKey,
+    );
+    expect(ctx.svm.getAccount(custodianShareAta)).to.be.null;
+  });
+
   it("second depositor gets proportional shares (vault-weighted)", async () => {
     // First depositor
     const user1 = Keypair.generate();
@@ -154,18 +222,23 @@ describe("deposit_multi", () => {
     );
 
     await ctx.program.methods
-      .depositMulti([new BN(1_000_000_000)])
+      .depositMulti([new BN(1_000_000_000)], null)
       .accounts({
         user: user1.publicKey,
+        receiver: user1.publicKey,
         basket: basketPda,
         mintAuthority,
         shareMint: shareMintKp.publicKey,
         userAllowList: null,
+        complianceRoot: null,
+        instructionsSysvar: null,
+        attestationNonce: null,
         tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
         program: ctx.program.programId,
       })
       .remainingAccounts([
-        { pubkey: basketTokenPda, isWritable: false, isSigner: false },
+        { pubkey: basketTokenPda, isWritable: true, isSigner: false },
         { pubkey: usdcMint, isWritable: false, isSigner: false },
         { pubkey: user1UsdcAta, isWritable: true, isSigner: false },
         { pubkey: vaultAta, isWritable: true, isSigner: false },
@@ -199,18 +272,23 @@ describe("deposit_multi", () => {
     );
 
     await ctx.program.methods
-      .depositMulti([new BN(1_000_000_000)])
+      .depositMulti([new BN(1_000_000_000)], null)
       .accounts({
         user: user2.publicKey,
+        receiver: user2.publicKey,
         basket: basketPda,
         mintAuthority,
         shareMint: shareMintKp.publicKey,
         userAllowList: null,
+        complianceRoot: null,
+        instructionsSysvar: null,
+        attestationNonce: null,
         tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
         program: ctx.program.programId,
       })
       .remainingAccounts([
-        { pubkey: basketTokenPda, isWritable: false, isSigner: false },
+        { pubkey: basketTokenPda, isWritable: true, isSigner: false },
         { pubkey: usdcMint, isWritable: false, isSigner: false },
         { pubkey: user2UsdcAta, isWritable: true, isSigner: false },
         { pubkey: vaultAta, isWritable: true, isSigner: false },
@@ -242,18 +320,23 @@ describe("deposit_multi", () => {
 
     try {
       await ctx.program.methods
-        .depositMulti([new BN(0)])
+        .depositMulti([new BN(0)], null)
         .accounts({
           user: user.publicKey,
+          receiver: user.publicKey,
           basket: basketPda,
           mintAuthority,
           shareMint: shareMintKp.publicKey,
           userAllowList: null,
+          complianceRoot: null,
+          instructionsSysvar: null,
+          attestationNonce: null,
           tokenProgram: TOKEN_PROGRAM_ID,
+          shareTokenProgram: TOKEN_PROGRAM_ID,
           program: ctx.program.programId,
         })
         .remainingAccounts([
-          { pubkey: basketTokenPda, isWritable: false, isSigner: false },
+          { pubkey: basketTokenPda, isWritable: true, isSigner: false },
           { pubkey: usdcMint, isWritable: false, isSigner: false },
           { pubkey: userUsdcAta, isWritable: true, isSigner: false },
           { pubkey: vaultAta, isWritable: true, isSigner: false },
@@ -266,4 +349,120 @@ describe("deposit_multi", () => {
       expect(err.toString()).to.include("ZeroDeposit");
     }
   });
+
+  async function depositExpectingError(
+    user: Keypair,
+    userUsdcAta: PublicKey,
+    expected: string,
+  ) {
+    try {
+      await ctx.program.methods
+        .depositMulti([new BN(1_000_000)], null)
+        .accounts({
+          user: user.publicKey,
+          receiver: user.publicKey,
+          basket: basketPda,
+          mintAuthority,
+          shareMint: shareMintKp.publicKey,
+          userAllowList: null,
+          complianceRoot: null,
+          instructionsSysvar: null,
+          attestationNonce: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          shareTokenProgram: TOKEN_PROGRAM_ID,
+          program: ctx.program.programId,
+        })
+        .remainingAccounts([
+          { pubkey: basketTokenPda, isWritable: true, isSigner: false },
+          { pubkey: usdcMint, isWritable: false, isSigner: false },
+          { pubkey: userUsdcAta, isWritable: true, isSigner: false },
+          { pubkey: vaultAta, isWritable: true, isSigner: false },
+          { pubkey: feeVaultPda, isWritable: true, isSigner: false },
+        ])
+        .signers([user])
+        .rpc();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include(expected);
+    }
+  }
+
+  it("rejects a user token account for a different mint", async () => {
+    const user = Keypair.generate();
+    fundAccount(ctx.svm, user.publicKey);
+    const otherMint = await createTestMint(ctx.provider, ctx.admin.publicKey, 6);
+    const otherAta = await createTestAta(ctx.provider, otherMint, user.publicKey);
+    await mintTestTokens(ctx.provider, otherMint, otherAta, ctx.admin, BigInt(1_000_000));
+
+    await depositExpectingError(user, otherAta, "UserAtaMintMismatch");
+  });
+
+  it("rejects a user token account owned by another wallet", async () => {
+    const user = Keypair.generate();
+    const other = Keypair.generate();
+    fundAccount(ctx.svm, user.publicKey);
+    const otherAta = await createTestAta(ctx.provider, usdcMint, other.publicKey);
+    await mintTestTokens(ctx.provider, usdcMint, otherAta, ctx.admin, BigInt(1_000_000));
+
+    await depositExpectingError(user, otherAta, "UserAtaOwnerMismatch");
+  });
+
+  it("rejects a user account not owned by the token program", async () => {
+    const user = Keypair.generate();
+    fundAccount(ctx.svm, user.publicKey);
+
+    await depositExpectingError(user, user.publicKey, "InvalidTokenProgramOwner");
+  });
+
+  it("rejects the same leg passed twice", async () => {
+    const usdtMint = await createTestMint(ctx.provider, ctx.admin.publicKey, 6);
+    await ctx.program.methods
+      .addTokens(0)
+      .accounts({
+        basket: basketPda,
+        underlyingMint: usdtMint,
+        vaultAuthority,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .rpc();
+
+    const user = Keypair.generate();
+    fundAccount(ctx.svm, user.publicKey);
+    const userUsdcAta = await createTestAta(ctx.provider, usdcMint, user.publicKey);
+    await mintTestTokens(ctx.provider, usdcMint, userUsdcAta, ctx.admin, BigInt(2_000_000));
+
+    const usdcLeg = [
+      { pubkey: basketTokenPda, isWritable: true, isSigner: false },
+      { pubkey: usdcMint, isWritable: false, isSigner: false },
+      { pubkey: userUsdcAta, isWritable: true, isSigner: false },
+      { pubkey: vaultAta, isWritable: true, isSigner: false },
+      { pubkey: feeVaultPda, isWritable: true, isSigner: false },
+    ];
+
+    try {
+      await ctx.program.methods
+        .depositMulti([new BN(1_000_000), new BN(1_000_000)], null)
+        .accounts({
+          user: user.publicKey,
+          receiver: user.publicKey,
+          basket: basketPda,
+          mintAuthority,
+          shareMint: shareMintKp.publicKey,
+          userAllowList: null,
+          complianceRoot: null,
+          instructionsSysvar: null,
+          attestationNonce: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          shareTokenProgram: TOKEN_PROGRAM_ID,
+          program: ctx.program.programId,
+        })
+        .remainingAccounts([...usdcLeg, ...usdcLeg])
+        .signers([user])
+        .rpc();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("DuplicateLeg");
+    }
+  });
 });
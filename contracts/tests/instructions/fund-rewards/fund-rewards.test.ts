@@ -0,0 +1,97 @@
+import { expect } from "chai";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+import { TOKEN_PROGRAM_ID, getAssociatedTokenAddress } from "@solana/spl-token";
+import {
+  createTestContext,
+  createTestAta,
+  mintTestTokens,
+  getTokenBalance,
+  createRewardFixture,
+  newHolder,
+  fundRewards,
+  type TestContext,
+  type RewardFixture,
+} from "../../setup";
+
+const DAY = 86_400;
+
+describe("fund_rewards", () => {
+  let ctx: TestContext;
+  let fx: RewardFixture;
+
+  let basketPda: PublicKey;
+  let shareMint: PublicKey;
+  let rewardMint: PublicKey;
+  let rewardPool: PublicKey;
+  let rewardVault: PublicKey;
+  let user: Keypair;
+  let userShareAta: PublicKey;
+
+  function warp(seconds: number) {
+    const clock = ctx.svm.getClock();
+    clock.unixTimestamp += BigInt(seconds);
+    ctx.svm.setClock(clock);
+  }
+
+  beforeEach(async () => {
+    ctx = createTestContext();
+    fx = await createRewardFixture(ctx);
+    ({ basket: basketPda, shareMint, rewardMint, rewardPool, rewardVault } = fx);
+
+    user = await newHolder(fx);
+    userShareAta = await getAssociatedTokenAddress(shareMint, user.publicKey);
+  });
+
+  it("streams the funded amount over the duration", async () => {
+    await fundRewards(fx, 1_000_000, 1_000);
+
+    const pool = await ctx.program.account.rewardPool.fetch(rewardPool);
+    expect(pool.rewardRate.toNumber()).to.equal(1_000);
+    expect(Number(await getTokenBalance(ctx.provider, rewardVault))).to.equal(1_000_000);
+  });
+
+  it("rolls undistributed rewards into a new period", async () => {
+    await fundRewards(fx, 1_000_000, 1_000);
+    warp(500);
+    await fundRewards(fx, 500_000, 1_000);
+
+    // 500_000 left over plus 500_000 new, over 1_000s.
+    const pool = await ctx.program.account.rewardPool.fetch(rewardPool);
+    expect(pool.rewardRate.toNumber()).to.equal(1_000);
+  });
+
+  it("rejects a zero duration", async () => {
+    try {
+      await fundRewards(fx, 1_000_000, 0);
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("InvalidRewardFunding");
+    }
+  });
+
+  it("rejects a non-owner", async () => {
+    const userRewardAta = await createTestAta(ctx.provider, rewardMint, user.publicKey);
+    await mintTestTokens(ctx.provider, rewardMint, userRewardAta, ctx.admin, BigInt(1_000_000));
+
+    try {
+      await ctx.program.methods
+        .fundRewards(new BN(1_000_000), new BN(1_000))
+        .accounts({
+          authority: user.publicKey,
+          basket: basketPda,
+          rewardPool,
+          rewardMint,
+          funderRewardAta: userRewardAta,
+          rewardVault,
+          rewardTokenProgram: TOKEN_PROGRAM_ID,
+          program: ctx.program.programId,
+        })
+        .signers([user])
+        .rpc();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("Unauthorized");
+    }
+  });
+});
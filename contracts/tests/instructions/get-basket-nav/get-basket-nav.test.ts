@@ -0,0 +1,213 @@
+import { expect } from "chai";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+import { TOKEN_PROGRAM_ID, getAssociatedTokenAddress } from "@solana/spl-token";
+import {
+  createTestContext,
+  fundAccount,
+  createTestMint,
+  createTestAta,
+  mintTestTokens,
+  getTokenBalance,
+  findBasketPda,
+  findVaultAuthorityPda,
+  findMintAuthorityPda,
+  findBasketTokenPda,
+  findFeeVaultPda,
+  findMetadataPda,
+  TOKEN_METADATA_PROGRAM_ID,
+  type TestContext,
+} from "../../setup";
+
+describe("get_basket_nav", () => {
+  let ctx: TestContext;
+  const whitelistAuth = Keypair.generate();
+  const basketId = new BN(1);
+
+  let basketPda: PublicKey;
+  let vaultAuthority: PublicKey;
+  let mintAuthority: PublicKey;
+  let shareMintKp: Keypair;
+  let usdcMint: PublicKey;
+  let usdtMint: PublicKey;
+  let btUsdc: PublicKey;
+  let btUsdt: PublicKey;
+  let fvUsdc: PublicKey;
+  let fvUsdt: PublicKey;
+  let vaultUsdc: PublicKey;
+  let vaultUsdt: PublicKey;
+  let user: Keypair;
+  let userUsdcAta: PublicKey;
+  let userUsdtAta: PublicKey;
+  let userShareAta: PublicKey;
+
+  function encodeName(name: string): number[] {
+    const buf = Buffer.alloc(32, 0);
+    buf.write(name, "utf-8");
+    return Array.from(buf);
+  }
+
+  function legAccounts() {
+    return [
+      { pubkey: btUsdc, isWritable: false, isSigner: false },
+      { pubkey: vaultUsdc, isWritable: false, isSigner: false },
+      { pubkey: btUsdt, isWritable: false, isSigner: false },
+      { pubkey: vaultUsdt, isWritable: false, isSigner: false },
+    ];
+  }
+
+  function getNav(remaining = legAccounts()) {
+    return ctx.program.methods
+      .getBasketNav()
+      .accounts({
+        basket: basketPda,
+        shareMint: shareMintKp.publicKey,
+      })
+      .remainingAccounts(remaining)
+      .view();
+  }
+
+  beforeEach(async () => {
+    ctx = createTestContext();
+
+    await ctx.program.methods
+      .initConfig(20, whitelistAuth.publicKey, false)
+      .accounts({ program: ctx.program.programId })
+      .rpc();
+
+    shareMintKp = Keypair.generate();
+    [basketPda] = findBasketPda(basketId);
+    [vaultAuthority] = findVaultAuthorityPda(basketId);
+    [mintAuthority] = findMintAuthorityPda(basketId);
+
+    await ctx.program.methods
+      .createBasket(basketId, encodeName("NAV Test"), null, false, "QSHARE", "")
+      .accounts({
+        shareMint: shareMintKp.publicKey,
+        shareMetadata: findMetadataPda(shareMintKp.publicKey)[0],
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        treasury: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .signers([shareMintKp])
+      .rpc();
+
+    usdcMint = await createTestMint(ctx.provider, ctx.admin.publicKey, 6);
+    usdtMint = await createTestMint(ctx.provider, ctx.admin.publicKey, 6);
+
+    [btUsdc] = findBasketTokenPda(basketPda, usdcMint);
+    [fvUsdc] = findFeeVaultPda(basketPda, usdcMint);
+    vaultUsdc = await getAssociatedTokenAddress(usdcMint, vaultAuthority, true);
+
+    [btUsdt] = findBasketTokenPda(basketPda, usdtMint);
+    [fvUsdt] = findFeeVaultPda(basketPda, usdtMint);
+    vaultUsdt = await getAssociatedTokenAddress(usdtMint, vaultAuthority, true);
+
+    for (const mint of [usdcMint, usdtMint]) {
+      await ctx.program.methods
+        .addTokens(0)
+        .accounts({
+          basket: basketPda,
+          underlyingMint: mint,
+          vaultAuthority,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          program: ctx.program.programId,
+        })
+        .rpc();
+    }
+
+    user = Keypair.generate();
+    fundAccount(ctx.svm, user.publicKey);
+    userUsdcAta = await createTestAta(ctx.provider, usdcMint, user.publicKey);
+    userUsdtAta = await createTestAta(ctx.provider, usdtMint, user.publicKey);
+    await mintTestTokens(ctx.provider, usdcMint, userUsdcAta, ctx.admin, BigInt(1_000_000_000));
+    await mintTestTokens(ctx.provider, usdtMint, userUsdtAta, ctx.admin, BigInt(500_000_000));
+
+    userShareAta = await getAssociatedTokenAddress(
+      shareMintKp.publicKey,
+      user.publicKey,
+    );
+  });
+
+  async function deposit() {
+    await ctx.program.methods
+      .depositMulti([new BN(1_000_000_000), new BN(500_000_000)], null)
+      .accounts({
+        user: user.publicKey,
+        receiver: user.publicKey,
+        basket: basketPda,
+        mintAuthority,
+        shareMint: shareMintKp.publicKey,
+        userAllowList: null,
+        complianceRoot: null,
+        instructionsSysvar: null,
+        attestationNonce: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .remainingAccounts([
+        { pubkey: btUsdc, isWritable: true, isSigner: false },
+        { pubkey: usdcMint, isWritable: false, isSigner: false },
+        { pubkey: userUsdcAta, isWritable: true, isSigner: false },
+        { pubkey: vaultUsdc, isWritable: true, isSigner: false },
+        { pubkey: fvUsdc, isWritable: true, isSigner: false },
+        { pubkey: btUsdt, isWritable: true, isSigner: false },
+        { pubkey: usdtMint, isWritable: false, isSigner: false },
+        { pubkey: userUsdtAta, isWritable: true, isSigner: false },
+        { pubkey: vaultUsdt, isWritable: true, isSigner: false },
+        { pubkey: fvUsdt, isWritable: true, isSigner: false },
+      ])
+      .signers([user])
+      .rpc();
+  }
+
+  it("prices an empty basket at 1.0", async () => {
+    const nav = await getNav();
+    expect(nav.totalSupply.toNumber()).to.equal(0);
+    expect(nav.totalValue.toNumber()).to.equal(0);
+    expect(nav.navPerShare.toNumber()).to.equal(1_000_000_000);
+  });
+
+  it("reports total value, supply and NAV after deposits", async () => {
+    await deposit();
+
+    const nav = await getNav();
+    const supply = await getTokenBalance(ctx.provider, userShareAta);
+    expect(nav.totalSupply.toString()).to.equal(supply.toString());
+    expect(nav.totalValue.toNumber()).to.equal(1_497_000_000);
+    expect(nav.navPerShare.toNumber()).to.equal(1_000_000_000);
+    // 6-decimal legs normalise exactly
+    expect(nav.confidence.toNumber()).to.equal(0);
+  });
+
+  it("reflects vault value accruing to existing shares", async () => {
+    await deposit();
+    await mintTestTokens(ctx.provider, usdcMint, vaultUsdc, ctx.admin, BigInt(1_497_000_000));
+
+    const nav = await getNav();
+    expect(nav.navPerShare.toNumber()).to.equal(2_000_000_000);
+  });
+
+  it("rejects missing leg accounts", async () => {
+    try {
+      await getNav(legAccounts().slice(0, 2));
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("InvalidRemainingAccounts");
+    }
+  });
+
+  it("rejects the same leg passed twice", async () => {
+    await deposit();
+    const usdcLeg = legAccounts().slice(0, 2);
+
+    try {
+      await getNav([...usdcLeg, ...usdcLeg]);
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("DuplicateLeg");
+    }
+  });
+});
@@ -25,7 +25,9 @@ describe("init_config", () => {
     expect(config.admin.toBase58()).to.equal(ctx.admin.publicKey.toBase58());
     expect(config.feeBps).to.equal(20);
     expect(config.complianceEnabled).to.equal(false);
-    expect(config.version).to.equal(1);
+    expect(config.version).to.equal(2);
+    expect(config.permissionlessCreation).to.equal(false);
+    expect(config.treasury.toBase58()).to.equal(ctx.admin.publicKey.toBase58());
   });
 
   it("rejects fee_bps below minimum (10)", async () => {
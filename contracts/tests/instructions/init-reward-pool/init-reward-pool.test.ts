@@ -0,0 +1,102 @@
+import { expect } from "chai";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+import { TOKEN_PROGRAM_ID, getAssociatedTokenAddress } from "@solana/spl-token";
+import {
+  createTestContext,
+  createTestMint,
+  createRewardFixture,
+  newHolder,
+  type TestContext,
+  type RewardFixture,
+} from "../../setup";
+
+const DAY = 86_400;
+
+describe("init_reward_pool", () => {
+  let ctx: TestContext;
+  let fx: RewardFixture;
+
+  let basketPda: PublicKey;
+  let shareMint: PublicKey;
+  let rewardMint: PublicKey;
+  let rewardPool: PublicKey;
+  let rewardVault: PublicKey;
+  let user: Keypair;
+  let userShareAta: PublicKey;
+
+  function warp(seconds: number) {
+    const clock = ctx.svm.getClock();
+    clock.unixTimestamp += BigInt(seconds);
+    ctx.svm.setClock(clock);
+  }
+
+  beforeEach(async () => {
+    ctx = createTestContext();
+    fx = await createRewardFixture(ctx);
+    ({ basket: basketPda, shareMint, rewardMint, rewardPool, rewardVault } = fx);
+
+    user = await newHolder(fx);
+    userShareAta = await getAssociatedTokenAddress(shareMint, user.publicKey);
+  });
+
+  it("creates the pool with its boosts", async () => {
+    const pool = await ctx.program.account.rewardPool.fetch(rewardPool);
+    expect(pool.basket.toBase58()).to.equal(basketPda.toBase58());
+    expect(pool.rewardMint.toBase58()).to.equal(rewardMint.toBase58());
+    expect(pool.rewardVault.toBase58()).to.equal(rewardVault.toBase58());
+    expect(pool.boostCount).to.equal(2);
+    expect(pool.boosts[1].multiplierBps).to.equal(20_000);
+  });
+
+  async function initPool(boosts: { minDurationSecs: BN; multiplierBps: number }[]) {
+    const otherMint = await createTestMint(ctx.provider, ctx.admin.publicKey, 6);
+    await ctx.program.methods
+      .initRewardPool(boosts)
+      .accounts({
+        basket: basketPda,
+        rewardMint: otherMint,
+        rewardTokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .rpc();
+  }
+
+  it("rejects a multiplier above the cap", async () => {
+    try {
+      await initPool([{ minDurationSecs: new BN(30 * DAY), multiplierBps: 30_001 }]);
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("InvalidRewardBoosts");
+    }
+  });
+
+  it("rejects a multiplier below 1x", async () => {
+    try {
+      await initPool([{ minDurationSecs: new BN(30 * DAY), multiplierBps: 9_999 }]);
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("InvalidRewardBoosts");
+    }
+  });
+
+  it("rejects a non-owner", async () => {
+    const otherMint = await createTestMint(ctx.provider, ctx.admin.publicKey, 6);
+    try {
+      await ctx.program.methods
+        .initRewardPool([])
+        .accounts({
+          authority: user.publicKey,
+          basket: basketPda,
+          rewardMint: otherMint,
+          rewardTokenProgram: TOKEN_PROGRAM_ID,
+          program: ctx.program.programId,
+        })
+        .signers([user])
+        .rpc();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("Unauthorized");
+    }
+  });
+});
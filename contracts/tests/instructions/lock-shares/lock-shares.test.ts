@@ -0,0 +1,243 @@
+import { expect } from "chai";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+import { TOKEN_PROGRAM_ID, getAssociatedTokenAddress } from "@solana/spl-token";
+import {
+  createTestContext,
+  fundAccount,
+  createTestMint,
+  createTestAta,
+  mintTestTokens,
+  getTokenBalance,
+  findBasketPda,
+  findVaultAuthorityPda,
+  findMintAuthorityPda,
+  findBasketTokenPda,
+  findFeeVaultPda,
+  findLockPositionPda,
+  findMetadataPda,
+  TOKEN_METADATA_PROGRAM_ID,
+  type TestContext,
+} from "../../setup";
+
+const DAY = 86_400;
+
+describe("lock_shares", () => {
+  let ctx: TestContext;
+  const whitelistAuth = Keypair.generate();
+  const basketId = new BN(1);
+
+  let basketPda: PublicKey;
+  let vaultAuthority: PublicKey;
+  let mintAuthority: PublicKey;
+  let shareMintKp: Keypair;
+  let usdcMint: PublicKey;
+  let user: Keypair;
+  let userShareAta: PublicKey;
+  let lockVault: PublicKey;
+  let lockPosition: PublicKey;
+
+  function encodeName(name: string): number[] {
+    const buf = Buffer.alloc(32, 0);
+    buf.write(name, "utf-8");
+    return Array.from(buf);
+  }
+
+  function now(): number {
+    return Number(ctx.svm.getClock().unixTimestamp);
+  }
+
+  function warp(seconds: number) {
+    const clock = ctx.svm.getClock();
+    clock.unixTimestamp += BigInt(seconds);
+    ctx.svm.setClock(clock);
+  }
+
+  async function setTiers(tiers: { minDurationSecs: BN; earlyExitPenaltyBps: number }[]) {
+    await ctx.program.methods
+      .setLockTiers(tiers)
+      .accounts({ basket: basketPda, program: ctx.program.programId })
+      .rpc();
+  }
+
+  async function lock(amount: number, unlockTs: number) {
+    await ctx.program.methods
+      .lockShares(new BN(amount), new BN(unlockTs))
+      .accounts({
+        owner: user.publicKey,
+        basket: basketPda,
+        shareMint: shareMintKp.publicKey,
+        vaultAuthority,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .signers([user])
+      .rpc();
+  }
+
+  beforeEach(async () => {
+    ctx = createTestContext();
+
+    await ctx.program.methods
+      .initConfig(20, whitelistAuth.publicKey, false)
+      .accounts({ program: ctx.program.programId })
+      .rpc();
+
+    shareMintKp = Keypair.generate();
+    [basketPda] = findBasketPda(basketId);
+    [vaultAuthority] = findVaultAuthorityPda(basketId);
+    [mintAuthority] = findMintAuthorityPda(basketId);
+
+    await ctx.program.methods
+      .createBasket(basketId, encodeName("Lock Test"), null, false, "QSHARE", "")
+      .accounts({
+        shareMint: shareMintKp.publicKey,
+        shareMetadata: findMetadataPda(shareMintKp.publicKey)[0],
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        treasury: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .signers([shareMintKp])
+      .rpc();
+
+    usdcMint = await createTestMint(ctx.provider, ctx.admin.publicKey, 6);
+    const [basketTokenPda] = findBasketTokenPda(basketPda, usdcMint);
+    const [feeVaultPda] = findFeeVaultPda(basketPda, usdcMint);
+    const vaultAta = await getAssociatedTokenAddress(usdcMint, vaultAuthority, true);
+
+    await ctx.program.methods
+      .addTokens(0)
+      .accounts({
+        basket: basketPda,
+        underlyingMint: usdcMint,
+        vaultAuthority,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .rpc();
+
+    user = Keypair.generate();
+    fundAccount(ctx.svm, user.publicKey);
+    const userUsdcAta = await createTestAta(ctx.provider, usdcMint, user.publicKey);
+    await mintTestTokens(
+      ctx.provider,
+      usdcMint,
+      userUsdcAta,
+      ctx.admin,
+      BigInt(1_000_000_000),
+    );
+    userShareAta = await getAssociatedTokenAddress(
+      shareMintKp.publicKey,
+      user.publicKey,
+    );
+
+    await ctx.program.methods
+      .depositMulti([new BN(1_000_000_000)], null)
+      .accounts({
+        user: user.publicKey,
+        receiver: user.publicKey,
+        basket: basketPda,
+        mintAuthority,
+        shareMint: shareMintKp.publicKey,
+        userAllowList: null,
+        complianceRoot: null,
+        instructionsSysvar: null,
+        attestationNonce: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .remainingAccounts([
+        { pubkey: basketTokenPda, isWritable: true, isSigner: false },
+        { pubkey: usdcMint, isWritable: false, isSigner: false },
+        { pubkey: userUsdcAta, isWritable: true, isSigner: false },
+        { pubkey: vaultAta, isWritable: true, isSigner: false },
+        { pubkey: feeVaultPda, isWritable: true, isSigner: false },
+      ])
+      .signers([user])
+      .rpc();
+
+    lockVault = await getAssociatedTokenAddress(
+      shareMintKp.publicKey,
+      vaultAuthority,
+      true,
+    );
+    [lockPosition] = findLockPositionPda(basketPda, user.publicKey);
+
+    await setTiers([
+      { minDurationSecs: new BN(30 * DAY), earlyExitPenaltyBps: 500 },
+      { minDurationSecs: new BN(180 * DAY), earlyExitPenaltyBps: 1_500 },
+    ]);
+  });
+
+  it("escrows shares and selects the tier from the duration", async () => {
+    const shares = await getTokenBalance(ctx.provider, userShareAta);
+    const unlockTs = now() + 200 * DAY;
+    await lock(100_000_000, unlockTs);
+
+    expect(Number(await getTokenBalance(ctx.provider, lockVault))).to.equal(100_000_000);
+    expect(Number(await getTokenBalance(ctx.provider, userShareAta))).to.equal(
+      Number(shares) - 100_000_000,
+    );
+
+    const position = await ctx.program.account.lockPosition.fetch(lockPosition);
+    expect(position.amount.toNumber()).to.equal(100_000_000);
+    expect(position.unlockTs.toNumber()).to.equal(unlockTs);
+    expect(position.tier).to.equal(1);
+    expect(position.earlyExitPenaltyBps).to.equal(1_500);
+  });
+
+  it("tops up an open position", async () => {
+    const unlockTs = now() + 60 * DAY;
+    await lock(100_000_000, unlockTs);
+    await lock(50_000_000, unlockTs + DAY);
+
+    const position = await ctx.program.account.lockPosition.fetch(lockPosition);
+    expect(position.amount.toNumber()).to.equal(150_000_000);
+    expect(position.unlockTs.toNumber()).to.equal(unlockTs + DAY);
+  });
+
+  it("keeps the stricter tier and penalty on a top-up", async () => {
+    const unlockTs = now() + 200 * DAY;
+    await lock(100_000_000, unlockTs);
+    // 100 days left only qualifies for the 30-day tier.
+    warp(100 * DAY);
+    await lock(50_000_000, unlockTs);
+
+    const position = await ctx.program.account.lockPosition.fetch(lockPosition);
+    expect(position.amount.toNumber()).to.equal(150_000_000);
+    expect(position.tier).to.equal(1);
+    expect(position.earlyExitPenaltyBps).to.equal(1_500);
+  });
+
+  it("rejects a top-up that shortens the lock", async () => {
+    const unlockTs = now() + 60 * DAY;
+    await lock(100_000_000, unlockTs);
+
+    try {
+      await lock(50_000_000, unlockTs - DAY);
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("LockShortened");
+    }
+  });
+
+  it("rejects a duration below the lowest tier", async () => {
+    try {
+      await lock(100_000_000, now() + DAY);
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("InvalidLockDuration");
+    }
+  });
+
+  it("rejects zero shares", async () => {
+    try {
+      await lock(0, now() + 60 * DAY);
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("ZeroLockAmount");
+    }
+  });
+});
@@ -0,0 +1,286 @@
+import { expect } from "chai";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+import {
+  createTestContext,
+  expectError,
+  fundAccount,
+  createTestMint,
+  createTestAta,
+  mintTestTokens,
+  findBasketPda,
+  findBasketTokenPda,
+  findBasketPricePda,
+  findBasketRegistryPda,
+  findConfigPda,
+  findVaultAuthorityPda,
+  findMintAuthorityPda,
+  type TestContext,
+} from "../../setup";
+
+describe("migrate_basket", () => {
+  let ctx: TestContext;
+  const whitelistAuth = Keypair.generate();
+  // Version-1 baskets chose their own ids.
+  const basketId = new BN(7);
+
+  let basketPda: PublicKey;
+  let basketTokenPda: PublicKey;
+  let usdcMint: PublicKey;
+  let vaultAta: PublicKey;
+  let shareMint: PublicKey;
+
+  function discriminator(name: string): Buffer {
+    return Buffer.from(ctx.program.idl.accounts.find((a) => a.name === name)!.discriminator);
+  }
+
+  function setLegacyAccount(address: PublicKey, data: Buffer) {
+    ctx.svm.setAccount(address, {
+      lamports: Number(ctx.svm.minimumBalanceForRentExemption(BigInt(data.length))),
+      data,
+      owner: ctx.program.programId,
+      executable: false,
+    });
+  }
+
+  /** A one-leg basket as written by the version-1 program. */
+  function setLegacyBasket() {
+    const [, basketBump] = findBasketPda(basketId);
+    const [vaultAuthority, vaultAuthorityBump] = findVaultAuthorityPda(basketId);
+    const [, mintAuthorityBump] = findMintAuthorityPda(basketId);
+
+    const basket = Buffer.alloc(8 + 144);
+    discriminator("basket").copy(basket, 0);
+    ctx.admin.publicKey.toBuffer().copy(basket, 8);
+    shareMint.toBuffer().copy(basket, 40);
+    vaultAuthority.toBuffer().copy(basket, 72);
+    basket.writeBigUInt64LE(BigInt(basketId.toNumber()), 104);
+    basket.write("Legacy", 112, "utf-8");
+    basket.writeUInt8(1, 147); // token_count
+    basket.writeUInt8(1, 148); // version
+    basket.writeUInt8(basketBump, 149);
+    basket.writeUInt8(vaultAuthorityBump, 150);
+    basket.writeUInt8(mintAuthorityBump, 151);
+    setLegacyAccount(basketPda, basket);
+
+    const [, basketTokenBump] = findBasketTokenPda(basketPda, usdcMint);
+    const basketToken = Buffer.alloc(8 + 131);
+    discriminator("basketToken").copy(basketToken, 0);
+    basketPda.toBuffer().copy(basketToken, 8);
+    usdcMint.toBuffer().copy(basketToken, 40);
+    vaultAta.toBuffer().copy(basketToken, 72);
+    basketToken.writeUInt8(6, 136); // decimals
+    basketToken.writeUInt8(1, 137); // enabled
+    basketToken.writeUInt8(basketTokenBump, 138);
+    setLegacyAccount(basketTokenPda, basketToken);
+  }
+
+  /** `[BasketToken, Mint, VaultATA]` for the one leg. */
+  function legAccounts() {
+    return [
+      { pubkey: basketTokenPda, isWritable: true, isSigner: false },
+      { pubkey: usdcMint, isWritable: false, isSigner: false },
+      { pubkey: vaultAta, isWritable: false, isSigner: false },
+    ];
+  }
+
+  async function migrate(legs = legAccounts(), signer = ctx.admin) {
+    await ctx.program.methods
+      .migrateBasket()
+      .accounts({
+        admin: signer.publicKey,
+        basket: basketPda,
+        shareMint,
+        program: ctx.program.programId,
+      })
+      .remainingAccounts(legs)
+      .signers(signer === ctx.admin ? [] : [signer])
+      .rpc();
+  }
+
+  beforeEach(async () => {
+    ctx = createTestContext();
+
+    await ctx.program.methods
+      .initConfig(20, whitelistAuth.publicKey, false)
+      .accounts({ program: ctx.program.programId })
+      .rpc();
+
+    // A freeze authority, which the version-1 program never recorded.
+    usdcMint = await createTestMint(ctx.provider, ctx.admin.publicKey, 6, ctx.admin.publicKey);
+    [basketPda] = findBasketPda(basketId);
+    [basketTokenPda] = findBasketTokenPda(basketPda, usdcMint);
+
+    // 1,000 USDC backing 500 QSHARE: NAV 2.0.
+    const [vaultAuthority] = findVaultAuthorityPda(basketId);
+    vaultAta = await createTestAta(ctx.provider, usdcMint, vaultAuthority, true);
+    await mintTestTokens(ctx.provider, usdcMint, vaultAta, ctx.admin, BigInt(1_000_000_000));
+    shareMint = await createTestMint(ctx.provider, ctx.admin.publicKey, 6);
+    const holderAta = await createTestAta(ctx.provider, shareMint, ctx.admin.publicKey);
+    await mintTestTokens(ctx.provider, shareMint, holderAta, ctx.admin, BigInt(500_000_000));
+
+    setLegacyBasket();
+  });
+
+  it("grows the basket and its legs", async () => {
+    await migrate();
+
+    const basket = await ctx.program.account.basket.fetch(basketPda);
+    expect(basket.owner.toBase58()).to.equal(ctx.admin.publicKey.toBase58());
+    expect(basket.shareMint.toBase58()).to.equal(shareMint.toBase58());
+    expect(basket.basketId.toNumber()).to.equal(7);
+    expect(basket.tokenCount).to.equal(1);
+    expect(basket.version).to.equal(2);
+    expect(ctx.svm.getAccount(basketPda)!.data.length).to.equal(8 + 248);
+
+    const basketToken = await ctx.program.account.basketToken.fetch(basketTokenPda);
+    expect(basketToken.mint.toBase58()).to.equal(usdcMint.toBase58());
+    expect(basketToken.decimals).to.equal(6);
+    expect(basketToken.enabled).to.equal(true);
+    expect(ctx.svm.getAccount(basketTokenPda)!.data.length).to.equal(8 + 204);
+  });
+
+  it("records the extensions on each leg's mint", async () => {
+    await migrate();
+
+    const basketToken = await ctx.program.account.basketToken.fetch(basketTokenPda);
+    expect(basketToken.extensionFlags).to.equal(1 << 4); // MINT_EXT_FREEZE_AUTHORITY
+  });
+
+  it("publishes the basket price", async () => {
+    await migrate();
+
+    const [basketPricePda] = findBasketPricePda(basketPda);
+    const price = await ctx.program.account.basketPrice.fetch(basketPricePda);
+    expect(price.basket.toBase58()).to.equal(basketPda.toBase58());
+    expect(price.navPerShare.toNumber()).to.equal(2_000_000_000);
+    expect(price.totalSupply.toNumber()).to.equal(500_000_000);
+    expect(price.navDecimals).to.equal(9);
+    expect(price.version).to.equal(2);
+  });
+
+  it("prices at spot and opens each leg's TWAP at the migration price", async () => {
+    await migrate();
+
+    const basket = await ctx.program.account.basket.fetch(basketPda);
+    expect(basket.mintPricingMode).to.equal(0);
+    expect(basket.redeemPricingMode).to.equal(0);
+
+    const now = Number(ctx.svm.getClock().unixTimestamp);
+    const basketToken = await ctx.program.account.basketToken.fetch(basketTokenPda);
+    // 1,000 USDC per 500 QSHARE.
+    expect(basketToken.lastPrice.toNumber()).to.equal(2_000_000_000);
+    expect(basketToken.lastUpdateTs.toNumber()).to.equal(now);
+    expect(basketToken.twapAnchorTs.toNumber()).to.equal(now);
+    expect(basketToken.twapUpdatedTs.toNumber()).to.equal(0);
+  });
+
+  it("leaves deposits uncapped", async () => {
+    await migrate();
+
+    const basket = await ctx.program.account.basket.fetch(basketPda);
+    expect(basket.maxTotalValue.toNumber()).to.equal(0);
+    expect(basket.maxUserShares.toNumber()).to.equal(0);
+  });
+
+  it("leaves the withdrawal limiter off", async () => {
+    await migrate();
+
+    const basket = await ctx.program.account.basket.fetch(basketPda);
+    expect(basket.maxWithdrawBps).to.equal(0);
+    expect(basket.emergencyMode).to.equal(0);
+    expect(basket.withdrawWindowSecs.toNumber()).to.equal(0);
+    expect(basket.windowStartSupply.toNumber()).to.equal(0);
+    expect(basket.burnedInWindow.toNumber()).to.equal(0);
+  });
+
+  it("keeps the owner with no transfer pending", async () => {
+    await migrate();
+
+    const basket = await ctx.program.account.basket.fetch(basketPda);
+    expect(basket.owner.toBase58()).to.equal(ctx.admin.publicKey.toBase58());
+    expect(basket.pendingOwner.toBase58()).to.equal(PublicKey.default.toBase58());
+  });
+
+  it("records no creation bond", async () => {
+    await migrate();
+
+    const basket = await ctx.program.account.basket.fetch(basketPda);
+    expect(basket.bondLamports.toNumber()).to.equal(0);
+  });
+
+  it("lists the basket in the registry without taking a new id", async () => {
+    await migrate();
+
+    const [registryPda] = findBasketRegistryPda(0);
+    const registry = await ctx.program.account.basketRegistry.fetch(registryPda);
+    expect(registry.count).to.equal(1);
+    expect(registry.baskets[0].toBase58()).to.equal(basketPda.toBase58());
+
+    // The id counter is the admin's to set in `migrate_config`.
+    const config = await ctx.program.account.config.fetch(findConfigPda()[0]);
+    expect(config.nextBasketId.toNumber()).to.equal(1);
+  });
+
+  it("rejects the wrong share mint", async () => {
+    const otherMint = await createTestMint(ctx.provider, ctx.admin.publicKey, 6);
+
+    await expectError(
+      ctx.program.methods
+        .migrateBasket()
+        .accounts({ basket: basketPda, shareMint: otherMint, program: ctx.program.programId })
+        .remainingAccounts(legAccounts())
+        .rpc(),
+      "ShareMintMismatch",
+    );
+  });
+
+  it("rejects a leg paired with the wrong mint", async () => {
+    const otherMint = await createTestMint(ctx.provider, ctx.admin.publicKey, 6);
+    const legs = legAccounts();
+    legs[1].pubkey = otherMint;
+
+    await expectError(migrate(legs), "MintConfigMismatch");
+  });
+
+  it("keeps other instructions off the basket until it is migrated", async () => {
+    await expectError(
+      ctx.program.methods
+        .updateBasketLimits(new BN(0), new BN(0), 0, new BN(0))
+        .accounts({
+          authority: ctx.admin.publicKey,
+          basket: basketPda,
+          program: ctx.program.programId,
+        })
+        .rpc(),
+      "AccountNotMigrated",
+    );
+
+    await migrate();
+    await ctx.program.methods
+      .updateBasketLimits(new BN(0), new BN(0), 0, new BN(0))
+      .accounts({
+        authority: ctx.admin.publicKey,
+        basket: basketPda,
+        program: ctx.program.programId,
+      })
+      .rpc();
+  });
+
+  it("rejects a second migration", async () => {
+    await migrate();
+
+    await expectError(migrate(), "AlreadyMigrated");
+  });
+
+  it("requires every leg", async () => {
+    await expectError(migrate([]), "InvalidRemainingAccounts");
+  });
+
+  it("rejects anyone but the admin", async () => {
+    const stranger = Keypair.generate();
+    fundAccount(ctx.svm, stranger.publicKey);
+
+    await expectError(migrate(legAccounts(), stranger), "Unauthorized");
+  });
+});
@@ -0,0 +1,95 @@
+import { expect } from "chai";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+import {
+  createTestContext,
+  expectError,
+  fundAccount,
+  findConfigPda,
+  type TestContext,
+} from "../../setup";
+
+describe("migrate_config", () => {
+  let ctx: TestContext;
+  const whitelistAuth = Keypair.generate();
+  let configPda: PublicKey;
+
+  /** A config as written by the version-1 program: 69 bytes after the discriminator. */
+  function setLegacyConfig() {
+    const [pda, bump] = findConfigPda();
+    const discriminator = ctx.program.idl.accounts.find((a) => a.name === "config")!.discriminator;
+    const data = Buffer.alloc(8 + 69);
+    Buffer.from(discriminator).copy(data, 0);
+    ctx.admin.publicKey.toBuffer().copy(data, 8);
+    whitelistAuth.publicKey.toBuffer().copy(data, 40);
+    data.writeUInt16LE(20, 72); // fee_bps
+    data.writeUInt8(1, 74); // compliance_enabled
+    data.writeUInt8(1, 75); // version
+    data.writeUInt8(bump, 76);
+
+    ctx.svm.setAccount(pda, {
+      lamports: Number(ctx.svm.minimumBalanceForRentExemption(BigInt(data.length))),
+      data,
+      owner: ctx.program.programId,
+      executable: false,
+    });
+  }
+
+  async function migrate(nextBasketId = 4, signer = ctx.admin) {
+    await ctx.program.methods
+      .migrateConfig(new BN(nextBasketId))
+      .accounts({
+        admin: signer.publicKey,
+        program: ctx.program.programId,
+      })
+      .signers(signer === ctx.admin ? [] : [signer])
+      .rpc();
+  }
+
+  beforeEach(() => {
+    ctx = createTestContext();
+    [configPda] = findConfigPda();
+    setLegacyConfig();
+  });
+
+  it("grows a version-1 config and keeps its settings", async () => {
+    await migrate();
+
+    const config = await ctx.program.account.config.fetch(configPda);
+    expect(config.admin.toBase58()).to.equal(ctx.admin.publicKey.toBase58());
+    expect(config.whitelistAuth.toBase58()).to.equal(whitelistAuth.publicKey.toBase58());
+    expect(config.feeBps).to.equal(20);
+    expect(config.complianceEnabled).to.equal(true);
+    expect(config.version).to.equal(2);
+    expect(config.mintExtensionPolicy).to.equal(0);
+    expect(config.permissionlessCreation).to.equal(false);
+    expect(config.creationFeeLamports.toNumber()).to.equal(0);
+    expect(config.creationBondLamports.toNumber()).to.equal(0);
+    expect(config.treasury.toBase58()).to.equal(ctx.admin.publicKey.toBase58());
+    expect(config.nextBasketId.toNumber()).to.equal(4);
+    expect(config.registryPage).to.equal(0);
+
+    const account = ctx.svm.getAccount(configPda)!;
+    expect(account.data.length).to.equal(8 + 131);
+    expect(Number(account.lamports)).to.be.at.least(
+      Number(ctx.svm.minimumBalanceForRentExemption(BigInt(8 + 131))),
+    );
+  });
+
+  it("rejects a second migration", async () => {
+    await migrate();
+
+    await expectError(migrate(), "AlreadyMigrated");
+  });
+
+  it("rejects anyone but the recorded admin", async () => {
+    const stranger = Keypair.generate();
+    fundAccount(ctx.svm, stranger.publicKey);
+
+    await expectError(migrate(4, stranger), "Unauthorized");
+  });
+
+  it("rejects a zero next basket id", async () => {
+    await expectError(migrate(0), "BasketIdMismatch");
+  });
+});
@@ -0,0 +1,222 @@
+import { expect } from "chai";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import {
+  createTestContext,
+  fundAccount,
+  createTestMint,
+  createTestAta,
+  mintTestTokens,
+  getTokenBalance,
+  findBasketPda,
+  findVaultAuthorityPda,
+  findMintAuthorityPda,
+  findBasketTokenPda,
+  findFeeVaultPda,
+  findMetadataPda,
+  TOKEN_METADATA_PROGRAM_ID,
+  type TestContext,
+} from "../../setup";
+import { getAssociatedTokenAddress } from "@solana/spl-token";
+
+describe("mint_exact_shares", () => {
+  let ctx: TestContext;
+  const whitelistAuth = Keypair.generate();
+  const basketId = new BN(1);
+
+  let basketPda: PublicKey;
+  let vaultAuthority: PublicKey;
+  let mintAuthority: PublicKey;
+  let shareMintKp: Keypair;
+  let usdcMint: PublicKey;
+  let basketTokenPda: PublicKey;
+  let feeVaultPda: PublicKey;
+  let vaultAta: PublicKey;
+
+  function encodeName(name: string): number[] {
+    const buf = Buffer.alloc(32, 0);
+    buf.write(name, "utf-8");
+    return Array.from(buf);
+  }
+
+  beforeEach(async () => {
+    ctx = createTestContext();
+
+    await ctx.program.methods
+      .initConfig(20, whitelistAuth.publicKey, false)
+      .accounts({ program: ctx.program.programId })
+      .rpc();
+
+    shareMintKp = Keypair.generate();
+    [basketPda] = findBasketPda(basketId);
+    [vaultAuthority] = findVaultAuthorityPda(basketId);
+    [mintAuthority] = findMintAuthorityPda(basketId);
+
+    await ctx.program.methods
+      .createBasket(basketId, encodeName("Mint Exact Test"), null, false, "QSHARE", "")
+      .accounts({
+        shareMint: shareMintKp.publicKey,
+        shareMetadata: findMetadataPda(shareMintKp.publicKey)[0],
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        treasury: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .signers([shareMintKp])
+      .rpc();
+
+    usdcMint = await createTestMint(ctx.provider, ctx.admin.publicKey, 6);
+    [basketTokenPda] = findBasketTokenPda(basketPda, usdcMint);
+    [feeVaultPda] = findFeeVaultPda(basketPda, usdcMint);
+    vaultAta = await getAssociatedTokenAddress(usdcMint, vaultAuthority, true);
+
+    await ctx.program.methods
+      .addTokens(0)
+      .accounts({
+        basket: basketPda,
+        underlyingMint: usdcMint,
+        vaultAuthority,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .rpc();
+  });
+
+  let user: Keypair;
+  let userUsdcAta: PublicKey;
+  let userShareAta: PublicKey;
+
+  function legAccounts(ata: PublicKey) {
+    return [
+      { pubkey: basketTokenPda, isWritable: true, isSigner: false },
+      { pubkey: usdcMint, isWritable: false, isSigner: false },
+      { pubkey: ata, isWritable: true, isSigner: false },
+      { pubkey: vaultAta, isWritable: true, isSigner: false },
+      { pubkey: feeVaultPda, isWritable: true, isSigner: false },
+    ];
+  }
+
+  function depositAccounts() {
+    return {
+      user: user.publicKey,
+      receiver: user.publicKey,
+      basket: basketPda,
+      mintAuthority,
+      shareMint: shareMintKp.publicKey,
+      userAllowList: null,
+      complianceRoot: null,
+      instructionsSysvar: null,
+      attestationNonce: null,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      shareTokenProgram: TOKEN_PROGRAM_ID,
+      program: ctx.program.programId,
+    };
+  }
+
+  async function fundUser() {
+    user = Keypair.generate();
+    fundAccount(ctx.svm, user.publicKey);
+    userUsdcAta = await createTestAta(ctx.provider, usdcMint, user.publicKey);
+    await mintTestTokens(
+      ctx.provider,
+      usdcMint,
+      userUsdcAta,
+      ctx.admin,
+      BigInt(2_000_000_000),
+    );
+    userShareAta = await getAssociatedTokenAddress(
+      shareMintKp.publicKey,
+      user.publicKey,
+    );
+  }
+
+  async function seed() {
+    await ctx.program.methods
+      .depositMulti([new BN(1_000_000_000)], null)
+      .accounts(depositAccounts())
+      .remainingAccounts(legAccounts(userUsdcAta))
+      .signers([user])
+      .rpc();
+  }
+
+  it("mints exactly the requested shares for the pro-rata amount plus fee", async () => {
+    await fundUser();
+    await seed();
+
+    const sharesBefore = await getTokenBalance(ctx.provider, userShareAta);
+    const vaultBefore = await getTokenBalance(ctx.provider, vaultAta);
+    const usdcBefore = await getTokenBalance(ctx.provider, userUsdcAta);
+    const shares = BigInt(100_000_000);
+
+    await ctx.program.methods
+      .mintExactShares(new BN(shares.toString()), [new BN(200_000_000)], null)
+      .accounts(depositAccounts())
+      .remainingAccounts(legAccounts(userUsdcAta))
+      .signers([user])
+      .rpc();
+
+    const sharesAfter = await getTokenBalance(ctx.provider, userShareAta);
+    expect((sharesAfter - sharesBefore).toString()).to.equal(shares.toString());
+
+    // Supply equals the vault after the seed, so the slice is 1:1 rounded up.
+    const vaultAfter = await getTokenBalance(ctx.provider, vaultAta);
+    const net = (vaultBefore * shares + sharesBefore - BigInt(1)) / sharesBefore;
+    expect((vaultAfter - vaultBefore).toString()).to.equal(net.toString());
+
+    // 20 bps grossed up: ceil(net * 10_000 / 9_980)
+    const gross = (net * BigInt(10_000) + BigInt(9_979)) / BigInt(9_980);
+    const usdcAfter = await getTokenBalance(ctx.provider, userUsdcAta);
+    expect((usdcBefore - usdcAfter).toString()).to.equal(gross.toString());
+  });
+
+  it("rejects when a leg needs more than its maximum", async () => {
+    await fundUser();
+    await seed();
+
+    try {
+      await ctx.program.methods
+        .mintExactShares(new BN(100_000_000), [new BN(100_000_000)], null)
+        .accounts(depositAccounts())
+        .remainingAccounts(legAccounts(userUsdcAta))
+        .signers([user])
+        .rpc();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("MaxAmountInExceeded");
+    }
+  });
+
+  it("rejects an empty basket", async () => {
+    await fundUser();
+
+    try {
+      await ctx.program.methods
+        .mintExactShares(new BN(100_000_000), [new BN(200_000_000)], null)
+        .accounts(depositAccounts())
+        .remainingAccounts(legAccounts(userUsdcAta))
+        .signers([user])
+        .rpc();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("EmptyBasket");
+    }
+  });
+
+  it("rejects zero shares", async () => {
+    await fundUser();
+    await seed();
+
+    try {
+      await ctx.program.methods
+        .mintExactShares(new BN(0), [new BN(200_000_000)], null)
+        .accounts(depositAccounts())
+        .remainingAccounts(legAccounts(userUsdcAta))
+        .signers([user])
+        .rpc();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("ZeroSharesMinted");
+    }
+  });
+});
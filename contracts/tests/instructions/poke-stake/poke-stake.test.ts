@@ -0,0 +1,78 @@
+import { expect } from "chai";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import {
+  createTestContext,
+  findStakePositionPda,
+  createRewardFixture,
+  newHolder,
+  fundRewards,
+  stake,
+  claimRewards,
+  type TestContext,
+  type RewardFixture,
+} from "../../setup";
+
+const DAY = 86_400;
+
+describe("poke_stake", () => {
+  let ctx: TestContext;
+  let fx: RewardFixture;
+
+  let rewardPool: PublicKey;
+  let user: Keypair;
+
+  function warp(seconds: number) {
+    const clock = ctx.svm.getClock();
+    clock.unixTimestamp += BigInt(seconds);
+    ctx.svm.setClock(clock);
+  }
+
+  async function poke(holder: Keypair) {
+    const [stakePosition] = findStakePositionPda(rewardPool, holder.publicKey);
+    await ctx.program.methods
+      .pokeStake()
+      .accounts({ rewardPool, stakePosition, program: ctx.program.programId })
+      .rpc();
+  }
+
+  beforeEach(async () => {
+    ctx = createTestContext();
+    fx = await createRewardFixture(ctx);
+    ({ rewardPool } = fx);
+
+    user = await newHolder(fx);
+  });
+
+  it("drops an ended boost to 1x", async () => {
+    const other = await newHolder(fx);
+    await stake(fx, user, 100_000_000, 0);
+    await stake(fx, other, 100_000_000, 30 * DAY);
+    warp(30 * DAY);
+
+    await poke(other);
+
+    const [stakePosition] = findStakePositionPda(rewardPool, other.publicKey);
+    const position = await ctx.program.account.stakePosition.fetch(stakePosition);
+    expect(position.multiplierBps).to.equal(10_000);
+    expect(position.weight.toNumber()).to.equal(100_000_000);
+    const pool = await ctx.program.account.rewardPool.fetch(rewardPool);
+    expect(pool.totalWeight.toNumber()).to.equal(200_000_000);
+
+    // Rewards streamed after the poke split evenly.
+    await fundRewards(fx, 2_000_000, 1_000);
+    warp(1_000);
+    expect(Number(await claimRewards(fx, user))).to.be.within(999_999, 1_000_000);
+    expect(Number(await claimRewards(fx, other))).to.be.within(999_999, 1_000_000);
+  });
+
+  it("rejects a poke before the commitment ends", async () => {
+    await stake(fx, user, 100_000_000, 30 * DAY);
+
+    try {
+      await poke(user);
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("StakeLocked");
+    }
+  });
+});
@@ -0,0 +1,214 @@
+import { expect } from "chai";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+import { TOKEN_PROGRAM_ID, getAssociatedTokenAddress } from "@solana/spl-token";
+import {
+  createTestContext,
+  fundAccount,
+  createTestMint,
+  createTestAta,
+  mintTestTokens,
+  getTokenBalance,
+  findBasketPda,
+  findVaultAuthorityPda,
+  findMintAuthorityPda,
+  findBasketTokenPda,
+  findFeeVaultPda,
+  findMetadataPda,
+  TOKEN_METADATA_PROGRAM_ID,
+  type TestContext,
+} from "../../setup";
+
+describe("preview_deposit", () => {
+  let ctx: TestContext;
+  const whitelistAuth = Keypair.generate();
+  const basketId = new BN(1);
+
+  let basketPda: PublicKey;
+  let vaultAuthority: PublicKey;
+  let mintAuthority: PublicKey;
+  let shareMintKp: Keypair;
+  let usdcMint: PublicKey;
+  let usdtMint: PublicKey;
+  let btUsdc: PublicKey;
+  let btUsdt: PublicKey;
+  let fvUsdc: PublicKey;
+  let fvUsdt: PublicKey;
+  let vaultUsdc: PublicKey;
+  let vaultUsdt: PublicKey;
+  let user: Keypair;
+  let userUsdcAta: PublicKey;
+  let userUsdtAta: PublicKey;
+  let userShareAta: PublicKey;
+
+  function encodeName(name: string): number[] {
+    const buf = Buffer.alloc(32, 0);
+    buf.write(name, "utf-8");
+    return Array.from(buf);
+  }
+
+  function previewAccounts() {
+    return [
+      { pubkey: btUsdc, isWritable: false, isSigner: false },
+      { pubkey: vaultUsdc, isWritable: false, isSigner: false },
+      { pubkey: btUsdt, isWritable: false, isSigner: false },
+      { pubkey: vaultUsdt, isWritable: false, isSigner: false },
+    ];
+  }
+
+  function preview(amounts: BN[]) {
+    return ctx.program.methods
+      .previewDeposit(amounts)
+      .accounts({
+        basket: basketPda,
+        shareMint: shareMintKp.publicKey,
+      })
+      .remainingAccounts(previewAccounts())
+      .view();
+  }
+
+  async function deposit(amounts: BN[]) {
+    await ctx.program.methods
+      .depositMulti(amounts, null)
+      .accounts({
+        user: user.publicKey,
+        receiver: user.publicKey,
+        basket: basketPda,
+        mintAuthority,
+        shareMint: shareMintKp.publicKey,
+        userAllowList: null,
+        complianceRoot: null,
+        instructionsSysvar: null,
+        attestationNonce: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .remainingAccounts([
+        { pubkey: btUsdc, isWritable: true, isSigner: false },
+        { pubkey: usdcMint, isWritable: false, isSigner: false },
+        { pubkey: userUsdcAta, isWritable: true, isSigner: false },
+        { pubkey: vaultUsdc, isWritable: true, isSigner: false },
+        { pubkey: fvUsdc, isWritable: true, isSigner: false },
+        { pubkey: btUsdt, isWritable: true, isSigner: false },
+        { pubkey: usdtMint, isWritable: false, isSigner: false },
+        { pubkey: userUsdtAta, isWritable: true, isSigner: false },
+        { pubkey: vaultUsdt, isWritable: true, isSigner: false },
+        { pubkey: fvUsdt, isWritable: true, isSigner: false },
+      ])
+      .signers([user])
+      .rpc();
+  }
+
+  beforeEach(async () => {
+    ctx = createTestContext();
+
+    await ctx.program.methods
+      .initConfig(20, whitelistAuth.publicKey, false)
+      .accounts({ program: ctx.program.programId })
+      .rpc();
+
+    shareMintKp = Keypair.generate();
+    [basketPda] = findBasketPda(basketId);
+    [vaultAuthority] = findVaultAuthorityPda(basketId);
+    [mintAuthority] = findMintAuthorityPda(basketId);
+
+    await ctx.program.methods
+      .createBasket(basketId, encodeName("Preview Test"), null, false, "QSHARE", "")
+      .accounts({
+        shareMint: shareMintKp.publicKey,
+        shareMetadata: findMetadataPda(shareMintKp.publicKey)[0],
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        treasury: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .signers([shareMintKp])
+      .rpc();
+
+    usdcMint = await createTestMint(ctx.provider, ctx.admin.publicKey, 6);
+    usdtMint = await createTestMint(ctx.provider, ctx.admin.publicKey, 6);
+
+    [btUsdc] = findBasketTokenPda(basketPda, usdcMint);
+    [fvUsdc] = findFeeVaultPda(basketPda, usdcMint);
+    vaultUsdc = await getAssociatedTokenAddress(usdcMint, vaultAuthority, true);
+
+    [btUsdt] = findBasketTokenPda(basketPda, usdtMint);
+    [fvUsdt] = findFeeVaultPda(basketPda, usdtMint);
+    vaultUsdt = await getAssociatedTokenAddress(usdtMint, vaultAuthority, true);
+
+    for (const mint of [usdcMint, usdtMint]) {
+      await ctx.program.methods
+        .addTokens(0)
+        .accounts({
+          basket: basketPda,
+          underlyingMint: mint,
+          vaultAuthority,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          program: ctx.program.programId,
+        })
+        .rpc();
+    }
+
+    user = Keypair.generate();
+    fundAccount(ctx.svm, user.publicKey);
+    userUsdcAta = await createTestAta(ctx.provider, usdcMint, user.publicKey);
+    userUsdtAta = await createTestAta(ctx.provider, usdtMint, user.publicKey);
+    await mintTestTokens(ctx.provider, usdcMint, userUsdcAta, ctx.admin, BigInt(2_000_000_000));
+    await mintTestTokens(ctx.provider, usdtMint, userUsdtAta, ctx.admin, BigInt(2_000_000_000));
+
+    userShareAta = await getAssociatedTokenAddress(
+      shareMintKp.publicKey,
+      user.publicKey,
+    );
+  });
+
+  it("quotes shares and fees for the first deposit", async () => {
+    const quote = await preview([new BN(1_000_000_000), new BN(500_000_000)]);
+
+    // 20 bps fee on each leg, shares = normalised net value
+    expect(quote.shares.toNumber()).to.equal(998_000_000 + 499_000_000);
+    expect(quote.legs[0].feeAmount.toNumber()).to.equal(2_000_000);
+    expect(quote.legs[0].netAmount.toNumber()).to.equal(998_000_000);
+    expect(quote.legs[1].feeAmount.toNumber()).to.equal(1_000_000);
+    expect(quote.legs[1].shares.toNumber()).to.equal(499_000_000);
+  });
+
+  it("matches the shares minted by deposit_multi", async () => {
+    await deposit([new BN(1_000_000_000), new BN(500_000_000)]);
+
+    const amounts = [new BN(300_000_000), new BN(700_000_000)];
+    const quote = await preview(amounts);
+
+    const before = await getTokenBalance(ctx.provider, userShareAta);
+    await deposit(amounts);
+    const after = await getTokenBalance(ctx.provider, userShareAta);
+
+    expect(Number(after - before)).to.equal(quote.shares.toNumber());
+  });
+
+  it("rejects a zero amount leg", async () => {
+    try {
+      await preview([new BN(1_000_000), new BN(0)]);
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("ZeroDeposit");
+    }
+  });
+
+  it("rejects missing leg accounts", async () => {
+    try {
+      await ctx.program.methods
+        .previewDeposit([new BN(1_000_000), new BN(1_000_000)])
+        .accounts({
+          basket: basketPda,
+          shareMint: shareMintKp.publicKey,
+        })
+        .remainingAccounts(previewAccounts().slice(0, 2))
+        .view();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("InvalidRemainingAccounts");
+    }
+  });
+});
@@ -0,0 +1,208 @@
+import { expect } from "chai";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+import { TOKEN_PROGRAM_ID, getAssociatedTokenAddress } from "@solana/spl-token";
+import {
+  createTestContext,
+  fundAccount,
+  createTestMint,
+  createTestAta,
+  mintTestTokens,
+  getTokenBalance,
+  findBasketPda,
+  findVaultAuthorityPda,
+  findMintAuthorityPda,
+  findBasketTokenPda,
+  findFeeVaultPda,
+  findMetadataPda,
+  TOKEN_METADATA_PROGRAM_ID,
+  type TestContext,
+} from "../../setup";
+
+describe("preview_withdraw", () => {
+  let ctx: TestContext;
+  const whitelistAuth = Keypair.generate();
+  const basketId = new BN(1);
+
+  let basketPda: PublicKey;
+  let vaultAuthority: PublicKey;
+  let mintAuthority: PublicKey;
+  let shareMintKp: Keypair;
+  let usdcMint: PublicKey;
+  let usdtMint: PublicKey;
+  let btUsdc: PublicKey;
+  let btUsdt: PublicKey;
+  let fvUsdc: PublicKey;
+  let fvUsdt: PublicKey;
+  let vaultUsdc: PublicKey;
+  let vaultUsdt: PublicKey;
+  let user: Keypair;
+  let userUsdcAta: PublicKey;
+  let userUsdtAta: PublicKey;
+  let userShareAta: PublicKey;
+
+  function encodeName(name: string): number[] {
+    const buf = Buffer.alloc(32, 0);
+    buf.write(name, "utf-8");
+    return Array.from(buf);
+  }
+
+  function preview(shares: BN) {
+    return ctx.program.methods
+      .previewWithdraw(shares)
+      .accounts({
+        basket: basketPda,
+        shareMint: shareMintKp.publicKey,
+      })
+      .remainingAccounts([
+        { pubkey: btUsdc, isWritable: false, isSigner: false },
+        { pubkey: vaultUsdc, isWritable: false, isSigner: false },
+        { pubkey: btUsdt, isWritable: false, isSigner: false },
+        { pubkey: vaultUsdt, isWritable: false, isSigner: false },
+      ])
+      .view();
+  }
+
+  beforeEach(async () => {
+    ctx = createTestContext();
+
+    await ctx.program.methods
+      .initConfig(20, whitelistAuth.publicKey, false)
+      .accounts({ program: ctx.program.programId })
+      .rpc();
+
+    shareMintKp = Keypair.generate();
+    [basketPda] = findBasketPda(basketId);
+    [vaultAuthority] = findVaultAuthorityPda(basketId);
+    [mintAuthority] = findMintAuthorityPda(basketId);
+
+    await ctx.program.methods
+      .createBasket(basketId, encodeName("Preview Test"), null, false, "QSHARE", "")
+      .accounts({
+        shareMint: shareMintKp.publicKey,
+        shareMetadata: findMetadataPda(shareMintKp.publicKey)[0],
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        treasury: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .signers([shareMintKp])
+      .rpc();
+
+    usdcMint = await createTestMint(ctx.provider, ctx.admin.publicKey, 6);
+    usdtMint = await createTestMint(ctx.provider, ctx.admin.publicKey, 6);
+
+    [btUsdc] = findBasketTokenPda(basketPda, usdcMint);
+    [fvUsdc] = findFeeVaultPda(basketPda, usdcMint);
+    vaultUsdc = await getAssociatedTokenAddress(usdcMint, vaultAuthority, true);
+
+    [btUsdt] = findBasketTokenPda(basketPda, usdtMint);
+    [fvUsdt] = findFeeVaultPda(basketPda, usdtMint);
+    vaultUsdt = await getAssociatedTokenAddress(usdtMint, vaultAuthority, true);
+
+    for (const mint of [usdcMint, usdtMint]) {
+      await ctx.program.methods
+        .addTokens(0)
+        .accounts({
+          basket: basketPda,
+          underlyingMint: mint,
+          vaultAuthority,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          program: ctx.program.programId,
+        })
+        .rpc();
+    }
+
+    user = Keypair.generate();
+    fundAccount(ctx.svm, user.publicKey);
+    userUsdcAta = await createTestAta(ctx.provider, usdcMint, user.publicKey);
+    userUsdtAta = await createTestAta(ctx.provider, usdtMint, user.publicKey);
+    await mintTestTokens(ctx.provider, usdcMint, userUsdcAta, ctx.admin, BigInt(1_000_000_000));
+    await mintTestTokens(ctx.provider, usdtMint, userUsdtAta, ctx.admin, BigInt(500_000_000));
+
+    userShareAta = await getAssociatedTokenAddress(
+      shareMintKp.publicKey,
+      user.publicKey,
+    );
+
+    await ctx.program.methods
+      .depositMulti([new BN(1_000_000_000), new BN(500_000_000)], null)
+      .accounts({
+        user: user.publicKey,
+        receiver: user.publicKey,
+        basket: basketPda,
+        mintAuthority,
+        shareMint: shareMintKp.publicKey,
+        userAllowList: null,
+        complianceRoot: null,
+        instructionsSysvar: null,
+        attestationNonce: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .remainingAccounts([
+        { pubkey: btUsdc, isWritable: true, isSigner: false },
+        { pubkey: usdcMint, isWritable: false, isSigner: false },
+        { pubkey: userUsdcAta, isWritable: true, isSigner: false },
+        { pubkey: vaultUsdc, isWritable: true, isSigner: false },
+        { pubkey: fvUsdc, isWritable: true, isSigner: false },
+        { pubkey: btUsdt, isWritable: true, isSigner: false },
+        { pubkey: usdtMint, isWritable: false, isSigner: false },
+        { pubkey: userUsdtAta, isWritable: true, isSigner: false },
+        { pubkey: vaultUsdt, isWritable: true, isSigner: false },
+        { pubkey: fvUsdt, isWritable: true, isSigner: false },
+      ])
+      .signers([user])
+      .rpc();
+  });
+
+  it("matches the underlying paid out by withdraw_multi", async () => {
+    const shares = new BN(Number(await getTokenBalance(ctx.provider, userShareAta)) / 3);
+    const quote = await preview(shares);
+    expect(quote.sharesBurned.toString()).to.equal(shares.toString());
+
+    const usdcBefore = await getTokenBalance(ctx.provider, userUsdcAta);
+    const usdtBefore = await getTokenBalance(ctx.provider, userUsdtAta);
+
+    await ctx.program.methods
+      .withdrawMulti(shares)
+      .accounts({
+        user: user.publicKey,
+        receiver: user.publicKey,
+        basket: basketPda,
+        shareMint: shareMintKp.publicKey,
+        vaultAuthority,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .remainingAccounts([
+        { pubkey: btUsdc, isWritable: true, isSigner: false },
+        { pubkey: usdcMint, isWritable: false, isSigner: false },
+        { pubkey: vaultUsdc, isWritable: true, isSigner: false },
+        { pubkey: userUsdcAta, isWritable: true, isSigner: false },
+        { pubkey: btUsdt, isWritable: true, isSigner: false },
+        { pubkey: usdtMint, isWritable: false, isSigner: false },
+        { pubkey: vaultUsdt, isWritable: true, isSigner: false },
+        { pubkey: userUsdtAta, isWritable: true, isSigner: false },
+      ])
+      .signers([user])
+      .rpc();
+
+    const usdcAfter = await getTokenBalance(ctx.provider, userUsdcAta);
+    const usdtAfter = await getTokenBalance(ctx.provider, userUsdtAta);
+    expect(Number(usdcAfter - usdcBefore)).to.equal(quote.amountsOut[0].toNumber());
+    expect(Number(usdtAfter - usdtBefore)).to.equal(quote.amountsOut[1].toNumber());
+  });
+
+  it("rejects more shares than the supply", async () => {
+    const supply = await getTokenBalance(ctx.provider, userShareAta);
+    try {
+      await preview(new BN((supply + BigInt(1)).toString()));
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("InsufficientShares");
+    }
+  });
+});
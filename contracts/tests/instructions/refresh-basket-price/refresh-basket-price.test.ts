@@ -0,0 +1,224 @@
+import { expect } from "chai";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+import { TOKEN_PROGRAM_ID, getAssociatedTokenAddress } from "@solana/spl-token";
+import {
+  createTestContext,
+  fundAccount,
+  createTestMint,
+  createTestAta,
+  mintTestTokens,
+  getTokenBalance,
+  findBasketPda,
+  findVaultAuthorityPda,
+  findMintAuthorityPda,
+  findBasketTokenPda,
+  findFeeVaultPda,
+  findBasketPricePda,
+  findMetadataPda,
+  TOKEN_METADATA_PROGRAM_ID,
+  type TestContext,
+} from "../../setup";
+
+describe("refresh_basket_price", () => {
+  let ctx: TestContext;
+  const whitelistAuth = Keypair.generate();
+  const basketId = new BN(1);
+
+  let basketPda: PublicKey;
+  let basketPrice: PublicKey;
+  let vaultAuthority: PublicKey;
+  let mintAuthority: PublicKey;
+  let shareMintKp: Keypair;
+  let usdcMint: PublicKey;
+  let usdtMint: PublicKey;
+  let btUsdc: PublicKey;
+  let btUsdt: PublicKey;
+  let fvUsdc: PublicKey;
+  let fvUsdt: PublicKey;
+  let vaultUsdc: PublicKey;
+  let vaultUsdt: PublicKey;
+  let user: Keypair;
+  let userUsdcAta: PublicKey;
+  let userUsdtAta: PublicKey;
+  let userShareAta: PublicKey;
+
+  function encodeName(name: string): number[] {
+    const buf = Buffer.alloc(32, 0);
+    buf.write(name, "utf-8");
+    return Array.from(buf);
+  }
+
+  function legAccounts() {
+    return [
+      { pubkey: btUsdc, isWritable: true, isSigner: false },
+      { pubkey: vaultUsdc, isWritable: false, isSigner: false },
+      { pubkey: btUsdt, isWritable: true, isSigner: false },
+      { pubkey: vaultUsdt, isWritable: false, isSigner: false },
+    ];
+  }
+
+  async function refresh(remaining = legAccounts()) {
+    await ctx.program.methods
+      .refreshBasketPrice()
+      .accounts({
+        basket: basketPda,
+        shareMint: shareMintKp.publicKey,
+        program: ctx.program.programId,
+      })
+      .remainingAccounts(remaining)
+      .rpc();
+  }
+
+  beforeEach(async () => {
+    ctx = createTestContext();
+
+    await ctx.program.methods
+      .initConfig(20, whitelistAuth.publicKey, false)
+      .accounts({ program: ctx.program.programId })
+      .rpc();
+
+    shareMintKp = Keypair.generate();
+    [basketPda] = findBasketPda(basketId);
+    [basketPrice] = findBasketPricePda(basketPda);
+    [vaultAuthority] = findVaultAuthorityPda(basketId);
+    [mintAuthority] = findMintAuthorityPda(basketId);
+
+    await ctx.program.methods
+      .createBasket(basketId, encodeName("Price Test"), null, false, "QSHARE", "")
+      .accounts({
+        shareMint: shareMintKp.publicKey,
+        shareMetadata: findMetadataPda(shareMintKp.publicKey)[0],
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        treasury: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .signers([shareMintKp])
+      .rpc();
+
+    usdcMint = await createTestMint(ctx.provider, ctx.admin.publicKey, 6);
+    usdtMint = await createTestMint(ctx.provider, ctx.admin.publicKey, 6);
+
+    [btUsdc] = findBasketTokenPda(basketPda, usdcMint);
+    [fvUsdc] = findFeeVaultPda(basketPda, usdcMint);
+    vaultUsdc = await getAssociatedTokenAddress(usdcMint, vaultAuthority, true);
+
+    [btUsdt] = findBasketTokenPda(basketPda, usdtMint);
+    [fvUsdt] = findFeeVaultPda(basketPda, usdtMint);
+    vaultUsdt = await getAssociatedTokenAddress(usdtMint, vaultAuthority, true);
+
+    for (const mint of [usdcMint, usdtMint]) {
+      await ctx.program.methods
+        .addTokens(0)
+        .accounts({
+          basket: basketPda,
+          underlyingMint: mint,
+          vaultAuthority,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          program: ctx.program.programId,
+        })
+        .rpc();
+    }
+
+    user = Keypair.generate();
+    fundAccount(ctx.svm, user.publicKey);
+    userUsdcAta = await createTestAta(ctx.provider, usdcMint, user.publicKey);
+    userUsdtAta = await createTestAta(ctx.provider, usdtMint, user.publicKey);
+    await mintTestTokens(ctx.provider, usdcMint, userUsdcAta, ctx.admin, BigInt(1_000_000_000));
+    await mintTestTokens(ctx.provider, usdtMint, userUsdtAta, ctx.admin, BigInt(500_000_000));
+
+    userShareAta = await getAssociatedTokenAddress(
+      shareMintKp.publicKey,
+      user.publicKey,
+    );
+  });
+
+  async function deposit() {
+    await ctx.program.methods
+      .depositMulti([new BN(1_000_000_000), new BN(500_000_000)], null)
+      .accounts({
+        user: user.publicKey,
+        receiver: user.publicKey,
+        basket: basketPda,
+        mintAuthority,
+        shareMint: shareMintKp.publicKey,
+        userAllowList: null,
+        complianceRoot: null,
+        instructionsSysvar: null,
+        attestationNonce: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .remainingAccounts([
+        { pubkey: btUsdc, isWritable: true, isSigner: false },
+        { pubkey: usdcMint, isWritable: false, isSigner: false },
+        { pubkey: userUsdcAta, isWritable: true, isSigner: false },
+        { pubkey: vaultUsdc, isWritable: true, isSigner: false },
+        { pubkey: fvUsdc, isWritable: true, isSigner: false },
+        { pubkey: btUsdt, isWritable: true, isSigner: false },
+        { pubkey: usdtMint, isWritable: false, isSigner: false },
+        { pubkey: userUsdtAta, isWritable: true, isSigner: false },
+        { pubkey: vaultUsdt, isWritable: true, isSigner: false },
+        { pubkey: fvUsdt, isWritable: true, isSigner: false },
+      ])
+      .signers([user])
+      .rpc();
+  }
+
+  it("publishes a 1.0 price at basket creation", async () => {
+    const price = await ctx.program.account.basketPrice.fetch(basketPrice);
+    expect(price.basket.toBase58()).to.equal(basketPda.toBase58());
+    expect(price.navPerShare.toNumber()).to.equal(1_000_000_000);
+    expect(price.confidence.toNumber()).to.equal(0);
+    expect(price.totalSupply.toNumber()).to.equal(0);
+    expect(price.navDecimals).to.equal(9);
+  });
+
+  it("republishes the price at the end of a deposit", async () => {
+    await deposit();
+
+    const price = await ctx.program.account.basketPrice.fetch(basketPrice);
+    const supply = await getTokenBalance(ctx.provider, userShareAta);
+    expect(price.totalSupply.toString()).to.equal(supply.toString());
+    expect(price.navPerShare.toNumber()).to.equal(1_000_000_000);
+  });
+
+  it("crank picks up vault value accrued outside deposits", async () => {
+    await deposit();
+    await mintTestTokens(ctx.provider, usdcMint, vaultUsdc, ctx.admin, BigInt(1_497_000_000));
+
+    let price = await ctx.program.account.basketPrice.fetch(basketPrice);
+    expect(price.navPerShare.toNumber()).to.equal(1_000_000_000);
+
+    await refresh();
+
+    price = await ctx.program.account.basketPrice.fetch(basketPrice);
+    expect(price.navPerShare.toNumber()).to.equal(2_000_000_000);
+  });
+
+  it("rejects missing leg accounts", async () => {
+    try {
+      await refresh(legAccounts().slice(0, 2));
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("InvalidRemainingAccounts");
+    }
+  });
+
+  it("rejects the same leg passed twice", async () => {
+    await deposit();
+    const usdcLeg = legAccounts().slice(0, 2);
+
+    try {
+      await refresh([...usdcLeg, ...usdcLeg]);
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("DuplicateLeg");
+    }
+
+    const price = await ctx.program.account.basketPrice.fetch(basketPrice);
+    expect(price.navPerShare.toNumber()).to.equal(1_000_000_000);
+  });
+});
@@ -0,0 +1,207 @@
+import { expect } from "chai";
+import { createHash } from "crypto";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import {
+  createTestContext,
+  expectError,
+  fundAccount,
+  getTokenBalance,
+  findDepositRequestPda,
+  createEpochFixture,
+  newEpochUser,
+  requestDeposit,
+  settle,
+  type TestContext,
+  type EpochFixture,
+} from "../../setup";
+
+describe("request_deposit", () => {
+  let ctx: TestContext;
+  let fx: EpochFixture;
+  const whitelistAuth = Keypair.generate();
+  const operator = Keypair.generate();
+
+  let basketPda: PublicKey;
+  let shareMint: PublicKey;
+  let usdcMint: PublicKey;
+  let basketTokenPda: PublicKey;
+  let feeVaultPda: PublicKey;
+  let vaultAta: PublicKey;
+  let epochQueue: PublicKey;
+  let pendingAta: PublicKey;
+
+  async function setLimits(maxTotalValue: number, maxUserShares: number) {
+    await ctx.program.methods
+      .updateBasketLimits(new BN(maxTotalValue), new BN(maxUserShares), 0, new BN(0))
+      .accounts({ basket: basketPda, program: ctx.program.programId })
+      .rpc();
+  }
+
+  beforeEach(async () => {
+    ctx = createTestContext();
+    fx = await createEpochFixture(ctx, operator, whitelistAuth.publicKey);
+    ({
+      basket: basketPda,
+      shareMint,
+      usdcMint,
+      basketToken: basketTokenPda,
+      feeVault: feeVaultPda,
+      vaultAta,
+      epochQueue,
+      pendingAta,
+    } = fx);
+  });
+
+  it("escrows the net amount and takes the fee", async () => {
+    const user = await newEpochUser(fx);
+    await requestDeposit(fx, user, 1_000_000_000);
+
+    expect(Number(await getTokenBalance(ctx.provider, pendingAta))).to.equal(998_000_000);
+    expect(Number(await getTokenBalance(ctx.provider, feeVaultPda))).to.equal(2_000_000);
+    expect(Number(await getTokenBalance(ctx.provider, vaultAta))).to.equal(0);
+
+    const [requestPda] = findDepositRequestPda(basketPda, user.kp.publicKey);
+    const request = await ctx.program.account.depositRequest.fetch(requestPda);
+    expect(request.epoch.toNumber()).to.equal(0);
+    expect(request.value.toNumber()).to.equal(998_000_000);
+    expect(request.legCount).to.equal(1);
+    expect(request.legs[0].mint.toBase58()).to.equal(usdcMint.toBase58());
+    expect(request.legs[0].amount.toNumber()).to.equal(998_000_000);
+    const queue = await ctx.program.account.epochQueue.fetch(epochQueue);
+    expect(queue.pendingDepositValue.toNumber()).to.equal(998_000_000);
+  });
+
+  it("tops up a request in the same epoch", async () => {
+    const user = await newEpochUser(fx);
+    await requestDeposit(fx, user, 500_000_000);
+    await requestDeposit(fx, user, 500_000_000);
+
+    const [requestPda] = findDepositRequestPda(basketPda, user.kp.publicKey);
+    const request = await ctx.program.account.depositRequest.fetch(requestPda);
+    expect(request.value.toNumber()).to.equal(998_000_000);
+    expect(request.legCount).to.equal(1);
+    expect(request.legs[0].amount.toNumber()).to.equal(998_000_000);
+  });
+
+  it("rejects a new request while an earlier one is unclaimed", async () => {
+    const user = await newEpochUser(fx);
+    await requestDeposit(fx, user, 500_000_000);
+    await settle(fx);
+
+    try {
+      await requestDeposit(fx, user, 500_000_000);
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("UnclaimedRequest");
+    }
+  });
+
+  it("rejects a zero amount", async () => {
+    const user = await newEpochUser(fx);
+    try {
+      await requestDeposit(fx, user, 0);
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("ZeroDeposit");
+    }
+  });
+
+  it("rejects an escrow that is not the queue's", async () => {
+    const user = await newEpochUser(fx);
+    try {
+      await ctx.program.methods
+        .requestDeposit([new BN(1_000_000)], null)
+        .accounts({
+          owner: user.kp.publicKey,
+          basket: basketPda,
+          shareMint,
+          userAllowList: null,
+          complianceRoot: null,
+          instructionsSysvar: null,
+          attestationNonce: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          shareTokenProgram: TOKEN_PROGRAM_ID,
+          program: ctx.program.programId,
+        })
+        .remainingAccounts([
+          { pubkey: basketTokenPda, isWritable: false, isSigner: false },
+          { pubkey: usdcMint, isWritable: false, isSigner: false },
+          { pubkey: user.usdcAta, isWritable: true, isSigner: false },
+          { pubkey: vaultAta, isWritable: false, isSigner: false },
+          { pubkey: vaultAta, isWritable: true, isSigner: false },
+          { pubkey: feeVaultPda, isWritable: true, isSigner: false },
+        ])
+        .signers([user.kp])
+        .rpc();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("InvalidEscrowAccount");
+    }
+  });
+
+  it("counts the whole queue against the value cap", async () => {
+    await setLimits(1_500_000_000, 0);
+    await requestDeposit(fx, await newEpochUser(fx), 1_000_000_000);
+
+    // 998 USDC queued plus another 998 would pass the 1,500 USDC cap.
+    await expectError(
+      requestDeposit(fx, await newEpochUser(fx), 1_000_000_000),
+      "DepositCapExceeded",
+    );
+  });
+
+  it("counts the owner's whole request against the share cap", async () => {
+    await setLimits(0, 900_000_000);
+    const user = await newEpochUser(fx);
+    await requestDeposit(fx, user, 500_000_000);
+
+    // The topped-up request would fill 998 shares at today's price.
+    await expectError(requestDeposit(fx, user, 500_000_000), "DepositCapExceeded");
+  });
+
+  describe("with compliance enabled", () => {
+    function leafHash(user: PublicKey, tier: number, expiry: BN): Buffer {
+      return createHash("sha256")
+        .update(Buffer.from([0]))
+        .update(user.toBuffer())
+        .update(Buffer.from([tier]))
+        .update(expiry.toArrayLike(Buffer, "le", 8))
+        .digest();
+    }
+
+    beforeEach(async () => {
+      await ctx.program.methods
+        .setConfig(null, null, true, null, null)
+        .accounts({ program: ctx.program.programId })
+        .rpc();
+      fundAccount(ctx.svm, whitelistAuth.publicKey);
+    });
+
+    it("admits an owner proven under the compliance root", async () => {
+      const user = await newEpochUser(fx);
+      const expiry = new BN(4_000_000_000);
+      // A single-leaf tree: the root is the leaf and the proof is empty.
+      await ctx.program.methods
+        .setComplianceRoot(Array.from(leafHash(user.kp.publicKey, 1, expiry)), new BN(1))
+        .accounts({
+          authority: whitelistAuth.publicKey,
+          basket: basketPda,
+          program: ctx.program.programId,
+        })
+        .signers([whitelistAuth])
+        .rpc();
+
+      await requestDeposit(fx, user, 1_000_000_000, { tier: 1, expiry, proof: [] });
+      expect(Number(await getTokenBalance(ctx.provider, pendingAta))).to.equal(998_000_000);
+    });
+
+    it("rejects an owner with no admission", async () => {
+      await expectError(
+        requestDeposit(fx, await newEpochUser(fx), 1_000_000_000),
+        "ComplianceDenied",
+      );
+    });
+  });
+});
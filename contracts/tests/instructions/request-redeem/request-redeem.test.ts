@@ -0,0 +1,72 @@
+import { expect } from "chai";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import {
+  createTestContext,
+  getTokenBalance,
+  findRedeemRequestPda,
+  createEpochFixture,
+  newEpochUser,
+  depositNow,
+  requestRedeem,
+  settle,
+  shareBalance,
+  type TestContext,
+  type EpochFixture,
+} from "../../setup";
+
+describe("request_redeem", () => {
+  let ctx: TestContext;
+  let fx: EpochFixture;
+  const operator = Keypair.generate();
+
+  let basketPda: PublicKey;
+  let epochQueue: PublicKey;
+  let shareEscrow: PublicKey;
+
+  beforeEach(async () => {
+    ctx = createTestContext();
+    fx = await createEpochFixture(ctx, operator);
+    ({ basket: basketPda, epochQueue, shareEscrow } = fx);
+  });
+
+  it("escrows the shares until settlement", async () => {
+    const user = await newEpochUser(fx);
+    await depositNow(fx, user);
+    await requestRedeem(fx, user, 400_000_000);
+
+    expect(await shareBalance(fx, user.kp.publicKey)).to.equal(598_000_000);
+    expect(Number(await getTokenBalance(ctx.provider, shareEscrow))).to.equal(400_000_000);
+
+    const [requestPda] = findRedeemRequestPda(basketPda, user.kp.publicKey);
+    const request = await ctx.program.account.redeemRequest.fetch(requestPda);
+    expect(request.shares.toNumber()).to.equal(400_000_000);
+    const queue = await ctx.program.account.epochQueue.fetch(epochQueue);
+    expect(queue.pendingRedeemShares.toNumber()).to.equal(400_000_000);
+  });
+
+  it("rejects more shares than the owner holds", async () => {
+    const user = await newEpochUser(fx);
+    await depositNow(fx, user);
+
+    try {
+      await requestRedeem(fx, user, 998_000_001);
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("InsufficientShares");
+    }
+  });
+
+  it("rejects a new request while an earlier one is unclaimed", async () => {
+    const user = await newEpochUser(fx);
+    await depositNow(fx, user);
+    await requestRedeem(fx, user, 100_000_000);
+    await settle(fx);
+
+    try {
+      await requestRedeem(fx, user, 100_000_000);
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("UnclaimedRequest");
+    }
+  });
+});
@@ -0,0 +1,350 @@
+import { expect } from "chai";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+import { TOKEN_PROGRAM_ID, getAssociatedTokenAddress } from "@solana/spl-token";
+import {
+  createTestContext,
+  fundAccount,
+  createTestMint,
+  createTestAta,
+  mintTestTokens,
+  getTokenBalance,
+  findBasketPda,
+  findVaultAuthorityPda,
+  findMintAuthorityPda,
+  findBasketTokenPda,
+  findFeeVaultPda,
+  findMetadataPda,
+  TOKEN_METADATA_PROGRAM_ID,
+  type TestContext,
+} from "../../setup";
+
+describe("set_basket_pricing", () => {
+  let ctx: TestContext;
+  const whitelistAuth = Keypair.generate();
+  const basketId = new BN(1);
+
+  let basketPda: PublicKey;
+  let vaultAuthority: PublicKey;
+  let mintAuthority: PublicKey;
+  let shareMintKp: Keypair;
+  let usdcMint: PublicKey;
+  let usdtMint: PublicKey;
+  let btUsdc: PublicKey;
+  let btUsdt: PublicKey;
+  let fvUsdc: PublicKey;
+  let fvUsdt: PublicKey;
+  let vaultUsdc: PublicKey;
+  let vaultUsdt: PublicKey;
+  let user: Keypair;
+  let userUsdcAta: PublicKey;
+  let userUsdtAta: PublicKey;
+  let userShareAta: PublicKey;
+
+  function encodeName(name: string): number[] {
+    const buf = Buffer.alloc(32, 0);
+    buf.write(name, "utf-8");
+    return Array.from(buf);
+  }
+
+  function legAccounts() {
+    return [
+      { pubkey: btUsdc, isWritable: true, isSigner: false },
+      { pubkey: vaultUsdc, isWritable: false, isSigner: false },
+      { pubkey: btUsdt, isWritable: true, isSigner: false },
+      { pubkey: vaultUsdt, isWritable: false, isSigner: false },
+    ];
+  }
+
+  async function refresh() {
+    await ctx.program.methods
+      .refreshBasketPrice()
+      .accounts({
+        basket: basketPda,
+        shareMint: shareMintKp.publicKey,
+        program: ctx.program.programId,
+      })
+      .remainingAccounts(legAccounts())
+      .rpc();
+  }
+
+  async function setPricing(mintMode: number, redeemMode: number, signer = ctx.admin) {
+    await ctx.program.methods
+      .setBasketPricing(mintMode, redeemMode)
+      .accounts({
+        authority: signer.publicKey,
+        basket: basketPda,
+        program: ctx.program.programId,
+      })
+      .signers(signer === ctx.admin ? [] : [signer])
+      .rpc();
+  }
+
+  function previewDeposit(amounts: BN[]) {
+    return ctx.program.methods
+      .previewDeposit(amounts)
+      .accounts({ basket: basketPda, shareMint: shareMintKp.publicKey })
+      .remainingAccounts(legAccounts())
+      .view();
+  }
+
+  function previewWithdraw(shares: BN) {
+    return ctx.program.methods
+      .previewWithdraw(shares)
+      .accounts({ basket: basketPda, shareMint: shareMintKp.publicKey })
+      .remainingAccounts(legAccounts())
+      .view();
+  }
+
+  function warp(seconds: number) {
+    const clock = ctx.svm.getClock();
+    clock.unixTimestamp += BigInt(seconds);
+    ctx.svm.setClock(clock);
+  }
+
+  // Deposit, let a full TWAP window elapse at NAV 1.0, then double the spot
+  // NAV with a direct vault donation.
+  async function seedTwapThenDonate() {
+    await deposit();
+    warp(1_800);
+    await refresh();
+    await mintTestTokens(ctx.provider, usdcMint, vaultUsdc, ctx.admin, BigInt(1_497_000_000));
+  }
+
+  beforeEach(async () => {
+    ctx = createTestContext();
+
+    await ctx.program.methods
+      .initConfig(20, whitelistAuth.publicKey, false)
+      .accounts({ program: ctx.program.programId })
+      .rpc();
+
+    shareMintKp = Keypair.generate();
+    [basketPda] = findBasketPda(basketId);
+    [vaultAuthority] = findVaultAuthorityPda(basketId);
+    [mintAuthority] = findMintAuthorityPda(basketId);
+
+    await ctx.program.methods
+      .createBasket(basketId, encodeName("Pricing Test"), null, false, "QSHARE", "")
+      .accounts({
+        shareMint: shareMintKp.publicKey,
+        shareMetadata: findMetadataPda(shareMintKp.publicKey)[0],
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        treasury: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .signers([shareMintKp])
+      .rpc();
+
+    usdcMint = await createTestMint(ctx.provider, ctx.admin.publicKey, 6);
+    usdtMint = await createTestMint(ctx.provider, ctx.admin.publicKey, 6);
+
+    [btUsdc] = findBasketTokenPda(basketPda, usdcMint);
+    [fvUsdc] = findFeeVaultPda(basketPda, usdcMint);
+    vaultUsdc = await getAssociatedTokenAddress(usdcMint, vaultAuthority, true);
+
+    [btUsdt] = findBasketTokenPda(basketPda, usdtMint);
+    [fvUsdt] = findFeeVaultPda(basketPda, usdtMint);
+    vaultUsdt = await getAssociatedTokenAddress(usdtMint, vaultAuthority, true);
+
+    for (const mint of [usdcMint, usdtMint]) {
+      await ctx.program.methods
+        .addTokens(0)
+        .accounts({
+          basket: basketPda,
+          underlyingMint: mint,
+          vaultAuthority,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          program: ctx.program.programId,
+        })
+        .rpc();
+    }
+
+    user = Keypair.generate();
+    fundAccount(ctx.svm, user.publicKey);
+    userUsdcAta = await createTestAta(ctx.provider, usdcMint, user.publicKey);
+    userUsdtAta = await createTestAta(ctx.provider, usdtMint, user.publicKey);
+    await mintTestTokens(ctx.provider, usdcMint, userUsdcAta, ctx.admin, BigInt(1_000_000_000));
+    await mintTestTokens(ctx.provider, usdtMint, userUsdtAta, ctx.admin, BigInt(500_000_000));
+
+    userShareAta = await getAssociatedTokenAddress(
+      shareMintKp.publicKey,
+      user.publicKey,
+    );
+  });
+
+  async function deposit() {
+    await ctx.program.methods
+      .depositMulti([new BN(1_000_000_000), new BN(500_000_000)], null)
+      .accounts({
+        user: user.publicKey,
+        receiver: user.publicKey,
+        basket: basketPda,
+        mintAuthority,
+        shareMint: shareMintKp.publicKey,
+        userAllowList: null,
+        complianceRoot: null,
+        instructionsSysvar: null,
+        attestationNonce: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .remainingAccounts([
+        { pubkey: btUsdc, isWritable: true, isSigner: false },
+        { pubkey: usdcMint, isWritable: false, isSigner: false },
+        { pubkey: userUsdcAta, isWritable: true, isSigner: false },
+        { pubkey: vaultUsdc, isWritable: true, isSigner: false },
+        { pubkey: fvUsdc, isWritable: true, isSigner: false },
+        { pubkey: btUsdt, isWritable: true, isSigner: false },
+        { pubkey: usdtMint, isWritable: false, isSigner: false },
+        { pubkey: userUsdtAta, isWritable: true, isSigner: false },
+        { pubkey: vaultUsdt, isWritable: true, isSigner: false },
+        { pubkey: fvUsdt, isWritable: true, isSigner: false },
+      ])
+      .signers([user])
+      .rpc();
+  }
+
+  async function withdraw(shares: BN) {
+    await ctx.program.methods
+      .withdrawMulti(shares)
+      .accounts({
+        user: user.publicKey,
+        receiver: user.publicKey,
+        basket: basketPda,
+        shareMint: shareMintKp.publicKey,
+        vaultAuthority,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .remainingAccounts([
+        { pubkey: btUsdc, isWritable: true, isSigner: false },
+        { pubkey: usdcMint, isWritable: false, isSigner: false },
+        { pubkey: vaultUsdc, isWritable: true, isSigner: false },
+        { pubkey: userUsdcAta, isWritable: true, isSigner: false },
+        { pubkey: btUsdt, isWritable: true, isSigner: false },
+        { pubkey: usdtMint, isWritable: false, isSigner: false },
+        { pubkey: vaultUsdt, isWritable: true, isSigner: false },
+        { pubkey: userUsdtAta, isWritable: true, isSigner: false },
+      ])
+      .signers([user])
+      .rpc();
+  }
+
+  async function shareBalance(): Promise<BN> {
+    return new BN((await getTokenBalance(ctx.provider, userShareAta)).toString());
+  }
+
+  async function topUpUser() {
+    await mintTestTokens(ctx.provider, usdcMint, userUsdcAta, ctx.admin, BigInt(1_000_000_000));
+    await mintTestTokens(ctx.provider, usdtMint, userUsdtAta, ctx.admin, BigInt(500_000_000));
+  }
+
+  it("sets mint and redeem pricing modes", async () => {
+    await setPricing(1, 2);
+
+    const basket = await ctx.program.account.basket.fetch(basketPda);
+    expect(basket.mintPricingMode).to.equal(1);
+    expect(basket.redeemPricingMode).to.equal(2);
+  });
+
+  it("rejects an unknown pricing mode", async () => {
+    try {
+      await setPricing(4, 0);
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("InvalidPricingMode");
+    }
+  });
+
+  it("rejects a non-owner", async () => {
+    const intruder = Keypair.generate();
+    fundAccount(ctx.svm, intruder.publicKey);
+    try {
+      await setPricing(1, 1, intruder);
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("Unauthorized");
+    }
+  });
+
+  it("TWAP minting ignores a spot NAV jump", async () => {
+    await seedTwapThenDonate();
+    const amounts = [new BN(1_000_000), new BN(1_000_000)];
+
+    const spot = await previewDeposit(amounts);
+    await setPricing(1, 0);
+    const twap = await previewDeposit(amounts);
+    await setPricing(3, 0);
+    const max = await previewDeposit(amounts);
+
+    // Spot NAV is 2.0, TWAP NAV ~1.0
+    expect(spot.shares.toNumber()).to.equal(998_000);
+    expect(twap.shares.toNumber()).to.equal(1_996_000);
+    expect(max.shares.toNumber()).to.equal(spot.shares.toNumber());
+  });
+
+  it("min redemption pricing haircuts payouts above TWAP", async () => {
+    await seedTwapThenDonate();
+    const shares = new BN(748_500_000);
+
+    const spot = await previewWithdraw(shares);
+    await setPricing(0, 2);
+    const min = await previewWithdraw(shares);
+
+    // Payouts scale by TWAP / spot = 999_999_999 / 2_000_000_000
+    spot.amountsOut.forEach((out: BN, i: number) => {
+      const expected = out.mul(new BN(999_999_999)).div(new BN(2_000_000_000));
+      expect(min.amountsOut[i].toString()).to.equal(expected.toString());
+    });
+  });
+
+  it("falls back to spot before a TWAP window completes", async () => {
+    await deposit();
+    await mintTestTokens(ctx.provider, usdcMint, vaultUsdc, ctx.admin, BigInt(1_497_000_000));
+    const amounts = [new BN(1_000_000), new BN(1_000_000)];
+
+    const spot = await previewDeposit(amounts);
+    await setPricing(1, 0);
+    const twap = await previewDeposit(amounts);
+
+    expect(twap.shares.toString()).to.equal(spot.shares.toString());
+  });
+
+  it("does not average the unpriced period before the first deposit", async () => {
+    await setPricing(1, 1);
+    warp(1_800);
+    await deposit();
+    const first = await shareBalance();
+
+    await topUpUser();
+    await deposit();
+    // Priced at spot (no completed window yet): the same deposit mints the same shares.
+    expect((await shareBalance()).sub(first).toString()).to.equal(first.toString());
+
+    const before = await getTokenBalance(ctx.provider, userUsdcAta);
+    await withdraw(first);
+    const received = Number(await getTokenBalance(ctx.provider, userUsdcAta)) - Number(before);
+    expect(received).to.equal(998_000_000);
+  });
+
+  it("restarts the TWAP window after supply returns to zero", async () => {
+    await setPricing(1, 0);
+    await deposit();
+    warp(1_800);
+    await refresh();
+
+    await withdraw(await shareBalance());
+    warp(1_800);
+    await topUpUser();
+    await deposit();
+    const first = await shareBalance();
+
+    await topUpUser();
+    await deposit();
+    expect((await shareBalance()).sub(first).toString()).to.equal(first.toString());
+  });
+});
@@ -0,0 +1,244 @@
+import { expect } from "chai";
+import { createHash } from "crypto";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+import { TOKEN_PROGRAM_ID, getAssociatedTokenAddress } from "@solana/spl-token";
+import {
+  createTestContext,
+  expectError,
+  fundAccount,
+  createTestMint,
+  createTestAta,
+  mintTestTokens,
+  getTokenBalance,
+  findBasketPda,
+  findVaultAuthorityPda,
+  findMintAuthorityPda,
+  findBasketTokenPda,
+  findFeeVaultPda,
+  findComplianceRootPda,
+  findMetadataPda,
+  TOKEN_METADATA_PROGRAM_ID,
+  type TestContext,
+} from "../../setup";
+
+type Leaf = { user: PublicKey; tier: number; expiry: BN };
+
+function leafHash({ user, tier, expiry }: Leaf): Buffer {
+  return createHash("sha256")
+    .update(Buffer.from([0]))
+    .update(user.toBuffer())
+    .update(Buffer.from([tier]))
+    .update(expiry.toArrayLike(Buffer, "le", 8))
+    .digest();
+}
+
+function nodeHash(a: Buffer, b: Buffer): Buffer {
+  const [lo, hi] = Buffer.compare(a, b) <= 0 ? [a, b] : [b, a];
+  return createHash("sha256").update(Buffer.from([1])).update(lo).update(hi).digest();
+}
+
+/** Root over three leaves: ((l0, l1), l2). */
+function buildTree(leaves: Leaf[]): { root: Buffer; proofs: Buffer[][] } {
+  const [l0, l1, l2] = leaves.map(leafHash);
+  const n01 = nodeHash(l0, l1);
+  return { root: nodeHash(n01, l2), proofs: [[l1, l2], [l0, l2], [n01]] };
+}
+
+describe("set_compliance_root", () => {
+  let ctx: TestContext;
+  const whitelistAuth = Keypair.generate();
+  const basketId = new BN(1);
+
+  let basketPda: PublicKey;
+  let vaultAuthority: PublicKey;
+  let mintAuthority: PublicKey;
+  let shareMintKp: Keypair;
+  let usdcMint: PublicKey;
+  let complianceRootPda: PublicKey;
+
+  function encodeName(name: string): number[] {
+    const buf = Buffer.alloc(32, 0);
+    buf.write(name, "utf-8");
+    return Array.from(buf);
+  }
+
+  function publish(root: Buffer, version: number) {
+    return ctx.program.methods
+      .setComplianceRoot(Array.from(root), new BN(version))
+      .accounts({
+        authority: whitelistAuth.publicKey,
+        basket: basketPda,
+        program: ctx.program.programId,
+      })
+      .signers([whitelistAuth])
+      .rpc();
+  }
+
+  async function fundedUser(): Promise<{ kp: Keypair; usdcAta: PublicKey }> {
+    const kp = Keypair.generate();
+    fundAccount(ctx.svm, kp.publicKey);
+    const usdcAta = await createTestAta(ctx.provider, usdcMint, kp.publicKey);
+    await mintTestTokens(ctx.provider, usdcMint, usdcAta, ctx.admin, BigInt(1_000_000_000));
+    return { kp, usdcAta };
+  }
+
+  async function deposit(
+    user: { kp: Keypair; usdcAta: PublicKey },
+    proof: { tier: number; expiry: BN; proof: number[][] } | null,
+  ) {
+    return ctx.program.methods
+      .depositMulti([new BN(1_000_000_000)], proof)
+      .accounts({
+        user: user.kp.publicKey,
+        receiver: user.kp.publicKey,
+        basket: basketPda,
+        mintAuthority,
+        shareMint: shareMintKp.publicKey,
+        userAllowList: null,
+        complianceRoot: complianceRootPda,
+        instructionsSysvar: null,
+        attestationNonce: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .remainingAccounts([
+        { pubkey: findBasketTokenPda(basketPda, usdcMint)[0], isWritable: true, isSigner: false },
+        { pubkey: usdcMint, isWritable: false, isSigner: false },
+        { pubkey: user.usdcAta, isWritable: true, isSigner: false },
+        {
+          pubkey: await getAssociatedTokenAddress(usdcMint, vaultAuthority, true),
+          isWritable: true,
+          isSigner: false,
+        },
+        { pubkey: findFeeVaultPda(basketPda, usdcMint)[0], isWritable: true, isSigner: false },
+      ])
+      .signers([user.kp])
+      .rpc();
+  }
+
+  beforeEach(async () => {
+    ctx = createTestContext();
+    fundAccount(ctx.svm, whitelistAuth.publicKey);
+
+    await ctx.program.methods
+      .initConfig(20, whitelistAuth.publicKey, true)
+      .accounts({ program: ctx.program.programId })
+      .rpc();
+
+    shareMintKp = Keypair.generate();
+    [basketPda] = findBasketPda(basketId);
+    [vaultAuthority] = findVaultAuthorityPda(basketId);
+    [mintAuthority] = findMintAuthorityPda(basketId);
+    [complianceRootPda] = findComplianceRootPda(basketPda);
+
+    await ctx.program.methods
+      .createBasket(basketId, encodeName("Merkle Test"), null, false, "QSHARE", "")
+      .accounts({
+        shareMint: shareMintKp.publicKey,
+        shareMetadata: findMetadataPda(shareMintKp.publicKey)[0],
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        treasury: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .signers([shareMintKp])
+      .rpc();
+
+    usdcMint = await createTestMint(ctx.provider, ctx.admin.publicKey, 6);
+    await ctx.program.methods
+      .addTokens(0)
+      .accounts({
+        basket: basketPda,
+        underlyingMint: usdcMint,
+        vaultAuthority,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .rpc();
+  });
+
+  it("publishes a root and requires the version to increase", async () => {
+    const root = Buffer.alloc(32, 7);
+    await publish(root, 1);
+
+    const account = await ctx.program.account.complianceRoot.fetch(complianceRootPda);
+    expect(Buffer.from(account.root).equals(root)).to.equal(true);
+    expect(account.version.toNumber()).to.equal(1);
+    expect(account.basket.toBase58()).to.equal(basketPda.toBase58());
+
+    await expectError(publish(Buffer.alloc(32, 8), 1), "StaleComplianceRoot");
+    await publish(Buffer.alloc(32, 8), 5);
+    const rotated = await ctx.program.account.complianceRoot.fetch(complianceRootPda);
+    expect(rotated.version.toNumber()).to.equal(5);
+  });
+
+  it("rejects a caller other than the whitelist authority", async () => {
+    await expectError(
+      ctx.program.methods
+        .setComplianceRoot(Array.from(Buffer.alloc(32, 1)), new BN(1))
+        .accounts({
+          authority: ctx.admin.publicKey,
+          basket: basketPda,
+          program: ctx.program.programId,
+        })
+        .rpc(),
+      "Unauthorized",
+    );
+  });
+
+  describe("deposits with a proof", () => {
+    const expiry = new BN(2_000_000_000);
+    let users: { kp: Keypair; usdcAta: PublicKey }[];
+    let proofs: Buffer[][];
+
+    beforeEach(async () => {
+      users = [await fundedUser(), await fundedUser(), await fundedUser()];
+      const tree = buildTree(users.map((u) => ({ user: u.kp.publicKey, tier: 1, expiry })));
+      proofs = tree.proofs;
+      await publish(tree.root, 1);
+    });
+
+    function proofFor(i: number, overrides: Partial<{ tier: number; expiry: BN }> = {}) {
+      return {
+        tier: 1,
+        expiry,
+        proof: proofs[i].map((node) => Array.from(node)),
+        ...overrides,
+      };
+    }
+
+    it("mints shares to a receiver in the tree", async () => {
+      await deposit(users[2], proofFor(2));
+
+      const shareAta = await getAssociatedTokenAddress(shareMintKp.publicKey, users[2].kp.publicKey);
+      expect(Number(await getTokenBalance(ctx.provider, shareAta))).to.be.greaterThan(0);
+    });
+
+    it("rejects another user's proof", async () => {
+      await expectError(deposit(users[0], proofFor(1)), "ComplianceDenied");
+    });
+
+    it("rejects a leaf with a different tier", async () => {
+      await expectError(deposit(users[0], proofFor(0, { tier: 2 })), "ComplianceDenied");
+    });
+
+    it("rejects a deposit with no proof", async () => {
+      await expectError(deposit(users[0], null), "ComplianceDenied");
+    });
+
+    it("rejects proofs for the previous root after rotation", async () => {
+      await publish(Buffer.alloc(32, 9), 2);
+      await expectError(deposit(users[0], proofFor(0)), "ComplianceDenied");
+    });
+
+    it("rejects an expired leaf", async () => {
+      const clock = ctx.svm.getClock();
+      clock.unixTimestamp = BigInt(expiry.toString());
+      ctx.svm.setClock(clock);
+
+      await expectError(deposit(users[0], proofFor(0)), "ComplianceProofExpired");
+    });
+  });
+});
@@ -21,7 +21,7 @@ describe("set_config", () => {
 
   it("updates fee_bps", async () => {
     await ctx.program.methods
-      .setConfig(40, null, null, null)
+      .setConfig(40, null, null, null, null)
       .accounts({ program: ctx.program.programId })
       .rpc();
 
@@ -32,7 +32,7 @@ describe("set_config", () => {
 
   it("updates compliance_enabled flag", async () => {
     await ctx.program.methods
-      .setConfig(null, null, true, null)
+      .setConfig(null, null, true, null, null)
       .accounts({ program: ctx.program.programId })
       .rpc();
 
@@ -44,7 +44,7 @@ describe("set_config", () => {
   it("transfers admin to new keypair", async () => {
     const newAdmin = Keypair.generate();
     await ctx.program.methods
-      .setConfig(null, null, null, newAdmin.publicKey)
+      .setConfig(null, null, null, newAdmin.publicKey, null)
       .accounts({ program: ctx.program.programId })
       .rpc();
 
@@ -60,7 +60,7 @@ describe("set_config", () => {
     try {
       // Build with impostor as signer — config.admin won't match
       await ctx.program.methods
-        .setConfig(30, null, null, null)
+        .setConfig(30, null, null, null, null)
         .accounts({ program: ctx.program.programId })
         .signers([impostor])
         .rpc();
@@ -73,7 +73,7 @@ describe("set_config", () => {
   it("rejects out-of-range fee_bps", async () => {
     try {
       await ctx.program.methods
-        .setConfig(200, null, null, null)
+        .setConfig(200, null, null, null, null)
         .accounts({ program: ctx.program.programId })
         .rpc();
       expect.fail("should have thrown");
@@ -81,4 +81,39 @@ describe("set_config", () => {
       expect(err.toString()).to.include("InvalidFee");
     }
   });
+
+  it("updates mint_extension_policy", async () => {
+    await ctx.program.methods
+      .setConfig(null, null, null, null, 0b10000)
+      .accounts({ program: ctx.program.programId })
+      .rpc();
+
+    const [configPda] = findConfigPda();
+    const config = await ctx.program.account.config.fetch(configPda);
+    expect(config.mintExtensionPolicy).to.equal(0b10000);
+  });
+
+  it("never allows transfer-hook legs", async () => {
+    try {
+      await ctx.program.methods
+        .setConfig(null, null, null, null, 0b10)
+        .accounts({ program: ctx.program.programId })
+        .rpc();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("InvalidExtensionPolicy");
+    }
+  });
+
+  it("rejects unknown mint_extension_policy flags", async () => {
+    try {
+      await ctx.program.methods
+        .setConfig(null, null, null, null, 0b1000000)
+        .accounts({ program: ctx.program.programId })
+        .rpc();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("InvalidExtensionPolicy");
+    }
+  });
 });
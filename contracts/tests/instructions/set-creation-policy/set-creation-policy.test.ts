@@ -0,0 +1,167 @@
+import { expect } from "chai";
+import { Keypair, LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import {
+  createTestContext,
+  expectError,
+  fundAccount,
+  findBasketPda,
+  findConfigPda,
+  findMetadataPda,
+  TOKEN_METADATA_PROGRAM_ID,
+  type TestContext,
+} from "../../setup";
+
+const FEE = LAMPORTS_PER_SOL / 2;
+const BOND = LAMPORTS_PER_SOL;
+
+describe("set_creation_policy", () => {
+  let ctx: TestContext;
+  const whitelistAuth = Keypair.generate();
+
+  let treasury: Keypair;
+  let creator: Keypair;
+
+  function encodeName(name: string): number[] {
+    const buf = Buffer.alloc(32, 0);
+    buf.write(name, "utf-8");
+    return Array.from(buf);
+  }
+
+  function balance(key: PublicKey): number {
+    return Number(ctx.svm.getBalance(key) ?? BigInt(0));
+  }
+
+  async function setPolicy(permissionless: boolean, signer = ctx.admin) {
+    await ctx.program.methods
+      .setCreationPolicy(permissionless, new BN(FEE), new BN(BOND), treasury.publicKey)
+      .accounts({
+        admin: signer.publicKey,
+        program: ctx.program.programId,
+      })
+      .signers(signer === ctx.admin ? [] : [signer])
+      .rpc();
+  }
+
+  async function createBasket(
+    basketId: number,
+    signer: Keypair,
+    treasuryKey: PublicKey | null = treasury.publicKey,
+  ): Promise<PublicKey> {
+    const shareMintKp = Keypair.generate();
+    await ctx.program.methods
+      .createBasket(new BN(basketId), encodeName("Curated"), null, false, "QSHARE", "")
+      .accounts({
+        creator: signer.publicKey,
+        shareMint: shareMintKp.publicKey,
+        shareMetadata: findMetadataPda(shareMintKp.publicKey)[0],
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        treasury: treasuryKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .signers(signer === ctx.admin ? [shareMintKp] : [signer, shareMintKp])
+      .rpc();
+    return findBasketPda(new BN(basketId))[0];
+  }
+
+  async function slash(basket: PublicKey, signer = ctx.admin) {
+    await ctx.program.methods
+      .slashBasketBond()
+      .accounts({
+        admin: signer.publicKey,
+        basket,
+        treasury: treasury.publicKey,
+        program: ctx.program.programId,
+      })
+      .signers(signer === ctx.admin ? [] : [signer])
+      .rpc();
+  }
+
+  beforeEach(async () => {
+    ctx = createTestContext();
+
+    await ctx.program.methods
+      .initConfig(20, whitelistAuth.publicKey, false)
+      .accounts({ program: ctx.program.programId })
+      .rpc();
+
+    treasury = Keypair.generate();
+    fundAccount(ctx.svm, treasury.publicKey);
+    creator = Keypair.generate();
+    fundAccount(ctx.svm, creator.publicKey);
+  });
+
+  it("stores the policy", async () => {
+    await setPolicy(true);
+
+    const [configPda] = findConfigPda();
+    const config = await ctx.program.account.config.fetch(configPda);
+    expect(config.permissionlessCreation).to.equal(true);
+    expect(config.creationFeeLamports.toNumber()).to.equal(FEE);
+    expect(config.creationBondLamports.toNumber()).to.equal(BOND);
+    expect(config.treasury.toBase58()).to.equal(treasury.publicKey.toBase58());
+  });
+
+  it("rejects a policy change by a non-admin", async () => {
+    await expectError(setPolicy(true, creator), "Unauthorized");
+  });
+
+  it("keeps creation admin-only until opened", async () => {
+    await setPolicy(false);
+    await expectError(createBasket(1, creator), "Unauthorized");
+  });
+
+  it("charges a permissionless creator the fee and bond", async () => {
+    await setPolicy(true);
+    const treasuryBefore = balance(treasury.publicKey);
+
+    const basketPda = await createBasket(1, creator);
+
+    expect(balance(treasury.publicKey) - treasuryBefore).to.equal(FEE);
+    const basket = await ctx.program.account.basket.fetch(basketPda);
+    expect(basket.owner.toBase58()).to.equal(creator.publicKey.toBase58());
+    expect(basket.bondLamports.toNumber()).to.equal(BOND);
+  });
+
+  it("requires the treasury when a fee is owed", async () => {
+    await setPolicy(true);
+    await expectError(createBasket(1, creator, null), "InvalidTreasury");
+    await expectError(createBasket(1, creator, creator.publicKey), "InvalidTreasury");
+  });
+
+  it("exempts the admin from fee and bond", async () => {
+    await setPolicy(true);
+    const treasuryBefore = balance(treasury.publicKey);
+
+    const basketPda = await createBasket(1, ctx.admin, null);
+
+    expect(balance(treasury.publicKey)).to.equal(treasuryBefore);
+    const basket = await ctx.program.account.basket.fetch(basketPda);
+    expect(basket.bondLamports.toNumber()).to.equal(0);
+  });
+
+  it("slashes the bond to the treasury", async () => {
+    await setPolicy(true);
+    const basketPda = await createBasket(1, creator);
+    const basketBefore = balance(basketPda);
+    const treasuryBefore = balance(treasury.publicKey);
+
+    await slash(basketPda);
+
+    expect(basketBefore - balance(basketPda)).to.equal(BOND);
+    expect(balance(treasury.publicKey) - treasuryBefore).to.equal(BOND);
+    const basket = await ctx.program.account.basket.fetch(basketPda);
+    expect(basket.bondLamports.toNumber()).to.equal(0);
+
+    await expectError(slash(basketPda), "NoBondPosted");
+  });
+
+  it("rejects a slash by a non-admin", async () => {
+    await setPolicy(true);
+    const basketPda = await createBasket(1, creator);
+
+    await expectError(slash(basketPda, creator), "Unauthorized");
+  });
+});
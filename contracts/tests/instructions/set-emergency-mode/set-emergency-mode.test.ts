@@ -0,0 +1,293 @@
+import { expect } from "chai";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+import { TOKEN_PROGRAM_ID, getAssociatedTokenAddress } from "@solana/spl-token";
+import {
+  createTestContext,
+  fundAccount,
+  createTestMint,
+  createTestAta,
+  mintTestTokens,
+  getTokenBalance,
+  parseEvents,
+  findBasketPda,
+  findVaultAuthorityPda,
+  findMintAuthorityPda,
+  findBasketTokenPda,
+  findFeeVaultPda,
+  findMetadataPda,
+  TOKEN_METADATA_PROGRAM_ID,
+  type TestContext,
+} from "../../setup";
+
+const HOUR = 3_600;
+
+describe("set_emergency_mode", () => {
+  let ctx: TestContext;
+  const whitelistAuth = Keypair.generate();
+  const basketId = new BN(1);
+
+  let basketPda: PublicKey;
+  let vaultAuthority: PublicKey;
+  let mintAuthority: PublicKey;
+  let shareMintKp: Keypair;
+  let usdcMint: PublicKey;
+  let basketTokenPda: PublicKey;
+  let feeVaultPda: PublicKey;
+  let vaultAta: PublicKey;
+  let user: Keypair;
+  let userUsdcAta: PublicKey;
+  let userShareAta: PublicKey;
+
+  function encodeName(name: string): number[] {
+    const buf = Buffer.alloc(32, 0);
+    buf.write(name, "utf-8");
+    return Array.from(buf);
+  }
+
+  function warp(seconds: number) {
+    const clock = ctx.svm.getClock();
+    clock.unixTimestamp += BigInt(seconds);
+    ctx.svm.setClock(clock);
+  }
+
+  // Limit outflows to `bps` of supply per hour.
+  async function setLimiter(bps: number) {
+    await ctx.program.methods
+      .updateBasketLimits(new BN(0), new BN(0), bps, new BN(HOUR))
+      .accounts({
+        authority: ctx.admin.publicKey,
+        basket: basketPda,
+        program: ctx.program.programId,
+      })
+      .rpc();
+  }
+
+  async function setEmergency(enabled: boolean, signer = ctx.admin) {
+    await ctx.program.methods
+      .setEmergencyMode(enabled)
+      .accounts({
+        admin: signer.publicKey,
+        basket: basketPda,
+        program: ctx.program.programId,
+      })
+      .signers(signer === ctx.admin ? [] : [signer])
+      .rpc();
+  }
+
+  async function withdraw(shares: number) {
+    await ctx.program.methods
+      .withdrawMulti(new BN(shares))
+      .accounts({
+        user: user.publicKey,
+        receiver: user.publicKey,
+        basket: basketPda,
+        shareMint: shareMintKp.publicKey,
+        vaultAuthority,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .remainingAccounts([
+        { pubkey: basketTokenPda, isWritable: true, isSigner: false },
+        { pubkey: usdcMint, isWritable: false, isSigner: false },
+        { pubkey: vaultAta, isWritable: true, isSigner: false },
+        { pubkey: userUsdcAta, isWritable: true, isSigner: false },
+      ])
+      .signers([user])
+      .rpc();
+  }
+
+  async function expectLimited(shares: number) {
+    try {
+      await withdraw(shares);
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("WithdrawLimitExceeded");
+    }
+  }
+
+  beforeEach(async () => {
+    ctx = createTestContext();
+
+    await ctx.program.methods
+      .initConfig(20, whitelistAuth.publicKey, false)
+      .accounts({ program: ctx.program.programId })
+      .rpc();
+
+    shareMintKp = Keypair.generate();
+    [basketPda] = findBasketPda(basketId);
+    [vaultAuthority] = findVaultAuthorityPda(basketId);
+    [mintAuthority] = findMintAuthorityPda(basketId);
+
+    await ctx.program.methods
+      .createBasket(basketId, encodeName("Limiter Test"), null, false, "QSHARE", "")
+      .accounts({
+        shareMint: shareMintKp.publicKey,
+        shareMetadata: findMetadataPda(shareMintKp.publicKey)[0],
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        treasury: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .signers([shareMintKp])
+      .rpc();
+
+    usdcMint = await createTestMint(ctx.provider, ctx.admin.publicKey, 6);
+    [basketTokenPda] = findBasketTokenPda(basketPda, usdcMint);
+    [feeVaultPda] = findFeeVaultPda(basketPda, usdcMint);
+    vaultAta = await getAssociatedTokenAddress(usdcMint, vaultAuthority, true);
+
+    await ctx.program.methods
+      .addTokens(0)
+      .accounts({
+        basket: basketPda,
+        underlyingMint: usdcMint,
+        vaultAuthority,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .rpc();
+
+    // 1,000 USDC nets 998 QSHARE after the 0.2% fee.
+    user = Keypair.generate();
+    fundAccount(ctx.svm, user.publicKey);
+    userUsdcAta = await createTestAta(ctx.provider, usdcMint, user.publicKey);
+    await mintTestTokens(ctx.provider, usdcMint, userUsdcAta, ctx.admin, BigInt(1_000_000_000));
+    userShareAta = await getAssociatedTokenAddress(shareMintKp.publicKey, user.publicKey);
+
+    await ctx.program.methods
+      .depositMulti([new BN(1_000_000_000)], null)
+      .accounts({
+        user: user.publicKey,
+        receiver: user.publicKey,
+        basket: basketPda,
+        mintAuthority,
+        shareMint: shareMintKp.publicKey,
+        userAllowList: null,
+        complianceRoot: null,
+        instructionsSysvar: null,
+        attestationNonce: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .remainingAccounts([
+        { pubkey: basketTokenPda, isWritable: true, isSigner: false },
+        { pubkey: usdcMint, isWritable: false, isSigner: false },
+        { pubkey: userUsdcAta, isWritable: true, isSigner: false },
+        { pubkey: vaultAta, isWritable: true, isSigner: false },
+        { pubkey: feeVaultPda, isWritable: true, isSigner: false },
+      ])
+      .signers([user])
+      .rpc();
+  });
+
+  it("stores the limiter parameters", async () => {
+    await setLimiter(1_000);
+
+    const basket = await ctx.program.account.basket.fetch(basketPda);
+    expect(basket.maxWithdrawBps).to.equal(1_000);
+    expect(basket.withdrawWindowSecs.toNumber()).to.equal(HOUR);
+    expect(basket.emergencyMode).to.equal(0);
+  });
+
+  it("rejects a limit above 100% or without a window", async () => {
+    for (const [bps, window] of [
+      [10_001, HOUR],
+      [1_000, 0],
+    ]) {
+      try {
+        await ctx.program.methods
+          .updateBasketLimits(new BN(0), new BN(0), bps, new BN(window))
+          .accounts({
+            authority: ctx.admin.publicKey,
+            basket: basketPda,
+            program: ctx.program.programId,
+          })
+          .rpc();
+        expect.fail("should have thrown");
+      } catch (err: any) {
+        expect(err.toString()).to.include("InvalidWithdrawLimit");
+      }
+    }
+  });
+
+  it("caps burns per window at a share of the opening supply", async () => {
+    // 10% of 998 QSHARE.
+    await setLimiter(1_000);
+
+    await withdraw(60_000_000);
+    await withdraw(39_800_000);
+    await expectLimited(1);
+
+    const basket = await ctx.program.account.basket.fetch(basketPda);
+    expect(basket.windowStartSupply.toNumber()).to.equal(998_000_000);
+    expect(basket.burnedInWindow.toNumber()).to.equal(99_800_000);
+  });
+
+  it("logs WithdrawLimiterTripped from the rejected withdrawal", async () => {
+    await setLimiter(1_000);
+    await withdraw(60_000_000);
+
+    try {
+      await withdraw(40_000_000);
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("WithdrawLimitExceeded");
+      const [event] = parseEvents(ctx.program, err.logs);
+      expect(event.name).to.equal("withdrawLimiterTripped");
+      expect(event.data.basket.toBase58()).to.equal(basketPda.toBase58());
+      expect(event.data.sharesRequested.toNumber()).to.equal(40_000_000);
+      expect(event.data.headroom.toNumber()).to.equal(39_800_000);
+    }
+  });
+
+  it("opens a new window once the last has elapsed", async () => {
+    await setLimiter(1_000);
+    await withdraw(99_800_000);
+    await expectLimited(1);
+
+    warp(HOUR);
+    // 10% of the remaining 898.2 QSHARE.
+    await expectLimited(89_820_001);
+    await withdraw(89_820_000);
+
+    const basket = await ctx.program.account.basket.fetch(basketPda);
+    expect(basket.windowStartSupply.toNumber()).to.equal(898_200_000);
+  });
+
+  it("lets withdrawals bypass the limiter in emergency mode", async () => {
+    await setLimiter(1_000);
+    await setEmergency(true);
+
+    const shares = await getTokenBalance(ctx.provider, userShareAta);
+    await withdraw(Number(shares));
+    expect(Number(await getTokenBalance(ctx.provider, userShareAta))).to.equal(0);
+  });
+
+  it("re-arms the limiter when emergency mode ends", async () => {
+    await setLimiter(1_000);
+    await setEmergency(true);
+    await withdraw(500_000_000);
+    await setEmergency(false);
+
+    const basket = await ctx.program.account.basket.fetch(basketPda);
+    expect(basket.emergencyMode).to.equal(0);
+    // A fresh window against the remaining 498 QSHARE.
+    await expectLimited(49_800_001);
+    await withdraw(49_800_000);
+  });
+
+  it("rejects a non-admin", async () => {
+    const stranger = Keypair.generate();
+    fundAccount(ctx.svm, stranger.publicKey);
+
+    try {
+      await setEmergency(true, stranger);
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("Unauthorized");
+    }
+  });
+});
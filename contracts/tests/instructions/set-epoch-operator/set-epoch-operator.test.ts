@@ -0,0 +1,75 @@
+import { expect } from "chai";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import {
+  createTestContext,
+  fundAccount,
+  createEpochFixture,
+  settle,
+  type TestContext,
+  type EpochFixture,
+} from "../../setup";
+
+describe("set_epoch_operator", () => {
+  let ctx: TestContext;
+  let fx: EpochFixture;
+  const operator = Keypair.generate();
+
+  let basketPda: PublicKey;
+  let epochQueue: PublicKey;
+
+  beforeEach(async () => {
+    ctx = createTestContext();
+    fx = await createEpochFixture(ctx, operator);
+    ({ basket: basketPda, epochQueue } = fx);
+  });
+
+  it("opens the queue with the operator", async () => {
+    const queue = await ctx.program.account.epochQueue.fetch(epochQueue);
+    expect(queue.basket.toBase58()).to.equal(basketPda.toBase58());
+    expect(queue.operator.toBase58()).to.equal(operator.publicKey.toBase58());
+    expect(queue.currentEpoch.toNumber()).to.equal(0);
+  });
+
+  it("hands settlement to a new operator without resetting the epoch", async () => {
+    await settle(fx);
+    const next = Keypair.generate();
+    fundAccount(ctx.svm, next.publicKey);
+
+    await ctx.program.methods
+      .setEpochOperator(next.publicKey)
+      .accounts({ basket: basketPda, program: ctx.program.programId })
+      .rpc();
+
+    const queue = await ctx.program.account.epochQueue.fetch(epochQueue);
+    expect(queue.operator.toBase58()).to.equal(next.publicKey.toBase58());
+    expect(queue.currentEpoch.toNumber()).to.equal(1);
+
+    try {
+      await settle(fx);
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("Unauthorized");
+    }
+    await settle(fx, next);
+  });
+
+  it("rejects a non-owner", async () => {
+    const stranger = Keypair.generate();
+    fundAccount(ctx.svm, stranger.publicKey);
+
+    try {
+      await ctx.program.methods
+        .setEpochOperator(stranger.publicKey)
+        .accounts({
+          authority: stranger.publicKey,
+          basket: basketPda,
+          program: ctx.program.programId,
+        })
+        .signers([stranger])
+        .rpc();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("Unauthorized");
+    }
+  });
+});
@@ -0,0 +1,220 @@
+import { expect } from "chai";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+import { TOKEN_PROGRAM_ID, getAssociatedTokenAddress } from "@solana/spl-token";
+import {
+  createTestContext,
+  fundAccount,
+  createTestMint,
+  createTestAta,
+  mintTestTokens,
+  getTokenBalance,
+  findBasketPda,
+  findVaultAuthorityPda,
+  findMintAuthorityPda,
+  findBasketTokenPda,
+  findFeeVaultPda,
+  findLockPositionPda,
+  findLockTiersPda,
+  findMetadataPda,
+  TOKEN_METADATA_PROGRAM_ID,
+  type TestContext,
+} from "../../setup";
+
+const DAY = 86_400;
+
+describe("set_lock_tiers", () => {
+  let ctx: TestContext;
+  const whitelistAuth = Keypair.generate();
+  const basketId = new BN(1);
+
+  let basketPda: PublicKey;
+  let vaultAuthority: PublicKey;
+  let mintAuthority: PublicKey;
+  let shareMintKp: Keypair;
+  let usdcMint: PublicKey;
+  let user: Keypair;
+  let userShareAta: PublicKey;
+  let lockVault: PublicKey;
+  let lockPosition: PublicKey;
+
+  function encodeName(name: string): number[] {
+    const buf = Buffer.alloc(32, 0);
+    buf.write(name, "utf-8");
+    return Array.from(buf);
+  }
+
+  function now(): number {
+    return Number(ctx.svm.getClock().unixTimestamp);
+  }
+
+  function warp(seconds: number) {
+    const clock = ctx.svm.getClock();
+    clock.unixTimestamp += BigInt(seconds);
+    ctx.svm.setClock(clock);
+  }
+
+  async function setTiers(tiers: { minDurationSecs: BN; earlyExitPenaltyBps: number }[]) {
+    await ctx.program.methods
+      .setLockTiers(tiers)
+      .accounts({ basket: basketPda, program: ctx.program.programId })
+      .rpc();
+  }
+
+  async function lock(amount: number, unlockTs: number) {
+    await ctx.program.methods
+      .lockShares(new BN(amount), new BN(unlockTs))
+      .accounts({
+        owner: user.publicKey,
+        basket: basketPda,
+        shareMint: shareMintKp.publicKey,
+        vaultAuthority,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .signers([user])
+      .rpc();
+  }
+
+  beforeEach(async () => {
+    ctx = createTestContext();
+
+    await ctx.program.methods
+      .initConfig(20, whitelistAuth.publicKey, false)
+      .accounts({ program: ctx.program.programId })
+      .rpc();
+
+    shareMintKp = Keypair.generate();
+    [basketPda] = findBasketPda(basketId);
+    [vaultAuthority] = findVaultAuthorityPda(basketId);
+    [mintAuthority] = findMintAuthorityPda(basketId);
+
+    await ctx.program.methods
+      .createBasket(basketId, encodeName("Lock Test"), null, false, "QSHARE", "")
+      .accounts({
+        shareMint: shareMintKp.publicKey,
+        shareMetadata: findMetadataPda(shareMintKp.publicKey)[0],
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        treasury: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .signers([shareMintKp])
+      .rpc();
+
+    usdcMint = await createTestMint(ctx.provider, ctx.admin.publicKey, 6);
+    const [basketTokenPda] = findBasketTokenPda(basketPda, usdcMint);
+    const [feeVaultPda] = findFeeVaultPda(basketPda, usdcMint);
+    const vaultAta = await getAssociatedTokenAddress(usdcMint, vaultAuthority, true);
+
+    await ctx.program.methods
+      .addTokens(0)
+      .accounts({
+        basket: basketPda,
+        underlyingMint: usdcMint,
+        vaultAuthority,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .rpc();
+
+    user = Keypair.generate();
+    fundAccount(ctx.svm, user.publicKey);
+    const userUsdcAta = await createTestAta(ctx.provider, usdcMint, user.publicKey);
+    await mintTestTokens(
+      ctx.provider,
+      usdcMint,
+      userUsdcAta,
+      ctx.admin,
+      BigInt(1_000_000_000),
+    );
+    userShareAta = await getAssociatedTokenAddress(
+      shareMintKp.publicKey,
+      user.publicKey,
+    );
+
+    await ctx.program.methods
+      .depositMulti([new BN(1_000_000_000)], null)
+      .accounts({
+        user: user.publicKey,
+        receiver: user.publicKey,
+        basket: basketPda,
+        mintAuthority,
+        shareMint: shareMintKp.publicKey,
+        userAllowList: null,
+        complianceRoot: null,
+        instructionsSysvar: null,
+        attestationNonce: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .remainingAccounts([
+        { pubkey: basketTokenPda, isWritable: true, isSigner: false },
+        { pubkey: usdcMint, isWritable: false, isSigner: false },
+        { pubkey: userUsdcAta, isWritable: true, isSigner: false },
+        { pubkey: vaultAta, isWritable: true, isSigner: false },
+        { pubkey: feeVaultPda, isWritable: true, isSigner: false },
+      ])
+      .signers([user])
+      .rpc();
+
+    lockVault = await getAssociatedTokenAddress(
+      shareMintKp.publicKey,
+      vaultAuthority,
+      true,
+    );
+    [lockPosition] = findLockPositionPda(basketPda, user.publicKey);
+
+    await setTiers([
+      { minDurationSecs: new BN(30 * DAY), earlyExitPenaltyBps: 500 },
+      { minDurationSecs: new BN(180 * DAY), earlyExitPenaltyBps: 1_500 },
+    ]);
+  });
+
+  it("stores ordered tiers", async () => {
+    const [lockTiersPda] = findLockTiersPda(basketPda);
+    const tiers = await ctx.program.account.lockTiers.fetch(lockTiersPda);
+    expect(tiers.tierCount).to.equal(2);
+    expect(tiers.tiers[1].minDurationSecs.toNumber()).to.equal(180 * DAY);
+    expect(tiers.tiers[1].earlyExitPenaltyBps).to.equal(1_500);
+  });
+
+  it("rejects unordered tiers", async () => {
+    try {
+      await setTiers([
+        { minDurationSecs: new BN(180 * DAY), earlyExitPenaltyBps: 500 },
+        { minDurationSecs: new BN(30 * DAY), earlyExitPenaltyBps: 500 },
+      ]);
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("InvalidLockTiers");
+    }
+  });
+
+  it("rejects a penalty above the cap", async () => {
+    try {
+      await setTiers([{ minDurationSecs: new BN(30 * DAY), earlyExitPenaltyBps: 5_001 }]);
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("InvalidLockTiers");
+    }
+  });
+
+  it("rejects a non-owner", async () => {
+    try {
+      await ctx.program.methods
+        .setLockTiers([])
+        .accounts({
+          authority: user.publicKey,
+          basket: basketPda,
+          program: ctx.program.programId,
+        })
+        .signers([user])
+        .rpc();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("Unauthorized");
+    }
+  });
+});
@@ -0,0 +1,158 @@
+import { expect } from "chai";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+import { getAssociatedTokenAddress } from "@solana/spl-token";
+import {
+  createTestContext,
+  mintTestTokens,
+  getTokenBalance,
+  parseEvents,
+  findBasketPricePda,
+  findEpochPda,
+  createEpochFixture,
+  newEpochUser,
+  depositNow,
+  requestDeposit,
+  requestRedeem,
+  currentEpoch,
+  settle,
+  claimRedeem,
+  type TestContext,
+  type EpochFixture,
+  type EpochUser,
+} from "../../setup";
+
+describe("settle_epoch", () => {
+  let ctx: TestContext;
+  let fx: EpochFixture;
+  const operator = Keypair.generate();
+
+  let basketPda: PublicKey;
+  let usdcMint: PublicKey;
+  let vaultAta: PublicKey;
+  let epochQueue: PublicKey;
+  let pendingAta: PublicKey;
+  let shareEscrow: PublicKey;
+
+  beforeEach(async () => {
+    ctx = createTestContext();
+    fx = await createEpochFixture(ctx, operator);
+    ({ basket: basketPda, usdcMint, vaultAta, epochQueue, pendingAta, shareEscrow } = fx);
+  });
+
+  it("mints the first epoch's deposits at 1.0", async () => {
+    const user = await newEpochUser(fx);
+    await requestDeposit(fx, user, 1_000_000_000);
+    const epochId = await settle(fx);
+
+    expect(Number(await getTokenBalance(ctx.provider, vaultAta))).to.equal(998_000_000);
+    expect(Number(await getTokenBalance(ctx.provider, pendingAta))).to.equal(0);
+    expect(Number(await getTokenBalance(ctx.provider, shareEscrow))).to.equal(998_000_000);
+
+    const [epochPda] = findEpochPda(basketPda, epochId);
+    const epoch = await ctx.program.account.epoch.fetch(epochPda);
+    expect(epoch.navPerShare.toNumber()).to.equal(1_000_000_000);
+    expect(epoch.unclaimedShares.toNumber()).to.equal(998_000_000);
+    expect(epoch.unclaimedDepositValue.toNumber()).to.equal(998_000_000);
+
+    const queue = await ctx.program.account.epochQueue.fetch(epochQueue);
+    expect(queue.currentEpoch.toNumber()).to.equal(1);
+    expect(queue.pendingDepositValue.toNumber()).to.equal(0);
+  });
+
+  it("fills deposits and redemptions at one NAV", async () => {
+    const holder = await newEpochUser(fx);
+    await depositNow(fx, holder);
+    // Vault doubles in value: NAV 2.0.
+    await mintTestTokens(ctx.provider, usdcMint, vaultAta, ctx.admin, BigInt(998_000_000));
+
+    await requestRedeem(fx, holder, 499_000_000);
+    const depositor = await newEpochUser(fx);
+    await requestDeposit(fx, depositor, 1_000_000_000);
+    const epochId = await settle(fx);
+
+    const [epochPda] = findEpochPda(basketPda, epochId);
+    const epoch = await ctx.program.account.epoch.fetch(epochPda);
+    expect(epoch.navPerShare.toNumber()).to.equal(2_000_000_000);
+    expect(epoch.unclaimedShares.toNumber()).to.equal(499_000_000);
+    expect(epoch.unclaimedRedeemShares.toNumber()).to.equal(499_000_000);
+
+    const payoutAta = await getAssociatedTokenAddress(usdcMint, epochPda, true);
+    expect(Number(await getTokenBalance(ctx.provider, payoutAta))).to.equal(998_000_000);
+    // 1,996 - 998 paid out + 998 deposited.
+    expect(Number(await getTokenBalance(ctx.provider, vaultAta))).to.equal(1_996_000_000);
+
+    const [basketPrice] = findBasketPricePda(basketPda);
+    const price = await ctx.program.account.basketPrice.fetch(basketPrice);
+    expect(price.navPerShare.toNumber()).to.equal(2_000_000_000);
+    expect(price.totalSupply.toNumber()).to.equal(998_000_000);
+  });
+
+  it("advances an empty epoch", async () => {
+    await settle(fx);
+    await settle(fx);
+    expect(await currentEpoch(fx)).to.equal(2);
+  });
+
+  it("rejects a signer other than the operator", async () => {
+    try {
+      await settle(fx, ctx.admin);
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("Unauthorized");
+    }
+  });
+
+  describe("with the outflow limiter", () => {
+    const HOUR = 3_600;
+    let holder: EpochUser;
+
+    beforeEach(async () => {
+      holder = await newEpochUser(fx);
+      await depositNow(fx, holder);
+      // 10% of supply per hour.
+      await ctx.program.methods
+        .updateBasketLimits(new BN(0), new BN(0), 1_000, new BN(HOUR))
+        .accounts({
+          authority: ctx.admin.publicKey,
+          basket: basketPda,
+          program: ctx.program.programId,
+        })
+        .rpc();
+    });
+
+    it("counts settled redemptions against the window", async () => {
+      await requestRedeem(fx, holder, 60_000_000);
+      const first = await settle(fx);
+      await claimRedeem(fx, holder, first);
+
+      await requestRedeem(fx, holder, 60_000_000);
+      try {
+        await settle(fx);
+        expect.fail("should have thrown");
+      } catch (err: any) {
+        expect(err.toString()).to.include("WithdrawLimitExceeded");
+        const [event] = parseEvents(ctx.program, err.logs);
+        expect(event.name).to.equal("withdrawLimiterTripped");
+        expect(event.data.basket.toBase58()).to.equal(basketPda.toBase58());
+        expect(event.data.sharesRequested.toNumber()).to.equal(60_000_000);
+        expect(event.data.headroom.toNumber()).to.equal(39_800_000);
+      }
+
+      const clock = ctx.svm.getClock();
+      clock.unixTimestamp += BigInt(HOUR);
+      ctx.svm.setClock(clock);
+      ctx.svm.expireBlockhash();
+      await settle(fx);
+    });
+
+    it("rejects a request no single window could settle", async () => {
+      try {
+        await requestRedeem(fx, holder, 100_000_000);
+        expect.fail("should have thrown");
+      } catch (err: any) {
+        expect(err.toString()).to.include("WithdrawLimitExceeded");
+      }
+    });
+  });
+});
@@ -0,0 +1,84 @@
+import { expect } from "chai";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { getAssociatedTokenAddress } from "@solana/spl-token";
+import {
+  createTestContext,
+  getTokenBalance,
+  findStakePositionPda,
+  createRewardFixture,
+  newHolder,
+  stake,
+  type TestContext,
+  type RewardFixture,
+} from "../../setup";
+
+const DAY = 86_400;
+
+describe("stake", () => {
+  let ctx: TestContext;
+  let fx: RewardFixture;
+
+  let shareMint: PublicKey;
+  let rewardPool: PublicKey;
+  let user: Keypair;
+  let userShareAta: PublicKey;
+
+  function warp(seconds: number) {
+    const clock = ctx.svm.getClock();
+    clock.unixTimestamp += BigInt(seconds);
+    ctx.svm.setClock(clock);
+  }
+
+  beforeEach(async () => {
+    ctx = createTestContext();
+    fx = await createRewardFixture(ctx);
+    ({ shareMint, rewardPool } = fx);
+
+    user = await newHolder(fx);
+    userShareAta = await getAssociatedTokenAddress(shareMint, user.publicKey);
+  });
+
+  it("escrows shares at 1x without a commitment", async () => {
+    const shares = await getTokenBalance(ctx.provider, userShareAta);
+    await stake(fx, user, 100_000_000, 0);
+
+    expect(Number(await getTokenBalance(ctx.provider, userShareAta))).to.equal(
+      Number(shares) - 100_000_000,
+    );
+    const [positionPda] = findStakePositionPda(rewardPool, user.publicKey);
+    const position = await ctx.program.account.stakePosition.fetch(positionPda);
+    expect(position.amount.toNumber()).to.equal(100_000_000);
+    expect(position.weight.toNumber()).to.equal(100_000_000);
+    expect(position.multiplierBps).to.equal(10_000);
+  });
+
+  it("boosts weight for a longer commitment", async () => {
+    await stake(fx, user, 100_000_000, 90 * DAY);
+
+    const [positionPda] = findStakePositionPda(rewardPool, user.publicKey);
+    const position = await ctx.program.account.stakePosition.fetch(positionPda);
+    expect(position.weight.toNumber()).to.equal(200_000_000);
+    const pool = await ctx.program.account.rewardPool.fetch(rewardPool);
+    expect(pool.totalStaked.toNumber()).to.equal(100_000_000);
+    expect(pool.totalWeight.toNumber()).to.equal(200_000_000);
+  });
+
+  it("keeps the later unlock time on a top-up", async () => {
+    await stake(fx, user, 100_000_000, 90 * DAY);
+    await stake(fx, user, 50_000_000, 0);
+
+    const [positionPda] = findStakePositionPda(rewardPool, user.publicKey);
+    const position = await ctx.program.account.stakePosition.fetch(positionPda);
+    expect(position.amount.toNumber()).to.equal(150_000_000);
+    expect(position.weight.toNumber()).to.equal(300_000_000);
+  });
+
+  it("rejects zero shares", async () => {
+    try {
+      await stake(fx, user, 0, 0);
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("ZeroStakeAmount");
+    }
+  });
+});
@@ -0,0 +1,250 @@
+import { expect } from "chai";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+import { TOKEN_PROGRAM_ID, getAssociatedTokenAddress } from "@solana/spl-token";
+import {
+  createTestContext,
+  fundAccount,
+  createTestMint,
+  findBasketPda,
+  findVaultAuthorityPda,
+  findMetadataPda,
+  TOKEN_METADATA_PROGRAM_ID,
+  type TestContext,
+} from "../../setup";
+
+describe("transfer_basket_ownership", () => {
+  let ctx: TestContext;
+  const whitelistAuth = Keypair.generate();
+  const basketId = new BN(1);
+
+  let basketPda: PublicKey;
+  let vaultAuthority: PublicKey;
+  let shareMintKp: Keypair;
+  let curator: Keypair;
+
+  function encodeName(name: string): number[] {
+    const buf = Buffer.alloc(32, 0);
+    buf.write(name, "utf-8");
+    return Array.from(buf);
+  }
+
+  async function nominate(newOwner: PublicKey, signer = ctx.admin) {
+    await ctx.program.methods
+      .transferBasketOwnership(newOwner)
+      .accounts({
+        owner: signer.publicKey,
+        basket: basketPda,
+        program: ctx.program.programId,
+      })
+      .signers(signer === ctx.admin ? [] : [signer])
+      .rpc();
+  }
+
+  async function accept(signer: Keypair) {
+    await ctx.program.methods
+      .acceptBasketOwnership()
+      .accounts({
+        newOwner: signer.publicKey,
+        basket: basketPda,
+        program: ctx.program.programId,
+      })
+      .signers([signer])
+      .rpc();
+  }
+
+  async function addToken(signer: Keypair) {
+    const mint = await createTestMint(ctx.provider, ctx.admin.publicKey, 6);
+    await ctx.program.methods
+      .addTokens(0)
+      .accounts({
+        authority: signer.publicKey,
+        basket: basketPda,
+        underlyingMint: mint,
+        vaultAuthority,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .signers(signer === ctx.admin ? [] : [signer])
+      .rpc();
+  }
+
+  async function setLimits(signer: Keypair) {
+    await ctx.program.methods
+      .updateBasketLimits(new BN(1_000_000), new BN(0), 0, new BN(0))
+      .accounts({
+        authority: signer.publicKey,
+        basket: basketPda,
+        program: ctx.program.programId,
+      })
+      .signers(signer === ctx.admin ? [] : [signer])
+      .rpc();
+  }
+
+  async function rename(signer: Keypair, name: string) {
+    await ctx.program.methods
+      .updateBasket(encodeName(name), null)
+      .accounts({
+        authority: signer.publicKey,
+        basket: basketPda,
+        program: ctx.program.programId,
+      })
+      .signers(signer === ctx.admin ? [] : [signer])
+      .rpc();
+  }
+
+  async function closeBasket(signer: Keypair, owner: PublicKey) {
+    await ctx.program.methods
+      .closeBasket()
+      .accounts({
+        authority: signer.publicKey,
+        owner,
+        basket: basketPda,
+        shareMint: shareMintKp.publicKey,
+        vaultAuthority,
+        shareEscrow: await getAssociatedTokenAddress(shareMintKp.publicKey, vaultAuthority, true),
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .signers(signer === ctx.admin ? [] : [signer])
+      .rpc();
+  }
+
+  async function expectUnauthorized(action: Promise<unknown>) {
+    try {
+      await action;
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("Unauthorized");
+    }
+  }
+
+  beforeEach(async () => {
+    ctx = createTestContext();
+
+    await ctx.program.methods
+      .initConfig(20, whitelistAuth.publicKey, false)
+      .accounts({ program: ctx.program.programId })
+      .rpc();
+
+    shareMintKp = Keypair.generate();
+    [basketPda] = findBasketPda(basketId);
+    [vaultAuthority] = findVaultAuthorityPda(basketId);
+
+    await ctx.program.methods
+      .createBasket(basketId, encodeName("Curated"), null, false, "QSHARE", "")
+      .accounts({
+        shareMint: shareMintKp.publicKey,
+        shareMetadata: findMetadataPda(shareMintKp.publicKey)[0],
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        treasury: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .signers([shareMintKp])
+      .rpc();
+
+    curator = Keypair.generate();
+    fundAccount(ctx.svm, curator.publicKey);
+  });
+
+  it("records the nominee without changing the owner", async () => {
+    await nominate(curator.publicKey);
+
+    const basket = await ctx.program.account.basket.fetch(basketPda);
+    expect(basket.owner.toBase58()).to.equal(ctx.admin.publicKey.toBase58());
+    expect(basket.pendingOwner.toBase58()).to.equal(curator.publicKey.toBase58());
+  });
+
+  it("hands over ownership when the nominee accepts", async () => {
+    await nominate(curator.publicKey);
+    await accept(curator);
+
+    const basket = await ctx.program.account.basket.fetch(basketPda);
+    expect(basket.owner.toBase58()).to.equal(curator.publicKey.toBase58());
+    expect(basket.pendingOwner.toBase58()).to.equal(PublicKey.default.toBase58());
+
+    // The previous owner can no longer act as owner.
+    await expectUnauthorized(nominate(ctx.admin.publicKey));
+  });
+
+  it("rejects acceptance by anyone but the nominee", async () => {
+    await nominate(curator.publicKey);
+    const stranger = Keypair.generate();
+    fundAccount(ctx.svm, stranger.publicKey);
+
+    await expectUnauthorized(accept(stranger));
+  });
+
+  it("cancels a nomination with the default key", async () => {
+    await nominate(curator.publicKey);
+    await nominate(PublicKey.default);
+
+    await expectUnauthorized(accept(curator));
+  });
+
+  it("rejects a nomination by a non-owner", async () => {
+    await expectUnauthorized(nominate(curator.publicKey, curator));
+  });
+
+  it("lets the new owner and the protocol admin add tokens", async () => {
+    await nominate(curator.publicKey);
+    await accept(curator);
+
+    await addToken(curator);
+    await addToken(ctx.admin);
+
+    const basket = await ctx.program.account.basket.fetch(basketPda);
+    expect(basket.tokenCount).to.equal(2);
+  });
+
+  it("rejects add_tokens from anyone else", async () => {
+    await expectUnauthorized(addToken(curator));
+  });
+
+  it("lets the new owner and the protocol admin manage the basket", async () => {
+    await nominate(curator.publicKey);
+    await accept(curator);
+
+    await rename(curator, "By Curator");
+    await setLimits(curator);
+    await rename(ctx.admin, "By Admin");
+    await setLimits(ctx.admin);
+
+    const basket = await ctx.program.account.basket.fetch(basketPda);
+    expect(Buffer.from(basket.name).toString("utf-8").replace(/\0+$/, "")).to.equal("By Admin");
+    expect(basket.maxTotalValue.toNumber()).to.equal(1_000_000);
+  });
+
+  it("rejects basket settings from anyone else", async () => {
+    await expectUnauthorized(rename(curator, "Hijacked"));
+    await expectUnauthorized(setLimits(curator));
+  });
+
+  it("keeps the nomination with the owner, not the admin", async () => {
+    await nominate(curator.publicKey);
+    await accept(curator);
+
+    await expectUnauthorized(nominate(ctx.admin.publicKey));
+  });
+
+  it("lets the admin close a basket, refunding its owner", async () => {
+    await nominate(curator.publicKey);
+    await accept(curator);
+    const before = Number(ctx.svm.getBalance(curator.publicKey));
+
+    await closeBasket(ctx.admin, curator.publicKey);
+
+    expect(Number(ctx.svm.getBalance(curator.publicKey))).to.be.greaterThan(before);
+    expect(ctx.svm.getAccount(basketPda)).to.equal(null);
+  });
+
+  it("rejects a close by anyone else, or with rent sent elsewhere", async () => {
+    await expectUnauthorized(closeBasket(curator, curator.publicKey));
+
+    await nominate(curator.publicKey);
+    await accept(curator);
+    await expectUnauthorized(closeBasket(ctx.admin, ctx.admin.publicKey));
+  });
+});
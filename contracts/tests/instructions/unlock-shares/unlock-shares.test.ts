@@ -0,0 +1,240 @@
+import { expect } from "chai";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+import { TOKEN_PROGRAM_ID, getAssociatedTokenAddress } from "@solana/spl-token";
+import {
+  createTestContext,
+  fundAccount,
+  createTestMint,
+  createTestAta,
+  mintTestTokens,
+  getTokenBalance,
+  findBasketPda,
+  findVaultAuthorityPda,
+  findMintAuthorityPda,
+  findBasketTokenPda,
+  findFeeVaultPda,
+  findLockPositionPda,
+  findMetadataPda,
+  TOKEN_METADATA_PROGRAM_ID,
+  type TestContext,
+} from "../../setup";
+
+const DAY = 86_400;
+
+describe("unlock_shares", () => {
+  let ctx: TestContext;
+  const whitelistAuth = Keypair.generate();
+  const basketId = new BN(1);
+
+  let basketPda: PublicKey;
+  let vaultAuthority: PublicKey;
+  let mintAuthority: PublicKey;
+  let shareMintKp: Keypair;
+  let usdcMint: PublicKey;
+  let user: Keypair;
+  let userShareAta: PublicKey;
+  let lockVault: PublicKey;
+  let lockPosition: PublicKey;
+
+  function encodeName(name: string): number[] {
+    const buf = Buffer.alloc(32, 0);
+    buf.write(name, "utf-8");
+    return Array.from(buf);
+  }
+
+  function now(): number {
+    return Number(ctx.svm.getClock().unixTimestamp);
+  }
+
+  function warp(seconds: number) {
+    const clock = ctx.svm.getClock();
+    clock.unixTimestamp += BigInt(seconds);
+    ctx.svm.setClock(clock);
+  }
+
+  async function setTiers(tiers: { minDurationSecs: BN; earlyExitPenaltyBps: number }[]) {
+    await ctx.program.methods
+      .setLockTiers(tiers)
+      .accounts({ basket: basketPda, program: ctx.program.programId })
+      .rpc();
+  }
+
+  async function lock(amount: number, unlockTs: number) {
+    await ctx.program.methods
+      .lockShares(new BN(amount), new BN(unlockTs))
+      .accounts({
+        owner: user.publicKey,
+        basket: basketPda,
+        shareMint: shareMintKp.publicKey,
+        vaultAuthority,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .signers([user])
+      .rpc();
+  }
+
+  beforeEach(async () => {
+    ctx = createTestContext();
+
+    await ctx.program.methods
+      .initConfig(20, whitelistAuth.publicKey, false)
+      .accounts({ program: ctx.program.programId })
+      .rpc();
+
+    shareMintKp = Keypair.generate();
+    [basketPda] = findBasketPda(basketId);
+    [vaultAuthority] = findVaultAuthorityPda(basketId);
+    [mintAuthority] = findMintAuthorityPda(basketId);
+
+    await ctx.program.methods
+      .createBasket(basketId, encodeName("Lock Test"), null, false, "QSHARE", "")
+      .accounts({
+        shareMint: shareMintKp.publicKey,
+        shareMetadata: findMetadataPda(shareMintKp.publicKey)[0],
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        treasury: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .signers([shareMintKp])
+      .rpc();
+
+    usdcMint = await createTestMint(ctx.provider, ctx.admin.publicKey, 6);
+    const [basketTokenPda] = findBasketTokenPda(basketPda, usdcMint);
+    const [feeVaultPda] = findFeeVaultPda(basketPda, usdcMint);
+    const vaultAta = await getAssociatedTokenAddress(usdcMint, vaultAuthority, true);
+
+    await ctx.program.methods
+      .addTokens(0)
+      .accounts({
+        basket: basketPda,
+        underlyingMint: usdcMint,
+        vaultAuthority,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .rpc();
+
+    user = Keypair.generate();
+    fundAccount(ctx.svm, user.publicKey);
+    const userUsdcAta = await createTestAta(ctx.provider, usdcMint, user.publicKey);
+    await mintTestTokens(
+      ctx.provider,
+      usdcMint,
+      userUsdcAta,
+      ctx.admin,
+      BigInt(1_000_000_000),
+    );
+    userShareAta = await getAssociatedTokenAddress(
+      shareMintKp.publicKey,
+      user.publicKey,
+    );
+
+    await ctx.program.methods
+      .depositMulti([new BN(1_000_000_000)], null)
+      .accounts({
+        user: user.publicKey,
+        receiver: user.publicKey,
+        basket: basketPda,
+        mintAuthority,
+        shareMint: shareMintKp.publicKey,
+        userAllowList: null,
+        complianceRoot: null,
+        instructionsSysvar: null,
+        attestationNonce: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .remainingAccounts([
+        { pubkey: basketTokenPda, isWritable: true, isSigner: false },
+        { pubkey: usdcMint, isWritable: false, isSigner: false },
+        { pubkey: userUsdcAta, isWritable: true, isSigner: false },
+        { pubkey: vaultAta, isWritable: true, isSigner: false },
+        { pubkey: feeVaultPda, isWritable: true, isSigner: false },
+      ])
+      .signers([user])
+      .rpc();
+
+    lockVault = await getAssociatedTokenAddress(
+      shareMintKp.publicKey,
+      vaultAuthority,
+      true,
+    );
+    [lockPosition] = findLockPositionPda(basketPda, user.publicKey);
+
+    await setTiers([
+      { minDurationSecs: new BN(30 * DAY), earlyExitPenaltyBps: 500 },
+      { minDurationSecs: new BN(180 * DAY), earlyExitPenaltyBps: 1_500 },
+    ]);
+  });
+
+  async function unlock(early = false) {
+    const builder = early
+      ? ctx.program.methods.exitLockEarly()
+      : ctx.program.methods.unlockShares();
+    await builder
+      .accounts({
+        owner: user.publicKey,
+        basket: basketPda,
+        shareMint: shareMintKp.publicKey,
+        vaultAuthority,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .signers([user])
+      .rpc();
+  }
+
+  it("rejects unlock before maturity", async () => {
+    await lock(100_000_000, now() + 60 * DAY);
+
+    try {
+      await unlock();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("LockNotMatured");
+    }
+  });
+
+  it("returns the full position after maturity and closes it", async () => {
+    const shares = await getTokenBalance(ctx.provider, userShareAta);
+    await lock(100_000_000, now() + 60 * DAY);
+    warp(60 * DAY);
+
+    await unlock();
+
+    expect((await getTokenBalance(ctx.provider, userShareAta)).toString()).to.equal(
+      shares.toString(),
+    );
+    expect(ctx.svm.getAccount(lockPosition)).to.be.null;
+  });
+
+  it("burns the tier penalty on early exit", async () => {
+    const shares = await getTokenBalance(ctx.provider, userShareAta);
+    await lock(100_000_000, now() + 60 * DAY);
+
+    await unlock(true);
+
+    // Tier 0 carries a 5% penalty.
+    expect(Number(await getTokenBalance(ctx.provider, userShareAta))).to.equal(
+      Number(shares) - 5_000_000,
+    );
+    expect(Number(await getTokenBalance(ctx.provider, lockVault))).to.equal(0);
+    expect(ctx.svm.getAccount(lockPosition)).to.be.null;
+  });
+
+  it("keeps the snapshotted penalty after the tiers change", async () => {
+    const shares = await getTokenBalance(ctx.provider, userShareAta);
+    await lock(100_000_000, now() + 60 * DAY);
+    await setTiers([{ minDurationSecs: new BN(30 * DAY), earlyExitPenaltyBps: 5_000 }]);
+
+    await unlock(true);
+
+    expect(Number(await getTokenBalance(ctx.provider, userShareAta))).to.equal(
+      Number(shares) - 5_000_000,
+    );
+  });
+});
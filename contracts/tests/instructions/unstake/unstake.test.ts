@@ -0,0 +1,79 @@
+import { expect } from "chai";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { getAssociatedTokenAddress } from "@solana/spl-token";
+import {
+  createTestContext,
+  getTokenBalance,
+  findStakePositionPda,
+  createRewardFixture,
+  newHolder,
+  stake,
+  unstake,
+  type TestContext,
+  type RewardFixture,
+} from "../../setup";
+
+const DAY = 86_400;
+
+describe("unstake", () => {
+  let ctx: TestContext;
+  let fx: RewardFixture;
+
+  let shareMint: PublicKey;
+  let rewardPool: PublicKey;
+  let user: Keypair;
+  let userShareAta: PublicKey;
+
+  function warp(seconds: number) {
+    const clock = ctx.svm.getClock();
+    clock.unixTimestamp += BigInt(seconds);
+    ctx.svm.setClock(clock);
+  }
+
+  beforeEach(async () => {
+    ctx = createTestContext();
+    fx = await createRewardFixture(ctx);
+    ({ shareMint, rewardPool } = fx);
+
+    user = await newHolder(fx);
+    userShareAta = await getAssociatedTokenAddress(shareMint, user.publicKey);
+  });
+
+  it("rejects unstaking during the commitment", async () => {
+    await stake(fx, user, 100_000_000, 30 * DAY);
+
+    try {
+      await unstake(fx, user, 100_000_000);
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("StakeLocked");
+    }
+  });
+
+  it("returns shares once the commitment ends", async () => {
+    const shares = await getTokenBalance(ctx.provider, userShareAta);
+    await stake(fx, user, 100_000_000, 30 * DAY);
+    warp(30 * DAY);
+
+    await unstake(fx, user, 40_000_000);
+
+    expect(Number(await getTokenBalance(ctx.provider, userShareAta))).to.equal(
+      Number(shares) - 60_000_000,
+    );
+    const [positionPda] = findStakePositionPda(rewardPool, user.publicKey);
+    const position = await ctx.program.account.stakePosition.fetch(positionPda);
+    expect(position.amount.toNumber()).to.equal(60_000_000);
+    expect(position.multiplierBps).to.equal(10_000);
+  });
+
+  it("rejects more than the staked amount", async () => {
+    await stake(fx, user, 100_000_000, 0);
+
+    try {
+      await unstake(fx, user, 100_000_001);
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("InsufficientShares");
+    }
+  });
+});
@@ -0,0 +1,154 @@
+import { expect } from "chai";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import {
+  createTestContext,
+  expectError,
+  fundAccount,
+  findBasketPda,
+  findUserAllowListPda,
+  findMetadataPda,
+  TOKEN_METADATA_PROGRAM_ID,
+  type TestContext,
+} from "../../setup";
+
+describe("update_allow_list_batch", () => {
+  let ctx: TestContext;
+  const whitelistAuth = Keypair.generate();
+  const basketId = new BN(1);
+  let basketPda: PublicKey;
+
+  function encodeName(name: string): number[] {
+    const buf = Buffer.alloc(32, 0);
+    buf.write(name, "utf-8");
+    return Array.from(buf);
+  }
+
+  function entryAccounts(entries: PublicKey[]) {
+    return entries.map((pubkey) => ({ pubkey, isWritable: true, isSigner: false }));
+  }
+
+  function batch(users: PublicKey[], allowed: boolean[], entries?: PublicKey[]) {
+    return ctx.program.methods
+      .updateAllowListBatch(users, allowed)
+      .accounts({
+        authority: whitelistAuth.publicKey,
+        basket: basketPda,
+        program: ctx.program.programId,
+      })
+      .remainingAccounts(
+        entryAccounts(entries ?? users.map((u) => findUserAllowListPda(basketPda, u)[0])),
+      )
+      .signers([whitelistAuth]);
+  }
+
+  beforeEach(async () => {
+    ctx = createTestContext();
+    fundAccount(ctx.svm, whitelistAuth.publicKey);
+
+    await ctx.program.methods
+      .initConfig(20, whitelistAuth.publicKey, true)
+      .accounts({ program: ctx.program.programId })
+      .rpc();
+
+    const shareMint = Keypair.generate();
+    [basketPda] = findBasketPda(basketId);
+
+    await ctx.program.methods
+      .createBasket(basketId, encodeName("Batch Allow"), null, false, "QSHARE", "")
+      .accounts({
+        shareMint: shareMint.publicKey,
+        shareMetadata: findMetadataPda(shareMint.publicKey)[0],
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        treasury: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .signers([shareMint])
+      .rpc();
+  });
+
+  it("creates entries for every user in the batch", async () => {
+    const users = [0, 1, 2].map(() => Keypair.generate().publicKey);
+
+    await batch(users, [true, false, true]).rpc();
+
+    const expected = [true, false, true];
+    for (let i = 0; i < users.length; i++) {
+      const [pda] = findUserAllowListPda(basketPda, users[i]);
+      const entry = await ctx.program.account.userAllowList.fetch(pda);
+      expect(entry.allowed).to.equal(expected[i]);
+      expect(entry.user.toBase58()).to.equal(users[i].toBase58());
+      expect(entry.basket.toBase58()).to.equal(basketPda.toBase58());
+    }
+  });
+
+  it("updates existing entries alongside new ones", async () => {
+    const existing = Keypair.generate().publicKey;
+    const fresh = Keypair.generate().publicKey;
+
+    await ctx.program.methods
+      .updateAllowList(true)
+      .accounts({
+        authority: whitelistAuth.publicKey,
+        basket: basketPda,
+        user: existing,
+        program: ctx.program.programId,
+      })
+      .signers([whitelistAuth])
+      .rpc();
+
+    await batch([existing, fresh], [false, true]).rpc();
+
+    const [existingPda] = findUserAllowListPda(basketPda, existing);
+    const [freshPda] = findUserAllowListPda(basketPda, fresh);
+    expect((await ctx.program.account.userAllowList.fetch(existingPda)).allowed).to.equal(false);
+    expect((await ctx.program.account.userAllowList.fetch(freshPda)).allowed).to.equal(true);
+  });
+
+  it("creates an entry whose PDA was pre-funded", async () => {
+    const user = Keypair.generate().publicKey;
+    const [pda] = findUserAllowListPda(basketPda, user);
+    fundAccount(ctx.svm, pda, 1_000);
+
+    await batch([user], [true]).rpc();
+
+    expect((await ctx.program.account.userAllowList.fetch(pda)).allowed).to.equal(true);
+  });
+
+  it("rejects a PDA that does not match its user", async () => {
+    const users = [Keypair.generate().publicKey, Keypair.generate().publicKey];
+    const [wrong] = findUserAllowListPda(basketPda, users[1]);
+
+    await expectError(batch(users, [true, true], [wrong, wrong]).rpc(), "InvalidAllowListEntry");
+  });
+
+  it("rejects mismatched lengths", async () => {
+    const users = [Keypair.generate().publicKey, Keypair.generate().publicKey];
+
+    await expectError(batch(users, [true]).rpc(), "AllowListBatchLengthMismatch");
+  });
+
+  it("rejects an empty batch", async () => {
+    await expectError(batch([], []).rpc(), "NoAllowListEntries");
+  });
+
+  it("rejects a caller other than the whitelist authority", async () => {
+    const user = Keypair.generate().publicKey;
+    const [pda] = findUserAllowListPda(basketPda, user);
+
+    await expectError(
+      ctx.program.methods
+        .updateAllowListBatch([user], [true])
+        .accounts({
+          authority: ctx.admin.publicKey,
+          basket: basketPda,
+          program: ctx.program.programId,
+        })
+        .remainingAccounts(entryAccounts([pda]))
+        .rpc(),
+      "Unauthorized",
+    );
+  });
+});
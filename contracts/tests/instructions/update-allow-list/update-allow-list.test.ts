@@ -7,6 +7,8 @@ import {
   fundAccount,
   findBasketPda,
   findUserAllowListPda,
+  findMetadataPda,
+  TOKEN_METADATA_PROGRAM_ID,
   type TestContext,
 } from "../../setup";
 
@@ -35,9 +37,12 @@ describe("update_allow_list", () => {
     [basketPda] = findBasketPda(basketId);
 
     await ctx.program.methods
-      .createBasket(basketId, encodeName("Allow Test"), null)
+      .createBasket(basketId, encodeName("Allow Test"), null, false, "QSHARE", "")
       .accounts({
         shareMint: shareMint.publicKey,
+        shareMetadata: findMetadataPda(shareMint.publicKey)[0],
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        treasury: null,
         tokenProgram: TOKEN_PROGRAM_ID,
         program: ctx.program.programId,
       })
@@ -0,0 +1,249 @@
+import { expect } from "chai";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+import { TOKEN_PROGRAM_ID, getAssociatedTokenAddress } from "@solana/spl-token";
+import {
+  createTestContext,
+  fundAccount,
+  createTestMint,
+  createTestAta,
+  mintTestTokens,
+  findBasketPda,
+  findVaultAuthorityPda,
+  findMintAuthorityPda,
+  findBasketTokenPda,
+  findFeeVaultPda,
+  findMetadataPda,
+  TOKEN_METADATA_PROGRAM_ID,
+  type TestContext,
+} from "../../setup";
+
+describe("update_basket_limits", () => {
+  let ctx: TestContext;
+  const whitelistAuth = Keypair.generate();
+  const basketId = new BN(1);
+
+  let basketPda: PublicKey;
+  let vaultAuthority: PublicKey;
+  let mintAuthority: PublicKey;
+  let shareMintKp: Keypair;
+  let usdcMint: PublicKey;
+  let basketTokenPda: PublicKey;
+  let feeVaultPda: PublicKey;
+  let vaultAta: PublicKey;
+
+  function encodeName(name: string): number[] {
+    const buf = Buffer.alloc(32, 0);
+    buf.write(name, "utf-8");
+    return Array.from(buf);
+  }
+
+  async function setLimits(
+    maxTotalValue: number,
+    maxUserShares: number,
+    signer = ctx.admin,
+    maxWithdrawBps = 0,
+    withdrawWindowSecs = 0
+  ) {
+    await ctx.program.methods
+      .updateBasketLimits(
+        new BN(maxTotalValue),
+        new BN(maxUserShares),
+        maxWithdrawBps,
+        new BN(withdrawWindowSecs)
+      )
+      .accounts({
+        authority: signer.publicKey,
+        basket: basketPda,
+        program: ctx.program.programId,
+      })
+      .signers(signer === ctx.admin ? [] : [signer])
+      .rpc();
+  }
+
+  /** A wallet holding 2,000 USDC. */
+  async function newUser(): Promise<{ kp: Keypair; usdcAta: PublicKey }> {
+    const kp = Keypair.generate();
+    fundAccount(ctx.svm, kp.publicKey);
+    const usdcAta = await createTestAta(ctx.provider, usdcMint, kp.publicKey);
+    await mintTestTokens(ctx.provider, usdcMint, usdcAta, ctx.admin, BigInt(2_000_000_000));
+    return { kp, usdcAta };
+  }
+
+  // 1,000 USDC nets 998 QSHARE-units of value after the 0.2% fee.
+  async function deposit(user: { kp: Keypair; usdcAta: PublicKey }) {
+    await ctx.program.methods
+      .depositMulti([new BN(1_000_000_000)], null)
+      .accounts({
+        user: user.kp.publicKey,
+        receiver: user.kp.publicKey,
+        basket: basketPda,
+        mintAuthority,
+        shareMint: shareMintKp.publicKey,
+        userAllowList: null,
+        complianceRoot: null,
+        instructionsSysvar: null,
+        attestationNonce: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .remainingAccounts([
+        { pubkey: basketTokenPda, isWritable: true, isSigner: false },
+        { pubkey: usdcMint, isWritable: false, isSigner: false },
+        { pubkey: user.usdcAta, isWritable: true, isSigner: false },
+        { pubkey: vaultAta, isWritable: true, isSigner: false },
+        { pubkey: feeVaultPda, isWritable: true, isSigner: false },
+      ])
+      .signers([user.kp])
+      .rpc();
+  }
+
+  async function expectCapExceeded(user: { kp: Keypair; usdcAta: PublicKey }) {
+    try {
+      await deposit(user);
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("DepositCapExceeded");
+    }
+  }
+
+  beforeEach(async () => {
+    ctx = createTestContext();
+
+    await ctx.program.methods
+      .initConfig(20, whitelistAuth.publicKey, false)
+      .accounts({ program: ctx.program.programId })
+      .rpc();
+
+    shareMintKp = Keypair.generate();
+    [basketPda] = findBasketPda(basketId);
+    [vaultAuthority] = findVaultAuthorityPda(basketId);
+    [mintAuthority] = findMintAuthorityPda(basketId);
+
+    await ctx.program.methods
+      .createBasket(basketId, encodeName("Limits Test"), null, false, "QSHARE", "")
+      .accounts({
+        shareMint: shareMintKp.publicKey,
+        shareMetadata: findMetadataPda(shareMintKp.publicKey)[0],
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        treasury: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .signers([shareMintKp])
+      .rpc();
+
+    usdcMint = await createTestMint(ctx.provider, ctx.admin.publicKey, 6);
+    [basketTokenPda] = findBasketTokenPda(basketPda, usdcMint);
+    [feeVaultPda] = findFeeVaultPda(basketPda, usdcMint);
+    vaultAta = await getAssociatedTokenAddress(usdcMint, vaultAuthority, true);
+
+    await ctx.program.methods
+      .addTokens(0)
+      .accounts({
+        basket: basketPda,
+        underlyingMint: usdcMint,
+        vaultAuthority,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .rpc();
+  });
+
+  it("starts uncapped", async () => {
+    const basket = await ctx.program.account.basket.fetch(basketPda);
+    expect(basket.maxTotalValue.toNumber()).to.equal(0);
+    expect(basket.maxUserShares.toNumber()).to.equal(0);
+  });
+
+  it("stores both caps", async () => {
+    await setLimits(5_000_000_000, 1_000_000_000);
+
+    const basket = await ctx.program.account.basket.fetch(basketPda);
+    expect(basket.maxTotalValue.toNumber()).to.equal(5_000_000_000);
+    expect(basket.maxUserShares.toNumber()).to.equal(1_000_000_000);
+  });
+
+  it("rejects a deposit that would exceed the value cap", async () => {
+    await setLimits(1_500_000_000, 0);
+    const user = await newUser();
+    await deposit(user);
+
+    await expectCapExceeded(await newUser());
+  });
+
+  it("rejects a deposit that would exceed the receiver's share cap", async () => {
+    await setLimits(0, 1_000_000_000);
+    const user = await newUser();
+    await deposit(user);
+
+    await expectCapExceeded(user);
+    // Other receivers are capped independently.
+    await deposit(await newUser());
+  });
+
+  it("lifts a cap when set to zero", async () => {
+    await setLimits(1_500_000_000, 0);
+    const user = await newUser();
+    await deposit(user);
+    await setLimits(0, 0);
+
+    await deposit(user);
+  });
+
+  it("rejects a non-owner", async () => {
+    const stranger = Keypair.generate();
+    fundAccount(ctx.svm, stranger.publicKey);
+
+    try {
+      await setLimits(1, 1, stranger);
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("Unauthorized");
+    }
+  });
+
+  it("leaves the withdrawal limiter to the protocol admin", async () => {
+    const curator = Keypair.generate();
+    fundAccount(ctx.svm, curator.publicKey);
+    await ctx.program.methods
+      .transferBasketOwnership(curator.publicKey)
+      .accounts({
+        owner: ctx.admin.publicKey,
+        basket: basketPda,
+        program: ctx.program.programId,
+      })
+      .rpc();
+    await ctx.program.methods
+      .acceptBasketOwnership()
+      .accounts({
+        newOwner: curator.publicKey,
+        basket: basketPda,
+        program: ctx.program.programId,
+      })
+      .signers([curator])
+      .rpc();
+
+    for (const [bps, window] of [
+      [1, 365 * 86_400],
+      [0, 3_600],
+    ]) {
+      try {
+        await setLimits(0, 0, curator, bps, window);
+        expect.fail("should have thrown");
+      } catch (err: any) {
+        expect(err.toString()).to.include("Unauthorized");
+      }
+    }
+
+    await setLimits(0, 0, ctx.admin, 1_000, 3_600);
+    // The curator still sets caps, passing the limiter back unchanged.
+    await setLimits(5_000_000_000, 0, curator, 1_000, 3_600);
+
+    const basket = await ctx.program.account.basket.fetch(basketPda);
+    expect(basket.maxTotalValue.toNumber()).to.equal(5_000_000_000);
+    expect(basket.maxWithdrawBps).to.equal(1_000);
+    expect(basket.withdrawWindowSecs.toNumber()).to.equal(3_600);
+  });
+});
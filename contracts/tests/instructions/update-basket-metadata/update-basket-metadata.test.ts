@@ -0,0 +1,135 @@
+import { expect } from "chai";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+import { TOKEN_2022_PROGRAM_ID, getTokenMetadata } from "@solana/spl-token";
+import {
+  createTestContext,
+  fundAccount,
+  findBasketPda,
+  findMintAuthorityPda,
+  type TestContext,
+} from "../../setup";
+
+describe("update_basket_metadata", () => {
+  let ctx: TestContext;
+  const whitelistAuth = Keypair.generate();
+  const basketId = new BN(1);
+  let basketPda: PublicKey;
+  let mintAuthority: PublicKey;
+  let shareMint: Keypair;
+
+  function encodeName(name: string): number[] {
+    const buf = Buffer.alloc(32, 0);
+    buf.write(name, "utf-8");
+    return Array.from(buf);
+  }
+
+  beforeEach(async () => {
+    ctx = createTestContext();
+
+    await ctx.program.methods
+      .initConfig(20, whitelistAuth.publicKey, false)
+      .accounts({ program: ctx.program.programId })
+      .rpc();
+
+    shareMint = Keypair.generate();
+    [basketPda] = findBasketPda(basketId);
+    [mintAuthority] = findMintAuthorityPda(basketId);
+
+    await ctx.program.methods
+      .createBasket(
+        basketId,
+        encodeName("Metadata Basket"),
+        null,
+        false,
+        "QMETA",
+        "https://example.com/q.json",
+      )
+      .accounts({
+        shareMint: shareMint.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .signers([shareMint])
+      .rpc();
+  });
+
+  it("initialises Token-2022 metadata from the basket name", async () => {
+    const metadata = await getTokenMetadata(
+      ctx.provider.connection,
+      shareMint.publicKey,
+    );
+    expect(metadata?.name).to.equal("Metadata Basket");
+    expect(metadata?.symbol).to.equal("QMETA");
+    expect(metadata?.uri).to.equal("https://example.com/q.json");
+  });
+
+  it("lets the owner replace symbol and URI", async () => {
+    await ctx.program.methods
+      .updateBasketMetadata("QNEW", "https://example.com/a-much-longer-uri.json")
+      .accounts({
+        basket: basketPda,
+        mintAuthority,
+        shareMint: shareMint.publicKey,
+        shareMetadata: null,
+        tokenMetadataProgram: null,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .rpc();
+
+    const metadata = await getTokenMetadata(
+      ctx.provider.connection,
+      shareMint.publicKey,
+    );
+    expect(metadata?.symbol).to.equal("QNEW");
+    expect(metadata?.uri).to.equal(
+      "https://example.com/a-much-longer-uri.json",
+    );
+  });
+
+  it("rejects non-owner caller", async () => {
+    const impostor = Keypair.generate();
+    fundAccount(ctx.svm, impostor.publicKey);
+
+    try {
+      await ctx.program.methods
+        .updateBasketMetadata("EVIL", "")
+        .accounts({
+          authority: impostor.publicKey,
+          basket: basketPda,
+          mintAuthority,
+          shareMint: shareMint.publicKey,
+          shareMetadata: null,
+          tokenMetadataProgram: null,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          program: ctx.program.programId,
+        })
+        .signers([impostor])
+        .rpc();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("Unauthorized");
+    }
+  });
+
+  it("rejects an oversized symbol", async () => {
+    try {
+      await ctx.program.methods
+        .updateBasketMetadata("WAYTOOLONGSYMBOL", "")
+        .accounts({
+          basket: basketPda,
+          mintAuthority,
+          shareMint: shareMint.publicKey,
+          shareMetadata: null,
+          tokenMetadataProgram: null,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+          program: ctx.program.programId,
+        })
+        .rpc();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("InvalidMetadata");
+    }
+  });
+});
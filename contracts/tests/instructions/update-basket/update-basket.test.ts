@@ -0,0 +1,120 @@
+import { expect } from "chai";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import {
+  createTestContext,
+  fundAccount,
+  findBasketPda,
+  findMetadataPda,
+  TOKEN_METADATA_PROGRAM_ID,
+  type TestContext,
+} from "../../setup";
+
+describe("update_basket", () => {
+  let ctx: TestContext;
+  const whitelistAuth = Keypair.generate();
+  const basketId = new BN(1);
+
+  let basketPda: PublicKey;
+
+  function encodeName(name: string): number[] {
+    const buf = Buffer.alloc(32, 0);
+    buf.write(name, "utf-8");
+    return Array.from(buf);
+  }
+
+  function decodeName(bytes: number[]): string {
+    return Buffer.from(bytes).toString("utf-8").replace(/\0+$/, "");
+  }
+
+  async function updateBasket(
+    name: string | null,
+    feeBpsOverride: number | null,
+    signer = ctx.admin,
+  ) {
+    await ctx.program.methods
+      .updateBasket(name === null ? null : encodeName(name), feeBpsOverride)
+      .accounts({
+        authority: signer.publicKey,
+        basket: basketPda,
+        program: ctx.program.programId,
+      })
+      .signers(signer === ctx.admin ? [] : [signer])
+      .rpc();
+  }
+
+  beforeEach(async () => {
+    ctx = createTestContext();
+
+    await ctx.program.methods
+      .initConfig(20, whitelistAuth.publicKey, false)
+      .accounts({ program: ctx.program.programId })
+      .rpc();
+
+    const shareMintKp = Keypair.generate();
+    [basketPda] = findBasketPda(basketId);
+
+    await ctx.program.methods
+      .createBasket(basketId, encodeName("Original"), 30, false, "QSHARE", "")
+      .accounts({
+        shareMint: shareMintKp.publicKey,
+        shareMetadata: findMetadataPda(shareMintKp.publicKey)[0],
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        treasury: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .signers([shareMintKp])
+      .rpc();
+  });
+
+  it("renames the basket and keeps the fee override", async () => {
+    await updateBasket("Renamed", null);
+
+    const basket = await ctx.program.account.basket.fetch(basketPda);
+    expect(decodeName(basket.name)).to.equal("Renamed");
+    expect(basket.hasFeeOverride).to.equal(1);
+    expect(basket.feeBpsOverride).to.equal(30);
+  });
+
+  it("sets a new fee override and keeps the name", async () => {
+    await updateBasket(null, 45);
+
+    const basket = await ctx.program.account.basket.fetch(basketPda);
+    expect(decodeName(basket.name)).to.equal("Original");
+    expect(basket.hasFeeOverride).to.equal(1);
+    expect(basket.feeBpsOverride).to.equal(45);
+  });
+
+  it("clears the fee override with 0", async () => {
+    await updateBasket(null, 0);
+
+    const basket = await ctx.program.account.basket.fetch(basketPda);
+    expect(basket.hasFeeOverride).to.equal(0);
+    expect(basket.feeBpsOverride).to.equal(0);
+  });
+
+  it("rejects an override outside the fee bounds", async () => {
+    for (const bps of [5, 51]) {
+      try {
+        await updateBasket(null, bps);
+        expect.fail("should have thrown");
+      } catch (err: any) {
+        expect(err.toString()).to.include("InvalidFee");
+      }
+    }
+  });
+
+  it("rejects a non-owner", async () => {
+    const stranger = Keypair.generate();
+    fundAccount(ctx.svm, stranger.publicKey);
+
+    try {
+      await updateBasket("Hijacked", null, stranger);
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("Unauthorized");
+    }
+  });
+});
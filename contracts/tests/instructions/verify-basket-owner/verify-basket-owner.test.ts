@@ -6,6 +6,8 @@ import {
   createTestContext,
   findBasketPda,
   findVaultAuthorityPda,
+  findMetadataPda,
+  TOKEN_METADATA_PROGRAM_ID,
   type TestContext,
 } from "../../setup";
 
@@ -35,9 +37,12 @@ describe("verify_basket_owner", () => {
     [vaultAuthority] = findVaultAuthorityPda(basketId);
 
     await ctx.program.methods
-      .createBasket(basketId, encodeName("Verify Test"), null)
+      .createBasket(basketId, encodeName("Verify Test"), null, false, "QSHARE", "")
       .accounts({
         shareMint: shareMintKp.publicKey,
+        shareMetadata: findMetadataPda(shareMintKp.publicKey)[0],
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        treasury: null,
         tokenProgram: TOKEN_PROGRAM_ID,
         program: ctx.program.programId,
       })
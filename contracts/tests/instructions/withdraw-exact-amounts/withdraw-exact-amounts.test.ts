@@ -0,0 +1,249 @@
+import { expect } from "chai";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { BN } from "@coral-xyz/anchor";
+import { TOKEN_PROGRAM_ID, getAssociatedTokenAddress } from "@solana/spl-token";
+import {
+  createTestContext,
+  fundAccount,
+  createTestMint,
+  createTestAta,
+  mintTestTokens,
+  getTokenBalance,
+  findBasketPda,
+  findVaultAuthorityPda,
+  findMintAuthorityPda,
+  findBasketTokenPda,
+  findFeeVaultPda,
+  findMetadataPda,
+  TOKEN_METADATA_PROGRAM_ID,
+  type TestContext,
+} from "../../setup";
+
+describe("withdraw_exact_amounts", () => {
+  let ctx: TestContext;
+  const whitelistAuth = Keypair.generate();
+  const basketId = new BN(1);
+
+  let basketPda: PublicKey;
+  let vaultAuthority: PublicKey;
+  let mintAuthority: PublicKey;
+  let shareMintKp: Keypair;
+  let usdcMint: PublicKey;
+  let usdtMint: PublicKey;
+  let btUsdc: PublicKey;
+  let btUsdt: PublicKey;
+  let fvUsdc: PublicKey;
+  let fvUsdt: PublicKey;
+  let vaultUsdc: PublicKey;
+  let vaultUsdt: PublicKey;
+  let user: Keypair;
+  let userUsdcAta: PublicKey;
+  let userUsdtAta: PublicKey;
+  let userShareAta: PublicKey;
+
+  function encodeName(name: string): number[] {
+    const buf = Buffer.alloc(32, 0);
+    buf.write(name, "utf-8");
+    return Array.from(buf);
+  }
+
+  beforeEach(async () => {
+    ctx = createTestContext();
+
+    await ctx.program.methods
+      .initConfig(20, whitelistAuth.publicKey, false)
+      .accounts({ program: ctx.program.programId })
+      .rpc();
+
+    shareMintKp = Keypair.generate();
+    [basketPda] = findBasketPda(basketId);
+    [vaultAuthority] = findVaultAuthorityPda(basketId);
+    [mintAuthority] = findMintAuthorityPda(basketId);
+
+    await ctx.program.methods
+      .createBasket(basketId, encodeName("Withdraw Exact Test"), null, false, "QSHARE", "")
+      .accounts({
+        shareMint: shareMintKp.publicKey,
+        shareMetadata: findMetadataPda(shareMintKp.publicKey)[0],
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        treasury: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .signers([shareMintKp])
+      .rpc();
+
+    usdcMint = await createTestMint(ctx.provider, ctx.admin.publicKey, 6);
+    usdtMint = await createTestMint(ctx.provider, ctx.admin.publicKey, 6);
+
+    [btUsdc] = findBasketTokenPda(basketPda, usdcMint);
+    [fvUsdc] = findFeeVaultPda(basketPda, usdcMint);
+    vaultUsdc = await getAssociatedTokenAddress(usdcMint, vaultAuthority, true);
+
+    [btUsdt] = findBasketTokenPda(basketPda, usdtMint);
+    [fvUsdt] = findFeeVaultPda(basketPda, usdtMint);
+    vaultUsdt = await getAssociatedTokenAddress(usdtMint, vaultAuthority, true);
+
+    await ctx.program.methods
+      .addTokens(0)
+      .accounts({
+        basket: basketPda,
+        underlyingMint: usdcMint,
+        vaultAuthority,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .rpc();
+
+    await ctx.program.methods
+      .addTokens(0)
+      .accounts({
+        basket: basketPda,
+        underlyingMint: usdtMint,
+        vaultAuthority,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .rpc();
+
+    // Deposit as user to seed vault + shares
+    user = Keypair.generate();
+    fundAccount(ctx.svm, user.publicKey);
+    userUsdcAta = await createTestAta(ctx.provider, usdcMint, user.publicKey);
+    userUsdtAta = await createTestAta(ctx.provider, usdtMint, user.publicKey);
+    await mintTestTokens(
+      ctx.provider,
+      usdcMint,
+      userUsdcAta,
+      ctx.admin,
+      BigInt(1_000_000_000),
+    );
+    await mintTestTokens(
+      ctx.provider,
+      usdtMint,
+      userUsdtAta,
+      ctx.admin,
+      BigInt(500_000_000),
+    );
+
+    userShareAta = await getAssociatedTokenAddress(
+      shareMintKp.publicKey,
+      user.publicKey,
+    );
+
+    await ctx.program.methods
+      .depositMulti([new BN(1_000_000_000), new BN(500_000_000)], null)
+      .accounts({
+        user: user.publicKey,
+        receiver: user.publicKey,
+        basket: basketPda,
+        mintAuthority,
+        shareMint: shareMintKp.publicKey,
+        userAllowList: null,
+        complianceRoot: null,
+        instructionsSysvar: null,
+        attestationNonce: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .remainingAccounts([
+        { pubkey: btUsdc, isWritable: true, isSigner: false },
+        { pubkey: usdcMint, isWritable: false, isSigner: false },
+        { pubkey: userUsdcAta, isWritable: true, isSigner: false },
+        { pubkey: vaultUsdc, isWritable: true, isSigner: false },
+        { pubkey: fvUsdc, isWritable: true, isSigner: false },
+        { pubkey: btUsdt, isWritable: true, isSigner: false },
+        { pubkey: usdtMint, isWritable: false, isSigner: false },
+        { pubkey: userUsdtAta, isWritable: true, isSigner: false },
+        { pubkey: vaultUsdt, isWritable: true, isSigner: false },
+        { pubkey: fvUsdt, isWritable: true, isSigner: false },
+      ])
+      .signers([user])
+      .rpc();
+  });
+
+  async function withdrawExact(amountsOut: number[], maxSharesIn: BN) {
+    await ctx.program.methods
+      .withdrawExactAmounts(
+        amountsOut.map((a) => new BN(a)),
+        maxSharesIn,
+      )
+      .accounts({
+        user: user.publicKey,
+        receiver: user.publicKey,
+        basket: basketPda,
+        shareMint: shareMintKp.publicKey,
+        vaultAuthority,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .remainingAccounts([
+        { pubkey: btUsdc, isWritable: true, isSigner: false },
+        { pubkey: usdcMint, isWritable: false, isSigner: false },
+        { pubkey: vaultUsdc, isWritable: true, isSigner: false },
+        { pubkey: userUsdcAta, isWritable: true, isSigner: false },
+        { pubkey: btUsdt, isWritable: true, isSigner: false },
+        { pubkey: usdtMint, isWritable: false, isSigner: false },
+        { pubkey: vaultUsdt, isWritable: true, isSigner: false },
+        { pubkey: userUsdtAta, isWritable: true, isSigner: false },
+      ])
+      .signers([user])
+      .rpc();
+  }
+
+  it("burns the minimum shares covering the requested amount", async () => {
+    const supply = await getTokenBalance(ctx.provider, userShareAta);
+    const usdcVault = await getTokenBalance(ctx.provider, vaultUsdc);
+    const usdtVault = await getTokenBalance(ctx.provider, vaultUsdt);
+    const usdcBefore = await getTokenBalance(ctx.provider, userUsdcAta);
+    const usdtBefore = await getTokenBalance(ctx.provider, userUsdtAta);
+    const requested = BigInt(100_000_000);
+
+    await withdrawExact([100_000_000, 0], new BN(supply.toString()));
+
+    // ceil(requested * supply / vault)
+    const expectedBurn = (requested * supply + usdcVault - BigInt(1)) / usdcVault;
+    const sharesAfter = await getTokenBalance(ctx.provider, userShareAta);
+    expect((supply - sharesAfter).toString()).to.equal(expectedBurn.toString());
+
+    const usdcOut = (await getTokenBalance(ctx.provider, userUsdcAta)) - usdcBefore;
+    expect(usdcOut >= requested).to.equal(true);
+
+    // Unrequested legs still pay their pro-rata slice.
+    const usdtOut = (await getTokenBalance(ctx.provider, userUsdtAta)) - usdtBefore;
+    expect(usdtOut.toString()).to.equal(((usdtVault * expectedBurn) / supply).toString());
+  });
+
+  it("rejects when the burn exceeds max_shares_in", async () => {
+    try {
+      await withdrawExact([100_000_000, 0], new BN(1_000));
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("MaxSharesInExceeded");
+    }
+  });
+
+  it("rejects amounts larger than the vault", async () => {
+    const supply = await getTokenBalance(ctx.provider, userShareAta);
+
+    try {
+      await withdrawExact([0, 600_000_000], new BN((supply * BigInt(2)).toString()));
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("InsufficientShares");
+    }
+  });
+
+  it("rejects a request with no amounts", async () => {
+    const supply = await getTokenBalance(ctx.provider, userShareAta);
+
+    try {
+      await withdrawExact([0, 0], new BN(supply.toString()));
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("InsufficientShares");
+    }
+  });
+});
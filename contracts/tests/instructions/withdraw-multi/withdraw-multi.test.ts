@@ -14,6 +14,8 @@ import {
   findMintAuthorityPda,
   findBasketTokenPda,
   findFeeVaultPda,
+  findMetadataPda,
+  TOKEN_METADATA_PROGRAM_ID,
   type TestContext,
 } from "../../setup";
 
@@ -59,9 +61,12 @@ describe("withdraw_multi", () => {
     [mintAuthority] = findMintAuthorityPda(basketId);
 
     await ctx.program.methods
-      .createBasket(basketId, encodeName("Withdraw Test"), null)
+      .createBasket(basketId, encodeName("Withdraw Test"), null, false, "QSHARE", "")
       .accounts({
         shareMint: shareMintKp.publicKey,
+        shareMetadata: findMetadataPda(shareMintKp.publicKey)[0],
+        tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+        treasury: null,
         tokenProgram: TOKEN_PROGRAM_ID,
         program: ctx.program.programId,
       })
@@ -80,7 +85,7 @@ describe("withdraw_multi", () => {
     vaultUsdt = await getAssociatedTokenAddress(usdtMint, vaultAuthority, true);
 
     await ctx.program.methods
-      .addTokens()
+      .addTokens(0)
       .accounts({
         basket: basketPda,
         underlyingMint: usdcMint,
@@ -91,7 +96,7 @@ describe("withdraw_multi", () => {
       .rpc();
 
     await ctx.program.methods
-      .addTokens()
+      .addTokens(0)
       .accounts({
         basket: basketPda,
         underlyingMint: usdtMint,
@@ -127,23 +132,28 @@ describe("withdraw_multi", () => {
     );
 
     await ctx.program.methods
-      .depositMulti([new BN(1_000_000_000), new BN(500_000_000)])
+      .depositMulti([new BN(1_000_000_000), new BN(500_000_000)], null)
       .accounts({
         user: user.publicKey,
+        receiver: user.publicKey,
         basket: basketPda,
         mintAuthority,
         shareMint: shareMintKp.publicKey,
         userAllowList: null,
+        complianceRoot: null,
+        instructionsSysvar: null,
+        attestationNonce: null,
         tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
         program: ctx.program.programId,
       })
       .remainingAccounts([
-        { pubkey: btUsdc, isWritable: false, isSigner: false },
+        { pubkey: btUsdc, isWritable: true, isSigner: false },
         { pubkey: usdcMint, isWritable: false, isSigner: false },
         { pubkey: userUsdcAta, isWritable: true, isSigner: false },
         { pubkey: vaultUsdc, isWritable: true, isSigner: false },
         { pubkey: fvUsdc, isWritable: true, isSigner: false },
-        { pubkey: btUsdt, isWritable: false, isSigner: false },
+        { pubkey: btUsdt, isWritable: true, isSigner: false },
         { pubkey: usdtMint, isWritable: false, isSigner: false },
         { pubkey: userUsdtAta, isWritable: true, isSigner: false },
         { pubkey: vaultUsdt, isWritable: true, isSigner: false },
@@ -161,18 +171,20 @@ describe("withdraw_multi", () => {
       .withdrawMulti(new BN(Number(sharesBefore)))
       .accounts({
         user: user.publicKey,
+        receiver: user.publicKey,
         basket: basketPda,
         shareMint: shareMintKp.publicKey,
         vaultAuthority,
         tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
         program: ctx.program.programId,
       })
       .remainingAccounts([
-        { pubkey: btUsdc, isWritable: false, isSigner: false },
+        { pubkey: btUsdc, isWritable: true, isSigner: false },
         { pubkey: usdcMint, isWritable: false, isSigner: false },
         { pubkey: vaultUsdc, isWritable: true, isSigner: false },
         { pubkey: userUsdcAta, isWritable: true, isSigner: false },
-        { pubkey: btUsdt, isWritable: false, isSigner: false },
+        { pubkey: btUsdt, isWritable: true, isSigner: false },
         { pubkey: usdtMint, isWritable: false, isSigner: false },
         { pubkey: vaultUsdt, isWritable: true, isSigner: false },
         { pubkey: userUsdtAta, isWritable: true, isSigner: false },
@@ -196,6 +208,123 @@ describe("withdraw_multi", () => {
     expect(Number(vaultUsdtBalance)).to.equal(0);
   });
 
+  it("pays underlying to a separate receiver", async () => {
+    const client = Keypair.generate();
+    const clientUsdcAta = await createTestAta(ctx.provider, usdcMint, client.publicKey);
+    const clientUsdtAta = await createTestAta(ctx.provider, usdtMint, client.publicKey);
+    const sharesBefore = await getTokenBalance(ctx.provider, userShareAta);
+
+    await ctx.program.methods
+      .withdrawMulti(new BN(Number(sharesBefore)))
+      .accounts({
+        user: user.publicKey,
+        receiver: client.publicKey,
+        basket: basketPda,
+        shareMint: shareMintKp.publicKey,
+        vaultAuthority,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        shareTokenProgram: TOKEN_PROGRAM_ID,
+        program: ctx.program.programId,
+      })
+      .remainingAccounts([
+        { pubkey: btUsdc, isWritable: true, isSigner: false },
+        { pubkey: usdcMint, isWritable: false, isSigner: false },
+        { pubkey: vaultUsdc, isWritable: true, isSigner: false },
+        { pubkey: clientUsdcAta, isWritable: true, isSigner: false },
+        { pubkey: btUsdt, isWritable: true, isSigner: false },
+        { pubkey: usdtMint, isWritable: false, isSigner: false },
+        { pubkey: vaultUsdt, isWritable: true, isSigner: false },
+        { pubkey: clientUsdtAta, isWritable: true, isSigner: false },
+      ])
+      .signers([user])
+      .rpc();
+
+    expect(Number(await getTokenBalance(ctx.provider, userShareAta))).to.equal(0);
+    expect(Number(await getTokenBalance(ctx.provider, clientUsdcAta))).to.equal(998_000_000);
+    expect(Number(await getTokenBalance(ctx.provider, clientUsdtAta))).to.equal(499_000_000);
+  });
+
+  it("rejects payout accounts not owned by the receiver", async () => {
+    const client = Keypair.generate();
+    const sharesBefore = await getTokenBalance(ctx.provider, userShareAta);
+
+    try {
+      await ctx.program.methods
+        .withdrawMulti(new BN(Number(sharesBefore)))
+        .accounts({
+          user: user.publicKey,
+          receiver: client.publicKey,
+          basket: basketPda,
+          shareMint: shareMintKp.publicKey,
+          vaultAuthority,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          shareTokenProgram: TOKEN_PROGRAM_ID,
+          program: ctx.program.programId,
+        })
+        .remainingAccounts([
+          { pubkey: btUsdc, isWritable: true, isSigner: false },
+          { pubkey: usdcMint, isWritable: false, isSigner: false },
+          { pubkey: vaultUsdc, isWritable: true, isSigner: false },
+          { pubkey: userUsdcAta, isWritable: true, isSigner: false },
+          { pubkey: btUsdt, isWritable: true, isSigner: false },
+          { pubkey: usdtMint, isWritable: false, isSigner: false },
+          { pubkey: vaultUsdt, isWritable: true, isSigner: false },
+          { pubkey: userUsdtAta, isWritable: true, isSigner: false },
+        ])
+        .signers([user])
+        .rpc();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("ReceiverMismatch");
+    }
+  });
+
+  async function withdrawExpectingError(
+    usdcPayout: PublicKey,
+    usdtPayout: PublicKey,
+    expected: string,
+  ) {
+    const sharesBefore = await getTokenBalance(ctx.provider, userShareAta);
+
+    try {
+      await ctx.program.methods
+        .withdrawMulti(new BN(Number(sharesBefore)))
+        .accounts({
+          user: user.publicKey,
+          receiver: user.publicKey,
+          basket: basketPda,
+          shareMint: shareMintKp.publicKey,
+          vaultAuthority,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          shareTokenProgram: TOKEN_PROGRAM_ID,
+          program: ctx.program.programId,
+        })
+        .remainingAccounts([
+          { pubkey: btUsdc, isWritable: true, isSigner: false },
+          { pubkey: usdcMint, isWritable: false, isSigner: false },
+          { pubkey: vaultUsdc, isWritable: true, isSigner: false },
+          { pubkey: usdcPayout, isWritable: true, isSigner: false },
+          { pubkey: btUsdt, isWritable: true, isSigner: false },
+          { pubkey: usdtMint, isWritable: false, isSigner: false },
+          { pubkey: vaultUsdt, isWritable: true, isSigner: false },
+          { pubkey: usdtPayout, isWritable: true, isSigner: false },
+        ])
+        .signers([user])
+        .rpc();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include(expected);
+    }
+  }
+
+  it("rejects payout accounts for the wrong mint", async () => {
+    await withdrawExpectingError(userUsdtAta, userUsdcAta, "UserAtaMintMismatch");
+  });
+
+  it("rejects payout accounts not owned by the token program", async () => {
+    await withdrawExpectingError(user.publicKey, userUsdtAta, "InvalidTokenProgramOwner");
+  });
+
   it("rejects incomplete withdrawal (only 1 of 2 tokens)", async () => {
     const sharesBefore = await getTokenBalance(ctx.provider, userShareAta);
 
@@ -204,15 +333,17 @@ describe("withdraw_multi", () => {
         .withdrawMulti(new BN(Number(sharesBefore)))
         .accounts({
           user: user.publicKey,
+          receiver: user.publicKey,
           basket: basketPda,
           shareMint: shareMintKp.publicKey,
           vaultAuthority,
           tokenProgram: TOKEN_PROGRAM_ID,
+          shareTokenProgram: TOKEN_PROGRAM_ID,
           program: ctx.program.programId,
         })
         .remainingAccounts([
           // Only providing USDC — missing USDT
-          { pubkey: btUsdc, isWritable: false, isSigner: false },
+          { pubkey: btUsdc, isWritable: true, isSigner: false },
           { pubkey: usdcMint, isWritable: false, isSigner: false },
           { pubkey: vaultUsdc, isWritable: true, isSigner: false },
           { pubkey: userUsdcAta, isWritable: true, isSigner: false },
@@ -231,18 +362,20 @@ describe("withdraw_multi", () => {
         .withdrawMulti(new BN(0))
         .accounts({
           user: user.publicKey,
+          receiver: user.publicKey,
           basket: basketPda,
           shareMint: shareMintKp.publicKey,
           vaultAuthority,
           tokenProgram: TOKEN_PROGRAM_ID,
+          shareTokenProgram: TOKEN_PROGRAM_ID,
           program: ctx.program.programId,
         })
         .remainingAccounts([
-          { pubkey: btUsdc, isWritable: false, isSigner: false },
+          { pubkey: btUsdc, isWritable: true, isSigner: false },
           { pubkey: usdcMint, isWritable: false, isSigner: false },
           { pubkey: vaultUsdc, isWritable: true, isSigner: false },
           { pubkey: userUsdcAta, isWritable: true, isSigner: false },
-          { pubkey: btUsdt, isWritable: false, isSigner: false },
+          { pubkey: btUsdt, isWritable: true, isSigner: false },
           { pubkey: usdtMint, isWritable: false, isSigner: false },
           { pubkey: vaultUsdt, isWritable: true, isSigner: false },
           { pubkey: userUsdtAta, isWritable: true, isSigner: false },
@@ -254,4 +387,35 @@ describe("withdraw_multi", () => {
       expect(err.toString()).to.include("InsufficientShares");
     }
   });
+
+  it("rejects the same leg passed twice", async () => {
+    const sharesBefore = await getTokenBalance(ctx.provider, userShareAta);
+    const usdcLeg = [
+      { pubkey: btUsdc, isWritable: true, isSigner: false },
+      { pubkey: usdcMint, isWritable: false, isSigner: false },
+      { pubkey: vaultUsdc, isWritable: true, isSigner: false },
+      { pubkey: userUsdcAta, isWritable: true, isSigner: false },
+    ];
+
+    try {
+      await ctx.program.methods
+        .withdrawMulti(new BN(Number(sharesBefore)))
+        .accounts({
+          user: user.publicKey,
+          receiver: user.publicKey,
+          basket: basketPda,
+          shareMint: shareMintKp.publicKey,
+          vaultAuthority,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          shareTokenProgram: TOKEN_PROGRAM_ID,
+          program: ctx.program.programId,
+        })
+        .remainingAccounts([...usdcLeg, ...usdcLeg])
+        .signers([user])
+        .rpc();
+      expect.fail("should have thrown");
+    } catch (err: any) {
+      expect(err.toString()).to.include("DuplicateLeg");
+    }
+  });
 });
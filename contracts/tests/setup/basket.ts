@@ -0,0 +1,126 @@
+import { BN } from "@coral-xyz/anchor";
+import { TOKEN_PROGRAM_ID, getAssociatedTokenAddress } from "@solana/spl-token";
+import { Keypair, PublicKey } from "@solana/web3.js";
+
+import type { TestContext } from "./program";
+import {
+  TOKEN_METADATA_PROGRAM_ID,
+  findBasketPda,
+  findVaultAuthorityPda,
+  findMintAuthorityPda,
+  findBasketTokenPda,
+  findFeeVaultPda,
+  findMetadataPda,
+} from "./pda";
+import { createTestMint } from "./token";
+
+/** Basket 1 holding a single 6-decimal USDC leg, under a config charging 0.2%. */
+export interface UsdcBasket {
+  ctx: TestContext;
+  basketId: BN;
+  basket: PublicKey;
+  vaultAuthority: PublicKey;
+  mintAuthority: PublicKey;
+  shareMint: PublicKey;
+  usdcMint: PublicKey;
+  basketToken: PublicKey;
+  feeVault: PublicKey;
+  vaultAta: PublicKey;
+}
+
+function encodeName(name: string): number[] {
+  const buf = Buffer.alloc(32, 0);
+  buf.write(name, "utf-8");
+  return Array.from(buf);
+}
+
+export async function createUsdcBasket(
+  ctx: TestContext,
+  name: string,
+  whitelistAuthority: PublicKey = Keypair.generate().publicKey,
+): Promise<UsdcBasket> {
+  const basketId = new BN(1);
+
+  await ctx.program.methods
+    .initConfig(20, whitelistAuthority, false)
+    .accounts({ program: ctx.program.programId })
+    .rpc();
+
+  const shareMintKp = Keypair.generate();
+  const [basket] = findBasketPda(basketId);
+  const [vaultAuthority] = findVaultAuthorityPda(basketId);
+  const [mintAuthority] = findMintAuthorityPda(basketId);
+
+  await ctx.program.methods
+    .createBasket(basketId, encodeName(name), null, false, "QSHARE", "")
+    .accounts({
+      shareMint: shareMintKp.publicKey,
+      shareMetadata: findMetadataPda(shareMintKp.publicKey)[0],
+      tokenMetadataProgram: TOKEN_METADATA_PROGRAM_ID,
+      treasury: null,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      program: ctx.program.programId,
+    })
+    .signers([shareMintKp])
+    .rpc();
+
+  const usdcMint = await createTestMint(ctx.provider, ctx.admin.publicKey, 6);
+  await ctx.program.methods
+    .addTokens(0)
+    .accounts({
+      basket,
+      underlyingMint: usdcMint,
+      vaultAuthority,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      program: ctx.program.programId,
+    })
+    .rpc();
+
+  return {
+    ctx,
+    basketId,
+    basket,
+    vaultAuthority,
+    mintAuthority,
+    shareMint: shareMintKp.publicKey,
+    usdcMint,
+    basketToken: findBasketTokenPda(basket, usdcMint)[0],
+    feeVault: findFeeVaultPda(basket, usdcMint)[0],
+    vaultAta: await getAssociatedTokenAddress(usdcMint, vaultAuthority, true),
+  };
+}
+
+/** Deposits `amount` USDC from `usdcAta` through `deposit_multi`. */
+export async function depositUsdc(
+  basket: UsdcBasket,
+  user: Keypair,
+  usdcAta: PublicKey,
+  amount: number,
+) {
+  const { ctx } = basket;
+  await ctx.program.methods
+    .depositMulti([new BN(amount)], null)
+    .accounts({
+      user: user.publicKey,
+      receiver: user.publicKey,
+      basket: basket.basket,
+      mintAuthority: basket.mintAuthority,
+      shareMint: basket.shareMint,
+      userAllowList: null,
+      complianceRoot: null,
+      instructionsSysvar: null,
+      attestationNonce: null,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      shareTokenProgram: TOKEN_PROGRAM_ID,
+      program: ctx.program.programId,
+    })
+    .remainingAccounts([
+      { pubkey: basket.basketToken, isWritable: true, isSigner: false },
+      { pubkey: basket.usdcMint, isWritable: false, isSigner: false },
+      { pubkey: usdcAta, isWritable: true, isSigner: false },
+      { pubkey: basket.vaultAta, isWritable: true, isSigner: false },
+      { pubkey: basket.feeVault, isWritable: true, isSigner: false },
+    ])
+    .signers([user])
+    .rpc();
+}
@@ -0,0 +1,200 @@
+import { BN } from "@coral-xyz/anchor";
+import { TOKEN_PROGRAM_ID, getAssociatedTokenAddress } from "@solana/spl-token";
+import { Keypair, PublicKey } from "@solana/web3.js";
+
+import { fundAccount, type TestContext } from "./program";
+import { findEpochQueuePda, findEpochPda, findComplianceRootPda } from "./pda";
+import { createTestAta, mintTestTokens, getTokenBalance } from "./token";
+import { createUsdcBasket, depositUsdc, type UsdcBasket } from "./basket";
+
+/** A single-leg USDC basket with an epoch operator set and the queue's escrow created. */
+export interface EpochFixture extends UsdcBasket {
+  operator: Keypair;
+  epochQueue: PublicKey;
+  pendingAta: PublicKey;
+  shareEscrow: PublicKey;
+}
+
+export interface EpochUser {
+  kp: Keypair;
+  usdcAta: PublicKey;
+}
+
+export interface ComplianceProof {
+  tier: number;
+  expiry: BN;
+  proof: number[][];
+}
+
+export async function createEpochFixture(
+  ctx: TestContext,
+  operator: Keypair,
+  whitelistAuthority?: PublicKey,
+): Promise<EpochFixture> {
+  const basket = await createUsdcBasket(ctx, "Epoch Test", whitelistAuthority);
+  fundAccount(ctx.svm, operator.publicKey);
+
+  await ctx.program.methods
+    .setEpochOperator(operator.publicKey)
+    .accounts({ basket: basket.basket, program: ctx.program.programId })
+    .rpc();
+  const [epochQueue] = findEpochQueuePda(basket.basket);
+
+  return {
+    ...basket,
+    operator,
+    epochQueue,
+    pendingAta: await createTestAta(ctx.provider, basket.usdcMint, epochQueue, true),
+    shareEscrow: await getAssociatedTokenAddress(basket.shareMint, basket.vaultAuthority, true),
+  };
+}
+
+/** A wallet holding 1,000 USDC and no shares. */
+export async function newEpochUser(fx: EpochFixture): Promise<EpochUser> {
+  const { ctx } = fx;
+  const kp = Keypair.generate();
+  fundAccount(ctx.svm, kp.publicKey);
+  const usdcAta = await createTestAta(ctx.provider, fx.usdcMint, kp.publicKey);
+  await mintTestTokens(ctx.provider, fx.usdcMint, usdcAta, ctx.admin, BigInt(1_000_000_000));
+  return { kp, usdcAta };
+}
+
+/** Instant deposit of the user's whole USDC balance through `deposit_multi`. */
+export async function depositNow(fx: EpochFixture, user: EpochUser) {
+  await depositUsdc(fx, user.kp, user.usdcAta, 1_000_000_000);
+}
+
+export async function requestDeposit(
+  fx: EpochFixture,
+  user: EpochUser,
+  amount: number,
+  proof: ComplianceProof | null = null,
+) {
+  const { ctx } = fx;
+  await ctx.program.methods
+    .requestDeposit([new BN(amount)], proof)
+    .accounts({
+      owner: user.kp.publicKey,
+      basket: fx.basket,
+      shareMint: fx.shareMint,
+      userAllowList: null,
+      complianceRoot: proof ? findComplianceRootPda(fx.basket)[0] : null,
+      instructionsSysvar: null,
+      attestationNonce: null,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      shareTokenProgram: TOKEN_PROGRAM_ID,
+      program: ctx.program.programId,
+    })
+    .remainingAccounts([
+      { pubkey: fx.basketToken, isWritable: false, isSigner: false },
+      { pubkey: fx.usdcMint, isWritable: false, isSigner: false },
+      { pubkey: user.usdcAta, isWritable: true, isSigner: false },
+      { pubkey: fx.vaultAta, isWritable: false, isSigner: false },
+      { pubkey: fx.pendingAta, isWritable: true, isSigner: false },
+      { pubkey: fx.feeVault, isWritable: true, isSigner: false },
+    ])
+    .signers([user.kp])
+    .rpc();
+}
+
+export async function requestRedeem(fx: EpochFixture, user: { kp: Keypair }, shares: number) {
+  const { ctx } = fx;
+  await ctx.program.methods
+    .requestRedeem(new BN(shares))
+    .accounts({
+      owner: user.kp.publicKey,
+      basket: fx.basket,
+      shareMint: fx.shareMint,
+      vaultAuthority: fx.vaultAuthority,
+      shareTokenProgram: TOKEN_PROGRAM_ID,
+      program: ctx.program.programId,
+    })
+    .signers([user.kp])
+    .rpc();
+}
+
+export async function currentEpoch(fx: EpochFixture): Promise<number> {
+  const queue = await fx.ctx.program.account.epochQueue.fetch(fx.epochQueue);
+  return queue.currentEpoch.toNumber();
+}
+
+/** Settle the current epoch, creating its payout ATA first. */
+export async function settle(fx: EpochFixture, signer: Keypair = fx.operator): Promise<number> {
+  const { ctx } = fx;
+  const epochId = await currentEpoch(fx);
+  const [epoch] = findEpochPda(fx.basket, epochId);
+  const payoutAta = await getAssociatedTokenAddress(fx.usdcMint, epoch, true);
+  if (!ctx.svm.getAccount(payoutAta)) {
+    await createTestAta(ctx.provider, fx.usdcMint, epoch, true);
+  }
+
+  await ctx.program.methods
+    .settleEpoch()
+    .accounts({
+      operator: signer.publicKey,
+      basket: fx.basket,
+      epoch,
+      shareMint: fx.shareMint,
+      mintAuthority: fx.mintAuthority,
+      vaultAuthority: fx.vaultAuthority,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      shareTokenProgram: TOKEN_PROGRAM_ID,
+      program: ctx.program.programId,
+    })
+    .remainingAccounts([
+      { pubkey: fx.basketToken, isWritable: true, isSigner: false },
+      { pubkey: fx.usdcMint, isWritable: false, isSigner: false },
+      { pubkey: fx.vaultAta, isWritable: true, isSigner: false },
+      { pubkey: fx.pendingAta, isWritable: true, isSigner: false },
+      { pubkey: payoutAta, isWritable: true, isSigner: false },
+    ])
+    .signers([signer])
+    .rpc();
+  return epochId;
+}
+
+export async function claimDeposit(fx: EpochFixture, user: { kp: Keypair }, epochId: number) {
+  const { ctx } = fx;
+  const [epoch] = findEpochPda(fx.basket, epochId);
+  await ctx.program.methods
+    .claimDeposit()
+    .accounts({
+      owner: user.kp.publicKey,
+      basket: fx.basket,
+      epoch,
+      shareMint: fx.shareMint,
+      vaultAuthority: fx.vaultAuthority,
+      shareTokenProgram: TOKEN_PROGRAM_ID,
+      program: ctx.program.programId,
+    })
+    .signers([user.kp])
+    .rpc();
+}
+
+export async function claimRedeem(fx: EpochFixture, user: EpochUser, epochId: number) {
+  const { ctx } = fx;
+  const [epoch] = findEpochPda(fx.basket, epochId);
+  const payoutAta = await getAssociatedTokenAddress(fx.usdcMint, epoch, true);
+  await ctx.program.methods
+    .claimRedeem()
+    .accounts({
+      owner: user.kp.publicKey,
+      basket: fx.basket,
+      epoch,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      program: ctx.program.programId,
+    })
+    .remainingAccounts([
+      { pubkey: fx.basketToken, isWritable: false, isSigner: false },
+      { pubkey: fx.usdcMint, isWritable: false, isSigner: false },
+      { pubkey: payoutAta, isWritable: true, isSigner: false },
+      { pubkey: user.usdcAta, isWritable: true, isSigner: false },
+    ])
+    .signers([user.kp])
+    .rpc();
+}
+
+export async function shareBalance(fx: EpochFixture, owner: PublicKey): Promise<number> {
+  const ata = await getAssociatedTokenAddress(fx.shareMint, owner);
+  return Number(await getTokenBalance(fx.ctx.provider, ata));
+}
@@ -1,6 +1,14 @@
-export { createTestContext, fundAccount, type TestContext } from "./program";
+export {
+  createTestContext,
+  fundAccount,
+  parseEvents,
+  expectError,
+  type TestContext,
+} from "./program";
 export {
   PROGRAM_ID,
+  SHARE_HOOK_PROGRAM_ID,
+  TOKEN_METADATA_PROGRAM_ID,
   findConfigPda,
   findBasketPda,
   findVaultAuthorityPda,
@@ -8,6 +16,20 @@ export {
   findBasketTokenPda,
   findFeeVaultPda,
   findUserAllowListPda,
+  findBasketPricePda,
+  findLockTiersPda,
+  findLockPositionPda,
+  findRewardPoolPda,
+  findStakePositionPda,
+  findEpochQueuePda,
+  findEpochPda,
+  findDepositRequestPda,
+  findRedeemRequestPda,
+  findBasketRegistryPda,
+  findComplianceRootPda,
+  findAttestationNoncePda,
+  findExtraAccountMetaListPda,
+  findMetadataPda,
 } from "./pda";
 export {
   createTestMint,
@@ -16,3 +38,28 @@ export {
   getTestAtaAddress,
   getTokenBalance,
 } from "./token";
+export {
+  createEpochFixture,
+  newEpochUser,
+  depositNow,
+  requestDeposit,
+  requestRedeem,
+  currentEpoch,
+  settle,
+  claimDeposit,
+  claimRedeem,
+  shareBalance,
+  type EpochFixture,
+  type EpochUser,
+  type ComplianceProof,
+} from "./epoch";
+export {
+  createRewardFixture,
+  newHolder,
+  fundRewards,
+  stake,
+  unstake,
+  claimRewards,
+  type RewardFixture,
+} from "./rewards";
+export { createUsdcBasket, depositUsdc, type UsdcBasket } from "./basket";
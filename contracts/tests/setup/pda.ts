@@ -5,6 +5,14 @@ export const PROGRAM_ID = new PublicKey(
   "2rQ6Auaeqovph67yWxhFpuhitrJJkGU3jrZwUUSYJKs6"
 );
 
+export const SHARE_HOOK_PROGRAM_ID = new PublicKey(
+  "Fg6DPXfnvSov9aDYBk6rZ2qsMP5SKyybMmQ2LbH4WsqZ"
+);
+
+export const TOKEN_METADATA_PROGRAM_ID = new PublicKey(
+  "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
+);
+
 const CONFIG_SEED = Buffer.from("config");
 const BASKET_SEED = Buffer.from("basket");
 const BASKET_TOKEN_SEED = Buffer.from("basket_token");
@@ -12,6 +20,20 @@ const VAULT_AUTHORITY_SEED = Buffer.from("vault_authority");
 const MINT_AUTHORITY_SEED = Buffer.from("mint_authority");
 const FEE_VAULT_SEED = Buffer.from("fee_vault");
 const USER_ALLOW_SEED = Buffer.from("user_allow");
+const BASKET_PRICE_SEED = Buffer.from("basket_price");
+const LOCK_TIERS_SEED = Buffer.from("lock_tiers");
+const LOCK_POSITION_SEED = Buffer.from("lock_position");
+const REWARD_POOL_SEED = Buffer.from("reward_pool");
+const STAKE_POSITION_SEED = Buffer.from("stake_position");
+const EPOCH_QUEUE_SEED = Buffer.from("epoch_queue");
+const EPOCH_SEED = Buffer.from("epoch");
+const DEPOSIT_REQUEST_SEED = Buffer.from("deposit_request");
+const REDEEM_REQUEST_SEED = Buffer.from("redeem_request");
+const BASKET_REGISTRY_SEED = Buffer.from("basket_registry");
+const COMPLIANCE_ROOT_SEED = Buffer.from("compliance_root");
+const ATTESTATION_NONCE_SEED = Buffer.from("attestation_nonce");
+const EXTRA_ACCOUNT_METAS_SEED = Buffer.from("extra-account-metas");
+const METADATA_SEED = Buffer.from("metadata");
 
 function u64ToLeBytes(value: BN | number): Buffer {
   const bn = new BN(value);
@@ -77,3 +99,128 @@ export function findUserAllowListPda(
     PROGRAM_ID
   );
 }
+
+export function findExtraAccountMetaListPda(
+  mint: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [EXTRA_ACCOUNT_METAS_SEED, mint.toBuffer()],
+    SHARE_HOOK_PROGRAM_ID
+  );
+}
+
+export function findBasketPricePda(basket: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [BASKET_PRICE_SEED, basket.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
+export function findLockTiersPda(basket: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [LOCK_TIERS_SEED, basket.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
+export function findLockPositionPda(
+  basket: PublicKey,
+  owner: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [LOCK_POSITION_SEED, basket.toBuffer(), owner.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
+export function findRewardPoolPda(
+  basket: PublicKey,
+  rewardMint: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [REWARD_POOL_SEED, basket.toBuffer(), rewardMint.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
+export function findStakePositionPda(
+  pool: PublicKey,
+  owner: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [STAKE_POSITION_SEED, pool.toBuffer(), owner.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
+export function findEpochQueuePda(basket: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [EPOCH_QUEUE_SEED, basket.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
+export function findEpochPda(
+  basket: PublicKey,
+  epochId: BN | number
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [EPOCH_SEED, basket.toBuffer(), u64ToLeBytes(epochId)],
+    PROGRAM_ID
+  );
+}
+
+export function findDepositRequestPda(
+  basket: PublicKey,
+  owner: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [DEPOSIT_REQUEST_SEED, basket.toBuffer(), owner.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
+export function findRedeemRequestPda(
+  basket: PublicKey,
+  owner: PublicKey
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [REDEEM_REQUEST_SEED, basket.toBuffer(), owner.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
+export function findBasketRegistryPda(page: number): [PublicKey, number] {
+  const pageBytes = Buffer.alloc(4);
+  pageBytes.writeUInt32LE(page);
+  return PublicKey.findProgramAddressSync(
+    [BASKET_REGISTRY_SEED, pageBytes],
+    PROGRAM_ID
+  );
+}
+
+export function findComplianceRootPda(basket: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [COMPLIANCE_ROOT_SEED, basket.toBuffer()],
+    PROGRAM_ID
+  );
+}
+
+export function findAttestationNoncePda(
+  basket: PublicKey,
+  user: PublicKey,
+  nonce: BN
+): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [ATTESTATION_NONCE_SEED, basket.toBuffer(), user.toBuffer(), u64ToLeBytes(nonce)],
+    PROGRAM_ID
+  );
+}
+
+/** Metaplex metadata account of a legacy SPL share mint. */
+export function findMetadataPda(mint: PublicKey): [PublicKey, number] {
+  return PublicKey.findProgramAddressSync(
+    [METADATA_SEED, TOKEN_METADATA_PROGRAM_ID.toBuffer(), mint.toBuffer()],
+    TOKEN_METADATA_PROGRAM_ID
+  );
+}
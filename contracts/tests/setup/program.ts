@@ -1,19 +1,31 @@
 import { fromWorkspace, LiteSVMProvider } from "anchor-litesvm";
-import { Program, Wallet, BN } from "@coral-xyz/anchor";
+import { Program, Wallet, BN, EventParser } from "@coral-xyz/anchor";
 import { Keypair, PublicKey, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import { expect } from "chai";
 import { LiteSVM } from "litesvm";
+import * as path from "path";
+
+import { TOKEN_METADATA_PROGRAM_ID } from "./pda";
 
 import type { Contracts } from "../../target/types/contracts";
+import type { ShareHook } from "../../target/types/share_hook";
 const IDL = require("../../target/idl/contracts.json");
+const SHARE_HOOK_IDL = require("../../target/idl/share_hook.json");
+
+// Legacy SPL share mints need Metaplex metadata. Dump the mainnet program
+// once with `npm run fixtures`.
+const TOKEN_METADATA_SO = path.join(__dirname, "../fixtures/mpl_token_metadata.so");
 
 export interface TestContext {
   svm: LiteSVM;
   provider: LiteSVMProvider;
   program: Program<Contracts>;
+  shareHook: Program<ShareHook>;
   admin: Keypair;
 }
 export function createTestContext(): TestContext {
   const svm = fromWorkspace(".");
+  svm.addProgramFromFile(TOKEN_METADATA_PROGRAM_ID, TOKEN_METADATA_SO);
   const admin = Keypair.generate();
 
   // Fund admin — generous amount, these aren't real funds
@@ -21,8 +33,9 @@ export function createTestContext(): TestContext {
 
   const provider = new LiteSVMProvider(svm, new Wallet(admin));
   const program = new Program<Contracts>(IDL, provider);
+  const shareHook = new Program<ShareHook>(SHARE_HOOK_IDL, provider);
 
-  return { svm, provider, program, admin };
+  return { svm, provider, program, shareHook, admin };
 }
 
 export function fundAccount(
@@ -32,3 +45,20 @@ export function fundAccount(
 ): void {
   svm.airdrop(pubkey, BigInt(lamports));
 }
+
+// Events logged with `emit!` by a transaction, including one that failed:
+// pass the rejected call's `err.logs`.
+export function parseEvents(program: Program<any>, logs: string[]) {
+  const parser = new EventParser(program.programId, program.coder);
+  return Array.from(parser.parseLogs(logs));
+}
+
+// Awaits `action` and asserts it was rejected with `code` in the error.
+export async function expectError(action: Promise<unknown>, code: string) {
+  try {
+    await action;
+    expect.fail("should have thrown");
+  } catch (err: any) {
+    expect(err.toString()).to.include(code);
+  }
+}
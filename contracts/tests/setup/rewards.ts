@@ -0,0 +1,136 @@
+import { BN } from "@coral-xyz/anchor";
+import { TOKEN_PROGRAM_ID, getAssociatedTokenAddress } from "@solana/spl-token";
+import { Keypair, PublicKey } from "@solana/web3.js";
+
+import { fundAccount, type TestContext } from "./program";
+import { findRewardPoolPda } from "./pda";
+import { createTestMint, createTestAta, mintTestTokens, getTokenBalance } from "./token";
+import { createUsdcBasket, depositUsdc, type UsdcBasket } from "./basket";
+
+const DAY = 86_400;
+
+/**
+ * A single-leg USDC basket with a reward pool boosting 30-day commitments
+ * to 1.5x and 90-day ones to 2x. The admin holds 10,000 reward tokens.
+ */
+export interface RewardFixture extends UsdcBasket {
+  rewardMint: PublicKey;
+  rewardPool: PublicKey;
+  rewardVault: PublicKey;
+  adminRewardAta: PublicKey;
+}
+
+export async function createRewardFixture(ctx: TestContext): Promise<RewardFixture> {
+  const basket = await createUsdcBasket(ctx, "Reward Test");
+
+  const rewardMint = await createTestMint(ctx.provider, ctx.admin.publicKey, 6);
+  const adminRewardAta = await createTestAta(ctx.provider, rewardMint, ctx.admin.publicKey);
+  await mintTestTokens(ctx.provider, rewardMint, adminRewardAta, ctx.admin, BigInt(10_000_000_000));
+  const [rewardPool] = findRewardPoolPda(basket.basket, rewardMint);
+
+  await ctx.program.methods
+    .initRewardPool([
+      { minDurationSecs: new BN(30 * DAY), multiplierBps: 15_000 },
+      { minDurationSecs: new BN(90 * DAY), multiplierBps: 20_000 },
+    ])
+    .accounts({
+      basket: basket.basket,
+      rewardMint,
+      rewardTokenProgram: TOKEN_PROGRAM_ID,
+      program: ctx.program.programId,
+    })
+    .rpc();
+
+  return {
+    ...basket,
+    rewardMint,
+    rewardPool,
+    rewardVault: await getAssociatedTokenAddress(rewardMint, rewardPool, true),
+    adminRewardAta,
+  };
+}
+
+/** A wallet holding QSHARE from a 1,000 USDC deposit. */
+export async function newHolder(fx: RewardFixture): Promise<Keypair> {
+  const { ctx } = fx;
+  const holder = Keypair.generate();
+  fundAccount(ctx.svm, holder.publicKey);
+  const usdcAta = await createTestAta(ctx.provider, fx.usdcMint, holder.publicKey);
+  await mintTestTokens(ctx.provider, fx.usdcMint, usdcAta, ctx.admin, BigInt(1_000_000_000));
+  await depositUsdc(fx, holder, usdcAta, 1_000_000_000);
+  return holder;
+}
+
+export async function fundRewards(fx: RewardFixture, amount: number, durationSecs: number) {
+  const { ctx } = fx;
+  await ctx.program.methods
+    .fundRewards(new BN(amount), new BN(durationSecs))
+    .accounts({
+      basket: fx.basket,
+      rewardPool: fx.rewardPool,
+      rewardMint: fx.rewardMint,
+      funderRewardAta: fx.adminRewardAta,
+      rewardVault: fx.rewardVault,
+      rewardTokenProgram: TOKEN_PROGRAM_ID,
+      program: ctx.program.programId,
+    })
+    .rpc();
+}
+
+export async function stake(
+  fx: RewardFixture,
+  holder: Keypair,
+  amount: number,
+  lockDurationSecs: number,
+) {
+  const { ctx } = fx;
+  await ctx.program.methods
+    .stake(new BN(amount), new BN(lockDurationSecs))
+    .accounts({
+      owner: holder.publicKey,
+      basket: fx.basket,
+      rewardPool: fx.rewardPool,
+      shareMint: fx.shareMint,
+      vaultAuthority: fx.vaultAuthority,
+      shareTokenProgram: TOKEN_PROGRAM_ID,
+      program: ctx.program.programId,
+    })
+    .signers([holder])
+    .rpc();
+}
+
+export async function unstake(fx: RewardFixture, holder: Keypair, amount: number) {
+  const { ctx } = fx;
+  await ctx.program.methods
+    .unstake(new BN(amount))
+    .accounts({
+      owner: holder.publicKey,
+      basket: fx.basket,
+      rewardPool: fx.rewardPool,
+      shareMint: fx.shareMint,
+      vaultAuthority: fx.vaultAuthority,
+      shareTokenProgram: TOKEN_PROGRAM_ID,
+      program: ctx.program.programId,
+    })
+    .signers([holder])
+    .rpc();
+}
+
+/** Claims for `holder` and returns their reward token balance afterwards. */
+export async function claimRewards(fx: RewardFixture, holder: Keypair): Promise<bigint> {
+  const { ctx } = fx;
+  await ctx.program.methods
+    .claimRewards()
+    .accounts({
+      owner: holder.publicKey,
+      rewardPool: fx.rewardPool,
+      rewardMint: fx.rewardMint,
+      rewardVault: fx.rewardVault,
+      rewardTokenProgram: TOKEN_PROGRAM_ID,
+      program: ctx.program.programId,
+    })
+    .signers([holder])
+    .rpc();
+  const ata = await getAssociatedTokenAddress(fx.rewardMint, holder.publicKey);
+  return getTokenBalance(ctx.provider, ata);
+}
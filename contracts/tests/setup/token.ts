@@ -18,7 +18,8 @@ import { LiteSVMProvider } from "anchor-litesvm";
 export async function createTestMint(
   provider: LiteSVMProvider,
   mintAuthority: PublicKey,
-  decimals: number = 6
+  decimals: number = 6,
+  freezeAuthority: PublicKey | null = null
 ): Promise<PublicKey> {
   const mintKeypair = Keypair.generate();
 
@@ -38,7 +39,7 @@ export async function createTestMint(
       mintKeypair.publicKey,
       decimals,
       mintAuthority,
-      null, // freeze authority
+      freezeAuthority,
       TOKEN_PROGRAM_ID
     )
   );
@@ -51,9 +52,10 @@ export async function createTestMint(
 export async function createTestAta(
   provider: LiteSVMProvider,
   mint: PublicKey,
-  owner: PublicKey
+  owner: PublicKey,
+  allowOwnerOffCurve: boolean = false
 ): Promise<PublicKey> {
-  const ata = await getAssociatedTokenAddress(mint, owner);
+  const ata = await getAssociatedTokenAddress(mint, owner, allowOwnerOffCurve);
 
   const tx = new Transaction().add(
     createAssociatedTokenAccountInstruction(
@@ -99,8 +101,14 @@ export async function getTestAtaAddress(
 
 export async function getTokenBalance(
   provider: LiteSVMProvider,
-  tokenAccount: PublicKey
+  tokenAccount: PublicKey,
+  programId: PublicKey = TOKEN_PROGRAM_ID
 ): Promise<bigint> {
-  const account = await getAccount(provider.connection, tokenAccount);
+  const account = await getAccount(
+    provider.connection,
+    tokenAccount,
+    undefined,
+    programId
+  );
   return account.amount;
 }
@@ -1,24 +1,67 @@
-import type { Address, Rpc, GetAccountInfoApi, GetProgramAccountsApi, Base64EncodedBytes } from "@solana/kit";
-import { fetchEncodedAccount, getAddressEncoder } from "@solana/kit";
+import type {
+  Address,
+  Rpc,
+  GetAccountInfoApi,
+  GetMultipleAccountsApi,
+  GetProgramAccountsApi,
+  Base64EncodedBytes,
+} from "@solana/kit";
+import { fetchEncodedAccount, fetchEncodedAccounts, getAddressEncoder } from "@solana/kit";
 import { PROGRAM_ID, DISCRIMINATORS, DISCRIMINATOR_SIZE } from "./constants";
 import {
   getConfigPda,
   getBasketPda,
   getBasketTokenPda,
   getUserAllowListPda,
+  getBasketPricePda,
+  getLockTiersPda,
+  getLockPositionPda,
+  getRewardPoolPda,
+  getStakePositionPda,
+  getEpochQueuePda,
+  getEpochPda,
+  getDepositRequestPda,
+  getRedeemRequestPda,
+  getBasketRegistryPda,
+  getComplianceRootPda,
 } from "./pdas";
 import {
   type ParsedConfig,
   type ParsedBasket,
   type ParsedBasketToken,
   type ParsedUserAllowList,
+  type ParsedBasketPrice,
+  type ParsedLockTiers,
+  type ParsedLockPosition,
+  type ParsedRewardPool,
+  type ParsedStakePosition,
+  type ParsedEpochQueue,
+  type ParsedEpoch,
+  type ParsedDepositRequest,
+  type ParsedRedeemRequest,
+  type ParsedBasketRegistry,
+  type ParsedComplianceRoot,
   parseConfig,
   parseBasket,
   parseBasketToken,
   parseUserAllowList,
+  parseBasketPrice,
+  parseLockTiers,
+  parseLockPosition,
+  parseRewardPool,
+  parseStakePosition,
+  parseEpochQueue,
+  parseEpoch,
+  parseDepositRequest,
+  parseRedeemRequest,
+  parseBasketRegistry,
+  parseComplianceRoot,
 } from "./types";
 
-type FetchRpc = Rpc<GetAccountInfoApi & GetProgramAccountsApi>;
+type FetchRpc = Rpc<GetAccountInfoApi & GetMultipleAccountsApi & GetProgramAccountsApi>;
+
+// getMultipleAccounts accepts at most 100 addresses per call.
+const MULTIPLE_ACCOUNTS_LIMIT = 100;
 
 async function fetchAndDecode<T>(
   rpc: FetchRpc,
@@ -61,6 +104,100 @@ export async function fetchUserAllowList(
   return fetchAndDecode(rpc, address, parseUserAllowList);
 }
 
+export async function fetchBasketPrice(
+  rpc: FetchRpc,
+  basket: Address,
+): Promise<ParsedBasketPrice | null> {
+  const [address] = await getBasketPricePda(basket);
+  return fetchAndDecode(rpc, address, parseBasketPrice);
+}
+
+export async function fetchLockTiers(
+  rpc: FetchRpc,
+  basket: Address,
+): Promise<ParsedLockTiers | null> {
+  const [address] = await getLockTiersPda(basket);
+  return fetchAndDecode(rpc, address, parseLockTiers);
+}
+
+export async function fetchLockPosition(
+  rpc: FetchRpc,
+  basket: Address,
+  owner: Address,
+): Promise<ParsedLockPosition | null> {
+  const [address] = await getLockPositionPda(basket, owner);
+  return fetchAndDecode(rpc, address, parseLockPosition);
+}
+
+export async function fetchRewardPool(
+  rpc: FetchRpc,
+  basket: Address,
+  rewardMint: Address,
+): Promise<ParsedRewardPool | null> {
+  const [address] = await getRewardPoolPda(basket, rewardMint);
+  return fetchAndDecode(rpc, address, parseRewardPool);
+}
+
+export async function fetchStakePosition(
+  rpc: FetchRpc,
+  pool: Address,
+  owner: Address,
+): Promise<ParsedStakePosition | null> {
+  const [address] = await getStakePositionPda(pool, owner);
+  return fetchAndDecode(rpc, address, parseStakePosition);
+}
+
+export async function fetchEpochQueue(
+  rpc: FetchRpc,
+  basket: Address,
+): Promise<ParsedEpochQueue | null> {
+  const [address] = await getEpochQueuePda(basket);
+  return fetchAndDecode(rpc, address, parseEpochQueue);
+}
+
+export async function fetchEpoch(
+  rpc: FetchRpc,
+  basket: Address,
+  epochId: bigint,
+): Promise<ParsedEpoch | null> {
+  const [address] = await getEpochPda(basket, epochId);
+  return fetchAndDecode(rpc, address, parseEpoch);
+}
+
+export async function fetchDepositRequest(
+  rpc: FetchRpc,
+  basket: Address,
+  owner: Address,
+): Promise<ParsedDepositRequest | null> {
+  const [address] = await getDepositRequestPda(basket, owner);
+  return fetchAndDecode(rpc, address, parseDepositRequest);
+}
+
+export async function fetchRedeemRequest(
+  rpc: FetchRpc,
+  basket: Address,
+  owner: Address,
+): Promise<ParsedRedeemRequest | null> {
+  const [address] = await getRedeemRequestPda(basket, owner);
+  return fetchAndDecode(rpc, address, parseRedeemRequest);
+}
+
+export async function fetchBasketRegistry(
+  rpc: FetchRpc,
+  page: number,
+): Promise<ParsedBasketRegistry | null> {
+  const [address] = await getBasketRegistryPda(page);
+  return fetchAndDecode(rpc, address, parseBasketRegistry);
+}
+
+export async function fetchComplianceRoot(
+  rpc: FetchRpc,
+  basket: Address,
+): Promise<ParsedComplianceRoot | null> {
+  const [address] = await getComplianceRootPda(basket);
+  return fetchAndDecode(rpc, address, parseComplianceRoot);
+}
+
 function extractAccountData(account: { data: unknown }): Uint8Array {
   const d = account.data;
   if (d instanceof Uint8Array) return d;
@@ -69,25 +206,24 @@ function extractAccountData(account: { data: unknown }): Uint8Array {
   return new Uint8Array(d as ArrayBuffer);
 }
 
+/** Every basket in creation order, read through the registry pages. */
 export async function fetchAllBaskets(rpc: FetchRpc): Promise<ParsedBasket[]> {
-  const accounts = await rpc
-    .getProgramAccounts(PROGRAM_ID, {
-      encoding: "base64",
-      filters: [
-        {
-          memcmp: {
-            offset: BigInt(0),
-            bytes: Buffer.from(DISCRIMINATORS.basket).toString("base64") as Base64EncodedBytes,
-            encoding: "base64",
-          },
-        },
-      ],
-    })
-    .send();
+  const config = await fetchConfig(rpc);
+  if (!config) return [];
 
-  return (accounts as unknown as { account: { data: unknown } }[]).map((a) =>
-    parseBasket(extractAccountData(a.account)),
+  const pages = await Promise.all(
+    Array.from({ length: config.registryPage + 1 }, (_, page) => fetchBasketRegistry(rpc, page)),
   );
+  const addresses = pages.flatMap((page) => page?.baskets ?? []);
+
+  const baskets: ParsedBasket[] = [];
+  for (let i = 0; i < addresses.length; i += MULTIPLE_ACCOUNTS_LIMIT) {
+    const accounts = await fetchEncodedAccounts(rpc, addresses.slice(i, i + MULTIPLE_ACCOUNTS_LIMIT));
+    for (const account of accounts) {
+      if (account.exists) baskets.push(parseBasket(new Uint8Array(account.data)));
+    }
+  }
+  return baskets;
 }
 
 export async function fetchAllBasketTokens(
@@ -2,13 +2,23 @@ import {
   getStructCodec,
   getU8Codec,
   getU16Codec,
+  getU32Codec,
   getU64Codec,
+  getU128Codec,
+  getI64Codec,
   getBooleanCodec,
   getAddressCodec,
   fixCodecSize,
   getBytesCodec,
+  getArrayCodec,
 } from "@solana/kit";
-import { DISCRIMINATOR_SIZE } from "./constants";
+import {
+  DISCRIMINATOR_SIZE,
+  MAX_LOCK_TIERS,
+  MAX_REWARD_BOOSTS,
+  MAX_TOKENS_PER_BASKET,
+  REGISTRY_PAGE_SIZE,
+} from "./constants";
 
 const addressCodec = getAddressCodec();
 
@@ -19,6 +29,13 @@ export const configCodec = getStructCodec([
   ["complianceEnabled", getBooleanCodec()],
   ["version", getU8Codec()],
   ["bump", getU8Codec()],
+  ["mintExtensionPolicy", getU8Codec()],
+  ["permissionlessCreation", getBooleanCodec()],
+  ["creationFeeLamports", getU64Codec()],
+  ["creationBondLamports", getU64Codec()],
+  ["treasury", addressCodec],
+  ["nextBasketId", getU64Codec()],
+  ["registryPage", getU32Codec()],
 ]);
 
 export const basketCodec = getStructCodec([
@@ -34,6 +51,20 @@ export const basketCodec = getStructCodec([
   ["basketBump", getU8Codec()],
   ["vaultAuthorityBump", getU8Codec()],
   ["mintAuthorityBump", getU8Codec()],
+  ["mintPricingMode", getU8Codec()],
+  ["redeemPricingMode", getU8Codec()],
+  ["padding", fixCodecSize(getBytesCodec(), 6)],
+  ["maxTotalValue", getU64Codec()],
+  ["maxUserShares", getU64Codec()],
+  ["maxWithdrawBps", getU16Codec()],
+  ["emergencyMode", getU8Codec()],
+  ["limiterPadding", fixCodecSize(getBytesCodec(), 5)],
+  ["withdrawWindowSecs", getI64Codec()],
+  ["windowStartTs", getI64Codec()],
+  ["windowStartSupply", getU64Codec()],
+  ["burnedInWindow", getU64Codec()],
+  ["pendingOwner", addressCodec],
+  ["bondLamports", getU64Codec()],
 ]);
 
 export const basketTokenCodec = getStructCodec([
@@ -44,6 +75,14 @@ export const basketTokenCodec = getStructCodec([
   ["decimals", getU8Codec()],
   ["enabled", getBooleanCodec()],
   ["bump", getU8Codec()],
+  ["extensionFlags", getU8Codec()],
+  ["lastPrice", getU64Codec()],
+  ["priceCumulative", getU128Codec()],
+  ["lastUpdateTs", getI64Codec()],
+  ["twapAnchorCumulative", getU128Codec()],
+  ["twapAnchorTs", getI64Codec()],
+  ["twapPrice", getU64Codec()],
+  ["twapUpdatedTs", getI64Codec()],
 ]);
 
 export const userAllowListCodec = getStructCodec([
@@ -53,6 +92,132 @@ export const userAllowListCodec = getStructCodec([
   ["bump", getU8Codec()],
 ]);
 
+export const basketPriceCodec = getStructCodec([
+  ["basket", addressCodec],
+  ["navPerShare", getU64Codec()],
+  ["confidence", getU64Codec()],
+  ["totalSupply", getU64Codec()],
+  ["lastUpdateSlot", getU64Codec()],
+  ["lastUpdateTs", getI64Codec()],
+  ["navDecimals", getU8Codec()],
+  ["version", getU8Codec()],
+  ["bump", getU8Codec()],
+]);
+
+export const lockTierCodec = getStructCodec([
+  ["minDurationSecs", getI64Codec()],
+  ["earlyExitPenaltyBps", getU16Codec()],
+]);
+
+export const lockTiersCodec = getStructCodec([
+  ["basket", addressCodec],
+  ["tiers", getArrayCodec(lockTierCodec, { size: MAX_LOCK_TIERS })],
+  ["tierCount", getU8Codec()],
+  ["bump", getU8Codec()],
+]);
+
+export const lockPositionCodec = getStructCodec([
+  ["basket", addressCodec],
+  ["owner", addressCodec],
+  ["amount", getU64Codec()],
+  ["lockedTs", getI64Codec()],
+  ["unlockTs", getI64Codec()],
+  ["tier", getU8Codec()],
+  ["earlyExitPenaltyBps", getU16Codec()],
+  ["bump", getU8Codec()],
+]);
+
+export const rewardBoostCodec = getStructCodec([
+  ["minDurationSecs", getI64Codec()],
+  ["multiplierBps", getU16Codec()],
+]);
+
+export const rewardPoolCodec = getStructCodec([
+  ["basket", addressCodec],
+  ["rewardMint", addressCodec],
+  ["rewardVault", addressCodec],
+  ["totalStaked", getU64Codec()],
+  ["totalWeight", getU64Codec()],
+  ["rewardRate", getU64Codec()],
+  ["periodEnd", getI64Codec()],
+  ["lastUpdateTs", getI64Codec()],
+  ["accRewardPerWeight", getU128Codec()],
+  ["boosts", getArrayCodec(rewardBoostCodec, { size: MAX_REWARD_BOOSTS })],
+  ["boostCount", getU8Codec()],
+  ["bump", getU8Codec()],
+]);
+
+export const stakePositionCodec = getStructCodec([
+  ["pool", addressCodec],
+  ["owner", addressCodec],
+  ["amount", getU64Codec()],
+  ["weight", getU64Codec()],
+  ["multiplierBps", getU16Codec()],
+  ["unlockTs", getI64Codec()],
+  ["rewardDebt", getU128Codec()],
+  ["rewardsOwed", getU64Codec()],
+  ["bump", getU8Codec()],
+]);
+
+export const epochQueueCodec = getStructCodec([
+  ["basket", addressCodec],
+  ["operator", addressCodec],
+  ["currentEpoch", getU64Codec()],
+  ["pendingDepositValue", getU64Codec()],
+  ["pendingRedeemShares", getU64Codec()],
+  ["unclaimedRedeemShares", getU64Codec()],
+  ["bump", getU8Codec()],
+]);
+
+export const epochCodec = getStructCodec([
+  ["basket", addressCodec],
+  ["epochId", getU64Codec()],
+  ["navPerShare", getU64Codec()],
+  ["unclaimedDepositValue", getU64Codec()],
+  ["unclaimedShares", getU64Codec()],
+  ["unclaimedRedeemShares", getU64Codec()],
+  ["settledTs", getI64Codec()],
+  ["bump", getU8Codec()],
+]);
+
+export const requestLegCodec = getStructCodec([
+  ["mint", addressCodec],
+  ["amount", getU64Codec()],
+]);
+
+export const depositRequestCodec = getStructCodec([
+  ["basket", addressCodec],
+  ["owner", addressCodec],
+  ["epoch", getU64Codec()],
+  ["value", getU64Codec()],
+  ["legs", getArrayCodec(requestLegCodec, { size: MAX_TOKENS_PER_BASKET })],
+  ["legCount", getU8Codec()],
+  ["bump", getU8Codec()],
+]);
+
+export const redeemRequestCodec = getStructCodec([
+  ["basket", addressCodec],
+  ["owner", addressCodec],
+  ["epoch", getU64Codec()],
+  ["shares", getU64Codec()],
+  ["bump", getU8Codec()],
+]);
+
+export const basketRegistryCodec = getStructCodec([
+  ["page", getU32Codec()],
+  ["count", getU8Codec()],
+  ["bump", getU8Codec()],
+  ["baskets", getArrayCodec(addressCodec, { size: REGISTRY_PAGE_SIZE })],
+]);
+
+export const complianceRootCodec = getStructCodec([
+  ["basket", addressCodec],
+  ["root", fixCodecSize(getBytesCodec(), 32)],
+  ["version", getU64Codec()],
+  ["updatedAt", getI64Codec()],
+  ["bump", getU8Codec()],
+]);
+
 export function decodeAccount<T>(
   codec: { read: (bytes: Uint8Array, offset: number) => [T, number] },
   data: Uint8Array,
@@ -10,7 +10,20 @@ export const VAULT_AUTHORITY_SEED = new TextEncoder().encode("vault_authority");
 export const MINT_AUTHORITY_SEED = new TextEncoder().encode("mint_authority");
 export const FEE_VAULT_SEED = new TextEncoder().encode("fee_vault");
 export const USER_ALLOW_SEED = new TextEncoder().encode("user_allow");
+export const BASKET_PRICE_SEED = new TextEncoder().encode("basket_price");
+export const LOCK_TIERS_SEED = new TextEncoder().encode("lock_tiers");
+export const LOCK_POSITION_SEED = new TextEncoder().encode("lock_position");
+export const REWARD_POOL_SEED = new TextEncoder().encode("reward_pool");
+export const STAKE_POSITION_SEED = new TextEncoder().encode("stake_position");
+export const EPOCH_QUEUE_SEED = new TextEncoder().encode("epoch_queue");
+export const EPOCH_SEED = new TextEncoder().encode("epoch");
+export const DEPOSIT_REQUEST_SEED = new TextEncoder().encode("deposit_request");
+export const REDEEM_REQUEST_SEED = new TextEncoder().encode("redeem_request");
+export const BASKET_REGISTRY_SEED = new TextEncoder().encode("basket_registry");
+export const COMPLIANCE_ROOT_SEED = new TextEncoder().encode("compliance_root");
+export const ATTESTATION_NONCE_SEED = new TextEncoder().encode("attestation_nonce");
 export const EVENT_AUTHORITY_SEED = new TextEncoder().encode("__event_authority");
+export const METADATA_SEED = new TextEncoder().encode("metadata");
 
 export const SYSTEM_PROGRAM_ID =
   "11111111111111111111111111111111" as Address<"11111111111111111111111111111111">;
@@ -18,15 +31,39 @@ export const TOKEN_PROGRAM_ID =
   "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA" as Address<"TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA">;
 export const ASSOCIATED_TOKEN_PROGRAM_ID =
   "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL" as Address<"ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL">;
+export const TOKEN_METADATA_PROGRAM_ID =
+  "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s" as Address<"metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s">;
+export const ED25519_PROGRAM_ID =
+  "Ed25519SigVerify111111111111111111111111111" as Address<"Ed25519SigVerify111111111111111111111111111">;
+export const SYSVAR_INSTRUCTIONS_ID =
+  "Sysvar1nstructions1111111111111111111111111" as Address<"Sysvar1nstructions1111111111111111111111111">;
 
 export const FEE_BPS_MIN = 10;
 export const FEE_BPS_MAX = 50;
 export const MAX_TOKENS_PER_BASKET = 10;
 export const MAX_NAME_LEN = 32;
+export const REGISTRY_PAGE_SIZE = 64;
 export const QSHARE_DECIMALS = 6;
+export const NAV_DECIMALS = 9;
+
+export const PRICING_MODE_SPOT = 0;
+export const PRICING_MODE_TWAP = 1;
+export const PRICING_MODE_MIN = 2;
+export const PRICING_MODE_MAX = 3;
+
+export const MAX_LOCK_TIERS = 4;
+export const EARLY_EXIT_PENALTY_BPS_MAX = 5_000;
+
+export const MAX_REWARD_BOOSTS = 4;
+export const REWARD_MULTIPLIER_BPS_MAX = 30_000;
 
 export const DEPOSIT_ACCOUNTS_PER_TOKEN = 5;
 export const WITHDRAW_ACCOUNTS_PER_TOKEN = 4;
+export const REQUEST_DEPOSIT_ACCOUNTS_PER_TOKEN = 6;
+export const CANCEL_DEPOSIT_ACCOUNTS_PER_TOKEN = 4;
+export const SETTLE_ACCOUNTS_PER_TOKEN = 5;
+export const CLAIM_REDEEM_ACCOUNTS_PER_TOKEN = 4;
+export const CLOSE_BASKET_ACCOUNTS_PER_TOKEN = 6;
 
 export const DISCRIMINATOR_SIZE = 8;
 
@@ -35,15 +72,65 @@ export const DISCRIMINATORS = {
   basket: new Uint8Array([219, 79, 107, 135, 231, 243, 218, 248]),
   basketToken: new Uint8Array([128, 193, 26, 209, 248, 236, 236, 212]),
   userAllowList: new Uint8Array([137, 62, 29, 246, 93, 233, 210, 156]),
+  basketPrice: new Uint8Array([219, 148, 57, 24, 180, 126, 153, 56]),
+  lockTiers: new Uint8Array([239, 99, 104, 203, 208, 149, 191, 134]),
+  lockPosition: new Uint8Array([95, 235, 144, 78, 23, 171, 45, 9]),
+  rewardPool: new Uint8Array([134, 121, 197, 211, 133, 154, 82, 32]),
+  stakePosition: new Uint8Array([78, 165, 30, 111, 171, 125, 11, 220]),
+  epochQueue: new Uint8Array([29, 63, 240, 124, 184, 4, 13, 49]),
+  epoch: new Uint8Array([93, 83, 120, 89, 151, 138, 152, 108]),
+  depositRequest: new Uint8Array([86, 27, 56, 8, 25, 62, 62, 243]),
+  redeemRequest: new Uint8Array([103, 82, 139, 51, 199, 234, 111, 115]),
+  basketRegistry: new Uint8Array([88, 13, 22, 72, 126, 230, 175, 237]),
+  complianceRoot: new Uint8Array([82, 155, 48, 171, 92, 202, 228, 251]),
+  attestationNonce: new Uint8Array([233, 109, 6, 91, 66, 143, 182, 243]),
 } as const;
 
 export const IX_DISCRIMINATORS = {
   initConfig: new Uint8Array([23, 235, 115, 232, 168, 96, 1, 231]),
   setConfig: new Uint8Array([108, 158, 154, 175, 212, 98, 52, 66]),
+  setCreationPolicy: new Uint8Array([108, 181, 116, 203, 225, 160, 30, 208]),
+  slashBasketBond: new Uint8Array([12, 137, 135, 38, 45, 225, 136, 43]),
+  migrateConfig: new Uint8Array([92, 131, 58, 105, 210, 154, 224, 193]),
+  migrateBasket: new Uint8Array([175, 192, 1, 69, 202, 121, 115, 124]),
   createBasket: new Uint8Array([47, 105, 155, 148, 15, 169, 202, 211]),
+  setBasketPricing: new Uint8Array([39, 105, 83, 242, 243, 31, 115, 163]),
+  updateBasket: new Uint8Array([217, 126, 45, 225, 229, 7, 68, 72]),
+  transferBasketOwnership: new Uint8Array([178, 176, 87, 234, 193, 16, 189, 66]),
+  acceptBasketOwnership: new Uint8Array([38, 214, 41, 219, 93, 210, 214, 122]),
+  closeBasket: new Uint8Array([193, 180, 238, 204, 116, 243, 127, 45]),
+  updateBasketLimits: new Uint8Array([6, 254, 85, 147, 73, 235, 226, 241]),
+  setEmergencyMode: new Uint8Array([79, 138, 190, 94, 0, 162, 205, 253]),
   addTokens: new Uint8Array([28, 218, 30, 209, 175, 155, 153, 240]),
   depositMulti: new Uint8Array([249, 115, 113, 22, 161, 239, 200, 3]),
+  mintExactShares: new Uint8Array([149, 86, 40, 12, 193, 119, 70, 210]),
   withdrawMulti: new Uint8Array([251, 170, 190, 101, 141, 83, 90, 187]),
+  withdrawExactAmounts: new Uint8Array([242, 13, 141, 180, 31, 166, 191, 251]),
   updateAllowList: new Uint8Array([165, 6, 31, 198, 26, 197, 208, 181]),
+  updateAllowListBatch: new Uint8Array([25, 37, 28, 43, 169, 151, 198, 244]),
+  setComplianceRoot: new Uint8Array([168, 75, 130, 91, 96, 171, 173, 215]),
+  closeAllowListEntry: new Uint8Array([217, 136, 12, 138, 205, 149, 220, 131]),
+  closeAllowListEntries: new Uint8Array([228, 198, 227, 70, 135, 200, 52, 162]),
   verifyBasketOwner: new Uint8Array([56, 82, 151, 199, 34, 243, 50, 105]),
+  previewDeposit: new Uint8Array([16, 61, 8, 235, 146, 126, 80, 84]),
+  previewWithdraw: new Uint8Array([66, 3, 217, 38, 187, 176, 144, 135]),
+  refreshBasketPrice: new Uint8Array([12, 1, 201, 142, 101, 200, 28, 138]),
+  getBasketNav: new Uint8Array([223, 134, 63, 176, 48, 98, 246, 206]),
+  setLockTiers: new Uint8Array([239, 108, 172, 187, 148, 125, 9, 114]),
+  lockShares: new Uint8Array([22, 197, 217, 24, 91, 91, 177, 70]),
+  unlockShares: new Uint8Array([66, 248, 156, 242, 54, 2, 176, 160]),
+  exitLockEarly: new Uint8Array([57, 134, 15, 95, 139, 1, 203, 227]),
+  initRewardPool: new Uint8Array([40, 91, 119, 84, 139, 51, 53, 228]),
+  fundRewards: new Uint8Array([114, 64, 163, 112, 175, 167, 19, 121]),
+  stake: new Uint8Array([206, 176, 202, 18, 200, 209, 179, 108]),
+  unstake: new Uint8Array([90, 95, 107, 42, 205, 124, 50, 225]),
+  claimRewards: new Uint8Array([4, 144, 132, 71, 116, 23, 151, 80]),
+  pokeStake: new Uint8Array([90, 199, 139, 89, 123, 245, 125, 77]),
+  setEpochOperator: new Uint8Array([231, 8, 59, 55, 23, 102, 133, 212]),
+  requestDeposit: new Uint8Array([243, 202, 197, 215, 135, 97, 213, 109]),
+  requestRedeem: new Uint8Array([105, 49, 44, 38, 207, 241, 33, 173]),
+  settleEpoch: new Uint8Array([148, 223, 178, 38, 201, 158, 167, 13]),
+  cancelRequest: new Uint8Array([65, 196, 177, 247, 83, 151, 33, 130]),
+  claimDeposit: new Uint8Array([201, 106, 1, 224, 122, 144, 210, 155]),
+  claimRedeem: new Uint8Array([125, 14, 137, 237, 160, 63, 225, 226]),
 } as const;
@@ -0,0 +1,21 @@
+import { type Address, type Instruction, AccountRole } from "@solana/kit";
+import { PROGRAM_ID, IX_DISCRIMINATORS } from "../constants";
+import { getEventAuthorityPda } from "../pdas";
+
+export async function buildAcceptBasketOwnershipIx(
+  newOwner: Address,
+  basketAddress: Address,
+): Promise<Instruction> {
+  const [eventAuthority] = await getEventAuthorityPda();
+
+  return {
+    programAddress: PROGRAM_ID,
+    accounts: [
+      { address: newOwner, role: AccountRole.READONLY_SIGNER },
+      { address: basketAddress, role: AccountRole.WRITABLE },
+      { address: eventAuthority, role: AccountRole.READONLY },
+      { address: PROGRAM_ID, role: AccountRole.READONLY },
+    ],
+    data: new Uint8Array(IX_DISCRIMINATORS.acceptBasketOwnership),
+  };
+}
@@ -23,6 +23,7 @@ export async function buildAddTokensIx(
   underlyingMint: Address,
   vaultAuthority: Address,
   tokenProgram: Address,
+  acknowledgedExtensions: number = 0,
 ): Promise<Instruction> {
   const [configAddress] = await getConfigPda();
   const [basketTokenAddress] = await getBasketTokenPda(basketAddress, underlyingMint);
@@ -47,6 +48,6 @@ export async function buildAddTokensIx(
       { address: eventAuthority, role: AccountRole.READONLY },
       { address: PROGRAM_ID, role: AccountRole.READONLY },
     ],
-    data: new Uint8Array([...IX_DISCRIMINATORS.addTokens]),
+    data: new Uint8Array([...IX_DISCRIMINATORS.addTokens, acknowledgedExtensions]),
   };
 }
@@ -0,0 +1,80 @@
+import { type Address, type Instruction, AccountRole } from "@solana/kit";
+import { PROGRAM_ID, IX_DISCRIMINATORS } from "../constants";
+import {
+  getBasketTokenPda,
+  getEpochQueuePda,
+  getDepositRequestPda,
+  getRedeemRequestPda,
+  getEventAuthorityPda,
+  getAssociatedTokenAddress,
+} from "../pdas";
+
+// Cancels the owner's deposit and/or redemption request in the epoch still
+// open. The owner's token ATAs must exist for a deposit refund.
+export async function buildCancelRequestIx(
+  owner: Address,
+  basketAddress: Address,
+  shareMint: Address,
+  vaultAuthority: Address,
+  tokenMints: Address[],
+  tokenProgram: Address,
+  shareTokenProgram: Address,
+  cancelDeposit: boolean,
+  cancelRedeem: boolean,
+  // Extra accounts for the share transfer hook, if the mint has one.
+  hookAccounts: { address: Address; role: AccountRole }[] = [],
+): Promise<Instruction> {
+  const [epochQueue] = await getEpochQueuePda(basketAddress);
+  const [depositRequest] = await getDepositRequestPda(basketAddress, owner);
+  const [redeemRequest] = await getRedeemRequestPda(basketAddress, owner);
+  const [ownerShareAta] = await getAssociatedTokenAddress(owner, shareTokenProgram, shareMint);
+  const [shareEscrow] = await getAssociatedTokenAddress(vaultAuthority, shareTokenProgram, shareMint);
+  const [eventAuthority] = await getEventAuthorityPda();
+
+  // Absent optional accounts are passed as the program id.
+  const accounts: { address: Address; role: AccountRole }[] = [
+    { address: owner, role: AccountRole.WRITABLE_SIGNER },
+    { address: basketAddress, role: AccountRole.READONLY },
+    { address: epochQueue, role: AccountRole.WRITABLE },
+    {
+      address: cancelDeposit ? depositRequest : PROGRAM_ID,
+      role: cancelDeposit ? AccountRole.WRITABLE : AccountRole.READONLY,
+    },
+    {
+      address: cancelRedeem ? redeemRequest : PROGRAM_ID,
+      role: cancelRedeem ? AccountRole.WRITABLE : AccountRole.READONLY,
+    },
+    { address: shareMint, role: AccountRole.READONLY },
+    { address: vaultAuthority, role: AccountRole.READONLY },
+    {
+      address: cancelRedeem ? ownerShareAta : PROGRAM_ID,
+      role: cancelRedeem ? AccountRole.WRITABLE : AccountRole.READONLY,
+    },
+    {
+      address: cancelRedeem ? shareEscrow : PROGRAM_ID,
+      role: cancelRedeem ? AccountRole.WRITABLE : AccountRole.READONLY,
+    },
+    { address: tokenProgram, role: AccountRole.READONLY },
+    { address: shareTokenProgram, role: AccountRole.READONLY },
+    { address: eventAuthority, role: AccountRole.READONLY },
+    { address: PROGRAM_ID, role: AccountRole.READONLY },
+  ];
+
+  if (cancelDeposit) {
+    for (const mint of tokenMints) {
+      const [basketTokenPda] = await getBasketTokenPda(basketAddress, mint);
+      const [pendingAta] = await getAssociatedTokenAddress(epochQueue, tokenProgram, mint);
+      const [ownerAta] = await getAssociatedTokenAddress(owner, tokenProgram, mint);
+
+      accounts.push(
+        { address: basketTokenPda, role: AccountRole.READONLY },
+        { address: mint, role: AccountRole.READONLY },
+        { address: pendingAta, role: AccountRole.WRITABLE },
+        { address: ownerAta, role: AccountRole.WRITABLE },
+      );
+    }
+  }
+  accounts.push(...hookAccounts);
+
+  return { programAddress: PROGRAM_ID, accounts, data: new Uint8Array(IX_DISCRIMINATORS.cancelRequest) };
+}
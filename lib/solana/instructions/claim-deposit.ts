@@ -0,0 +1,52 @@
+import { type Address, type Instruction, AccountRole } from "@solana/kit";
+import {
+  PROGRAM_ID,
+  SYSTEM_PROGRAM_ID,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  IX_DISCRIMINATORS,
+} from "../constants";
+import {
+  getDepositRequestPda,
+  getEpochPda,
+  getEventAuthorityPda,
+  getAssociatedTokenAddress,
+} from "../pdas";
+
+// `epochId` is the epoch the request was queued in.
+export async function buildClaimDepositIx(
+  owner: Address,
+  basketAddress: Address,
+  epochId: bigint,
+  shareMint: Address,
+  vaultAuthority: Address,
+  shareTokenProgram: Address,
+  // Extra accounts for the share transfer hook, if the mint has one.
+  hookAccounts: { address: Address; role: AccountRole }[] = [],
+): Promise<Instruction> {
+  const [depositRequest] = await getDepositRequestPda(basketAddress, owner);
+  const [epoch] = await getEpochPda(basketAddress, epochId);
+  const [ownerShareAta] = await getAssociatedTokenAddress(owner, shareTokenProgram, shareMint);
+  const [shareEscrow] = await getAssociatedTokenAddress(vaultAuthority, shareTokenProgram, shareMint);
+  const [eventAuthority] = await getEventAuthorityPda();
+
+  return {
+    programAddress: PROGRAM_ID,
+    accounts: [
+      { address: owner, role: AccountRole.WRITABLE_SIGNER },
+      { address: basketAddress, role: AccountRole.READONLY },
+      { address: depositRequest, role: AccountRole.WRITABLE },
+      { address: epoch, role: AccountRole.WRITABLE },
+      { address: shareMint, role: AccountRole.READONLY },
+      { address: ownerShareAta, role: AccountRole.WRITABLE },
+      { address: vaultAuthority, role: AccountRole.READONLY },
+      { address: shareEscrow, role: AccountRole.WRITABLE },
+      { address: shareTokenProgram, role: AccountRole.READONLY },
+      { address: ASSOCIATED_TOKEN_PROGRAM_ID, role: AccountRole.READONLY },
+      { address: SYSTEM_PROGRAM_ID, role: AccountRole.READONLY },
+      { address: eventAuthority, role: AccountRole.READONLY },
+      { address: PROGRAM_ID, role: AccountRole.READONLY },
+      ...hookAccounts,
+    ],
+    data: new Uint8Array(IX_DISCRIMINATORS.claimDeposit),
+  };
+}
@@ -0,0 +1,54 @@
+import { type Address, type Instruction, AccountRole } from "@solana/kit";
+import { PROGRAM_ID, IX_DISCRIMINATORS } from "../constants";
+import {
+  getBasketTokenPda,
+  getRedeemRequestPda,
+  getEpochPda,
+  getEpochQueuePda,
+  getEventAuthorityPda,
+  getAssociatedTokenAddress,
+} from "../pdas";
+
+// `epochId` is the epoch the request was queued in.
+export async function buildClaimRedeemIx(
+  owner: Address,
+  basketAddress: Address,
+  epochId: bigint,
+  tokenMints: Address[],
+  tokenProgram: Address,
+): Promise<Instruction> {
+  const [redeemRequest] = await getRedeemRequestPda(basketAddress, owner);
+  const [epoch] = await getEpochPda(basketAddress, epochId);
+  const [epochQueue] = await getEpochQueuePda(basketAddress);
+  const [eventAuthority] = await getEventAuthorityPda();
+
+  const accounts: { address: Address; role: AccountRole }[] = [
+    { address: owner, role: AccountRole.WRITABLE_SIGNER },
+    { address: basketAddress, role: AccountRole.READONLY },
+    { address: redeemRequest, role: AccountRole.WRITABLE },
+    { address: epoch, role: AccountRole.WRITABLE },
+    { address: epochQueue, role: AccountRole.WRITABLE },
+    { address: tokenProgram, role: AccountRole.READONLY },
+    { address: eventAuthority, role: AccountRole.READONLY },
+    { address: PROGRAM_ID, role: AccountRole.READONLY },
+  ];
+
+  for (const mint of tokenMints) {
+    const [basketTokenPda] = await getBasketTokenPda(basketAddress, mint);
+    const [payoutAta] = await getAssociatedTokenAddress(epoch, tokenProgram, mint);
+    const [ownerAta] = await getAssociatedTokenAddress(owner, tokenProgram, mint);
+
+    accounts.push(
+      { address: basketTokenPda, role: AccountRole.READONLY },
+      { address: mint, role: AccountRole.READONLY },
+      { address: payoutAta, role: AccountRole.WRITABLE },
+      { address: ownerAta, role: AccountRole.WRITABLE },
+    );
+  }
+
+  return {
+    programAddress: PROGRAM_ID,
+    accounts,
+    data: new Uint8Array(IX_DISCRIMINATORS.claimRedeem),
+  };
+}
@@ -0,0 +1,44 @@
+import { type Address, type Instruction, AccountRole } from "@solana/kit";
+import {
+  PROGRAM_ID,
+  SYSTEM_PROGRAM_ID,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  IX_DISCRIMINATORS,
+} from "../constants";
+import {
+  getRewardPoolPda,
+  getStakePositionPda,
+  getEventAuthorityPda,
+  getAssociatedTokenAddress,
+} from "../pdas";
+
+export async function buildClaimRewardsIx(
+  owner: Address,
+  basketAddress: Address,
+  rewardMint: Address,
+  rewardTokenProgram: Address,
+): Promise<Instruction> {
+  const [rewardPool] = await getRewardPoolPda(basketAddress, rewardMint);
+  const [stakePosition] = await getStakePositionPda(rewardPool, owner);
+  const [rewardVault] = await getAssociatedTokenAddress(rewardPool, rewardTokenProgram, rewardMint);
+  const [ownerRewardAta] = await getAssociatedTokenAddress(owner, rewardTokenProgram, rewardMint);
+  const [eventAuthority] = await getEventAuthorityPda();
+
+  return {
+    programAddress: PROGRAM_ID,
+    accounts: [
+      { address: owner, role: AccountRole.WRITABLE_SIGNER },
+      { address: rewardPool, role: AccountRole.WRITABLE },
+      { address: stakePosition, role: AccountRole.WRITABLE },
+      { address: rewardMint, role: AccountRole.READONLY },
+      { address: rewardVault, role: AccountRole.WRITABLE },
+      { address: ownerRewardAta, role: AccountRole.WRITABLE },
+      { address: rewardTokenProgram, role: AccountRole.READONLY },
+      { address: ASSOCIATED_TOKEN_PROGRAM_ID, role: AccountRole.READONLY },
+      { address: SYSTEM_PROGRAM_ID, role: AccountRole.READONLY },
+      { address: eventAuthority, role: AccountRole.READONLY },
+      { address: PROGRAM_ID, role: AccountRole.READONLY },
+    ],
+    data: new Uint8Array(IX_DISCRIMINATORS.claimRewards),
+  };
+}
@@ -0,0 +1,33 @@
+import { type Address, type Instruction, AccountRole } from "@solana/kit";
+import { PROGRAM_ID, IX_DISCRIMINATORS } from "../constants";
+import { getConfigPda, getUserAllowListPda, getEventAuthorityPda } from "../pdas";
+
+// Whitelist authority only. Closes one entry per (basket, user) pair; all
+// rent goes to `recipient`. Keep batches small enough to fit a transaction.
+export async function buildCloseAllowListEntriesIx(
+  authority: Address,
+  entries: { basket: Address; user: Address }[],
+  recipient: Address,
+): Promise<Instruction> {
+  const [configAddress] = await getConfigPda();
+  const [eventAuthority] = await getEventAuthorityPda();
+
+  const accounts: { address: Address; role: AccountRole }[] = [
+    { address: authority, role: AccountRole.READONLY_SIGNER },
+    { address: configAddress, role: AccountRole.READONLY },
+    { address: recipient, role: AccountRole.WRITABLE },
+    { address: eventAuthority, role: AccountRole.READONLY },
+    { address: PROGRAM_ID, role: AccountRole.READONLY },
+  ];
+
+  for (const { basket, user } of entries) {
+    const [userAllowListAddress] = await getUserAllowListPda(basket, user);
+    accounts.push({ address: userAllowListAddress, role: AccountRole.WRITABLE });
+  }
+
+  return {
+    programAddress: PROGRAM_ID,
+    accounts,
+    data: new Uint8Array(IX_DISCRIMINATORS.closeAllowListEntries),
+  };
+}
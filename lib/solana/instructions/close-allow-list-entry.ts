@@ -0,0 +1,28 @@
+import { type Address, type Instruction, AccountRole } from "@solana/kit";
+import { PROGRAM_ID, IX_DISCRIMINATORS } from "../constants";
+import { getConfigPda, getUserAllowListPda, getEventAuthorityPda } from "../pdas";
+
+// Whitelist authority only. The entry's rent goes to `recipient`.
+export async function buildCloseAllowListEntryIx(
+  authority: Address,
+  basketAddress: Address,
+  userAddress: Address,
+  recipient: Address,
+): Promise<Instruction> {
+  const [configAddress] = await getConfigPda();
+  const [userAllowListAddress] = await getUserAllowListPda(basketAddress, userAddress);
+  const [eventAuthority] = await getEventAuthorityPda();
+
+  return {
+    programAddress: PROGRAM_ID,
+    accounts: [
+      { address: authority, role: AccountRole.READONLY_SIGNER },
+      { address: configAddress, role: AccountRole.READONLY },
+      { address: userAllowListAddress, role: AccountRole.WRITABLE },
+      { address: recipient, role: AccountRole.WRITABLE },
+      { address: eventAuthority, role: AccountRole.READONLY },
+      { address: PROGRAM_ID, role: AccountRole.READONLY },
+    ],
+    data: new Uint8Array(IX_DISCRIMINATORS.closeAllowListEntry),
+  };
+}
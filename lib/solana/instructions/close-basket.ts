@@ -0,0 +1,79 @@
+import { type Address, type Instruction, AccountRole } from "@solana/kit";
+import { PROGRAM_ID, IX_DISCRIMINATORS } from "../constants";
+import {
+  getConfigPda,
+  getBasketTokenPda,
+  getBasketPricePda,
+  getLockTiersPda,
+  getEpochQueuePda,
+  getComplianceRootPda,
+  getFeeVaultPda,
+  getEventAuthorityPda,
+  getAssociatedTokenAddress,
+} from "../pdas";
+
+// Basket owner or protocol admin. Requires zero share supply, empty vaults and no queued deposit
+// or unclaimed redemption; `tokenMints` must list every leg of the basket.
+// Rent goes to the basket owner whoever signs. Fees are swept to the ATAs of
+// `treasury` (the config's treasury), which must already exist.
+// Settled epochs and their payout ATAs are not closed and stay behind.
+export async function buildCloseBasketIx(
+  authority: Address,
+  owner: Address,
+  treasury: Address,
+  basketAddress: Address,
+  shareMint: Address,
+  vaultAuthority: Address,
+  tokenMints: Address[],
+  tokenProgram: Address,
+  shareTokenProgram: Address,
+): Promise<Instruction> {
+  const [configAddress] = await getConfigPda();
+  const [basketPrice] = await getBasketPricePda(basketAddress);
+  const [lockTiers] = await getLockTiersPda(basketAddress);
+  const [epochQueue] = await getEpochQueuePda(basketAddress);
+  const [complianceRoot] = await getComplianceRootPda(basketAddress);
+  const [shareEscrow] = await getAssociatedTokenAddress(vaultAuthority, shareTokenProgram, shareMint);
+  const [eventAuthority] = await getEventAuthorityPda();
+
+  const accounts: { address: Address; role: AccountRole }[] = [
+    { address: authority, role: AccountRole.READONLY_SIGNER },
+    { address: configAddress, role: AccountRole.READONLY },
+    { address: owner, role: AccountRole.WRITABLE },
+    { address: basketAddress, role: AccountRole.WRITABLE },
+    { address: basketPrice, role: AccountRole.WRITABLE },
+    { address: lockTiers, role: AccountRole.WRITABLE },
+    { address: epochQueue, role: AccountRole.WRITABLE },
+    { address: complianceRoot, role: AccountRole.WRITABLE },
+    { address: shareMint, role: AccountRole.READONLY },
+    { address: vaultAuthority, role: AccountRole.READONLY },
+    { address: shareEscrow, role: AccountRole.WRITABLE },
+    { address: tokenProgram, role: AccountRole.READONLY },
+    { address: shareTokenProgram, role: AccountRole.READONLY },
+    { address: eventAuthority, role: AccountRole.READONLY },
+    { address: PROGRAM_ID, role: AccountRole.READONLY },
+  ];
+
+  for (const mint of tokenMints) {
+    const [basketTokenPda] = await getBasketTokenPda(basketAddress, mint);
+    const [vaultAta] = await getAssociatedTokenAddress(vaultAuthority, tokenProgram, mint);
+    const [feeVault] = await getFeeVaultPda(basketAddress, mint);
+    const [pendingAta] = await getAssociatedTokenAddress(epochQueue, tokenProgram, mint);
+    const [treasuryAta] = await getAssociatedTokenAddress(treasury, tokenProgram, mint);
+
+    accounts.push(
+      { address: basketTokenPda, role: AccountRole.WRITABLE },
+      { address: mint, role: AccountRole.READONLY },
+      { address: vaultAta, role: AccountRole.WRITABLE },
+      { address: feeVault, role: AccountRole.WRITABLE },
+      { address: pendingAta, role: AccountRole.WRITABLE },
+      { address: treasuryAta, role: AccountRole.WRITABLE },
+    );
+  }
+
+  return {
+    programAddress: PROGRAM_ID,
+    accounts,
+    data: new Uint8Array(IX_DISCRIMINATORS.closeBasket),
+  };
+}
@@ -0,0 +1,65 @@
+import {
+  type Address,
+  type Instruction,
+  getAddressEncoder,
+  getI64Codec,
+  getU16Codec,
+  getU64Codec,
+} from "@solana/kit";
+import { ED25519_PROGRAM_ID, PROGRAM_ID } from "../constants";
+
+// Ed25519 program layout: count, padding, then seven u16 offsets/indices per
+// signature. Index 0xffff points at this instruction's own data.
+const HEADER_LEN = 2;
+const OFFSETS_LEN = 14;
+const SELF_INDEX = 0xffff;
+
+/** Bytes the whitelist authority signs to admit `user` into `basket` on this program. */
+export function getComplianceAttestationMessage(
+  basket: Address,
+  user: Address,
+  expiry: bigint,
+  nonce: bigint,
+): Uint8Array {
+  const addr = getAddressEncoder();
+  return new Uint8Array([
+    ...addr.encode(PROGRAM_ID),
+    ...addr.encode(basket),
+    ...addr.encode(user),
+    ...getI64Codec().encode(expiry),
+    ...getU64Codec().encode(nonce),
+  ]);
+}
+
+// Must sit immediately before the deposit instruction it authorises.
+export function buildComplianceAttestationIx(
+  signer: Address,
+  signature: Uint8Array,
+  message: Uint8Array,
+): Instruction {
+  const publicKeyOffset = HEADER_LEN + OFFSETS_LEN;
+  const signatureOffset = publicKeyOffset + 32;
+  const messageOffset = signatureOffset + 64;
+  const u16 = getU16Codec();
+
+  const data = new Uint8Array([
+    1,
+    0,
+    ...u16.encode(signatureOffset),
+    ...u16.encode(SELF_INDEX),
+    ...u16.encode(publicKeyOffset),
+    ...u16.encode(SELF_INDEX),
+    ...u16.encode(messageOffset),
+    ...u16.encode(message.length),
+    ...u16.encode(SELF_INDEX),
+    ...getAddressEncoder().encode(signer),
+    ...signature,
+    ...message,
+  ]);
+
+  return {
+    programAddress: ED25519_PROGRAM_ID,
+    accounts: [],
+    data,
+  };
+}
@@ -4,14 +4,25 @@ import {
   AccountRole,
   getU64Codec,
   getU16Codec,
+  getU32Codec,
 } from "@solana/kit";
-import { PROGRAM_ID, SYSTEM_PROGRAM_ID, IX_DISCRIMINATORS, MAX_NAME_LEN } from "../constants";
+import {
+  PROGRAM_ID,
+  SYSTEM_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+  TOKEN_METADATA_PROGRAM_ID,
+  IX_DISCRIMINATORS,
+  MAX_NAME_LEN,
+} from "../constants";
 import {
   getConfigPda,
   getBasketPda,
   getVaultAuthorityPda,
   getMintAuthorityPda,
+  getBasketPricePda,
+  getBasketRegistryPda,
   getEventAuthorityPda,
+  getMetadataPda,
 } from "../pdas";
 
 function encodeOption16(value: number | null): Uint8Array {
@@ -27,19 +38,37 @@ function encodeName(name: string): Uint8Array {
   return buf;
 }
 
+function encodeString(value: string): Uint8Array {
+  const bytes = new TextEncoder().encode(value);
+  return new Uint8Array([...getU32Codec().encode(bytes.length), ...bytes]);
+}
+
+// `basketId` and `registryPage` must be the config's `nextBasketId` and
+// `registryPage`. A legacy SPL share mint gets a Metaplex metadata account;
+// Token-2022 mints carry their metadata in the mint.
 export async function buildCreateBasketIx(
   payer: Address,
   basketId: bigint,
+  registryPage: number,
   name: string,
   shareMintAddress: Address,
   tokenProgram: Address,
   feeBpsOverride: number | null = null,
+  shareTransferHook: boolean = false,
+  symbol: string = "QSHARE",
+  uri: string = "",
+  // Required when a non-admin creator owes the config's creation fee.
+  treasury: Address | null = null,
 ): Promise<Instruction> {
   const [configAddress] = await getConfigPda();
   const [basketAddress] = await getBasketPda(basketId);
   const [vaultAuthority] = await getVaultAuthorityPda(basketId);
   const [mintAuthority] = await getMintAuthorityPda(basketId);
+  const [basketPrice] = await getBasketPricePda(basketAddress);
+  const [basketRegistry] = await getBasketRegistryPda(registryPage);
   const [eventAuthority] = await getEventAuthorityPda();
+  const legacyMint = tokenProgram === TOKEN_PROGRAM_ID;
+  const [shareMetadata] = await getMetadataPda(shareMintAddress);
 
   const u64 = getU64Codec();
   const data = new Uint8Array([
@@ -47,17 +76,34 @@ export async function buildCreateBasketIx(
     ...u64.encode(basketId),
     ...encodeName(name),
     ...encodeOption16(feeBpsOverride),
+    shareTransferHook ? 1 : 0,
+    ...encodeString(symbol),
+    ...encodeString(uri),
   ]);
 
   return {
     programAddress: PROGRAM_ID,
     accounts: [
       { address: payer, role: AccountRole.WRITABLE_SIGNER },
-      { address: configAddress, role: AccountRole.READONLY },
+      { address: configAddress, role: AccountRole.WRITABLE },
+      { address: basketRegistry, role: AccountRole.WRITABLE },
       { address: basketAddress, role: AccountRole.WRITABLE },
+      { address: basketPrice, role: AccountRole.WRITABLE },
       { address: vaultAuthority, role: AccountRole.READONLY },
       { address: mintAuthority, role: AccountRole.READONLY },
       { address: shareMintAddress, role: AccountRole.WRITABLE_SIGNER },
+      ...(legacyMint
+        ? [
+            { address: shareMetadata, role: AccountRole.WRITABLE },
+            { address: TOKEN_METADATA_PROGRAM_ID, role: AccountRole.READONLY },
+          ]
+        : [
+            { address: PROGRAM_ID, role: AccountRole.READONLY },
+            { address: PROGRAM_ID, role: AccountRole.READONLY },
+          ]),
+      treasury
+        ? { address: treasury, role: AccountRole.WRITABLE }
+        : { address: PROGRAM_ID, role: AccountRole.READONLY },
       { address: tokenProgram, role: AccountRole.READONLY },
       { address: SYSTEM_PROGRAM_ID, role: AccountRole.READONLY },
       { address: eventAuthority, role: AccountRole.READONLY },
@@ -2,24 +2,49 @@ import {
   type Address,
   type Instruction,
   AccountRole,
-  getU64Codec,
+  getU8Codec,
   getU32Codec,
+  getU64Codec,
+  getI64Codec,
 } from "@solana/kit";
 import {
   PROGRAM_ID,
   SYSTEM_PROGRAM_ID,
   ASSOCIATED_TOKEN_PROGRAM_ID,
+  SYSVAR_INSTRUCTIONS_ID,
   IX_DISCRIMINATORS,
 } from "../constants";
 import {
   getConfigPda,
   getMintAuthorityPda,
   getBasketTokenPda,
+  getBasketPricePda,
+  getComplianceRootPda,
+  getAttestationNoncePda,
   getFeeVaultPda,
   getEventAuthorityPda,
   getAssociatedTokenAddress,
 } from "../pdas";
 
+/** Merkle proof of (receiver, tier, expiry) under the basket's compliance root. */
+export type ComplianceProof = {
+  tier: number;
+  expiry: bigint;
+  proof: Uint8Array[];
+};
+
+/** Borsh `Option<ComplianceProof>` argument shared by the deposit paths. */
+export function encodeComplianceProof(proof: ComplianceProof | null): Uint8Array {
+  if (!proof) return new Uint8Array([0]);
+  return new Uint8Array([
+    1,
+    ...getU8Codec().encode(proof.tier),
+    ...getI64Codec().encode(proof.expiry),
+    ...getU32Codec().encode(proof.proof.length),
+    ...proof.proof.flatMap((node) => [...node]),
+  ]);
+}
+
 export async function buildDepositMultiIx(
   payer: Address,
   basketAddress: Address,
@@ -30,12 +55,11 @@ export async function buildDepositMultiIx(
   vaultAuthority: Address,
   tokenProgram: Address,
   userAllowList: Address | null = null,
+  shareTokenProgram: Address = tokenProgram,
+  receiver: Address = payer,
+  complianceProof: ComplianceProof | null = null,
+  attestationNonce: bigint | null = null,
 ): Promise<Instruction> {
-  const [configAddress] = await getConfigPda();
-  const [mintAuthority] = await getMintAuthorityPda(basketId);
-  const [userShareAta] = await getAssociatedTokenAddress(payer, tokenProgram, shareMint);
-  const [eventAuthority] = await getEventAuthorityPda();
-
   const u64 = getU64Codec();
   const u32 = getU32Codec();
   const amountsData = new Uint8Array([
@@ -43,23 +67,89 @@ export async function buildDepositMultiIx(
     ...amounts.flatMap((a) => [...u64.encode(a)]),
   ]);
 
-  const data = new Uint8Array([...IX_DISCRIMINATORS.depositMulti, ...amountsData]);
+  const data = new Uint8Array([
+    ...IX_DISCRIMINATORS.depositMulti,
+    ...amountsData,
+    ...encodeComplianceProof(complianceProof),
+  ]);
+
+  return {
+    programAddress: PROGRAM_ID,
+    accounts: await getDepositAccounts(
+      payer,
+      basketAddress,
+      basketId,
+      shareMint,
+      tokenMints,
+      vaultAuthority,
+      tokenProgram,
+      userAllowList,
+      shareTokenProgram,
+      receiver,
+      complianceProof !== null,
+      attestationNonce,
+    ),
+    data,
+  };
+}
+
+/**
+ * Account list shared by `deposit_multi` and `mint_exact_shares`. Pass
+ * `attestationNonce` when the transaction carries an Ed25519 attestation
+ * (see `buildComplianceAttestationIx`) right before the deposit.
+ */
+export async function getDepositAccounts(
+  payer: Address,
+  basketAddress: Address,
+  basketId: bigint,
+  shareMint: Address,
+  tokenMints: Address[],
+  vaultAuthority: Address,
+  tokenProgram: Address,
+  userAllowList: Address | null,
+  shareTokenProgram: Address,
+  receiver: Address,
+  withComplianceRoot: boolean,
+  attestationNonce: bigint | null,
+): Promise<{ address: Address; role: AccountRole }[]> {
+  const [configAddress] = await getConfigPda();
+  const [mintAuthority] = await getMintAuthorityPda(basketId);
+  const [receiverShareAta] = await getAssociatedTokenAddress(receiver, shareTokenProgram, shareMint);
+  const [basketPrice] = await getBasketPricePda(basketAddress);
+  const [eventAuthority] = await getEventAuthorityPda();
 
   const accounts: { address: Address; role: AccountRole }[] = [
     { address: payer, role: AccountRole.WRITABLE_SIGNER },
+    { address: receiver, role: AccountRole.READONLY },
     { address: configAddress, role: AccountRole.READONLY },
     { address: basketAddress, role: AccountRole.READONLY },
+    { address: basketPrice, role: AccountRole.WRITABLE },
     { address: mintAuthority, role: AccountRole.READONLY },
     { address: shareMint, role: AccountRole.WRITABLE },
-    { address: userShareAta, role: AccountRole.WRITABLE },
+    { address: receiverShareAta, role: AccountRole.WRITABLE },
   ];
 
-  if (userAllowList) {
-    accounts.push({ address: userAllowList, role: AccountRole.READONLY });
-  }
-
+  // Absent optional accounts are passed as the program id.
+  const complianceRoot = withComplianceRoot
+    ? (await getComplianceRootPda(basketAddress))[0]
+    : PROGRAM_ID;
+  const nonceAccount =
+    attestationNonce === null
+      ? PROGRAM_ID
+      : (await getAttestationNoncePda(basketAddress, receiver, attestationNonce))[0];
   accounts.push(
+    { address: userAllowList ?? PROGRAM_ID, role: AccountRole.READONLY },
+    { address: complianceRoot, role: AccountRole.READONLY },
+    {
+      address: attestationNonce === null ? PROGRAM_ID : SYSVAR_INSTRUCTIONS_ID,
+      role: AccountRole.READONLY,
+    },
+    {
+      address: nonceAccount,
+      role: attestationNonce === null ? AccountRole.READONLY : AccountRole.WRITABLE,
+    },
     { address: tokenProgram, role: AccountRole.READONLY },
+    { address: shareTokenProgram, role: AccountRole.READONLY },
     { address: ASSOCIATED_TOKEN_PROGRAM_ID, role: AccountRole.READONLY },
     { address: SYSTEM_PROGRAM_ID, role: AccountRole.READONLY },
     { address: eventAuthority, role: AccountRole.READONLY },
@@ -74,7 +164,7 @@ export async function buildDepositMultiIx(
     const [feeVault] = await getFeeVaultPda(basketAddress, mint);
 
     accounts.push(
-      { address: basketTokenPda, role: AccountRole.READONLY },
+      { address: basketTokenPda, role: AccountRole.WRITABLE },
       { address: mint, role: AccountRole.READONLY },
       { address: userAta, role: AccountRole.WRITABLE },
       { address: vaultAta, role: AccountRole.WRITABLE },
@@ -82,9 +172,5 @@ export async function buildDepositMultiIx(
     );
   }
 
-  return {
-    programAddress: PROGRAM_ID,
-    accounts,
-    data,
-  };
+  return accounts;
 }
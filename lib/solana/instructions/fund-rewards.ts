@@ -0,0 +1,47 @@
+import {
+  type Address,
+  type Instruction,
+  AccountRole,
+  getI64Codec,
+  getU64Codec,
+} from "@solana/kit";
+import { PROGRAM_ID, IX_DISCRIMINATORS } from "../constants";
+import { getConfigPda, getRewardPoolPda, getEventAuthorityPda, getAssociatedTokenAddress } from "../pdas";
+
+export async function buildFundRewardsIx(
+  authority: Address,
+  basketAddress: Address,
+  rewardMint: Address,
+  rewardTokenProgram: Address,
+  amount: bigint,
+  durationSecs: bigint,
+): Promise<Instruction> {
+  const [rewardPool] = await getRewardPoolPda(basketAddress, rewardMint);
+  const [rewardVault] = await getAssociatedTokenAddress(rewardPool, rewardTokenProgram, rewardMint);
+  const [funderAta] = await getAssociatedTokenAddress(authority, rewardTokenProgram, rewardMint);
+  const [configAddress] = await getConfigPda();
+  const [eventAuthority] = await getEventAuthorityPda();
+
+  const data = new Uint8Array([
+    ...IX_DISCRIMINATORS.fundRewards,
+    ...getU64Codec().encode(amount),
+    ...getI64Codec().encode(durationSecs),
+  ]);
+
+  return {
+    programAddress: PROGRAM_ID,
+    accounts: [
+      { address: authority, role: AccountRole.READONLY_SIGNER },
+      { address: configAddress, role: AccountRole.READONLY },
+      { address: basketAddress, role: AccountRole.READONLY },
+      { address: rewardPool, role: AccountRole.WRITABLE },
+      { address: rewardMint, role: AccountRole.READONLY },
+      { address: funderAta, role: AccountRole.WRITABLE },
+      { address: rewardVault, role: AccountRole.WRITABLE },
+      { address: rewardTokenProgram, role: AccountRole.READONLY },
+      { address: eventAuthority, role: AccountRole.READONLY },
+      { address: PROGRAM_ID, role: AccountRole.READONLY },
+    ],
+    data,
+  };
+}
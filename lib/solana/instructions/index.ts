@@ -1,7 +1,45 @@
 export { buildInitConfigIx } from "./init-config";
 export { buildSetConfigIx } from "./set-config";
+export { buildSetCreationPolicyIx } from "./set-creation-policy";
 export { buildCreateBasketIx } from "./create-basket";
+export { buildSlashBasketBondIx } from "./slash-basket-bond";
+export { buildMigrateConfigIx } from "./migrate-config";
+export { buildMigrateBasketIx } from "./migrate-basket";
 export { buildAddTokensIx } from "./add-tokens";
-export { buildDepositMultiIx } from "./deposit-multi";
+export { buildDepositMultiIx, type ComplianceProof } from "./deposit-multi";
+export {
+  buildComplianceAttestationIx,
+  getComplianceAttestationMessage,
+} from "./compliance-attestation";
+export { buildMintExactSharesIx } from "./mint-exact-shares";
 export { buildWithdrawMultiIx } from "./withdraw-multi";
+export { buildWithdrawExactAmountsIx } from "./withdraw-exact-amounts";
 export { buildUpdateAllowListIx } from "./update-allow-list";
+export { buildUpdateAllowListBatchIx } from "./update-allow-list-batch";
+export { buildSetComplianceRootIx } from "./set-compliance-root";
+export { buildCloseAllowListEntryIx } from "./close-allow-list-entry";
+export { buildCloseAllowListEntriesIx } from "./close-allow-list-entries";
+export { buildRefreshBasketPriceIx } from "./refresh-basket-price";
+export { buildUpdateBasketIx } from "./update-basket";
+export { buildTransferBasketOwnershipIx } from "./transfer-basket-ownership";
+export { buildAcceptBasketOwnershipIx } from "./accept-basket-ownership";
+export { buildCloseBasketIx } from "./close-basket";
+export { buildSetBasketPricingIx } from "./set-basket-pricing";
+export { buildUpdateBasketLimitsIx } from "./update-basket-limits";
+export { buildSetEmergencyModeIx } from "./set-emergency-mode";
+export { buildSetLockTiersIx } from "./set-lock-tiers";
+export { buildLockSharesIx } from "./lock-shares";
+export { buildUnlockSharesIx } from "./unlock-shares";
+export { buildInitRewardPoolIx } from "./init-reward-pool";
+export { buildFundRewardsIx } from "./fund-rewards";
+export { buildStakeIx } from "./stake";
+export { buildUnstakeIx } from "./unstake";
+export { buildClaimRewardsIx } from "./claim-rewards";
+export { buildPokeStakeIx } from "./poke-stake";
+export { buildSetEpochOperatorIx } from "./set-epoch-operator";
+export { buildRequestDepositIx } from "./request-deposit";
+export { buildRequestRedeemIx } from "./request-redeem";
+export { buildSettleEpochIx } from "./settle-epoch";
+export { buildCancelRequestIx } from "./cancel-request";
+export { buildClaimDepositIx } from "./claim-deposit";
+export { buildClaimRedeemIx } from "./claim-redeem";
@@ -0,0 +1,55 @@
+import {
+  type Address,
+  type Instruction,
+  AccountRole,
+  getI64Codec,
+  getU16Codec,
+  getU32Codec,
+} from "@solana/kit";
+import {
+  PROGRAM_ID,
+  SYSTEM_PROGRAM_ID,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  IX_DISCRIMINATORS,
+} from "../constants";
+import { getConfigPda, getRewardPoolPda, getEventAuthorityPda, getAssociatedTokenAddress } from "../pdas";
+import type { RewardBoost } from "../types";
+
+export async function buildInitRewardPoolIx(
+  authority: Address,
+  basketAddress: Address,
+  rewardMint: Address,
+  rewardTokenProgram: Address,
+  boosts: RewardBoost[],
+): Promise<Instruction> {
+  const [rewardPool] = await getRewardPoolPda(basketAddress, rewardMint);
+  const [rewardVault] = await getAssociatedTokenAddress(rewardPool, rewardTokenProgram, rewardMint);
+  const [configAddress] = await getConfigPda();
+  const [eventAuthority] = await getEventAuthorityPda();
+
+  const i64 = getI64Codec();
+  const u16 = getU16Codec();
+  const data = new Uint8Array([
+    ...IX_DISCRIMINATORS.initRewardPool,
+    ...getU32Codec().encode(boosts.length),
+    ...boosts.flatMap((b) => [...i64.encode(b.minDurationSecs), ...u16.encode(b.multiplierBps)]),
+  ]);
+
+  return {
+    programAddress: PROGRAM_ID,
+    accounts: [
+      { address: authority, role: AccountRole.WRITABLE_SIGNER },
+      { address: configAddress, role: AccountRole.READONLY },
+      { address: basketAddress, role: AccountRole.READONLY },
+      { address: rewardMint, role: AccountRole.READONLY },
+      { address: rewardPool, role: AccountRole.WRITABLE },
+      { address: rewardVault, role: AccountRole.WRITABLE },
+      { address: rewardTokenProgram, role: AccountRole.READONLY },
+      { address: ASSOCIATED_TOKEN_PROGRAM_ID, role: AccountRole.READONLY },
+      { address: SYSTEM_PROGRAM_ID, role: AccountRole.READONLY },
+      { address: eventAuthority, role: AccountRole.READONLY },
+      { address: PROGRAM_ID, role: AccountRole.READONLY },
+    ],
+    data,
+  };
+}
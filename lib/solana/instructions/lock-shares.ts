@@ -0,0 +1,64 @@
+import {
+  type Address,
+  type Instruction,
+  AccountRole,
+  getI64Codec,
+  getU64Codec,
+} from "@solana/kit";
+import {
+  PROGRAM_ID,
+  SYSTEM_PROGRAM_ID,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  IX_DISCRIMINATORS,
+} from "../constants";
+import {
+  getLockTiersPda,
+  getLockPositionPda,
+  getEventAuthorityPda,
+  getAssociatedTokenAddress,
+} from "../pdas";
+
+export async function buildLockSharesIx(
+  owner: Address,
+  basketAddress: Address,
+  shareMint: Address,
+  vaultAuthority: Address,
+  amount: bigint,
+  unlockTs: bigint,
+  shareTokenProgram: Address,
+  // Extra accounts for the share transfer hook, if the mint has one.
+  hookAccounts: { address: Address; role: AccountRole }[] = [],
+): Promise<Instruction> {
+  const [lockTiers] = await getLockTiersPda(basketAddress);
+  const [lockPosition] = await getLockPositionPda(basketAddress, owner);
+  const [ownerShareAta] = await getAssociatedTokenAddress(owner, shareTokenProgram, shareMint);
+  const [lockVault] = await getAssociatedTokenAddress(vaultAuthority, shareTokenProgram, shareMint);
+  const [eventAuthority] = await getEventAuthorityPda();
+
+  const data = new Uint8Array([
+    ...IX_DISCRIMINATORS.lockShares,
+    ...getU64Codec().encode(amount),
+    ...getI64Codec().encode(unlockTs),
+  ]);
+
+  return {
+    programAddress: PROGRAM_ID,
+    accounts: [
+      { address: owner, role: AccountRole.WRITABLE_SIGNER },
+      { address: basketAddress, role: AccountRole.READONLY },
+      { address: lockTiers, role: AccountRole.READONLY },
+      { address: lockPosition, role: AccountRole.WRITABLE },
+      { address: shareMint, role: AccountRole.READONLY },
+      { address: ownerShareAta, role: AccountRole.WRITABLE },
+      { address: vaultAuthority, role: AccountRole.READONLY },
+      { address: lockVault, role: AccountRole.WRITABLE },
+      { address: shareTokenProgram, role: AccountRole.READONLY },
+      { address: ASSOCIATED_TOKEN_PROGRAM_ID, role: AccountRole.READONLY },
+      { address: SYSTEM_PROGRAM_ID, role: AccountRole.READONLY },
+      { address: eventAuthority, role: AccountRole.READONLY },
+      { address: PROGRAM_ID, role: AccountRole.READONLY },
+      ...hookAccounts,
+    ],
+    data,
+  };
+}
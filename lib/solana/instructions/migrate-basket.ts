@@ -0,0 +1,56 @@
+import { type Address, type Instruction, AccountRole } from "@solana/kit";
+import { PROGRAM_ID, SYSTEM_PROGRAM_ID, IX_DISCRIMINATORS } from "../constants";
+import {
+  getConfigPda,
+  getBasketTokenPda,
+  getBasketPricePda,
+  getBasketRegistryPda,
+  getEventAuthorityPda,
+  getAssociatedTokenAddress,
+} from "../pdas";
+
+// Admin-only, after `buildMigrateConfigIx`. `tokenMints` must list every leg
+// of the basket; `registryPage` must be the config's `registryPage`.
+export async function buildMigrateBasketIx(
+  admin: Address,
+  basketAddress: Address,
+  shareMint: Address,
+  vaultAuthority: Address,
+  tokenMints: Address[],
+  tokenProgram: Address,
+  registryPage: number,
+): Promise<Instruction> {
+  const [configAddress] = await getConfigPda();
+  const [basketRegistry] = await getBasketRegistryPda(registryPage);
+  const [basketPrice] = await getBasketPricePda(basketAddress);
+  const [eventAuthority] = await getEventAuthorityPda();
+
+  const accounts: { address: Address; role: AccountRole }[] = [
+    { address: admin, role: AccountRole.WRITABLE_SIGNER },
+    { address: configAddress, role: AccountRole.WRITABLE },
+    { address: basketRegistry, role: AccountRole.WRITABLE },
+    { address: basketAddress, role: AccountRole.WRITABLE },
+    { address: basketPrice, role: AccountRole.WRITABLE },
+    { address: shareMint, role: AccountRole.READONLY },
+    { address: SYSTEM_PROGRAM_ID, role: AccountRole.READONLY },
+    { address: eventAuthority, role: AccountRole.READONLY },
+    { address: PROGRAM_ID, role: AccountRole.READONLY },
+  ];
+
+  for (const mint of tokenMints) {
+    const [basketTokenPda] = await getBasketTokenPda(basketAddress, mint);
+    const [vaultAta] = await getAssociatedTokenAddress(vaultAuthority, tokenProgram, mint);
+
+    accounts.push(
+      { address: basketTokenPda, role: AccountRole.WRITABLE },
+      { address: mint, role: AccountRole.READONLY },
+      { address: vaultAta, role: AccountRole.READONLY },
+    );
+  }
+
+  return {
+    programAddress: PROGRAM_ID,
+    accounts,
+    data: new Uint8Array(IX_DISCRIMINATORS.migrateBasket),
+  };
+}
@@ -0,0 +1,28 @@
+import { type Address, type Instruction, AccountRole, getU64Codec } from "@solana/kit";
+import { PROGRAM_ID, SYSTEM_PROGRAM_ID, IX_DISCRIMINATORS } from "../constants";
+import { getConfigPda, getEventAuthorityPda } from "../pdas";
+
+// Admin-only, once per deployment that predates the version-2 layouts.
+// `nextBasketId` must be one past the highest basket id already in use.
+export async function buildMigrateConfigIx(
+  admin: Address,
+  nextBasketId: bigint,
+): Promise<Instruction> {
+  const [configAddress] = await getConfigPda();
+  const [eventAuthority] = await getEventAuthorityPda();
+
+  return {
+    programAddress: PROGRAM_ID,
+    accounts: [
+      { address: admin, role: AccountRole.WRITABLE_SIGNER },
+      { address: configAddress, role: AccountRole.WRITABLE },
+      { address: SYSTEM_PROGRAM_ID, role: AccountRole.READONLY },
+      { address: eventAuthority, role: AccountRole.READONLY },
+      { address: PROGRAM_ID, role: AccountRole.READONLY },
+    ],
+    data: new Uint8Array([
+      ...IX_DISCRIMINATORS.migrateConfig,
+      ...getU64Codec().encode(nextBasketId),
+    ]),
+  };
+}
@@ -0,0 +1,54 @@
+import {
+  type Address,
+  type Instruction,
+  getU64Codec,
+  getU32Codec,
+} from "@solana/kit";
+import { PROGRAM_ID, IX_DISCRIMINATORS } from "../constants";
+import { type ComplianceProof, encodeComplianceProof, getDepositAccounts } from "./deposit-multi";
+
+export async function buildMintExactSharesIx(
+  payer: Address,
+  basketAddress: Address,
+  basketId: bigint,
+  shareMint: Address,
+  shares: bigint,
+  maxAmountsIn: bigint[],
+  tokenMints: Address[],
+  vaultAuthority: Address,
+  tokenProgram: Address,
+  userAllowList: Address | null = null,
+  shareTokenProgram: Address = tokenProgram,
+  receiver: Address = payer,
+  complianceProof: ComplianceProof | null = null,
+  attestationNonce: bigint | null = null,
+): Promise<Instruction> {
+  const u64 = getU64Codec();
+  const u32 = getU32Codec();
+  const data = new Uint8Array([
+    ...IX_DISCRIMINATORS.mintExactShares,
+    ...u64.encode(shares),
+    ...u32.encode(maxAmountsIn.length),
+    ...maxAmountsIn.flatMap((a) => [...u64.encode(a)]),
+    ...encodeComplianceProof(complianceProof),
+  ]);
+
+  return {
+    programAddress: PROGRAM_ID,
+    accounts: await getDepositAccounts(
+      payer,
+      basketAddress,
+      basketId,
+      shareMint,
+      tokenMints,
+      vaultAuthority,
+      tokenProgram,
+      userAllowList,
+      shareTokenProgram,
+      receiver,
+      complianceProof !== null,
+      attestationNonce,
+    ),
+    data,
+  };
+}
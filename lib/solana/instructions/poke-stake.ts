@@ -0,0 +1,25 @@
+import { type Address, type Instruction, AccountRole } from "@solana/kit";
+import { PROGRAM_ID, IX_DISCRIMINATORS } from "../constants";
+import { getRewardPoolPda, getStakePositionPda, getEventAuthorityPda } from "../pdas";
+
+// Permissionless. Drops `owner`'s stake back to 1x once its commitment ends.
+export async function buildPokeStakeIx(
+  owner: Address,
+  basketAddress: Address,
+  rewardMint: Address,
+): Promise<Instruction> {
+  const [rewardPool] = await getRewardPoolPda(basketAddress, rewardMint);
+  const [stakePosition] = await getStakePositionPda(rewardPool, owner);
+  const [eventAuthority] = await getEventAuthorityPda();
+
+  return {
+    programAddress: PROGRAM_ID,
+    accounts: [
+      { address: rewardPool, role: AccountRole.WRITABLE },
+      { address: stakePosition, role: AccountRole.WRITABLE },
+      { address: eventAuthority, role: AccountRole.READONLY },
+      { address: PROGRAM_ID, role: AccountRole.READONLY },
+    ],
+    data: new Uint8Array(IX_DISCRIMINATORS.pokeStake),
+  };
+}
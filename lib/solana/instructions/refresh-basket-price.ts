@@ -0,0 +1,46 @@
+import { type Address, type Instruction, AccountRole } from "@solana/kit";
+import { PROGRAM_ID, SYSTEM_PROGRAM_ID, IX_DISCRIMINATORS } from "../constants";
+import {
+  getBasketTokenPda,
+  getBasketPricePda,
+  getEventAuthorityPda,
+  getAssociatedTokenAddress,
+} from "../pdas";
+
+export async function buildRefreshBasketPriceIx(
+  payer: Address,
+  basketAddress: Address,
+  shareMint: Address,
+  vaultAuthority: Address,
+  tokenMints: Address[],
+  tokenProgram: Address,
+): Promise<Instruction> {
+  const [basketPrice] = await getBasketPricePda(basketAddress);
+  const [eventAuthority] = await getEventAuthorityPda();
+
+  const accounts: { address: Address; role: AccountRole }[] = [
+    { address: payer, role: AccountRole.WRITABLE_SIGNER },
+    { address: basketAddress, role: AccountRole.READONLY },
+    { address: basketPrice, role: AccountRole.WRITABLE },
+    { address: shareMint, role: AccountRole.READONLY },
+    { address: SYSTEM_PROGRAM_ID, role: AccountRole.READONLY },
+    { address: eventAuthority, role: AccountRole.READONLY },
+    { address: PROGRAM_ID, role: AccountRole.READONLY },
+  ];
+
+  for (const mint of tokenMints) {
+    const [basketTokenPda] = await getBasketTokenPda(basketAddress, mint);
+    const [vaultAta] = await getAssociatedTokenAddress(vaultAuthority, tokenProgram, mint);
+
+    accounts.push(
+      { address: basketTokenPda, role: AccountRole.WRITABLE },
+      { address: vaultAta, role: AccountRole.READONLY },
+    );
+  }
+
+  return {
+    programAddress: PROGRAM_ID,
+    accounts,
+    data: new Uint8Array(IX_DISCRIMINATORS.refreshBasketPrice),
+  };
+}
@@ -0,0 +1,111 @@
+import {
+  type Address,
+  type Instruction,
+  AccountRole,
+  getU32Codec,
+  getU64Codec,
+} from "@solana/kit";
+import {
+  PROGRAM_ID,
+  SYSTEM_PROGRAM_ID,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  SYSVAR_INSTRUCTIONS_ID,
+  IX_DISCRIMINATORS,
+} from "../constants";
+import {
+  getConfigPda,
+  getBasketTokenPda,
+  getComplianceRootPda,
+  getAttestationNoncePda,
+  getFeeVaultPda,
+  getEpochQueuePda,
+  getDepositRequestPda,
+  getEventAuthorityPda,
+  getAssociatedTokenAddress,
+} from "../pdas";
+import { type ComplianceProof, encodeComplianceProof } from "./deposit-multi";
+
+// The epoch queue's pending ATAs must exist; create them idempotently
+// ahead of this instruction. Compliance works as for `deposit_multi`, with
+// the owner as the admitted user.
+export async function buildRequestDepositIx(
+  owner: Address,
+  basketAddress: Address,
+  shareMint: Address,
+  amounts: bigint[],
+  tokenMints: Address[],
+  vaultAuthority: Address,
+  tokenProgram: Address,
+  userAllowList: Address | null = null,
+  shareTokenProgram: Address = tokenProgram,
+  complianceProof: ComplianceProof | null = null,
+  attestationNonce: bigint | null = null,
+): Promise<Instruction> {
+  const [configAddress] = await getConfigPda();
+  const [epochQueue] = await getEpochQueuePda(basketAddress);
+  const [depositRequest] = await getDepositRequestPda(basketAddress, owner);
+  const [ownerShareAta] = await getAssociatedTokenAddress(owner, shareTokenProgram, shareMint);
+  const [eventAuthority] = await getEventAuthorityPda();
+
+  const u64 = getU64Codec();
+  const data = new Uint8Array([
+    ...IX_DISCRIMINATORS.requestDeposit,
+    ...getU32Codec().encode(amounts.length),
+    ...amounts.flatMap((a) => [...u64.encode(a)]),
+    ...encodeComplianceProof(complianceProof),
+  ]);
+
+  // Absent optional accounts are passed as the program id.
+  const complianceRoot = complianceProof
+    ? (await getComplianceRootPda(basketAddress))[0]
+    : PROGRAM_ID;
+  const nonceAccount =
+    attestationNonce === null
+      ? PROGRAM_ID
+      : (await getAttestationNoncePda(basketAddress, owner, attestationNonce))[0];
+
+  const accounts: { address: Address; role: AccountRole }[] = [
+    { address: owner, role: AccountRole.WRITABLE_SIGNER },
+    { address: configAddress, role: AccountRole.READONLY },
+    { address: basketAddress, role: AccountRole.READONLY },
+    { address: epochQueue, role: AccountRole.WRITABLE },
+    { address: depositRequest, role: AccountRole.WRITABLE },
+    { address: shareMint, role: AccountRole.READONLY },
+    { address: ownerShareAta, role: AccountRole.WRITABLE },
+    { address: userAllowList ?? PROGRAM_ID, role: AccountRole.READONLY },
+    { address: complianceRoot, role: AccountRole.READONLY },
+    {
+      address: attestationNonce === null ? PROGRAM_ID : SYSVAR_INSTRUCTIONS_ID,
+      role: AccountRole.READONLY,
+    },
+    {
+      address: nonceAccount,
+      role: attestationNonce === null ? AccountRole.READONLY : AccountRole.WRITABLE,
+    },
+    { address: tokenProgram, role: AccountRole.READONLY },
+    { address: shareTokenProgram, role: AccountRole.READONLY },
+    { address: ASSOCIATED_TOKEN_PROGRAM_ID, role: AccountRole.READONLY },
+    { address: SYSTEM_PROGRAM_ID, role: AccountRole.READONLY },
+    { address: eventAuthority, role: AccountRole.READONLY },
+    { address: PROGRAM_ID, role: AccountRole.READONLY },
+  ];
+
+  for (const mint of tokenMints) {
+    const [basketTokenPda] = await getBasketTokenPda(basketAddress, mint);
+    const [userAta] = await getAssociatedTokenAddress(owner, tokenProgram, mint);
+    const [vaultAta] = await getAssociatedTokenAddress(vaultAuthority, tokenProgram, mint);
+    const [pendingAta] = await getAssociatedTokenAddress(epochQueue, tokenProgram, mint);
+    const [feeVault] = await getFeeVaultPda(basketAddress, mint);
+
+    accounts.push(
+      { address: basketTokenPda, role: AccountRole.READONLY },
+      { address: mint, role: AccountRole.READONLY },
+      { address: userAta, role: AccountRole.WRITABLE },
+      { address: vaultAta, role: AccountRole.READONLY },
+      { address: pendingAta, role: AccountRole.WRITABLE },
+      { address: feeVault, role: AccountRole.WRITABLE },
+    );
+  }
+
+  return { programAddress: PROGRAM_ID, accounts, data };
+}
@@ -0,0 +1,53 @@
+import { type Address, type Instruction, AccountRole, getU64Codec } from "@solana/kit";
+import {
+  PROGRAM_ID,
+  SYSTEM_PROGRAM_ID,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  IX_DISCRIMINATORS,
+} from "../constants";
+import {
+  getEpochQueuePda,
+  getRedeemRequestPda,
+  getEventAuthorityPda,
+  getAssociatedTokenAddress,
+} from "../pdas";
+
+export async function buildRequestRedeemIx(
+  owner: Address,
+  basketAddress: Address,
+  shareMint: Address,
+  vaultAuthority: Address,
+  shares: bigint,
+  shareTokenProgram: Address,
+  // Extra accounts for the share transfer hook, if the mint has one.
+  hookAccounts: { address: Address; role: AccountRole }[] = [],
+): Promise<Instruction> {
+  const [epochQueue] = await getEpochQueuePda(basketAddress);
+  const [redeemRequest] = await getRedeemRequestPda(basketAddress, owner);
+  const [ownerShareAta] = await getAssociatedTokenAddress(owner, shareTokenProgram, shareMint);
+  const [shareEscrow] = await getAssociatedTokenAddress(vaultAuthority, shareTokenProgram, shareMint);
+  const [eventAuthority] = await getEventAuthorityPda();
+
+  const data = new Uint8Array([...IX_DISCRIMINATORS.requestRedeem, ...getU64Codec().encode(shares)]);
+
+  return {
+    programAddress: PROGRAM_ID,
+    accounts: [
+      { address: owner, role: AccountRole.WRITABLE_SIGNER },
+      { address: basketAddress, role: AccountRole.READONLY },
+      { address: epochQueue, role: AccountRole.WRITABLE },
+      { address: redeemRequest, role: AccountRole.WRITABLE },
+      { address: shareMint, role: AccountRole.READONLY },
+      { address: ownerShareAta, role: AccountRole.WRITABLE },
+      { address: vaultAuthority, role: AccountRole.READONLY },
+      { address: shareEscrow, role: AccountRole.WRITABLE },
+      { address: shareTokenProgram, role: AccountRole.READONLY },
+      { address: ASSOCIATED_TOKEN_PROGRAM_ID, role: AccountRole.READONLY },
+      { address: SYSTEM_PROGRAM_ID, role: AccountRole.READONLY },
+      { address: eventAuthority, role: AccountRole.READONLY },
+      { address: PROGRAM_ID, role: AccountRole.READONLY },
+      ...hookAccounts,
+    ],
+    data,
+  };
+}
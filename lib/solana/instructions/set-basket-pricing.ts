@@ -0,0 +1,31 @@
+import { type Address, type Instruction, AccountRole } from "@solana/kit";
+import { PROGRAM_ID, IX_DISCRIMINATORS } from "../constants";
+import { getConfigPda, getEventAuthorityPda } from "../pdas";
+
+export async function buildSetBasketPricingIx(
+  authority: Address,
+  basketAddress: Address,
+  mintPricingMode: number,
+  redeemPricingMode: number,
+): Promise<Instruction> {
+  const [configAddress] = await getConfigPda();
+  const [eventAuthority] = await getEventAuthorityPda();
+
+  const data = new Uint8Array([
+    ...IX_DISCRIMINATORS.setBasketPricing,
+    mintPricingMode,
+    redeemPricingMode,
+  ]);
+
+  return {
+    programAddress: PROGRAM_ID,
+    accounts: [
+      { address: authority, role: AccountRole.READONLY_SIGNER },
+      { address: configAddress, role: AccountRole.READONLY },
+      { address: basketAddress, role: AccountRole.WRITABLE },
+      { address: eventAuthority, role: AccountRole.READONLY },
+      { address: PROGRAM_ID, role: AccountRole.READONLY },
+    ],
+    data,
+  };
+}
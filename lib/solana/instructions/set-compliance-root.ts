@@ -0,0 +1,42 @@
+import {
+  type Address,
+  type Instruction,
+  AccountRole,
+  fixCodecSize,
+  getBytesCodec,
+  getU64Codec,
+} from "@solana/kit";
+import { PROGRAM_ID, SYSTEM_PROGRAM_ID, IX_DISCRIMINATORS } from "../constants";
+import { getConfigPda, getComplianceRootPda, getEventAuthorityPda } from "../pdas";
+
+// Whitelist authority only. `version` must exceed the published one.
+export async function buildSetComplianceRootIx(
+  authority: Address,
+  basketAddress: Address,
+  root: Uint8Array,
+  version: bigint,
+): Promise<Instruction> {
+  const [configAddress] = await getConfigPda();
+  const [complianceRoot] = await getComplianceRootPda(basketAddress);
+  const [eventAuthority] = await getEventAuthorityPda();
+
+  const data = new Uint8Array([
+    ...IX_DISCRIMINATORS.setComplianceRoot,
+    ...fixCodecSize(getBytesCodec(), 32).encode(root),
+    ...getU64Codec().encode(version),
+  ]);
+
+  return {
+    programAddress: PROGRAM_ID,
+    accounts: [
+      { address: authority, role: AccountRole.WRITABLE_SIGNER },
+      { address: configAddress, role: AccountRole.READONLY },
+      { address: basketAddress, role: AccountRole.READONLY },
+      { address: complianceRoot, role: AccountRole.WRITABLE },
+      { address: SYSTEM_PROGRAM_ID, role: AccountRole.READONLY },
+      { address: eventAuthority, role: AccountRole.READONLY },
+      { address: PROGRAM_ID, role: AccountRole.READONLY },
+    ],
+    data,
+  };
+}
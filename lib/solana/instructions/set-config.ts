@@ -2,6 +2,7 @@ import {
   type Address,
   type Instruction,
   AccountRole,
+  getU8Codec,
   getU16Codec,
   getBooleanCodec,
   getAddressCodec,
@@ -23,10 +24,12 @@ export async function buildSetConfigIx(
   whitelistAuth: Address | null = null,
   complianceEnabled: boolean | null = null,
   newAdmin: Address | null = null,
+  mintExtensionPolicy: number | null = null,
 ): Promise<Instruction> {
   const [configAddress] = await getConfigPda();
   const [eventAuthority] = await getEventAuthorityPda();
 
+  const u8 = getU8Codec();
   const u16 = getU16Codec();
   const bool = getBooleanCodec();
   const addr = getAddressCodec();
@@ -37,6 +40,7 @@ export async function buildSetConfigIx(
     ...encodeOption(whitelistAuth, (v) => new Uint8Array(addr.encode(v))),
     ...encodeOption(complianceEnabled, (v) => new Uint8Array(bool.encode(v))),
     ...encodeOption(newAdmin, (v) => new Uint8Array(addr.encode(v))),
+    ...encodeOption(mintExtensionPolicy, (v) => new Uint8Array(u8.encode(v))),
   ]);
 
   return {
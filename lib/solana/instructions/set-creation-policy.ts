@@ -0,0 +1,42 @@
+import {
+  type Address,
+  type Instruction,
+  AccountRole,
+  getAddressCodec,
+  getBooleanCodec,
+  getU64Codec,
+} from "@solana/kit";
+import { PROGRAM_ID, IX_DISCRIMINATORS } from "../constants";
+import { getConfigPda, getEventAuthorityPda } from "../pdas";
+
+// Admin-only. Fee and bond apply to non-admin creators.
+export async function buildSetCreationPolicyIx(
+  admin: Address,
+  permissionlessCreation: boolean,
+  creationFeeLamports: bigint,
+  creationBondLamports: bigint,
+  treasury: Address,
+): Promise<Instruction> {
+  const [configAddress] = await getConfigPda();
+  const [eventAuthority] = await getEventAuthorityPda();
+
+  const u64 = getU64Codec();
+  const data = new Uint8Array([
+    ...IX_DISCRIMINATORS.setCreationPolicy,
+    ...getBooleanCodec().encode(permissionlessCreation),
+    ...u64.encode(creationFeeLamports),
+    ...u64.encode(creationBondLamports),
+    ...getAddressCodec().encode(treasury),
+  ]);
+
+  return {
+    programAddress: PROGRAM_ID,
+    accounts: [
+      { address: admin, role: AccountRole.READONLY_SIGNER },
+      { address: configAddress, role: AccountRole.WRITABLE },
+      { address: eventAuthority, role: AccountRole.READONLY },
+      { address: PROGRAM_ID, role: AccountRole.READONLY },
+    ],
+    data,
+  };
+}
@@ -0,0 +1,30 @@
+import { type Address, type Instruction, AccountRole, getBooleanCodec } from "@solana/kit";
+import { PROGRAM_ID, IX_DISCRIMINATORS } from "../constants";
+import { getConfigPda, getEventAuthorityPda } from "../pdas";
+
+// Admin-only: while enabled, withdrawals bypass the basket's withdraw limiter.
+export async function buildSetEmergencyModeIx(
+  admin: Address,
+  basketAddress: Address,
+  enabled: boolean,
+): Promise<Instruction> {
+  const [configAddress] = await getConfigPda();
+  const [eventAuthority] = await getEventAuthorityPda();
+
+  const data = new Uint8Array([
+    ...IX_DISCRIMINATORS.setEmergencyMode,
+    ...getBooleanCodec().encode(enabled),
+  ]);
+
+  return {
+    programAddress: PROGRAM_ID,
+    accounts: [
+      { address: admin, role: AccountRole.READONLY_SIGNER },
+      { address: configAddress, role: AccountRole.READONLY },
+      { address: basketAddress, role: AccountRole.WRITABLE },
+      { address: eventAuthority, role: AccountRole.READONLY },
+      { address: PROGRAM_ID, role: AccountRole.READONLY },
+    ],
+    data,
+  };
+}
@@ -0,0 +1,32 @@
+import { type Address, type Instruction, AccountRole, getAddressEncoder } from "@solana/kit";
+import { PROGRAM_ID, SYSTEM_PROGRAM_ID, IX_DISCRIMINATORS } from "../constants";
+import { getConfigPda, getEpochQueuePda, getEventAuthorityPda } from "../pdas";
+
+export async function buildSetEpochOperatorIx(
+  authority: Address,
+  basketAddress: Address,
+  operator: Address,
+): Promise<Instruction> {
+  const [epochQueue] = await getEpochQueuePda(basketAddress);
+  const [configAddress] = await getConfigPda();
+  const [eventAuthority] = await getEventAuthorityPda();
+
+  const data = new Uint8Array([
+    ...IX_DISCRIMINATORS.setEpochOperator,
+    ...getAddressEncoder().encode(operator),
+  ]);
+
+  return {
+    programAddress: PROGRAM_ID,
+    accounts: [
+      { address: authority, role: AccountRole.WRITABLE_SIGNER },
+      { address: configAddress, role: AccountRole.READONLY },
+      { address: basketAddress, role: AccountRole.READONLY },
+      { address: epochQueue, role: AccountRole.WRITABLE },
+      { address: SYSTEM_PROGRAM_ID, role: AccountRole.READONLY },
+      { address: eventAuthority, role: AccountRole.READONLY },
+      { address: PROGRAM_ID, role: AccountRole.READONLY },
+    ],
+    data,
+  };
+}
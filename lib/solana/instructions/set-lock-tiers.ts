@@ -0,0 +1,47 @@
+import {
+  type Address,
+  type Instruction,
+  AccountRole,
+  getI64Codec,
+  getU16Codec,
+  getU32Codec,
+} from "@solana/kit";
+import { PROGRAM_ID, SYSTEM_PROGRAM_ID, IX_DISCRIMINATORS } from "../constants";
+import { getConfigPda, getLockTiersPda, getEventAuthorityPda } from "../pdas";
+import type { LockTier } from "../types";
+
+export async function buildSetLockTiersIx(
+  authority: Address,
+  basketAddress: Address,
+  tiers: LockTier[],
+): Promise<Instruction> {
+  const [lockTiers] = await getLockTiersPda(basketAddress);
+  const [configAddress] = await getConfigPda();
+  const [eventAuthority] = await getEventAuthorityPda();
+
+  const i64 = getI64Codec();
+  const u16 = getU16Codec();
+  const u32 = getU32Codec();
+  const data = new Uint8Array([
+    ...IX_DISCRIMINATORS.setLockTiers,
+    ...u32.encode(tiers.length),
+    ...tiers.flatMap((t) => [
+      ...i64.encode(t.minDurationSecs),
+      ...u16.encode(t.earlyExitPenaltyBps),
+    ]),
+  ]);
+
+  return {
+    programAddress: PROGRAM_ID,
+    accounts: [
+      { address: authority, role: AccountRole.WRITABLE_SIGNER },
+      { address: configAddress, role: AccountRole.READONLY },
+      { address: basketAddress, role: AccountRole.READONLY },
+      { address: lockTiers, role: AccountRole.WRITABLE },
+      { address: SYSTEM_PROGRAM_ID, role: AccountRole.READONLY },
+      { address: eventAuthority, role: AccountRole.READONLY },
+      { address: PROGRAM_ID, role: AccountRole.READONLY },
+    ],
+    data,
+  };
+}
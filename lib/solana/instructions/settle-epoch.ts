@@ -0,0 +1,77 @@
+import { type Address, type Instruction, AccountRole } from "@solana/kit";
+import {
+  PROGRAM_ID,
+  SYSTEM_PROGRAM_ID,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  IX_DISCRIMINATORS,
+} from "../constants";
+import {
+  getBasketTokenPda,
+  getBasketPricePda,
+  getMintAuthorityPda,
+  getEpochQueuePda,
+  getEpochPda,
+  getEventAuthorityPda,
+  getAssociatedTokenAddress,
+} from "../pdas";
+
+// `epochId` is the queue's current epoch. The queue's pending ATAs and the
+// epoch's payout ATAs must exist; create them idempotently ahead of this
+// instruction.
+export async function buildSettleEpochIx(
+  operator: Address,
+  basketAddress: Address,
+  basketId: bigint,
+  epochId: bigint,
+  shareMint: Address,
+  vaultAuthority: Address,
+  tokenMints: Address[],
+  tokenProgram: Address,
+  shareTokenProgram: Address = tokenProgram,
+): Promise<Instruction> {
+  const [basketPrice] = await getBasketPricePda(basketAddress);
+  const [epochQueue] = await getEpochQueuePda(basketAddress);
+  const [epoch] = await getEpochPda(basketAddress, epochId);
+  const [mintAuthority] = await getMintAuthorityPda(basketId);
+  const [shareEscrow] = await getAssociatedTokenAddress(vaultAuthority, shareTokenProgram, shareMint);
+  const [eventAuthority] = await getEventAuthorityPda();
+
+  const accounts: { address: Address; role: AccountRole }[] = [
+    { address: operator, role: AccountRole.WRITABLE_SIGNER },
+    { address: basketAddress, role: AccountRole.WRITABLE },
+    { address: basketPrice, role: AccountRole.WRITABLE },
+    { address: epochQueue, role: AccountRole.WRITABLE },
+    { address: epoch, role: AccountRole.WRITABLE },
+    { address: shareMint, role: AccountRole.WRITABLE },
+    { address: mintAuthority, role: AccountRole.READONLY },
+    { address: vaultAuthority, role: AccountRole.READONLY },
+    { address: shareEscrow, role: AccountRole.WRITABLE },
+    { address: tokenProgram, role: AccountRole.READONLY },
+    { address: shareTokenProgram, role: AccountRole.READONLY },
+    { address: ASSOCIATED_TOKEN_PROGRAM_ID, role: AccountRole.READONLY },
+    { address: SYSTEM_PROGRAM_ID, role: AccountRole.READONLY },
+    { address: eventAuthority, role: AccountRole.READONLY },
+    { address: PROGRAM_ID, role: AccountRole.READONLY },
+  ];
+
+  for (const mint of tokenMints) {
+    const [basketTokenPda] = await getBasketTokenPda(basketAddress, mint);
+    const [vaultAta] = await getAssociatedTokenAddress(vaultAuthority, tokenProgram, mint);
+    const [pendingAta] = await getAssociatedTokenAddress(epochQueue, tokenProgram, mint);
+    const [payoutAta] = await getAssociatedTokenAddress(epoch, tokenProgram, mint);
+
+    accounts.push(
+      { address: basketTokenPda, role: AccountRole.WRITABLE },
+      { address: mint, role: AccountRole.READONLY },
+      { address: vaultAta, role: AccountRole.WRITABLE },
+      { address: pendingAta, role: AccountRole.WRITABLE },
+      { address: payoutAta, role: AccountRole.WRITABLE },
+    );
+  }
+
+  return {
+    programAddress: PROGRAM_ID,
+    accounts,
+    data: new Uint8Array(IX_DISCRIMINATORS.settleEpoch),
+  };
+}
@@ -0,0 +1,26 @@
+import { type Address, type Instruction, AccountRole } from "@solana/kit";
+import { PROGRAM_ID, IX_DISCRIMINATORS } from "../constants";
+import { getConfigPda, getEventAuthorityPda } from "../pdas";
+
+// Admin-only: moves the basket's creation bond to the config treasury.
+export async function buildSlashBasketBondIx(
+  admin: Address,
+  basketAddress: Address,
+  treasury: Address,
+): Promise<Instruction> {
+  const [configAddress] = await getConfigPda();
+  const [eventAuthority] = await getEventAuthorityPda();
+
+  return {
+    programAddress: PROGRAM_ID,
+    accounts: [
+      { address: admin, role: AccountRole.READONLY_SIGNER },
+      { address: configAddress, role: AccountRole.READONLY },
+      { address: basketAddress, role: AccountRole.WRITABLE },
+      { address: treasury, role: AccountRole.WRITABLE },
+      { address: eventAuthority, role: AccountRole.READONLY },
+      { address: PROGRAM_ID, role: AccountRole.READONLY },
+    ],
+    data: new Uint8Array(IX_DISCRIMINATORS.slashBasketBond),
+  };
+}
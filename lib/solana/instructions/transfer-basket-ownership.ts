@@ -0,0 +1,29 @@
+import { type Address, type Instruction, AccountRole, getAddressCodec } from "@solana/kit";
+import { PROGRAM_ID, SYSTEM_PROGRAM_ID, IX_DISCRIMINATORS } from "../constants";
+import { getEventAuthorityPda } from "../pdas";
+
+// Nominates `newOwner`; the transfer completes once they accept.
+// Pass null to cancel a pending nomination.
+export async function buildTransferBasketOwnershipIx(
+  owner: Address,
+  basketAddress: Address,
+  newOwner: Address | null,
+): Promise<Instruction> {
+  const [eventAuthority] = await getEventAuthorityPda();
+
+  const data = new Uint8Array([
+    ...IX_DISCRIMINATORS.transferBasketOwnership,
+    ...getAddressCodec().encode(newOwner ?? SYSTEM_PROGRAM_ID),
+  ]);
+
+  return {
+    programAddress: PROGRAM_ID,
+    accounts: [
+      { address: owner, role: AccountRole.READONLY_SIGNER },
+      { address: basketAddress, role: AccountRole.WRITABLE },
+      { address: eventAuthority, role: AccountRole.READONLY },
+      { address: PROGRAM_ID, role: AccountRole.READONLY },
+    ],
+    data,
+  };
+}
@@ -0,0 +1,49 @@
+import { type Address, type Instruction, AccountRole } from "@solana/kit";
+import { PROGRAM_ID, IX_DISCRIMINATORS } from "../constants";
+import {
+  getLockPositionPda,
+  getEventAuthorityPda,
+  getAssociatedTokenAddress,
+} from "../pdas";
+
+/**
+ * Builds `unlock_shares`, or `exit_lock_early` when `early` is set; both
+ * take the same accounts.
+ */
+export async function buildUnlockSharesIx(
+  owner: Address,
+  basketAddress: Address,
+  shareMint: Address,
+  vaultAuthority: Address,
+  shareTokenProgram: Address,
+  early = false,
+  // Extra accounts for the share transfer hook, if the mint has one.
+  hookAccounts: { address: Address; role: AccountRole }[] = [],
+): Promise<Instruction> {
+  const [lockPosition] = await getLockPositionPda(basketAddress, owner);
+  const [ownerShareAta] = await getAssociatedTokenAddress(owner, shareTokenProgram, shareMint);
+  const [lockVault] = await getAssociatedTokenAddress(vaultAuthority, shareTokenProgram, shareMint);
+  const [eventAuthority] = await getEventAuthorityPda();
+
+  const data = new Uint8Array(
+    early ? IX_DISCRIMINATORS.exitLockEarly : IX_DISCRIMINATORS.unlockShares,
+  );
+
+  return {
+    programAddress: PROGRAM_ID,
+    accounts: [
+      { address: owner, role: AccountRole.WRITABLE_SIGNER },
+      { address: basketAddress, role: AccountRole.READONLY },
+      { address: lockPosition, role: AccountRole.WRITABLE },
+      { address: shareMint, role: AccountRole.WRITABLE },
+      { address: ownerShareAta, role: AccountRole.WRITABLE },
+      { address: vaultAuthority, role: AccountRole.READONLY },
+      { address: lockVault, role: AccountRole.WRITABLE },
+      { address: shareTokenProgram, role: AccountRole.READONLY },
+      { address: eventAuthority, role: AccountRole.READONLY },
+      { address: PROGRAM_ID, role: AccountRole.READONLY },
+      ...hookAccounts,
+    ],
+    data,
+  };
+}
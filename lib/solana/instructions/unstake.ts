@@ -0,0 +1,47 @@
+import { type Address, type Instruction, AccountRole, getU64Codec } from "@solana/kit";
+import { PROGRAM_ID, IX_DISCRIMINATORS } from "../constants";
+import {
+  getRewardPoolPda,
+  getStakePositionPda,
+  getEventAuthorityPda,
+  getAssociatedTokenAddress,
+} from "../pdas";
+
+export async function buildUnstakeIx(
+  owner: Address,
+  basketAddress: Address,
+  rewardMint: Address,
+  shareMint: Address,
+  vaultAuthority: Address,
+  amount: bigint,
+  shareTokenProgram: Address,
+  // Extra accounts for the share transfer hook, if the mint has one.
+  hookAccounts: { address: Address; role: AccountRole }[] = [],
+): Promise<Instruction> {
+  const [rewardPool] = await getRewardPoolPda(basketAddress, rewardMint);
+  const [stakePosition] = await getStakePositionPda(rewardPool, owner);
+  const [ownerShareAta] = await getAssociatedTokenAddress(owner, shareTokenProgram, shareMint);
+  const [stakeVault] = await getAssociatedTokenAddress(vaultAuthority, shareTokenProgram, shareMint);
+  const [eventAuthority] = await getEventAuthorityPda();
+
+  const data = new Uint8Array([...IX_DISCRIMINATORS.unstake, ...getU64Codec().encode(amount)]);
+
+  return {
+    programAddress: PROGRAM_ID,
+    accounts: [
+      { address: owner, role: AccountRole.READONLY_SIGNER },
+      { address: basketAddress, role: AccountRole.READONLY },
+      { address: rewardPool, role: AccountRole.WRITABLE },
+      { address: stakePosition, role: AccountRole.WRITABLE },
+      { address: shareMint, role: AccountRole.READONLY },
+      { address: ownerShareAta, role: AccountRole.WRITABLE },
+      { address: vaultAuthority, role: AccountRole.READONLY },
+      { address: stakeVault, role: AccountRole.WRITABLE },
+      { address: shareTokenProgram, role: AccountRole.READONLY },
+      { address: eventAuthority, role: AccountRole.READONLY },
+      { address: PROGRAM_ID, role: AccountRole.READONLY },
+      ...hookAccounts,
+    ],
+    data,
+  };
+}
@@ -0,0 +1,52 @@
+import {
+  type Address,
+  type Instruction,
+  AccountRole,
+  getAddressCodec,
+  getBooleanCodec,
+  getU32Codec,
+} from "@solana/kit";
+import { PROGRAM_ID, SYSTEM_PROGRAM_ID, IX_DISCRIMINATORS } from "../constants";
+import { getConfigPda, getUserAllowListPda, getEventAuthorityPda } from "../pdas";
+
+// Whitelist authority only; pays rent for any entry it creates. Keep batches
+// small enough to fit a transaction.
+export async function buildUpdateAllowListBatchIx(
+  authority: Address,
+  basketAddress: Address,
+  entries: { user: Address; allowed: boolean }[],
+): Promise<Instruction> {
+  const [configAddress] = await getConfigPda();
+  const [eventAuthority] = await getEventAuthorityPda();
+
+  const addr = getAddressCodec();
+  const bool = getBooleanCodec();
+  const u32 = getU32Codec();
+  const data = new Uint8Array([
+    ...IX_DISCRIMINATORS.updateAllowListBatch,
+    ...u32.encode(entries.length),
+    ...entries.flatMap(({ user }) => [...addr.encode(user)]),
+    ...u32.encode(entries.length),
+    ...entries.flatMap(({ allowed }) => [...bool.encode(allowed)]),
+  ]);
+
+  const accounts: { address: Address; role: AccountRole }[] = [
+    { address: authority, role: AccountRole.WRITABLE_SIGNER },
+    { address: configAddress, role: AccountRole.READONLY },
+    { address: basketAddress, role: AccountRole.READONLY },
+    { address: SYSTEM_PROGRAM_ID, role: AccountRole.READONLY },
+    { address: eventAuthority, role: AccountRole.READONLY },
+    { address: PROGRAM_ID, role: AccountRole.READONLY },
+  ];
+
+  for (const { user } of entries) {
+    const [userAllowListAddress] = await getUserAllowListPda(basketAddress, user);
+    accounts.push({ address: userAllowListAddress, role: AccountRole.WRITABLE });
+  }
+
+  return {
+    programAddress: PROGRAM_ID,
+    accounts,
+    data,
+  };
+}
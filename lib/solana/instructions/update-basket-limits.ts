@@ -0,0 +1,45 @@
+import {
+  type Address,
+  type Instruction,
+  AccountRole,
+  getI64Codec,
+  getU16Codec,
+  getU64Codec,
+} from "@solana/kit";
+import { PROGRAM_ID, IX_DISCRIMINATORS } from "../constants";
+import { getConfigPda, getEventAuthorityPda } from "../pdas";
+
+// Pass 0 to lift a cap or disable the withdraw limiter. Only the protocol
+// admin may change the limiter; an owner passes the current settings back.
+export async function buildUpdateBasketLimitsIx(
+  authority: Address,
+  basketAddress: Address,
+  maxTotalValue: bigint,
+  maxUserShares: bigint,
+  maxWithdrawBps: number,
+  withdrawWindowSecs: bigint,
+): Promise<Instruction> {
+  const [configAddress] = await getConfigPda();
+  const [eventAuthority] = await getEventAuthorityPda();
+
+  const u64 = getU64Codec();
+  const data = new Uint8Array([
+    ...IX_DISCRIMINATORS.updateBasketLimits,
+    ...u64.encode(maxTotalValue),
+    ...u64.encode(maxUserShares),
+    ...getU16Codec().encode(maxWithdrawBps),
+    ...getI64Codec().encode(withdrawWindowSecs),
+  ]);
+
+  return {
+    programAddress: PROGRAM_ID,
+    accounts: [
+      { address: authority, role: AccountRole.READONLY_SIGNER },
+      { address: configAddress, role: AccountRole.READONLY },
+      { address: basketAddress, role: AccountRole.WRITABLE },
+      { address: eventAuthority, role: AccountRole.READONLY },
+      { address: PROGRAM_ID, role: AccountRole.READONLY },
+    ],
+    data,
+  };
+}
@@ -0,0 +1,44 @@
+import { type Address, type Instruction, AccountRole, getU16Codec } from "@solana/kit";
+import { PROGRAM_ID, IX_DISCRIMINATORS, MAX_NAME_LEN } from "../constants";
+import { getConfigPda, getEventAuthorityPda } from "../pdas";
+
+function encodeName(name: string | undefined): Uint8Array {
+  if (name === undefined) return new Uint8Array([0]);
+  const buf = new Uint8Array(MAX_NAME_LEN);
+  buf.set(new TextEncoder().encode(name.slice(0, MAX_NAME_LEN)));
+  return new Uint8Array([1, ...buf]);
+}
+
+// `undefined` leaves the fee override untouched, `null` clears it (sent as 0).
+function encodeFeeOverride(value: number | null | undefined): Uint8Array {
+  if (value === undefined) return new Uint8Array([0]);
+  return new Uint8Array([1, ...getU16Codec().encode(value ?? 0)]);
+}
+
+export async function buildUpdateBasketIx(
+  authority: Address,
+  basketAddress: Address,
+  name?: string,
+  feeBpsOverride?: number | null,
+): Promise<Instruction> {
+  const [configAddress] = await getConfigPda();
+  const [eventAuthority] = await getEventAuthorityPda();
+
+  const data = new Uint8Array([
+    ...IX_DISCRIMINATORS.updateBasket,
+    ...encodeName(name),
+    ...encodeFeeOverride(feeBpsOverride),
+  ]);
+
+  return {
+    programAddress: PROGRAM_ID,
+    accounts: [
+      { address: authority, role: AccountRole.READONLY_SIGNER },
+      { address: configAddress, role: AccountRole.READONLY },
+      { address: basketAddress, role: AccountRole.WRITABLE },
+      { address: eventAuthority, role: AccountRole.READONLY },
+      { address: PROGRAM_ID, role: AccountRole.READONLY },
+    ],
+    data,
+  };
+}
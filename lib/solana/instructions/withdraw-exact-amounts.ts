@@ -0,0 +1,45 @@
+import {
+  type Address,
+  type Instruction,
+  getU64Codec,
+  getU32Codec,
+} from "@solana/kit";
+import { PROGRAM_ID, IX_DISCRIMINATORS } from "../constants";
+import { getWithdrawAccounts } from "./withdraw-multi";
+
+export async function buildWithdrawExactAmountsIx(
+  payer: Address,
+  basketAddress: Address,
+  shareMint: Address,
+  vaultAuthority: Address,
+  amountsOut: bigint[],
+  maxSharesIn: bigint,
+  tokenMints: Address[],
+  tokenProgram: Address,
+  shareTokenProgram: Address = tokenProgram,
+  receiver: Address = payer,
+): Promise<Instruction> {
+  const u64 = getU64Codec();
+  const u32 = getU32Codec();
+  const data = new Uint8Array([
+    ...IX_DISCRIMINATORS.withdrawExactAmounts,
+    ...u32.encode(amountsOut.length),
+    ...amountsOut.flatMap((a) => [...u64.encode(a)]),
+    ...u64.encode(maxSharesIn),
+  ]);
+
+  return {
+    programAddress: PROGRAM_ID,
+    accounts: await getWithdrawAccounts(
+      payer,
+      basketAddress,
+      shareMint,
+      vaultAuthority,
+      tokenMints,
+      tokenProgram,
+      shareTokenProgram,
+      receiver,
+    ),
+    data,
+  };
+}
@@ -12,6 +12,7 @@ import {
 import {
   getConfigPda,
   getBasketTokenPda,
+  getBasketPricePda,
   getEventAuthorityPda,
   getAssociatedTokenAddress,
 } from "../pdas";
@@ -24,22 +25,55 @@ export async function buildWithdrawMultiIx(
   sharesToBurn: bigint,
   tokenMints: Address[],
   tokenProgram: Address,
+  shareTokenProgram: Address = tokenProgram,
+  receiver: Address = payer,
 ): Promise<Instruction> {
-  const [configAddress] = await getConfigPda();
-  const [userShareAta] = await getAssociatedTokenAddress(payer, tokenProgram, shareMint);
-  const [eventAuthority] = await getEventAuthorityPda();
-
   const u64 = getU64Codec();
   const data = new Uint8Array([...IX_DISCRIMINATORS.withdrawMulti, ...u64.encode(sharesToBurn)]);
 
+  return {
+    programAddress: PROGRAM_ID,
+    accounts: await getWithdrawAccounts(
+      payer,
+      basketAddress,
+      shareMint,
+      vaultAuthority,
+      tokenMints,
+      tokenProgram,
+      shareTokenProgram,
+      receiver,
+    ),
+    data,
+  };
+}
+
+/** Account list shared by `withdraw_multi` and `withdraw_exact_amounts`. */
+export async function getWithdrawAccounts(
+  payer: Address,
+  basketAddress: Address,
+  shareMint: Address,
+  vaultAuthority: Address,
+  tokenMints: Address[],
+  tokenProgram: Address,
+  shareTokenProgram: Address,
+  receiver: Address,
+): Promise<{ address: Address; role: AccountRole }[]> {
+  const [configAddress] = await getConfigPda();
+  const [userShareAta] = await getAssociatedTokenAddress(payer, shareTokenProgram, shareMint);
+  const [basketPrice] = await getBasketPricePda(basketAddress);
+  const [eventAuthority] = await getEventAuthorityPda();
+
   const accounts: { address: Address; role: AccountRole }[] = [
     { address: payer, role: AccountRole.WRITABLE_SIGNER },
     { address: configAddress, role: AccountRole.READONLY },
-    { address: basketAddress, role: AccountRole.READONLY },
+    { address: basketAddress, role: AccountRole.WRITABLE },
+    { address: basketPrice, role: AccountRole.WRITABLE },
     { address: shareMint, role: AccountRole.WRITABLE },
     { address: userShareAta, role: AccountRole.WRITABLE },
+    { address: receiver, role: AccountRole.READONLY },
     { address: vaultAuthority, role: AccountRole.READONLY },
     { address: tokenProgram, role: AccountRole.READONLY },
+    { address: shareTokenProgram, role: AccountRole.READONLY },
     { address: SYSTEM_PROGRAM_ID, role: AccountRole.READONLY },
     { address: eventAuthority, role: AccountRole.READONLY },
     { address: PROGRAM_ID, role: AccountRole.READONLY },
@@ -49,19 +83,15 @@ export async function buildWithdrawMultiIx(
     const mint = tokenMints[i];
     const [basketTokenPda] = await getBasketTokenPda(basketAddress, mint);
     const [vaultAta] = await getAssociatedTokenAddress(vaultAuthority, tokenProgram, mint);
-    const [userAta] = await getAssociatedTokenAddress(payer, tokenProgram, mint);
+    const [receiverAta] = await getAssociatedTokenAddress(receiver, tokenProgram, mint);
 
     accounts.push(
-      { address: basketTokenPda, role: AccountRole.READONLY },
+      { address: basketTokenPda, role: AccountRole.WRITABLE },
       { address: mint, role: AccountRole.READONLY },
       { address: vaultAta, role: AccountRole.WRITABLE },
-      { address: userAta, role: AccountRole.WRITABLE },
+      { address: receiverAta, role: AccountRole.WRITABLE },
     );
   }
 
-  return {
-    programAddress: PROGRAM_ID,
-    accounts,
-    data,
-  };
+  return accounts;
 }
@@ -13,8 +13,22 @@ import {
   MINT_AUTHORITY_SEED,
   FEE_VAULT_SEED,
   USER_ALLOW_SEED,
+  BASKET_PRICE_SEED,
+  LOCK_TIERS_SEED,
+  LOCK_POSITION_SEED,
+  REWARD_POOL_SEED,
+  STAKE_POSITION_SEED,
+  EPOCH_QUEUE_SEED,
+  EPOCH_SEED,
+  DEPOSIT_REQUEST_SEED,
+  REDEEM_REQUEST_SEED,
+  BASKET_REGISTRY_SEED,
+  COMPLIANCE_ROOT_SEED,
+  ATTESTATION_NONCE_SEED,
   EVENT_AUTHORITY_SEED,
+  METADATA_SEED,
   ASSOCIATED_TOKEN_PROGRAM_ID,
+  TOKEN_METADATA_PROGRAM_ID,
 } from "./constants";
 
 const addressEncoder = getAddressEncoder();
@@ -90,6 +104,127 @@ export function getUserAllowListPda(
   });
 }
 
+export function getBasketPricePda(
+  basket: Address,
+): Promise<ProgramDerivedAddress> {
+  return getProgramDerivedAddress({
+    programAddress: PROGRAM_ID,
+    seeds: [BASKET_PRICE_SEED, addressEncoder.encode(basket)],
+  });
+}
+
+export function getLockTiersPda(
+  basket: Address,
+): Promise<ProgramDerivedAddress> {
+  return getProgramDerivedAddress({
+    programAddress: PROGRAM_ID,
+    seeds: [LOCK_TIERS_SEED, addressEncoder.encode(basket)],
+  });
+}
+
+export function getLockPositionPda(
+  basket: Address,
+  owner: Address,
+): Promise<ProgramDerivedAddress> {
+  return getProgramDerivedAddress({
+    programAddress: PROGRAM_ID,
+    seeds: [LOCK_POSITION_SEED, addressEncoder.encode(basket), addressEncoder.encode(owner)],
+  });
+}
+
+export function getRewardPoolPda(
+  basket: Address,
+  rewardMint: Address,
+): Promise<ProgramDerivedAddress> {
+  return getProgramDerivedAddress({
+    programAddress: PROGRAM_ID,
+    seeds: [REWARD_POOL_SEED, addressEncoder.encode(basket), addressEncoder.encode(rewardMint)],
+  });
+}
+
+export function getStakePositionPda(
+  pool: Address,
+  owner: Address,
+): Promise<ProgramDerivedAddress> {
+  return getProgramDerivedAddress({
+    programAddress: PROGRAM_ID,
+    seeds: [STAKE_POSITION_SEED, addressEncoder.encode(pool), addressEncoder.encode(owner)],
+  });
+}
+
+export function getEpochQueuePda(
+  basket: Address,
+): Promise<ProgramDerivedAddress> {
+  return getProgramDerivedAddress({
+    programAddress: PROGRAM_ID,
+    seeds: [EPOCH_QUEUE_SEED, addressEncoder.encode(basket)],
+  });
+}
+
+export function getEpochPda(
+  basket: Address,
+  epochId: bigint,
+): Promise<ProgramDerivedAddress> {
+  return getProgramDerivedAddress({
+    programAddress: PROGRAM_ID,
+    seeds: [EPOCH_SEED, addressEncoder.encode(basket), u64LE(epochId)],
+  });
+}
+
+export function getDepositRequestPda(
+  basket: Address,
+  owner: Address,
+): Promise<ProgramDerivedAddress> {
+  return getProgramDerivedAddress({
+    programAddress: PROGRAM_ID,
+    seeds: [DEPOSIT_REQUEST_SEED, addressEncoder.encode(basket), addressEncoder.encode(owner)],
+  });
+}
+
+export function getRedeemRequestPda(
+  basket: Address,
+  owner: Address,
+): Promise<ProgramDerivedAddress> {
+  return getProgramDerivedAddress({
+    programAddress: PROGRAM_ID,
+    seeds: [REDEEM_REQUEST_SEED, addressEncoder.encode(basket), addressEncoder.encode(owner)],
+  });
+}
+
+export function getBasketRegistryPda(page: number): Promise<ProgramDerivedAddress> {
+  const pageBytes = new Uint8Array(4);
+  new DataView(pageBytes.buffer).setUint32(0, page, true);
+  return getProgramDerivedAddress({
+    programAddress: PROGRAM_ID,
+    seeds: [BASKET_REGISTRY_SEED, pageBytes],
+  });
+}
+
+export function getComplianceRootPda(basket: Address): Promise<ProgramDerivedAddress> {
+  return getProgramDerivedAddress({
+    programAddress: PROGRAM_ID,
+    seeds: [COMPLIANCE_ROOT_SEED, addressEncoder.encode(basket)],
+  });
+}
+
+export function getAttestationNoncePda(
+  basket: Address,
+  user: Address,
+  nonce: bigint,
+): Promise<ProgramDerivedAddress> {
+  const nonceBytes = new Uint8Array(8);
+  new DataView(nonceBytes.buffer).setBigUint64(0, nonce, true);
+  return getProgramDerivedAddress({
+    programAddress: PROGRAM_ID,
+    seeds: [
+      ATTESTATION_NONCE_SEED,
+      addressEncoder.encode(basket),
+      addressEncoder.encode(user),
+      nonceBytes,
+    ],
+  });
+}
+
 export function getEventAuthorityPda(): Promise<ProgramDerivedAddress> {
   return getProgramDerivedAddress({
     programAddress: PROGRAM_ID,
@@ -111,3 +246,17 @@ export function getAssociatedTokenAddress(
     ],
   });
 }
+
+// Metaplex metadata account of a legacy SPL share mint.
+export function getMetadataPda(
+  mint: Address,
+): Promise<ProgramDerivedAddress> {
+  return getProgramDerivedAddress({
+    programAddress: TOKEN_METADATA_PROGRAM_ID,
+    seeds: [
+      METADATA_SEED,
+      addressEncoder.encode(TOKEN_METADATA_PROGRAM_ID),
+      addressEncoder.encode(mint),
+    ],
+  });
+}
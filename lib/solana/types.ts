@@ -4,8 +4,20 @@ import {
   basketCodec,
   basketTokenCodec,
   userAllowListCodec,
+  basketPriceCodec,
+  lockTiersCodec,
+  lockPositionCodec,
+  rewardPoolCodec,
+  stakePositionCodec,
+  epochQueueCodec,
+  epochCodec,
+  depositRequestCodec,
+  redeemRequestCodec,
+  basketRegistryCodec,
+  complianceRootCodec,
   decodeAccount,
 } from "./codecs";
+import { SYSTEM_PROGRAM_ID } from "./constants";
 
 export type ParsedConfig = {
   admin: Address;
@@ -14,6 +26,18 @@ export type ParsedConfig = {
   complianceEnabled: boolean;
   version: number;
   bump: number;
+  mintExtensionPolicy: number;
+  /** Whether anyone may create a basket; otherwise only the admin. */
+  permissionlessCreation: boolean;
+  /** Paid to `treasury` by non-admin creators. */
+  creationFeeLamports: bigint;
+  /** Escrowed on the basket by non-admin creators; slashable by the admin. */
+  creationBondLamports: bigint;
+  treasury: Address;
+  /** Id the next `create_basket` must use. */
+  nextBasketId: bigint;
+  /** Registry page the next basket is appended to. */
+  registryPage: number;
 };
 
 export type ParsedBasket = {
@@ -28,6 +52,24 @@ export type ParsedBasket = {
   basketBump: number;
   vaultAuthorityBump: number;
   mintAuthorityBump: number;
+  mintPricingMode: number;
+  redeemPricingMode: number;
+  /** Cap on total vault value in QSHARE units, or null when uncapped. */
+  maxTotalValue: bigint | null;
+  /** Cap on a receiver's QSHARE balance, or null when uncapped. */
+  maxUserShares: bigint | null;
+  /** Share of supply, in bps, burnable per withdraw window, or null when unlimited. */
+  maxWithdrawBps: number | null;
+  withdrawWindowSecs: bigint;
+  /** Admin bypass of the withdraw limiter. */
+  emergencyMode: boolean;
+  windowStartTs: bigint;
+  windowStartSupply: bigint;
+  burnedInWindow: bigint;
+  /** Nominee of a pending ownership transfer, or null. */
+  pendingOwner: Address | null;
+  /** Creation bond held on the basket account, in lamports. */
+  bondLamports: bigint;
 };
 
 export type ParsedBasketToken = {
@@ -38,6 +80,11 @@ export type ParsedBasketToken = {
   decimals: number;
   enabled: boolean;
   bump: number;
+  extensionFlags: number;
+  lastPrice: bigint;
+  lastUpdateTs: bigint;
+  // TWAP of the leg value per share; null until a window has completed.
+  twapPrice: bigint | null;
 };
 
 export type ParsedUserAllowList = {
@@ -47,6 +94,129 @@ export type ParsedUserAllowList = {
   bump: number;
 };
 
+export type ParsedBasketPrice = {
+  basket: Address;
+  navPerShare: bigint;
+  confidence: bigint;
+  totalSupply: bigint;
+  lastUpdateSlot: bigint;
+  lastUpdateTs: bigint;
+  navDecimals: number;
+  version: number;
+  bump: number;
+};
+
+export type LockTier = {
+  minDurationSecs: bigint;
+  earlyExitPenaltyBps: number;
+};
+
+export type ParsedLockTiers = {
+  basket: Address;
+  tiers: LockTier[];
+  bump: number;
+};
+
+export type ParsedLockPosition = {
+  basket: Address;
+  owner: Address;
+  amount: bigint;
+  lockedTs: bigint;
+  unlockTs: bigint;
+  tier: number;
+  earlyExitPenaltyBps: number;
+  bump: number;
+};
+
+export type RewardBoost = {
+  minDurationSecs: bigint;
+  multiplierBps: number;
+};
+
+export type ParsedRewardPool = {
+  basket: Address;
+  rewardMint: Address;
+  rewardVault: Address;
+  totalStaked: bigint;
+  totalWeight: bigint;
+  rewardRate: bigint;
+  periodEnd: bigint;
+  lastUpdateTs: bigint;
+  accRewardPerWeight: bigint;
+  boosts: RewardBoost[];
+  bump: number;
+};
+
+export type ParsedStakePosition = {
+  pool: Address;
+  owner: Address;
+  amount: bigint;
+  weight: bigint;
+  multiplierBps: number;
+  unlockTs: bigint;
+  rewardDebt: bigint;
+  rewardsOwed: bigint;
+  bump: number;
+};
+
+export type ParsedEpochQueue = {
+  basket: Address;
+  operator: Address;
+  currentEpoch: bigint;
+  pendingDepositValue: bigint;
+  pendingRedeemShares: bigint;
+  unclaimedRedeemShares: bigint;
+  bump: number;
+};
+
+export type ParsedEpoch = {
+  basket: Address;
+  epochId: bigint;
+  navPerShare: bigint;
+  unclaimedDepositValue: bigint;
+  unclaimedShares: bigint;
+  unclaimedRedeemShares: bigint;
+  settledTs: bigint;
+  bump: number;
+};
+
+export type RequestLeg = {
+  mint: Address;
+  amount: bigint;
+};
+
+export type ParsedDepositRequest = {
+  basket: Address;
+  owner: Address;
+  epoch: bigint;
+  value: bigint;
+  legs: RequestLeg[];
+  bump: number;
+};
+
+export type ParsedRedeemRequest = {
+  basket: Address;
+  owner: Address;
+  epoch: bigint;
+  shares: bigint;
+  bump: number;
+};
+
+export type ParsedBasketRegistry = {
+  page: number;
+  /** Basket addresses on this page, in creation order. */
+  baskets: Address[];
+  bump: number;
+};
+
+export type ParsedComplianceRoot = {
+  basket: Address;
+  root: Uint8Array;
+  version: bigint;
+  updatedAt: bigint;
+  bump: number;
+};
+
 export function parseConfig(data: Uint8Array): ParsedConfig {
   const raw = decodeAccount(configCodec, data);
   return {
@@ -56,6 +226,13 @@ export function parseConfig(data: Uint8Array): ParsedConfig {
     complianceEnabled: raw.complianceEnabled,
     version: raw.version,
     bump: raw.bump,
+    mintExtensionPolicy: raw.mintExtensionPolicy,
+    permissionlessCreation: raw.permissionlessCreation,
+    creationFeeLamports: raw.creationFeeLamports,
+    creationBondLamports: raw.creationBondLamports,
+    treasury: raw.treasury,
+    nextBasketId: raw.nextBasketId,
+    registryPage: raw.registryPage,
   };
 }
 
@@ -78,6 +255,19 @@ export function parseBasket(data: Uint8Array): ParsedBasket {
     basketBump: raw.basketBump,
     vaultAuthorityBump: raw.vaultAuthorityBump,
     mintAuthorityBump: raw.mintAuthorityBump,
+    mintPricingMode: raw.mintPricingMode,
+    redeemPricingMode: raw.redeemPricingMode,
+    maxTotalValue: raw.maxTotalValue === BigInt(0) ? null : raw.maxTotalValue,
+    maxUserShares: raw.maxUserShares === BigInt(0) ? null : raw.maxUserShares,
+    maxWithdrawBps: raw.maxWithdrawBps === 0 ? null : raw.maxWithdrawBps,
+    withdrawWindowSecs: raw.withdrawWindowSecs,
+    emergencyMode: raw.emergencyMode === 1,
+    windowStartTs: raw.windowStartTs,
+    windowStartSupply: raw.windowStartSupply,
+    burnedInWindow: raw.burnedInWindow,
+    // The all-zero default pubkey encodes as the system program address.
+    pendingOwner: raw.pendingOwner === SYSTEM_PROGRAM_ID ? null : raw.pendingOwner,
+    bondLamports: raw.bondLamports,
   };
 }
 
@@ -91,6 +281,10 @@ export function parseBasketToken(data: Uint8Array): ParsedBasketToken {
     decimals: raw.decimals,
     enabled: raw.enabled,
     bump: raw.bump,
+    extensionFlags: raw.extensionFlags,
+    lastPrice: raw.lastPrice,
+    lastUpdateTs: raw.lastUpdateTs,
+    twapPrice: raw.twapUpdatedTs === BigInt(0) ? null : raw.twapPrice,
   };
 }
 
@@ -103,3 +297,143 @@ export function parseUserAllowList(data: Uint8Array): ParsedUserAllowList {
     bump: raw.bump,
   };
 }
+
+export function parseBasketPrice(data: Uint8Array): ParsedBasketPrice {
+  const raw = decodeAccount(basketPriceCodec, data);
+  return {
+    basket: raw.basket,
+    navPerShare: raw.navPerShare,
+    confidence: raw.confidence,
+    totalSupply: raw.totalSupply,
+    lastUpdateSlot: raw.lastUpdateSlot,
+    lastUpdateTs: raw.lastUpdateTs,
+    navDecimals: raw.navDecimals,
+    version: raw.version,
+    bump: raw.bump,
+  };
+}
+
+export function parseLockTiers(data: Uint8Array): ParsedLockTiers {
+  const raw = decodeAccount(lockTiersCodec, data);
+  return {
+    basket: raw.basket,
+    tiers: raw.tiers.slice(0, raw.tierCount),
+    bump: raw.bump,
+  };
+}
+
+export function parseLockPosition(data: Uint8Array): ParsedLockPosition {
+  const raw = decodeAccount(lockPositionCodec, data);
+  return {
+    basket: raw.basket,
+    owner: raw.owner,
+    amount: raw.amount,
+    lockedTs: raw.lockedTs,
+    unlockTs: raw.unlockTs,
+    tier: raw.tier,
+    earlyExitPenaltyBps: raw.earlyExitPenaltyBps,
+    bump: raw.bump,
+  };
+}
+
+export function parseRewardPool(data: Uint8Array): ParsedRewardPool {
+  const raw = decodeAccount(rewardPoolCodec, data);
+  return {
+    basket: raw.basket,
+    rewardMint: raw.rewardMint,
+    rewardVault: raw.rewardVault,
+    totalStaked: raw.totalStaked,
+    totalWeight: raw.totalWeight,
+    rewardRate: raw.rewardRate,
+    periodEnd: raw.periodEnd,
+    lastUpdateTs: raw.lastUpdateTs,
+    accRewardPerWeight: raw.accRewardPerWeight,
+    boosts: raw.boosts.slice(0, raw.boostCount),
+    bump: raw.bump,
+  };
+}
+
+export function parseStakePosition(data: Uint8Array): ParsedStakePosition {
+  const raw = decodeAccount(stakePositionCodec, data);
+  return {
+    pool: raw.pool,
+    owner: raw.owner,
+    amount: raw.amount,
+    weight: raw.weight,
+    multiplierBps: raw.multiplierBps,
+    unlockTs: raw.unlockTs,
+    rewardDebt: raw.rewardDebt,
+    rewardsOwed: raw.rewardsOwed,
+    bump: raw.bump,
+  };
+}
+
+export function parseEpochQueue(data: Uint8Array): ParsedEpochQueue {
+  const raw = decodeAccount(epochQueueCodec, data);
+  return {
+    basket: raw.basket,
+    operator: raw.operator,
+    currentEpoch: raw.currentEpoch,
+    pendingDepositValue: raw.pendingDepositValue,
+    pendingRedeemShares: raw.pendingRedeemShares,
+    unclaimedRedeemShares: raw.unclaimedRedeemShares,
+    bump: raw.bump,
+  };
+}
+
+export function parseEpoch(data: Uint8Array): ParsedEpoch {
+  const raw = decodeAccount(epochCodec, data);
+  return {
+    basket: raw.basket,
+    epochId: raw.epochId,
+    navPerShare: raw.navPerShare,
+    unclaimedDepositValue: raw.unclaimedDepositValue,
+    unclaimedShares: raw.unclaimedShares,
+    unclaimedRedeemShares: raw.unclaimedRedeemShares,
+    settledTs: raw.settledTs,
+    bump: raw.bump,
+  };
+}
+
+export function parseDepositRequest(data: Uint8Array): ParsedDepositRequest {
+  const raw = decodeAccount(depositRequestCodec, data);
+  return {
+    basket: raw.basket,
+    owner: raw.owner,
+    epoch: raw.epoch,
+    value: raw.value,
+    legs: raw.legs.slice(0, raw.legCount),
+    bump: raw.bump,
+  };
+}
+
+export function parseRedeemRequest(data: Uint8Array): ParsedRedeemRequest {
+  const raw = decodeAccount(redeemRequestCodec, data);
+  return {
+    basket: raw.basket,
+    owner: raw.owner,
+    epoch: raw.epoch,
+    shares: raw.shares,
+    bump: raw.bump,
+  };
+}
+
+export function parseBasketRegistry(data: Uint8Array): ParsedBasketRegistry {
+  const raw = decodeAccount(basketRegistryCodec, data);
+  return {
+    page: raw.page,
+    baskets: raw.baskets.slice(0, raw.count),
+    bump: raw.bump,
+  };
+}
+
+export function parseComplianceRoot(data: Uint8Array): ParsedComplianceRoot {
+  const raw = decodeAccount(complianceRootCodec, data);
+  return {
+    basket: raw.basket,
+    root: new Uint8Array(raw.root),
+    version: raw.version,
+    updatedAt: raw.updatedAt,
+    bump: raw.bump,
+  };
+}